@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+use stylus_sdk::{
+    alloy_primitives::{B256, U256},
+    crypto::keccak,
+};
+
+use crate::cairo::felt::Felt;
+
+/// Samples are rejected above `31·p` so that reducing them is unbiased.
+const SAMPLE_BOUND: U256 = U256::from_limbs([0x1f, 0, 0, 0xf80000000000020f]);
+const POW_PREFIX: [u8; 8] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xed];
+
+/// Stone's Keccak Fiat-Shamir channel, reading the proof as it goes.
+///
+/// Prover messages are mixed in as `digest = keccak(digest + 1 || message)`; the `n`-th
+/// random word since the last message is `keccak(digest || n)`. Decommitments are read
+/// without mixing.
+pub struct Channel<'a> {
+    proof: &'a [u8],
+    digest: U256,
+    counter: U256,
+}
+
+impl<'a> Channel<'a> {
+    pub fn new(proof: &'a [u8], seed: B256) -> Self {
+        Self {
+            proof,
+            digest: seed.into(),
+            counter: U256::ZERO,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.proof.is_empty()
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.proof.len() < n {
+            return None;
+        }
+        let (head, tail) = self.proof.split_at(n);
+        self.proof = tail;
+        Some(head)
+    }
+
+    fn mix(&mut self, message: &[u8]) {
+        let mut data = Vec::with_capacity(32 + message.len());
+        data.extend_from_slice(&(self.digest.wrapping_add(U256::from(1))).to_be_bytes::<32>());
+        data.extend_from_slice(message);
+        self.digest = keccak(&data).into();
+        self.counter = U256::ZERO;
+    }
+
+    fn random_word(&mut self) -> U256 {
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(&self.digest.to_be_bytes::<32>());
+        data[32..].copy_from_slice(&self.counter.to_be_bytes::<32>());
+        self.counter += U256::from(1);
+        keccak(data).into()
+    }
+
+    /// Reads a Merkle root and mixes it in.
+    pub fn read_commitment(&mut self) -> Option<B256> {
+        let word = self.take(32)?;
+        self.mix(word);
+        Some(B256::from_slice(word))
+    }
+
+    /// Reads a field element and mixes it in.
+    pub fn read_felt(&mut self) -> Option<Felt> {
+        Some(self.read_felts(1)?[0])
+    }
+
+    /// Reads `n` field elements and mixes them in as one message.
+    pub fn read_felts(&mut self, n: usize) -> Option<Vec<Felt>> {
+        let bytes = self.take(32 * n)?;
+        self.mix(bytes);
+        bytes
+            .chunks_exact(32)
+            .map(|word| Felt::from_montgomery(U256::from_be_slice(word)))
+            .collect()
+    }
+
+    /// Reads a decommitted field element.
+    pub fn read_decommitted_felt(&mut self) -> Option<Felt> {
+        Felt::from_montgomery(U256::from_be_slice(self.take(32)?))
+    }
+
+    /// Reads a decommitted Merkle node.
+    pub fn read_node(&mut self) -> Option<B256> {
+        Some(B256::from_slice(self.take(32)?))
+    }
+
+    pub fn sample_felt(&mut self) -> Felt {
+        loop {
+            let word = self.random_word();
+            if word < SAMPLE_BOUND {
+                return Felt::reduce_montgomery(word);
+            }
+        }
+    }
+
+    /// Samples `n` query indices below `2^log_domain`, four per random word, most
+    /// significant first. The result is sorted and deduplicated.
+    pub fn sample_queries(&mut self, n: usize, log_domain: usize) -> Vec<usize> {
+        let mask = (1u64 << log_domain) - 1;
+        let mut queries = Vec::with_capacity(n);
+        while queries.len() < n {
+            let word = self.random_word().to_be_bytes::<32>();
+            for chunk in word.chunks_exact(8).take(n - queries.len()) {
+                let value = u64::from_be_bytes(chunk.try_into().unwrap_or_default()) & mask;
+                queries.push(value as usize);
+            }
+        }
+        queries.sort_unstable();
+        queries.dedup();
+        queries
+    }
+
+    /// Checks the grinding nonce: `keccak(keccak(prefix || digest || bits) || nonce)`
+    /// must have `bits` leading zeros. The nonce is then mixed in.
+    pub fn verify_proof_of_work(&mut self, bits: usize) -> Option<bool> {
+        if bits == 0 {
+            return Some(true);
+        }
+        let mut data = [0u8; 41];
+        data[..8].copy_from_slice(&POW_PREFIX);
+        data[8..40].copy_from_slice(&self.digest.to_be_bytes::<32>());
+        data[40] = u8::try_from(bits).ok()?;
+        let init = keccak(data);
+
+        let nonce = self.take(8)?;
+        let mut data = [0u8; 40];
+        data[..32].copy_from_slice(init.as_slice());
+        data[32..].copy_from_slice(nonce);
+        let work = U256::from_be_bytes(keccak(data).0);
+        self.mix(nonce);
+        Some(bits < 256 && work >> (256 - bits) == U256::ZERO)
+    }
+}
@@ -0,0 +1,6 @@
+pub const PROGRAM_SEGMENT: usize = 0;
+pub const EXECUTION_SEGMENT: usize = 1;
+pub const OUTPUT_SEGMENT: usize = 2;
+
+/// Bound on the log size of the evaluation domain, keeping indices in a wasm32 `usize`.
+pub const MAX_LOG_LDE: usize = 30;
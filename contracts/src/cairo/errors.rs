@@ -0,0 +1,45 @@
+use stylus_sdk::alloy_sol_types::{sol, SolError};
+
+use crate::common::VerificationError;
+
+sol! {
+    error InvalidLayout();
+    error InvalidPublicInput();
+    error OodsMismatch();
+}
+
+#[derive(Debug)]
+pub enum CairoError {
+    Common(VerificationError),
+    InvalidLayout,
+    InvalidPublicInput,
+    OodsMismatch,
+}
+
+impl CairoError {
+    pub fn abi_encode(&self) -> alloc::vec::Vec<u8> {
+        match self {
+            CairoError::Common(e) => e.abi_encode(),
+            CairoError::InvalidLayout => InvalidLayout {}.abi_encode(),
+            CairoError::InvalidPublicInput => InvalidPublicInput {}.abi_encode(),
+            CairoError::OodsMismatch => OodsMismatch {}.abi_encode(),
+        }
+    }
+}
+
+impl From<VerificationError> for CairoError {
+    fn from(error: VerificationError) -> Self {
+        CairoError::Common(error)
+    }
+}
+
+impl CairoError {
+    pub const VERIFICATION_FAILED: CairoError =
+        CairoError::Common(VerificationError::VerificationFailed);
+    pub const INVALID_INITIALIZATION: CairoError =
+        CairoError::Common(VerificationError::InvalidInitialization);
+    pub const ALREADY_INITIALIZED: CairoError =
+        CairoError::Common(VerificationError::AlreadyInitialized);
+    pub const INVALID_PROOF_DATA: CairoError =
+        CairoError::Common(VerificationError::InvalidProofData);
+}
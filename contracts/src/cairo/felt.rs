@@ -0,0 +1,161 @@
+//! The Stark field `p = 2^251 + 17·2^192 + 1`.
+//!
+//! Elements are kept canonical; Stone proofs carry them in Montgomery form with
+//! `R = 2^256`.
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::common::stark::{ExtensionField, Field, TwoAdicField};
+
+pub const P: U256 = U256::from_limbs([1, 0, 0, 0x0800000000000011]);
+/// `2^256 mod p`.
+const R: U256 = U256::from_limbs([
+    0xffffffffffffffe1,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x7fffffffffffdf0,
+]);
+/// `2^-256 mod p`.
+const R_INV: U256 = U256::from_limbs([0, 0, 0x1000000000000121, 0x40000000000001]);
+/// Generator of the subgroup of order `2^192`.
+const TWO_ADIC_GENERATOR: U256 = U256::from_limbs([
+    0x6070024f42f8ef94,
+    0xad187148e11a6161,
+    0x3f0464519c8b0fa5,
+    0x5282db87529cfa,
+]);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Felt(U256);
+
+impl Felt {
+    pub const ZERO: Felt = Felt(U256::ZERO);
+    pub const ONE: Felt = Felt(U256::from_limbs([1, 0, 0, 0]));
+
+    pub fn from_u64(x: u64) -> Self {
+        Self(U256::from(x))
+    }
+
+    /// Reads a canonical value, rejecting values not below `p`.
+    pub fn from_canonical(x: U256) -> Option<Self> {
+        (x < P).then_some(Self(x))
+    }
+
+    /// Reads a word in Montgomery form, rejecting values not below `p`.
+    pub fn from_montgomery(x: U256) -> Option<Self> {
+        (x < P).then(|| Self(x.mul_mod(R_INV, P)))
+    }
+
+    /// Reduces any word and leaves Montgomery form, as the channel does for samples.
+    pub fn reduce_montgomery(x: U256) -> Self {
+        Self(x.reduce_mod(P).mul_mod(R_INV, P))
+    }
+
+    /// Wraps a constant known to be below `p`.
+    pub const fn from_canonical_unchecked(x: U256) -> Self {
+        Self(x)
+    }
+
+    pub const fn as_u256(&self) -> U256 {
+        self.0
+    }
+
+    /// The word in Montgomery form, as hashed in Merkle leaves.
+    pub fn as_montgomery(&self) -> U256 {
+        self.0.mul_mod(R, P)
+    }
+
+    /// `self^e` for a full-width exponent.
+    pub fn pow_u256(self, e: U256) -> Self {
+        Self(self.0.pow_mod(e, P))
+    }
+}
+
+impl Field for Felt {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn inv(self) -> Self {
+        Self(self.0.inv_mod(P).unwrap_or_default())
+    }
+}
+
+impl TwoAdicField for Felt {
+    const TWO_ADICITY: usize = 192;
+    const GENERATOR: Self = Felt(U256::from_limbs([3, 0, 0, 0]));
+
+    fn two_adic_generator(bits: usize) -> Self {
+        let mut g = Self(TWO_ADIC_GENERATOR);
+        for _ in bits..Self::TWO_ADICITY {
+            g = g.square();
+        }
+        g
+    }
+}
+
+impl ExtensionField<Felt> for Felt {
+    const DEGREE: usize = 1;
+
+    fn from_base(x: Felt) -> Self {
+        x
+    }
+
+    fn mul_base(self, x: Felt) -> Self {
+        self * x
+    }
+}
+
+impl Add for Felt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.add_mod(rhs.0, P))
+    }
+}
+
+impl Sub for Felt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Felt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0.mul_mod(rhs.0, P))
+    }
+}
+
+impl Neg for Felt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.0.is_zero() {
+            self
+        } else {
+            Self(P - self.0)
+        }
+    }
+}
+
+impl AddAssign for Felt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Felt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Felt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
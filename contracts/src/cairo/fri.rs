@@ -0,0 +1,99 @@
+use alloc::{vec, vec::Vec};
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::cairo::{channel::Channel, felt::Felt, layout::FriParams, merkle};
+use crate::common::stark::{fri::reverse_bits, poly, Field, TwoAdicField};
+
+/// The FRI commit phase: the root of every layer before the last, each followed by the
+/// point it is folded at, and the coefficients of the last layer.
+pub struct FriCommitments {
+    betas: Vec<Felt>,
+    roots: Vec<B256>,
+    last_layer: Vec<Felt>,
+}
+
+impl FriCommitments {
+    pub fn read(channel: &mut Channel, params: &FriParams) -> Option<Self> {
+        let n = params.steps.len() - 1;
+        let mut betas = Vec::with_capacity(n);
+        let mut roots = Vec::with_capacity(n);
+        for _ in 0..n {
+            roots.push(channel.read_commitment()?);
+            betas.push(channel.sample_felt());
+        }
+        let last_layer = channel.read_felts(1 << params.log_last_layer_degree)?;
+        Some(Self {
+            betas,
+            roots,
+            last_layer,
+        })
+    }
+
+    /// Folds the queries down to the last layer.
+    ///
+    /// Layers are evaluated in bit-reversed order over `<ω>`, where Stone places FRI rather
+    /// than on the trace's coset `3 · <ω>`, and a step of `s` folds cosets of `2^s`
+    /// consecutive positions. The first layer is the DEEP composition, known at the
+    /// queries; `first_layer` holds it sorted by position. Each layer's cosets are
+    /// completed from the channel and checked against its root before it is folded. A fold
+    /// by `2^s` yields `2^s` times the coset interpolant at the evaluation point.
+    pub fn verify(
+        &self,
+        channel: &mut Channel,
+        params: &FriParams,
+        log_lde: usize,
+        first_layer: Vec<(usize, Felt)>,
+    ) -> Option<()> {
+        let mut layer = first_layer;
+        let mut log_height = log_lde;
+        let rounds = params.steps[1..].iter().zip(&self.betas).zip(&self.roots);
+        for ((step, beta), root) in rounds {
+            let arity = 1usize << step;
+            let log_folded = log_height.checked_sub(*step)?;
+            let omega = Felt::two_adic_generator(*step);
+            let generator = Felt::two_adic_generator(log_height);
+            let scale = Felt::from_u64(arity as u64);
+
+            let mut next = Vec::new();
+            let mut leaves = Vec::new();
+            let mut queried = layer.into_iter().peekable();
+            while let Some((index, _)) = queried.peek() {
+                let coset = index >> step;
+                let mut values = vec![Felt::ZERO; arity];
+                for (t, value) in values.iter_mut().enumerate() {
+                    *value = match queried.next_if(|(i, _)| *i == coset << step | t) {
+                        Some((_, value)) => value,
+                        None => channel.read_decommitted_felt()?,
+                    };
+                }
+                leaves.push((coset, merkle::hash_leaf(&values)));
+
+                let mut evals = vec![Felt::ZERO; arity];
+                for (t, value) in values.into_iter().enumerate() {
+                    evals[reverse_bits(t, *step)] = value;
+                }
+                let shift = generator.pow(reverse_bits(coset, log_folded) as u64);
+                next.push((
+                    coset,
+                    poly::interpolate_coset(&evals, shift, omega, *beta) * scale,
+                ));
+            }
+            merkle::verify_batch(channel, root, log_folded, &leaves)?;
+
+            layer = next;
+            log_height = log_folded;
+        }
+
+        if log_height != params.log_last_layer_degree + params.log_blowup {
+            return None;
+        }
+        let generator = Felt::two_adic_generator(log_height);
+        layer
+            .iter()
+            .all(|(index, value)| {
+                let x = generator.pow(reverse_bits(*index, log_height) as u64);
+                poly::eval(&self.last_layer, x) == *value
+            })
+            .then_some(())
+    }
+}
@@ -0,0 +1,233 @@
+//! Host-side import of Stone proofs.
+//!
+//! Reads the JSON written by Stone's `cpu_air_prover` and encodes it as the `proof` and
+//! `public_input` arguments of `verify_proof`.
+
+use std::{collections::BTreeMap, fmt, string::String, vec::Vec};
+
+use serde::Deserialize;
+use stylus_sdk::{
+    alloy_primitives::{hex, U256},
+    alloy_sol_types::SolValue,
+};
+
+use crate::cairo::types::{CairoPublicInput, MemoryCell, MemorySegment};
+
+/// Segments in the order every Stone layout lays them out; a layout uses the ones it has.
+const SEGMENT_ORDER: [&str; 14] = [
+    "program",
+    "execution",
+    "output",
+    "pedersen",
+    "range_check",
+    "ecdsa",
+    "bitwise",
+    "ec_op",
+    "keccak",
+    "poseidon",
+    "range_check96",
+    "add_mod",
+    "mul_mod",
+    "gas_builtin",
+];
+
+#[derive(Debug)]
+pub enum HostError {
+    Json(serde_json::Error),
+    /// A value is not a hexadecimal integer below `2^256`.
+    InvalidNumber(String),
+    /// `proof_hex` is not hexadecimal.
+    InvalidProofHex,
+    /// The layout name does not fit in a word.
+    InvalidLayout(String),
+    /// `n_steps` is not a power of two.
+    InvalidSteps(u64),
+    /// A segment is not one Stone knows, or the program or execution segment is missing.
+    InvalidSegments,
+    /// Public memory spans several pages; the verifier reads one.
+    UnsupportedPages,
+    EmptyPublicMemory,
+}
+
+impl fmt::Display for HostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostError::Json(e) => write!(f, "invalid Stone proof JSON: {e}"),
+            HostError::InvalidNumber(value) => write!(f, "invalid number {value:?}"),
+            HostError::InvalidProofHex => write!(f, "proof_hex is not hexadecimal"),
+            HostError::InvalidLayout(name) => write!(f, "invalid layout name {name:?}"),
+            HostError::InvalidSteps(n) => write!(f, "n_steps {n} is not a power of two"),
+            HostError::InvalidSegments => write!(f, "unknown or missing memory segments"),
+            HostError::UnsupportedPages => write!(f, "public memory spans several pages"),
+            HostError::EmptyPublicMemory => write!(f, "public memory is empty"),
+        }
+    }
+}
+
+impl std::error::Error for HostError {}
+
+impl From<serde_json::Error> for HostError {
+    fn from(e: serde_json::Error) -> Self {
+        HostError::Json(e)
+    }
+}
+
+/// A Stone proof, as `verify_proof` takes it.
+pub struct StoneProof {
+    pub proof: Vec<u8>,
+    pub public_input: CairoPublicInput,
+}
+
+impl StoneProof {
+    /// Reads the JSON of `cpu_air_prover`.
+    pub fn from_json(json: &str) -> Result<Self, HostError> {
+        let file: serde_repr::ProofFile = serde_json::from_str(json)?;
+        let proof = hex::decode(&file.proof_hex).map_err(|_| HostError::InvalidProofHex)?;
+        Ok(Self {
+            proof,
+            public_input: public_input(&file.public_input)?,
+        })
+    }
+
+    pub fn encode_public_input(&self) -> Vec<u8> {
+        self.public_input.abi_encode()
+    }
+}
+
+fn public_input(input: &serde_repr::PublicInput) -> Result<CairoPublicInput, HostError> {
+    let name = input.layout.as_bytes();
+    if name.is_empty() || name.len() > 32 {
+        return Err(HostError::InvalidLayout(input.layout.clone()));
+    }
+    if !input.n_steps.is_power_of_two() {
+        return Err(HostError::InvalidSteps(input.n_steps));
+    }
+
+    if input
+        .memory_segments
+        .keys()
+        .any(|name| !SEGMENT_ORDER.contains(&name.as_str()))
+        || !input.memory_segments.contains_key("program")
+        || !input.memory_segments.contains_key("execution")
+    {
+        return Err(HostError::InvalidSegments);
+    }
+    let segments = SEGMENT_ORDER
+        .iter()
+        .filter_map(|name| input.memory_segments.get(*name))
+        .map(|segment| MemorySegment {
+            begin: U256::from(segment.begin_addr),
+            stop: U256::from(segment.stop_ptr),
+        })
+        .collect();
+
+    if input.public_memory.iter().any(|cell| cell.page != 0) {
+        return Err(HostError::UnsupportedPages);
+    }
+    let public_memory = input
+        .public_memory
+        .iter()
+        .map(|cell| {
+            Ok(MemoryCell {
+                addr: U256::from(cell.address),
+                value: hex_number(&cell.value)?,
+            })
+        })
+        .collect::<Result<Vec<_>, HostError>>()?;
+    // Stone pads the public memory with its first cell.
+    let padding = public_memory
+        .first()
+        .cloned()
+        .ok_or(HostError::EmptyPublicMemory)?;
+
+    Ok(CairoPublicInput {
+        layout: U256::from_be_slice(name),
+        logNSteps: U256::from(input.n_steps.trailing_zeros()),
+        rangeCheckMin: U256::from(input.rc_min),
+        rangeCheckMax: U256::from(input.rc_max),
+        segments,
+        paddingAddr: padding.addr,
+        paddingValue: padding.value,
+        publicMemory: public_memory,
+    })
+}
+
+fn hex_number(value: &str) -> Result<U256, HostError> {
+    U256::from_str_radix(value.trim_start_matches("0x"), 16)
+        .map_err(|_| HostError::InvalidNumber(value.into()))
+}
+
+/// Mirror of the `cpu_air_prover` JSON layout.
+mod serde_repr {
+    use super::*;
+
+    #[derive(Deserialize)]
+    pub struct ProofFile {
+        pub proof_hex: String,
+        pub public_input: PublicInput,
+    }
+
+    #[derive(Deserialize)]
+    pub struct PublicInput {
+        pub layout: String,
+        pub n_steps: u64,
+        pub rc_min: u64,
+        pub rc_max: u64,
+        pub memory_segments: BTreeMap<String, Segment>,
+        pub public_memory: Vec<Cell>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Segment {
+        pub begin_addr: u64,
+        pub stop_ptr: u64,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Cell {
+        pub address: u64,
+        pub page: u64,
+        pub value: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STONE_PROOF: &str = include_str!("../../testdata/cairo/small/stone_proof.json");
+    const PROOF: &[u8] = include_bytes!("../../testdata/cairo/small/proof.bin");
+    const PUBLIC_INPUT: &[u8] = include_bytes!("../../testdata/cairo/small/public_input.bin");
+
+    #[test]
+    fn encodes_fixture_proof() {
+        let stone = StoneProof::from_json(STONE_PROOF).unwrap();
+        assert_eq!(stone.proof, PROOF);
+        assert_eq!(stone.encode_public_input(), PUBLIC_INPUT);
+
+        let input = &stone.public_input;
+        assert_eq!(input.layout, U256::from_be_slice(b"small"));
+        assert_eq!(input.logNSteps, U256::from(9));
+        let segments: Vec<_> = input.segments.iter().map(|s| (s.begin, s.stop)).collect();
+        assert_eq!(
+            segments,
+            [(1, 5), (34, 95), (95, 97), (97, 97), (289, 289), (353, 353)]
+                .map(|(begin, stop)| (U256::from(begin), U256::from(stop)))
+        );
+        assert_eq!(input.publicMemory.len(), 37);
+        assert_eq!(
+            (input.paddingAddr, input.paddingValue),
+            (input.publicMemory[0].addr, input.publicMemory[0].value)
+        );
+    }
+
+    #[test]
+    fn rejects_multiple_pages() {
+        let paged = STONE_PROOF.replacen("\"page\" : 0", "\"page\" : 1", 1);
+        assert_ne!(paged, STONE_PROOF);
+        assert!(matches!(
+            StoneProof::from_json(&paged),
+            Err(HostError::UnsupportedPages)
+        ));
+    }
+}
@@ -0,0 +1,254 @@
+//! A Cairo layout: the AIR of the Cairo CPU for one builtin selection, together with the
+//! prover parameters it is proven under.
+//!
+//! Stone generates the constraints of each layout as code; here they are an expression
+//! program evaluated at the out-of-domain point. The verifier pins the hash of the blob on
+//! initialization. It reads, big-endian,
+//!
+//! ```text
+//! 32 layout code | u32 log_rows_per_step | u32 constraint_degree
+//! u32 original_columns | u32 interaction_columns | u32 interaction_elements
+//! u32 log_public_memory_step
+//! u32 log_blowup | u32 n_queries | u32 pow_bits | u32 log_last_layer_degree
+//! u32 n_fri_steps | n_fri_steps × u32
+//! u32 n_mask | n_mask × (u32 column | u32 row_offset as i32)
+//! u32 n_periodic | n_periodic × (u32 log_period | u32 n | n × 32 coefficient)
+//! u32 n_exprs | n_exprs × expr
+//! u32 n_constraints | n_constraints × u32 expr
+//! ```
+//!
+//! Each expression is an opcode byte and its operands:
+//!
+//! | op | node | operands |
+//! |----|------|----------|
+//! | 0 | constant | 32 felt |
+//! | 1 | mask value | u32 mask index |
+//! | 2 | periodic column | u32 column |
+//! | 3 | public value | u32 index |
+//! | 4 | interaction element | u32 index |
+//! | 5, 6, 7, 8 | `a + b`, `a - b`, `a · b`, `a / b` | u32 a, u32 b (earlier expressions) |
+//! | 9 | `z^n` | u64 n |
+//! | 10 | `g^n`, `g` the trace generator | u64 n as i64 |
+//! | 11 | `z^(T / 2^k)`, `T` the trace length | u32 k |
+//!
+//! Public values are `rc_min`, `rc_max`, the public memory quotient and then the begin
+//! and stop address of every memory segment.
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::cairo::{felt::Felt, reader::Reader};
+use crate::common::stark::{poly, Field, TwoAdicField};
+
+pub struct FriParams {
+    pub log_blowup: usize,
+    pub n_queries: usize,
+    pub pow_bits: usize,
+    pub log_last_layer_degree: usize,
+    /// As in Stone, `steps[0]` is 0: the DEEP layer is committed unfolded, and each later
+    /// step folds the layer committed before it.
+    pub steps: Vec<usize>,
+}
+
+pub struct MaskItem {
+    pub column: usize,
+    pub row_offset: i64,
+}
+
+struct PeriodicColumn {
+    log_period: usize,
+    coeffs: Vec<Felt>,
+}
+
+#[derive(Clone, Copy)]
+enum Expr {
+    Const(Felt),
+    Mask(usize),
+    Periodic(usize),
+    Public(usize),
+    Interaction(usize),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Div(usize, usize),
+    PointPow(u64),
+    TraceGenPow(i64),
+    PointPowTrace(usize),
+}
+
+pub struct Layout {
+    pub code: U256,
+    pub log_rows_per_step: usize,
+    pub constraint_degree: usize,
+    pub original_columns: usize,
+    pub interaction_columns: usize,
+    pub interaction_elements: usize,
+    pub log_public_memory_step: usize,
+    pub fri: FriParams,
+    pub mask: Vec<MaskItem>,
+    periodic: Vec<PeriodicColumn>,
+    exprs: Vec<Expr>,
+    constraints: Vec<usize>,
+}
+
+/// Values the constraints are evaluated at.
+pub struct Point<'a> {
+    pub z: Felt,
+    pub log_trace_length: usize,
+    pub mask_values: &'a [Felt],
+    pub public_values: &'a [Felt],
+    pub interaction_elements: &'a [Felt],
+}
+
+impl Layout {
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(bytes);
+        let code = r.word()?;
+        let log_rows_per_step = r.usize()?;
+        let constraint_degree = r.usize()?;
+        let original_columns = r.usize()?;
+        let interaction_columns = r.usize()?;
+        let interaction_elements = r.usize()?;
+        let log_public_memory_step = r.usize()?;
+        let fri = FriParams {
+            log_blowup: r.usize()?,
+            n_queries: r.usize()?,
+            pow_bits: r.usize()?,
+            log_last_layer_degree: r.usize()?,
+            steps: {
+                let n = r.length()?;
+                (0..n).map(|_| r.usize()).collect::<Option<_>>()?
+            },
+        };
+        if !(1..=16).contains(&constraint_degree)
+            || original_columns == 0
+            || fri.steps.first() != Some(&0)
+            || fri.steps.iter().any(|s| *s > 4)
+            || fri.n_queries == 0
+            || fri.pow_bits > 64
+        {
+            return None;
+        }
+
+        let n_mask = r.length()?;
+        let mask = (0..n_mask)
+            .map(|_| {
+                let column = r.usize()?;
+                let row_offset = r.u32()? as i32 as i64;
+                (column < original_columns + interaction_columns)
+                    .then_some(MaskItem { column, row_offset })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let n_periodic = r.length()?;
+        let periodic = (0..n_periodic)
+            .map(|_| {
+                let log_period = r.usize()?;
+                let n = r.length()?;
+                Some(PeriodicColumn {
+                    log_period,
+                    coeffs: r.felts(n)?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let n_exprs = r.length()?;
+        let mut exprs = Vec::with_capacity(n_exprs);
+        for i in 0..n_exprs {
+            let expr = match r.u32()? {
+                0 => Expr::Const(r.felt()?),
+                1 => Expr::Mask(r.usize()?),
+                2 => Expr::Periodic(r.usize()?),
+                3 => Expr::Public(r.usize()?),
+                4 => Expr::Interaction(r.usize()?),
+                op @ 5..=8 => {
+                    let (a, b) = (r.usize()?, r.usize()?);
+                    if a >= i || b >= i {
+                        return None;
+                    }
+                    match op {
+                        5 => Expr::Add(a, b),
+                        6 => Expr::Sub(a, b),
+                        7 => Expr::Mul(a, b),
+                        _ => Expr::Div(a, b),
+                    }
+                }
+                9 => Expr::PointPow(r.u64()?),
+                10 => Expr::TraceGenPow(r.u64()? as i64),
+                11 => Expr::PointPowTrace(r.usize()?),
+                _ => return None,
+            };
+            let in_range = match expr {
+                Expr::Mask(j) => j < mask.len(),
+                Expr::Periodic(j) => j < periodic.len(),
+                Expr::Interaction(j) => j < interaction_elements,
+                _ => true,
+            };
+            if !in_range {
+                return None;
+            }
+            exprs.push(expr);
+        }
+
+        let n_constraints = r.length()?;
+        let constraints = (0..n_constraints)
+            .map(|_| r.usize().filter(|i| *i < exprs.len()))
+            .collect::<Option<Vec<_>>>()?;
+
+        r.is_empty().then_some(Self {
+            code,
+            log_rows_per_step,
+            constraint_degree,
+            original_columns,
+            interaction_columns,
+            interaction_elements,
+            log_public_memory_step,
+            fri,
+            mask,
+            periodic,
+            exprs,
+            constraints,
+        })
+    }
+
+    /// Evaluates `Σ alpha^i · constraint_i` at the out-of-domain point.
+    pub fn eval_composition(&self, point: &Point, alpha: Felt) -> Option<Felt> {
+        let trace_length = 1u64 << point.log_trace_length;
+        let g = Felt::two_adic_generator(point.log_trace_length);
+        let mut values: Vec<Felt> = Vec::with_capacity(self.exprs.len());
+        for expr in &self.exprs {
+            let value = match *expr {
+                Expr::Const(x) => x,
+                Expr::Mask(i) => *point.mask_values.get(i)?,
+                Expr::Periodic(i) => {
+                    let column = &self.periodic[i];
+                    let log_step = point.log_trace_length.checked_sub(column.log_period)?;
+                    poly::eval(&column.coeffs, point.z.pow(1 << log_step))
+                }
+                Expr::Public(i) => *point.public_values.get(i)?,
+                Expr::Interaction(i) => *point.interaction_elements.get(i)?,
+                Expr::Add(a, b) => values[a] + values[b],
+                Expr::Sub(a, b) => values[a] - values[b],
+                Expr::Mul(a, b) => values[a] * values[b],
+                Expr::Div(a, b) => {
+                    if values[b] == Felt::ZERO {
+                        return None;
+                    }
+                    values[a] * values[b].inv()
+                }
+                Expr::PointPow(n) => point.z.pow(n),
+                Expr::TraceGenPow(n) => g.pow(n.rem_euclid(trace_length as i64) as u64),
+                Expr::PointPowTrace(k) => point.z.pow(1 << point.log_trace_length.checked_sub(k)?),
+            };
+            values.push(value);
+        }
+
+        let mut acc = Felt::ZERO;
+        let mut alpha_pow = Felt::ONE;
+        for i in &self.constraints {
+            acc += alpha_pow * values[*i];
+            alpha_pow *= alpha;
+        }
+        Some(acc)
+    }
+}
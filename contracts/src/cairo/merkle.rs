@@ -0,0 +1,72 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use stylus_sdk::{alloy_primitives::B256, crypto::keccak};
+
+use crate::cairo::{channel::Channel, felt::Felt};
+
+/// Keccak-256 truncated to its leading 160 bits, as used for Stone commitments.
+fn keccak160(data: &[u8]) -> B256 {
+    let mut hash = keccak(data);
+    hash.0[20..].fill(0);
+    hash
+}
+
+/// Hashes a row of field elements by their Montgomery words. A single element is its
+/// own leaf.
+pub fn hash_leaf(row: &[Felt]) -> B256 {
+    let words: Vec<u8> = row
+        .iter()
+        .flat_map(|x| x.as_montgomery().to_be_bytes::<32>())
+        .collect();
+    if row.len() == 1 {
+        B256::from_slice(&words)
+    } else {
+        keccak160(&words)
+    }
+}
+
+/// Verifies a batch of leaves under `root` in a tree of `2^log_height` leaves.
+///
+/// `leaves` must be sorted by index without duplicates. Siblings that cannot be computed
+/// from the batch are read from the channel, in the order a breadth-first walk up from
+/// the leaves needs them.
+pub fn verify_batch(
+    channel: &mut Channel,
+    root: &B256,
+    log_height: usize,
+    leaves: &[(usize, B256)],
+) -> Option<()> {
+    let offset = 1usize.checked_shl(log_height as u32)?;
+    let mut queue: VecDeque<(usize, B256)> = leaves
+        .iter()
+        .map(|(index, hash)| (offset + index, *hash))
+        .collect();
+    if leaves.iter().any(|(index, _)| *index >= offset)
+        || leaves.windows(2).any(|w| w[0].0 >= w[1].0)
+    {
+        return None;
+    }
+
+    while let Some((index, hash)) = queue.pop_front() {
+        if index == 1 {
+            return (hash == *root && queue.is_empty()).then_some(());
+        }
+        let sibling = match queue.front() {
+            Some((next, next_hash)) if *next == index ^ 1 => {
+                let next_hash = *next_hash;
+                queue.pop_front();
+                next_hash
+            }
+            _ => channel.read_node()?,
+        };
+        let (left, right) = if index & 1 == 0 {
+            (hash, sibling)
+        } else {
+            (sibling, hash)
+        };
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(left.as_slice());
+        data[32..].copy_from_slice(right.as_slice());
+        queue.push_back((index >> 1, keccak160(&data)));
+    }
+    None
+}
@@ -0,0 +1,17 @@
+pub mod channel;
+pub mod config;
+pub mod errors;
+pub mod felt;
+pub mod fri;
+#[cfg(feature = "cairo-host")]
+pub mod host;
+pub mod layout;
+pub mod merkle;
+pub mod pedersen;
+pub mod reader;
+pub mod types;
+pub mod verifier;
+
+pub use errors::CairoError;
+pub use types::CairoPublicInput;
+pub use verifier::{verify_stone_proof, CairoVerifier, ICairoVerifier};
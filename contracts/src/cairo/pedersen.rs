@@ -0,0 +1,165 @@
+//! StarkWare's Pedersen hash over the Stark curve `y^2 = x^3 + x + β`, which Cairo uses
+//! for program hashes.
+//!
+//! `H(a, b)` is the `x` coordinate of `P0 + a_low·P1 + a_high·P2 + b_low·P3 + b_high·P4`,
+//! where `low` is the low 248 bits of an element and `high` the remaining 4.
+
+use stylus_sdk::alloy_primitives::{uint, U256};
+
+use crate::cairo::felt::Felt;
+use crate::common::stark::Field;
+
+const LOW_BITS: usize = 248;
+const HIGH_BITS: usize = 4;
+
+type Affine = (Felt, Felt);
+
+const fn point(x: U256, y: U256) -> Affine {
+    (
+        Felt::from_canonical_unchecked(x),
+        Felt::from_canonical_unchecked(y),
+    )
+}
+
+const P0: Affine = point(
+    uint!(0x49ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804_U256),
+    uint!(0x3ca0cfe4b3bc6ddf346d49d06ea0ed34e621062c0e056c1d0405d266e10268a_U256),
+);
+const P1: Affine = point(
+    uint!(0x234287dcbaffe7f969c748655fca9e58fa8120b6d56eb0c1080d17957ebe47b_U256),
+    uint!(0x3b056f100f96fb21e889527d41f4e39940135dd7a6c94cc6ed0268ee89e5615_U256),
+);
+const P2: Affine = point(
+    uint!(0x4fa56f376c83db33f9dab2656558f3399099ec1de5e3018b7a6932dba8aa378_U256),
+    uint!(0x3fa0984c931c9e38113e0c0e47e4401562761f92a7a23b45168f4e80ff5b54d_U256),
+);
+const P3: Affine = point(
+    uint!(0x4ba4cc166be8dec764910f75b45f74b40c690c74709e90f3aa372f0bd2d6997_U256),
+    uint!(0x40301cf5c1751f4b971e46c4ede85fcac5c59a5ce5ae7c48151f27b24b219c_U256),
+);
+const P4: Affine = point(
+    uint!(0x54302dcb0e6cc1c6e44cca8f61a63bb2ca65048d53fb325d36ff12c49a58202_U256),
+    uint!(0x1b77b3e37d13504b348046268d8ae25ce98ad783c25561a879dcc77e99c2426_U256),
+);
+
+/// A point in Jacobian coordinates, `(X / Z^2, Y / Z^3)`; `Z = 0` is the identity.
+#[derive(Clone, Copy)]
+struct Jacobian {
+    x: Felt,
+    y: Felt,
+    z: Felt,
+}
+
+impl Jacobian {
+    const IDENTITY: Self = Self {
+        x: Felt::ONE,
+        y: Felt::ONE,
+        z: Felt::ZERO,
+    };
+
+    fn double(self) -> Self {
+        if self.z == Felt::ZERO || self.y == Felt::ZERO {
+            return Self::IDENTITY;
+        }
+        let xx = self.x.square();
+        let yy = self.y.square();
+        let zz = self.z.square();
+        let s = Felt::from_u64(4) * self.x * yy;
+        // The curve's `a` is 1.
+        let m = Felt::from_u64(3) * xx + zz.square();
+        let x = m.square() - s - s;
+        let y = m * (s - x) - Felt::from_u64(8) * yy.square();
+        let z = (self.y + self.y) * self.z;
+        Self { x, y, z }
+    }
+
+    fn add_affine(self, (x2, y2): Affine) -> Self {
+        if self.z == Felt::ZERO {
+            return Self {
+                x: x2,
+                y: y2,
+                z: Felt::ONE,
+            };
+        }
+        let zz = self.z.square();
+        let h = x2 * zz - self.x;
+        let r = y2 * zz * self.z - self.y;
+        if h == Felt::ZERO {
+            return if r == Felt::ZERO {
+                self.double()
+            } else {
+                Self::IDENTITY
+            };
+        }
+        let hh = h.square();
+        let hhh = hh * h;
+        let v = self.x * hh;
+        let x = r.square() - hhh - v - v;
+        let y = r * (v - x) - self.y * hhh;
+        let z = self.z * h;
+        Self { x, y, z }
+    }
+
+    fn affine_x(self) -> Felt {
+        self.x * self.z.square().inv()
+    }
+}
+
+/// The Pedersen hash of two field elements.
+pub fn pedersen_hash(a: Felt, b: Felt) -> Felt {
+    let (a, b) = (a.as_u256(), b.as_u256());
+    // One double-and-add over the four scalars together, from the top bit down; bit `i` of
+    // a high part is bit `248 + i` of its element.
+    let mut acc = Jacobian::IDENTITY;
+    for bit in (0..LOW_BITS).rev() {
+        acc = acc.double();
+        for (scalar, base) in [(a, P1), (b, P3)] {
+            if scalar.bit(bit) {
+                acc = acc.add_affine(base);
+            }
+        }
+        for (scalar, base) in [(a, P2), (b, P4)] {
+            if bit < HIGH_BITS && scalar.bit(LOW_BITS + bit) {
+                acc = acc.add_affine(base);
+            }
+        }
+    }
+    acc.add_affine(P0).affine_x()
+}
+
+/// Cairo's `compute_hash_on_elements`: the elements are chained from zero and the length
+/// is hashed last.
+pub fn hash_elements(elements: &[Felt]) -> Felt {
+    let hash = elements
+        .iter()
+        .fold(Felt::ZERO, |acc, element| pedersen_hash(acc, *element));
+    pedersen_hash(hash, Felt::from_u64(elements.len() as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn felt(hex: &str) -> Felt {
+        Felt::from_canonical(U256::from_str_radix(hex, 16).unwrap()).unwrap()
+    }
+
+    // Vectors from StarkWare's signature test data.
+    #[test]
+    fn matches_known_hashes() {
+        assert_eq!(
+            pedersen_hash(
+                felt("03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb"),
+                felt("0208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a"),
+            ),
+            felt("030e480bed5fe53fa909cc0f8c4d99b8f9f2c016be4c41e13a4848797979c662"),
+        );
+        assert_eq!(
+            pedersen_hash(
+                felt("058f580910a6ca59b28927c08fe6c43e2e303ca384badc365795fc645d479d45"),
+                felt("078734f65a067be9bdb39de18434d71e79f7b6466a4b66bbd979ab9e7515fe0b"),
+            ),
+            felt("068cc0b76cddd1dd4ed2301ada9b7c872b23875d5ff837b3a87993e0d9996b87"),
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::cairo::felt::Felt;
+
+/// Big-endian cursor over a layout blob.
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    pub fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    pub fn usize(&mut self) -> Option<usize> {
+        usize::try_from(self.u32()?).ok()
+    }
+
+    pub fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_be_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    /// A length prefix, bounded by the remaining bytes so that allocations stay small.
+    pub fn length(&mut self) -> Option<usize> {
+        let n = self.usize()?;
+        (n <= self.bytes.len()).then_some(n)
+    }
+
+    pub fn word(&mut self) -> Option<U256> {
+        Some(U256::from_be_slice(self.take(32)?))
+    }
+
+    /// A canonical field element.
+    pub fn felt(&mut self) -> Option<Felt> {
+        Felt::from_canonical(self.word()?)
+    }
+
+    pub fn felts(&mut self, n: usize) -> Option<Vec<Felt>> {
+        (0..n).map(|_| self.felt()).collect()
+    }
+}
@@ -0,0 +1,68 @@
+use alloc::vec::Vec;
+use stylus_sdk::{
+    alloy_primitives::{B256, U256},
+    alloy_sol_types::sol,
+    crypto::keccak,
+};
+
+sol! {
+    struct MemorySegment {
+        uint256 begin;
+        uint256 stop;
+    }
+
+    struct MemoryCell {
+        uint256 addr;
+        uint256 value;
+    }
+
+    /// Public input of a Stone Cairo proof. Segments follow the layout's order, starting
+    /// with program, execution and output.
+    struct CairoPublicInput {
+        uint256 layout;
+        uint256 logNSteps;
+        uint256 rangeCheckMin;
+        uint256 rangeCheckMax;
+        MemorySegment[] segments;
+        uint256 paddingAddr;
+        uint256 paddingValue;
+        MemoryCell[] publicMemory;
+    }
+}
+
+impl CairoPublicInput {
+    /// Seed of the channel, as Stone derives it: Keccak-256 of `logNSteps`, the range
+    /// check bounds, the layout, the segments and the padding cell, followed by the one
+    /// public memory page as its size and the Keccak-256 of its cells, all as 32-byte
+    /// words.
+    pub fn hash(&self) -> B256 {
+        let cells = self
+            .publicMemory
+            .iter()
+            .flat_map(|cell| [cell.addr, cell.value]);
+        let header = [
+            self.logNSteps,
+            self.rangeCheckMin,
+            self.rangeCheckMax,
+            self.layout,
+        ]
+        .into_iter()
+        .chain(
+            self.segments
+                .iter()
+                .flat_map(|segment| [segment.begin, segment.stop]),
+        )
+        .chain([
+            self.paddingAddr,
+            self.paddingValue,
+            U256::from(1),
+            U256::from(self.publicMemory.len()),
+            keccak(to_bytes(cells)).into(),
+        ]);
+        keccak(to_bytes(header))
+    }
+}
+
+fn to_bytes(words: impl Iterator<Item = U256>) -> Vec<u8> {
+    words.flat_map(|word| word.to_be_bytes::<32>()).collect()
+}
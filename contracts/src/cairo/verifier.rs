@@ -0,0 +1,576 @@
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{Address, B256, U256},
+    alloy_sol_types::SolType,
+    crypto::keccak,
+    prelude::*,
+};
+
+use crate::cairo::{
+    channel::Channel,
+    config::{EXECUTION_SEGMENT, MAX_LOG_LDE, OUTPUT_SEGMENT, PROGRAM_SEGMENT},
+    errors::CairoError,
+    felt::Felt,
+    fri::FriCommitments,
+    layout::{Layout, Point},
+    merkle, pedersen,
+    types::CairoPublicInput,
+};
+use crate::common::{
+    ownable::{IOwnable, Ownable},
+    stark::{batch_inverse, fri::reverse_bits, Field, TwoAdicField},
+};
+
+pub trait ICairoVerifier {
+    type Error;
+
+    /// Pins the layout to accept (see [`Layout`]); owner-only and callable once.
+    fn initialize(&mut self, layout: Vec<u8>) -> Result<(), Self::Error>;
+
+    /// Verifies a Stone proof and returns the program hash and the program's outputs.
+    fn verify_proof(
+        &self,
+        proof: Vec<u8>,
+        public_input: Vec<u8>,
+        layout: Vec<u8>,
+    ) -> Result<(B256, Vec<U256>), Self::Error>;
+
+    fn layout_hash(&self) -> B256;
+}
+
+sol_storage! {
+    pub struct CairoVerifier {
+        bytes32 layout_hash;
+        Ownable ownable;
+    }
+}
+
+#[public]
+impl ICairoVerifier for CairoVerifier {
+    type Error = Vec<u8>;
+
+    fn initialize(&mut self, layout: Vec<u8>) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        if self.layout_hash.get() != B256::ZERO {
+            return Err(CairoError::ALREADY_INITIALIZED.abi_encode());
+        }
+        if Layout::from_bytes(&layout).is_none() {
+            return Err(CairoError::InvalidLayout.abi_encode());
+        }
+
+        self.layout_hash.set(keccak(&layout));
+
+        Ok(())
+    }
+
+    fn verify_proof(
+        &self,
+        proof: Vec<u8>,
+        public_input: Vec<u8>,
+        layout: Vec<u8>,
+    ) -> Result<(B256, Vec<U256>), Self::Error> {
+        let layout_hash = self.layout_hash.get();
+        if layout_hash == B256::ZERO {
+            return Err(CairoError::INVALID_INITIALIZATION.abi_encode());
+        }
+        if keccak(&layout) != layout_hash {
+            return Err(CairoError::InvalidLayout.abi_encode());
+        }
+        let layout =
+            Layout::from_bytes(&layout).ok_or_else(|| CairoError::InvalidLayout.abi_encode())?;
+        let public_input = <CairoPublicInput as SolType>::abi_decode(&public_input, true)
+            .map_err(|_| CairoError::InvalidPublicInput.abi_encode())?;
+        verify_stone_proof(&layout, &public_input, &proof).map_err(|e| e.abi_encode())
+    }
+
+    fn layout_hash(&self) -> B256 {
+        self.layout_hash.get()
+    }
+}
+
+#[public]
+impl IOwnable for CairoVerifier {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.ownable.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.ownable
+            .transfer_ownership(new_owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.ownable.accept_ownership().map_err(|e| e.abi_encode())
+    }
+}
+
+impl CairoVerifier {
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable
+            .set_initial_owner(owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn only_owner(&self) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner().map_err(|e| e.abi_encode())
+    }
+}
+
+fn felt(x: U256) -> Result<Felt, CairoError> {
+    Felt::from_canonical(x).ok_or(CairoError::InvalidPublicInput)
+}
+
+/// Verifies a Stone proof of a Cairo run under `layout`, returning the Cairo program hash
+/// and the values of the output segment.
+pub fn verify_stone_proof(
+    layout: &Layout,
+    public_input: &CairoPublicInput,
+    proof: &[u8],
+) -> Result<(B256, Vec<U256>), CairoError> {
+    let fri = &layout.fri;
+    let log_trace_length = usize::try_from(public_input.logNSteps)
+        .ok()
+        .and_then(|log_n_steps| log_n_steps.checked_add(layout.log_rows_per_step))
+        .ok_or(CairoError::InvalidPublicInput)?;
+    let log_lde = log_trace_length
+        .checked_add(fri.log_blowup)
+        .ok_or(CairoError::InvalidPublicInput)?;
+    if public_input.layout != layout.code
+        || public_input.segments.len() <= OUTPUT_SEGMENT
+        || public_input.rangeCheckMin > public_input.rangeCheckMax
+        || log_lde > MAX_LOG_LDE
+        || layout.log_public_memory_step > log_trace_length
+    {
+        return Err(CairoError::InvalidPublicInput);
+    }
+    let (program_hash, outputs) = read_program_and_outputs(public_input)?;
+
+    let mut channel = Channel::new(proof, public_input.hash());
+    let mut roots = vec![channel
+        .read_commitment()
+        .ok_or(CairoError::INVALID_PROOF_DATA)?];
+    let mut interaction_elements = Vec::new();
+    if layout.interaction_columns > 0 {
+        interaction_elements = (0..layout.interaction_elements)
+            .map(|_| channel.sample_felt())
+            .collect();
+        roots.push(
+            channel
+                .read_commitment()
+                .ok_or(CairoError::INVALID_PROOF_DATA)?,
+        );
+    }
+    let composition_alpha = channel.sample_felt();
+    roots.push(
+        channel
+            .read_commitment()
+            .ok_or(CairoError::INVALID_PROOF_DATA)?,
+    );
+
+    // Out-of-domain consistency: the constraints at `z` against the composition columns,
+    // which are evaluated at `z^d`.
+    let z = channel.sample_felt();
+    let degree = layout.constraint_degree;
+    let oods_values = (0..layout.mask.len() + degree)
+        .map(|_| channel.read_felt())
+        .collect::<Option<Vec<_>>>()
+        .ok_or(CairoError::INVALID_PROOF_DATA)?;
+    let (mask_values, composition_values) = oods_values.split_at(layout.mask.len());
+    let public_values = public_values(
+        public_input,
+        &interaction_elements,
+        log_trace_length - layout.log_public_memory_step,
+    )?;
+    let point = Point {
+        z,
+        log_trace_length,
+        mask_values,
+        public_values: &public_values,
+        interaction_elements: &interaction_elements,
+    };
+    let expected = layout
+        .eval_composition(&point, composition_alpha)
+        .ok_or(CairoError::VERIFICATION_FAILED)?;
+    let claimed = composition_values
+        .iter()
+        .rev()
+        .fold(Felt::ZERO, |acc, value| acc * z + *value);
+    if expected != claimed {
+        return Err(CairoError::OodsMismatch);
+    }
+
+    let deep_alpha = channel.sample_felt();
+    let fri_commitments =
+        FriCommitments::read(&mut channel, fri).ok_or(CairoError::INVALID_PROOF_DATA)?;
+    if !channel
+        .verify_proof_of_work(fri.pow_bits)
+        .ok_or(CairoError::INVALID_PROOF_DATA)?
+    {
+        return Err(CairoError::VERIFICATION_FAILED);
+    }
+    let queries = channel.sample_queries(fri.n_queries, log_lde);
+
+    let widths = [layout.original_columns, layout.interaction_columns, degree];
+    let widths = widths.iter().filter(|w| **w > 0);
+    let mut rows: Vec<Vec<Vec<Felt>>> = Vec::with_capacity(roots.len());
+    for (root, width) in roots.iter().zip(widths) {
+        let mut table = Vec::with_capacity(queries.len());
+        let mut leaves = Vec::with_capacity(queries.len());
+        for query in &queries {
+            let row = (0..*width)
+                .map(|_| channel.read_decommitted_felt())
+                .collect::<Option<Vec<_>>>()
+                .ok_or(CairoError::INVALID_PROOF_DATA)?;
+            leaves.push((*query, merkle::hash_leaf(&row)));
+            table.push(row);
+        }
+        merkle::verify_batch(&mut channel, root, log_lde, &leaves)
+            .ok_or(CairoError::VERIFICATION_FAILED)?;
+        rows.push(table);
+    }
+
+    let first_layer = deep_values(
+        layout,
+        &rows,
+        &queries,
+        log_lde,
+        log_trace_length,
+        z,
+        &oods_values,
+        deep_alpha,
+    );
+    fri_commitments
+        .verify(
+            &mut channel,
+            fri,
+            log_lde,
+            queries.into_iter().zip(first_layer).collect(),
+        )
+        .ok_or(CairoError::VERIFICATION_FAILED)?;
+    if !channel.is_empty() {
+        return Err(CairoError::INVALID_PROOF_DATA);
+    }
+
+    Ok((program_hash, outputs))
+}
+
+/// `rc_min`, `rc_max`, the public memory quotient and the segment addresses.
+///
+/// The quotient is `z^S / Π (z - (addr + α · value))` over the `S` public memory slots,
+/// unused slots holding the padding cell; `z` and `α` are the first two interaction
+/// elements.
+fn public_values(
+    public_input: &CairoPublicInput,
+    interaction_elements: &[Felt],
+    log_public_memory_slots: usize,
+) -> Result<Vec<Felt>, CairoError> {
+    let mut values = vec![
+        felt(public_input.rangeCheckMin)?,
+        felt(public_input.rangeCheckMax)?,
+    ];
+
+    let slots = 1u64 << log_public_memory_slots;
+    let cells = public_input.publicMemory.len() as u64;
+    if cells > slots {
+        return Err(CairoError::InvalidPublicInput);
+    }
+    let quotient = match interaction_elements {
+        [z, alpha, ..] => {
+            let padding =
+                *z - (felt(public_input.paddingAddr)? + *alpha * felt(public_input.paddingValue)?);
+            let mut denominator = padding.pow(slots - cells);
+            for cell in &public_input.publicMemory {
+                denominator *= *z - (felt(cell.addr)? + *alpha * felt(cell.value)?);
+            }
+            if denominator == Felt::ZERO {
+                return Err(CairoError::VERIFICATION_FAILED);
+            }
+            z.pow(slots) * denominator.inv()
+        }
+        _ => Felt::ONE,
+    };
+    values.push(quotient);
+
+    for segment in &public_input.segments {
+        values.push(felt(segment.begin)?);
+        values.push(felt(segment.stop)?);
+    }
+    Ok(values)
+}
+
+/// Reads the values at `[begin, stop)` from public memory, requiring every address once.
+fn read_memory(
+    public_input: &CairoPublicInput,
+    begin: U256,
+    stop: U256,
+) -> Result<Vec<U256>, CairoError> {
+    let len = stop
+        .checked_sub(begin)
+        .and_then(|len| usize::try_from(len).ok())
+        .filter(|len| *len <= public_input.publicMemory.len())
+        .ok_or(CairoError::InvalidPublicInput)?;
+    let mut values: Vec<Option<U256>> = vec![None; len];
+    for cell in &public_input.publicMemory {
+        if cell.addr < begin || cell.addr >= stop {
+            continue;
+        }
+        let slot = &mut values[usize::try_from(cell.addr - begin).unwrap_or_default()];
+        if slot.is_some_and(|value| value != cell.value) {
+            return Err(CairoError::InvalidPublicInput);
+        }
+        *slot = Some(cell.value);
+    }
+    values
+        .into_iter()
+        .collect::<Option<Vec<_>>>()
+        .ok_or(CairoError::InvalidPublicInput)
+}
+
+/// Hashes the program and reads the outputs. As in Stone's bootloader convention, the
+/// program is the code from the initial `pc` up to the two cells below the initial `ap`,
+/// and its hash is Cairo's Pedersen `compute_hash_on_elements`.
+fn read_program_and_outputs(
+    public_input: &CairoPublicInput,
+) -> Result<(B256, Vec<U256>), CairoError> {
+    let program_end = public_input.segments[EXECUTION_SEGMENT]
+        .begin
+        .checked_sub(U256::from(2))
+        .ok_or(CairoError::InvalidPublicInput)?;
+    let program = read_memory(
+        public_input,
+        public_input.segments[PROGRAM_SEGMENT].begin,
+        program_end,
+    )?
+    .into_iter()
+    .map(felt)
+    .collect::<Result<Vec<_>, _>>()?;
+    let program_hash = pedersen::hash_elements(&program).as_u256();
+
+    let output = &public_input.segments[OUTPUT_SEGMENT];
+    let outputs = read_memory(public_input, output.begin, output.stop)?;
+    Ok((program_hash.into(), outputs))
+}
+
+/// Evaluates the DEEP composition at every query:
+/// `Σ α^k (column_k(x) - v_k) / (x - z · g^offset_k)` over the mask, followed by the
+/// composition columns against `z^d`.
+#[allow(clippy::too_many_arguments)]
+fn deep_values(
+    layout: &Layout,
+    rows: &[Vec<Vec<Felt>>],
+    queries: &[usize],
+    log_lde: usize,
+    log_trace_length: usize,
+    z: Felt,
+    oods_values: &[Felt],
+    alpha: Felt,
+) -> Vec<Felt> {
+    let trace_length = 1i64 << log_trace_length;
+    let g = Felt::two_adic_generator(log_trace_length);
+    let mut points: Vec<Felt> = layout
+        .mask
+        .iter()
+        .map(|item| z * g.pow(item.row_offset.rem_euclid(trace_length) as u64))
+        .collect();
+    points.push(z.pow(layout.constraint_degree as u64));
+    let composition_point = points.len() - 1;
+
+    let lde_generator = Felt::two_adic_generator(log_lde);
+    let composition_rows = rows.len() - 1;
+    queries
+        .iter()
+        .enumerate()
+        .map(|(i, query)| {
+            let x = Felt::GENERATOR * lde_generator.pow(reverse_bits(*query, log_lde) as u64);
+            let mut inverses: Vec<Felt> = points.iter().map(|p| x - *p).collect();
+            batch_inverse(&mut inverses);
+
+            let column = |c: usize| {
+                if c < layout.original_columns {
+                    rows[0][i][c]
+                } else {
+                    rows[1][i][c - layout.original_columns]
+                }
+            };
+            let terms = layout
+                .mask
+                .iter()
+                .enumerate()
+                .map(|(k, item)| (column(item.column), inverses[k]))
+                .chain(
+                    rows[composition_rows][i]
+                        .iter()
+                        .map(|value| (*value, inverses[composition_point])),
+                );
+
+            let mut acc = Felt::ZERO;
+            let mut alpha_pow = Felt::ONE;
+            for ((value, inverse), oods_value) in terms.zip(oods_values) {
+                acc += alpha_pow * (value - *oods_value) * inverse;
+                alpha_pow *= alpha;
+            }
+            acc
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::VerificationError;
+    use stylus_sdk::alloy_primitives::uint;
+
+    // Stone's proof of a Fibonacci run over 512 steps of the `small` layout, writing
+    // `[10, 144]` to the output segment (`stone_proof.json`, encoded by `cairo::host`), and
+    // the layout compiled by `testdata/cairo/compile_layout.py`.
+    const LAYOUT: &[u8] = include_bytes!("../../testdata/cairo/small/layout.bin");
+    const PUBLIC_INPUT: &[u8] = include_bytes!("../../testdata/cairo/small/public_input.bin");
+    const PROOF: &[u8] = include_bytes!("../../testdata/cairo/small/proof.bin");
+
+    // Byte offsets into the proof: the three trace roots precede the 203 OODS values; the
+    // two FRI roots, 64 last layer coefficients and the nonce precede the trace openings;
+    // the first FRI layer's coset values follow the trace openings.
+    const OODS_VALUES: usize = 3 * 32;
+    const NONCE: usize = OODS_VALUES + 203 * 32 + 2 * 32 + 64 * 32;
+    const TRACE_OPENINGS: usize = NONCE + 8;
+    const FRI_LAYER_VALUES: usize = 43944;
+
+    fn layout() -> Layout {
+        Layout::from_bytes(LAYOUT).unwrap()
+    }
+
+    fn public_input() -> CairoPublicInput {
+        <CairoPublicInput as SolType>::abi_decode(PUBLIC_INPUT, true).unwrap()
+    }
+
+    fn verify_altered(offset: usize) -> Result<(B256, Vec<U256>), CairoError> {
+        let mut altered = PROOF.to_vec();
+        // The low bit of a word keeps it below `p`.
+        altered[offset + 31] ^= 1;
+        verify_stone_proof(&layout(), &public_input(), &altered)
+    }
+
+    #[test]
+    fn verifies_stone_proof() {
+        let (program_hash, outputs) =
+            verify_stone_proof(&layout(), &public_input(), PROOF).unwrap();
+
+        // Pedersen `compute_hash_on_elements` of the 31 program words, as Stone and the
+        // Cairo bootloader compute it.
+        assert_eq!(
+            program_hash,
+            B256::from(uint!(
+                0x04254c90357ad6cfae1f1eb79a7afd5d50b9ae9607e9d1f42b7d258b9a7f7cb8_U256
+            ))
+        );
+        assert_eq!(outputs, [U256::from(10), U256::from(144)]);
+    }
+
+    #[test]
+    fn rejects_altered_oods_values() {
+        assert!(matches!(
+            verify_altered(OODS_VALUES),
+            Err(CairoError::OodsMismatch)
+        ));
+        // The composition columns.
+        assert!(matches!(
+            verify_altered(OODS_VALUES + 202 * 32),
+            Err(CairoError::OodsMismatch)
+        ));
+    }
+
+    #[test]
+    fn rejects_altered_openings() {
+        assert!(matches!(
+            verify_altered(TRACE_OPENINGS),
+            Err(CairoError::Common(VerificationError::VerificationFailed))
+        ));
+        assert!(matches!(
+            verify_altered(FRI_LAYER_VALUES),
+            Err(CairoError::Common(VerificationError::VerificationFailed))
+        ));
+    }
+
+    #[test]
+    fn rejects_insufficient_work() {
+        let mut altered = PROOF.to_vec();
+        altered[NONCE + 7] ^= 1;
+        assert!(matches!(
+            verify_stone_proof(&layout(), &public_input(), &altered),
+            Err(CairoError::Common(VerificationError::VerificationFailed))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_proofs() {
+        let truncated = &PROOF[..OODS_VALUES + 100 * 32];
+        assert!(matches!(
+            verify_stone_proof(&layout(), &public_input(), truncated),
+            Err(CairoError::Common(VerificationError::InvalidProofData))
+        ));
+        // Short of the last FRI layer's authentication path.
+        let truncated = &PROOF[..PROOF.len() - 1];
+        assert!(matches!(
+            verify_stone_proof(&layout(), &public_input(), truncated),
+            Err(CairoError::Common(VerificationError::VerificationFailed))
+        ));
+
+        let mut extended = PROOF.to_vec();
+        extended.push(0);
+        assert!(matches!(
+            verify_stone_proof(&layout(), &public_input(), &extended),
+            Err(CairoError::Common(VerificationError::InvalidProofData))
+        ));
+    }
+
+    #[test]
+    fn rejects_other_layouts() {
+        let mut public_input = public_input();
+        public_input.layout = U256::from_be_slice(b"dex");
+        assert!(matches!(
+            verify_stone_proof(&layout(), &public_input, PROOF),
+            Err(CairoError::InvalidPublicInput)
+        ));
+
+        // The last constraint replaced by the first expression.
+        let mut altered = LAYOUT.to_vec();
+        let len = altered.len();
+        altered[len - 4..].fill(0);
+        let altered = Layout::from_bytes(&altered).unwrap();
+        assert!(matches!(
+            verify_stone_proof(&altered, &self::public_input(), PROOF),
+            Err(CairoError::OodsMismatch)
+        ));
+    }
+
+    #[test]
+    fn rejects_altered_public_memory() {
+        // The first output, `10`.
+        let mut public_input = public_input();
+        let cell = public_input
+            .publicMemory
+            .iter_mut()
+            .find(|cell| cell.addr == U256::from(95))
+            .unwrap();
+        cell.value = U256::from(11);
+        assert!(verify_stone_proof(&layout(), &public_input, PROOF).is_err());
+
+        // A program word missing from public memory leaves the program unknown.
+        let mut public_input = self::public_input();
+        public_input
+            .publicMemory
+            .retain(|cell| cell.addr != U256::from(2));
+        assert!(matches!(
+            verify_stone_proof(&layout(), &public_input, PROOF),
+            Err(CairoError::InvalidPublicInput)
+        ));
+    }
+}
@@ -5,6 +5,14 @@ sol! {
     error InvalidInitialization();
     error AlreadyInitialized();
     error InvalidProofData();
+
+    /// Emitted by the state-changing verify paths; `system` is the `VMType` of the proof.
+    event ProofVerified(
+        uint8 indexed system,
+        bytes32 indexed programId,
+        bytes32 publicInputsDigest,
+        address caller
+    );
 }
 
 #[derive(Debug)]
@@ -24,4 +32,4 @@ impl VerificationError {
             VerificationError::InvalidProofData => InvalidProofData {}.abi_encode(),
         }
     }
-} 
\ No newline at end of file
+}
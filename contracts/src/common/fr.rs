@@ -0,0 +1,92 @@
+//! Arithmetic over the BN254 scalar field `Fr`, on canonical `U256` values (`< R`).
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::U256;
+
+use super::groth16::R;
+
+pub fn add(a: U256, b: U256) -> U256 {
+    a.add_mod(b, R)
+}
+
+pub fn sub(a: U256, b: U256) -> U256 {
+    a.add_mod(neg(b), R)
+}
+
+pub fn neg(a: U256) -> U256 {
+    if a.is_zero() {
+        a
+    } else {
+        R - a
+    }
+}
+
+pub fn mul(a: U256, b: U256) -> U256 {
+    a.mul_mod(b, R)
+}
+
+pub fn square(a: U256) -> U256 {
+    a.mul_mod(a, R)
+}
+
+pub fn pow(a: U256, exp: U256) -> U256 {
+    a.pow_mod(exp, R)
+}
+
+/// Multiplicative inverse, `None` for zero.
+pub fn inv(a: U256) -> Option<U256> {
+    if a.is_zero() {
+        return None;
+    }
+    a.inv_mod(R)
+}
+
+/// Reduces a big-endian byte string (e.g. a hash output) into the field.
+pub fn from_be_bytes_mod_order(bytes: &[u8]) -> U256 {
+    U256::from_be_slice(bytes).reduce_mod(R)
+}
+
+/// Montgomery batch inversion; `None` if any element is zero.
+pub fn batch_inverse(values: &[U256]) -> Option<Vec<U256>> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = U256::from(1u8);
+    for v in values {
+        prefix.push(acc);
+        acc = mul(acc, *v);
+    }
+
+    let mut inv_acc = inv(acc)?;
+    let mut out = alloc::vec![U256::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        out[i] = mul(inv_acc, prefix[i]);
+        inv_acc = mul(inv_acc, values[i]);
+    }
+    Some(out)
+}
+
+/// Evaluates the Lagrange basis over `points` at `x`: `L_i(x) = ∏_{j≠i} (x - x_j) / (x_i - x_j)`.
+pub fn lagrange_basis(points: &[U256], x: U256) -> Option<Vec<U256>> {
+    let mut numerators = Vec::with_capacity(points.len());
+    let mut denominators = Vec::with_capacity(points.len());
+    for (i, xi) in points.iter().enumerate() {
+        let mut num = U256::from(1u8);
+        let mut den = U256::from(1u8);
+        for (j, xj) in points.iter().enumerate() {
+            if i != j {
+                num = mul(num, sub(x, *xj));
+                den = mul(den, sub(*xi, *xj));
+            }
+        }
+        numerators.push(num);
+        denominators.push(den);
+    }
+
+    let inverses = batch_inverse(&denominators)?;
+    Some(
+        numerators
+            .into_iter()
+            .zip(inverses)
+            .map(|(n, d)| mul(n, d))
+            .collect(),
+    )
+}
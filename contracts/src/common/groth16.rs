@@ -1,8 +1,7 @@
 use alloc::vec::Vec;
-use stylus_sdk::{
-    alloy_primitives::{uint, Address, U256},
-    call::RawCall,
-};
+use stylus_sdk::alloy_primitives::{uint, U256};
+#[cfg(not(test))]
+use stylus_sdk::{alloy_primitives::Address, call::RawCall};
 
 use super::types::{G1Point, G2Point, VMType, VerificationKey};
 
@@ -13,6 +12,77 @@ const EC_ADD_BYTES: [u8; 20] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 const EC_MUL_BYTES: [u8; 20] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7];
 const EC_PAIRING_BYTES: [u8; 20] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8];
 
+/// `p + q` through the `ecAdd` precompile.
+pub fn ec_add(p: &G1Point, q: &G1Point) -> Option<G1Point> {
+    ec_call(&EC_ADD_BYTES, &[p.x, p.y, q.x, q.y])
+}
+
+/// `s·p` through the `ecMul` precompile.
+pub fn ec_mul(p: &G1Point, s: U256) -> Option<G1Point> {
+    ec_call(&EC_MUL_BYTES, &[p.x, p.y, s])
+}
+
+/// `p - q`.
+pub fn ec_sub(p: &G1Point, q: &G1Point) -> Option<G1Point> {
+    ec_add(p, &negate_g1(q))
+}
+
+pub fn negate_g1(p: &G1Point) -> G1Point {
+    if p.x.is_zero() && p.y.is_zero() {
+        *p
+    } else {
+        G1Point {
+            x: p.x,
+            y: Q.wrapping_sub(p.y),
+        }
+    }
+}
+
+/// Checks `∏ e(g1s[i], g2s[i]) == 1` through the `ecPairing` precompile.
+pub fn pairing_check(g1s: &[G1Point], g2s: &[G2Point]) -> Option<bool> {
+    if g1s.len() != g2s.len() {
+        return None;
+    }
+
+    let mut calldata = Vec::with_capacity(g1s.len() * 192); // 6 * 32 bytes per pair
+
+    for (g1, g2) in g1s.iter().zip(g2s.iter()) {
+        calldata.extend_from_slice(&g1.x.to_be_bytes::<32>());
+        calldata.extend_from_slice(&g1.y.to_be_bytes::<32>());
+        calldata.extend_from_slice(&g2.x[0].to_be_bytes::<32>());
+        calldata.extend_from_slice(&g2.x[1].to_be_bytes::<32>());
+        calldata.extend_from_slice(&g2.y[0].to_be_bytes::<32>());
+        calldata.extend_from_slice(&g2.y[1].to_be_bytes::<32>());
+    }
+
+    precompile(&EC_PAIRING_BYTES, &calldata).map(|ret| !U256::from_be_slice(&ret[0..32]).is_zero())
+}
+
+fn ec_call(addr_bytes: &[u8; 20], params: &[U256]) -> Option<G1Point> {
+    let calldata: Vec<u8> = params.iter().flat_map(|x| x.to_be_bytes::<32>()).collect();
+
+    precompile(addr_bytes, &calldata).map(|ret| G1Point {
+        x: U256::from_be_slice(&ret[0..32]),
+        y: U256::from_be_slice(&ret[32..64]),
+    })
+}
+
+#[cfg(not(test))]
+fn precompile(addr_bytes: &[u8; 20], calldata: &[u8]) -> Option<Vec<u8>> {
+    unsafe {
+        RawCall::new_static()
+            .gas(u64::MAX)
+            .call(Address::from(*addr_bytes), calldata)
+    }
+    .ok()
+}
+
+/// Unit tests run without an EVM, so the precompiles are computed natively.
+#[cfg(test)]
+fn precompile(addr_bytes: &[u8; 20], calldata: &[u8]) -> Option<Vec<u8>> {
+    precompiles::call(addr_bytes[19], calldata)
+}
+
 pub struct Groth16Verifier;
 
 impl Groth16Verifier {
@@ -34,8 +104,8 @@ impl Groth16Verifier {
         }
 
         let vk_x = match self.compute_vk_x(vk, public_signals) {
-            Ok(x) => x,
-            Err(_) => return false,
+            Some(x) => x,
+            None => return false,
         };
 
         let proof_a = G1Point { x: a[0], y: a[1] };
@@ -48,39 +118,13 @@ impl Groth16Verifier {
         self.verify_pairing(vm_type, &proof_a, &proof_b, &proof_c, &vk_x, vk)
     }
 
-    fn compute_vk_x(&self, vk: &VerificationKey, signals: &[U256]) -> Result<G1Point, ()> {
+    fn compute_vk_x(&self, vk: &VerificationKey, signals: &[U256]) -> Option<G1Point> {
         let mut vk_x = vk.ic[0];
         for (sig, ic) in signals.iter().zip(&vk.ic[1..]) {
-            let mul_result = self.ec_call(&EC_MUL_BYTES, &[ic.x, ic.y, *sig])?;
-            vk_x = self.ec_call(&EC_ADD_BYTES, &[vk_x.x, vk_x.y, mul_result.x, mul_result.y])?;
-        }
-        Ok(vk_x)
-    }
-
-    fn ec_call(&self, addr_bytes: &[u8; 20], params: &[U256]) -> Result<G1Point, ()> {
-        let calldata: Vec<u8> = params.iter().flat_map(|x| x.to_be_bytes::<32>()).collect();
-
-        unsafe {
-            RawCall::new_static()
-                .gas(u64::MAX)
-                .call(Address::from(*addr_bytes), &calldata)
-        }
-        .map(|ret| G1Point {
-            x: U256::from_be_slice(&ret[0..32]),
-            y: U256::from_be_slice(&ret[32..64]),
-        })
-        .map_err(|_| ())
-    }
-
-    fn negate_g1(&self, p: &G1Point) -> G1Point {
-        if p.x.is_zero() && p.y.is_zero() {
-            *p
-        } else {
-            G1Point {
-                x: p.x,
-                y: Q.wrapping_sub(p.y),
-            }
+            let mul_result = ec_mul(ic, *sig)?;
+            vk_x = ec_add(&vk_x, &mul_result)?;
         }
+        Some(vk_x)
     }
 
     fn verify_pairing(
@@ -94,7 +138,7 @@ impl Groth16Verifier {
     ) -> bool {
         let (g1s, g2s) = match vm {
             VMType::Risc0 => (
-                [self.negate_g1(a), vk.alpha1, *l, *c],
+                [negate_g1(a), vk.alpha1, *l, *c],
                 [*b, vk.beta2, vk.gamma2, vk.delta2],
             ),
             VMType::Sp1 => (
@@ -103,33 +147,112 @@ impl Groth16Verifier {
             ),
         };
 
-        self.pairing_check(&g1s, &g2s).unwrap_or(false)
+        pairing_check(&g1s, &g2s).unwrap_or(false)
+    }
+}
+
+impl Default for Groth16Verifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod precompiles {
+    use alloc::{vec, vec::Vec};
+    use stylus_sdk::alloy_primitives::U256;
+    use substrate_bn::{pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+
+    use super::R;
+
+    /// Output of the precompile `id` on `input`, or `None` where it would revert.
+    pub fn call(id: u8, input: &[u8]) -> Option<Vec<u8>> {
+        match id {
+            6 => {
+                let input = padded(input, 128);
+                encode_g1(read_g1(&input[0..64])? + read_g1(&input[64..128])?)
+            }
+            7 => {
+                let input = padded(input, 96);
+                // The precompile takes any 256-bit scalar; `Fr` only accepts reduced ones.
+                let s = U256::from_be_slice(&input[64..96]) % R;
+                let s = Fr::from_slice(&s.to_be_bytes::<32>()).ok()?;
+                encode_g1(read_g1(&input[0..64])? * s)
+            }
+            8 => {
+                let chunks = input.chunks_exact(192);
+                if !chunks.remainder().is_empty() {
+                    return None;
+                }
+                let mut pairs = Vec::with_capacity(chunks.len());
+                for chunk in chunks {
+                    pairs.push((read_g1(&chunk[0..64])?, read_g2(&chunk[64..192])?));
+                }
+                let mut out = vec![0u8; 32];
+                out[31] = (pairing_batch(&pairs) == Gt::one()) as u8;
+                Some(out)
+            }
+            _ => None,
+        }
+    }
+
+    fn padded(input: &[u8], len: usize) -> Vec<u8> {
+        let mut input = input.to_vec();
+        input.resize(input.len().max(len), 0);
+        input
+    }
+
+    fn read_fq(bytes: &[u8]) -> Option<Fq> {
+        Fq::from_slice(bytes).ok()
     }
 
-    fn pairing_check(&self, g1s: &[G1Point; 4], g2s: &[G2Point; 4]) -> Result<bool, ()> {
-        let mut calldata = Vec::with_capacity(768); // 4 * 6 * 32 bytes
+    fn read_g1(bytes: &[u8]) -> Option<G1> {
+        let (x, y) = (read_fq(&bytes[0..32])?, read_fq(&bytes[32..64])?);
+        if x.is_zero() && y.is_zero() {
+            Some(G1::zero())
+        } else {
+            AffineG1::new(x, y).ok().map(Into::into)
+        }
+    }
 
-        for (g1, g2) in g1s.iter().zip(g2s.iter()) {
-            calldata.extend_from_slice(&g1.x.to_be_bytes::<32>());
-            calldata.extend_from_slice(&g1.y.to_be_bytes::<32>());
-            calldata.extend_from_slice(&g2.x[0].to_be_bytes::<32>());
-            calldata.extend_from_slice(&g2.x[1].to_be_bytes::<32>());
-            calldata.extend_from_slice(&g2.y[0].to_be_bytes::<32>());
-            calldata.extend_from_slice(&g2.y[1].to_be_bytes::<32>());
+    /// Reads a G2 point with its coordinates in the precompile's (imaginary, real) order.
+    fn read_g2(bytes: &[u8]) -> Option<G2> {
+        let x = Fq2::new(read_fq(&bytes[32..64])?, read_fq(&bytes[0..32])?);
+        let y = Fq2::new(read_fq(&bytes[96..128])?, read_fq(&bytes[64..96])?);
+        if x.is_zero() && y.is_zero() {
+            Some(G2::zero())
+        } else {
+            AffineG2::new(x, y).ok().map(Into::into)
         }
+    }
 
-        unsafe {
-            RawCall::new_static()
-                .gas(u64::MAX)
-                .call(Address::from(EC_PAIRING_BYTES), &calldata)
+    fn encode_g1(p: G1) -> Option<Vec<u8>> {
+        let mut out = vec![0u8; 64];
+        if let Some(p) = AffineG1::from_jacobian(p) {
+            p.x().to_big_endian(&mut out[0..32]).ok()?;
+            p.y().to_big_endian(&mut out[32..64]).ok()?;
         }
-        .map(|ret| !U256::from_be_slice(&ret[0..32]).is_zero())
-        .map_err(|_| ())
+        Some(out)
     }
 }
 
-impl Default for Groth16Verifier {
-    fn default() -> Self {
-        Self::new()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mul(p: &G1Point, s: U256) -> Option<(U256, U256)> {
+        ec_mul(p, s).map(|p| (p.x, p.y))
+    }
+
+    #[test]
+    fn ec_mul_takes_unreduced_scalars() {
+        let g = G1Point {
+            x: U256::from(1),
+            y: U256::from(2),
+        };
+        let s = U256::from(5);
+        assert_eq!(mul(&g, s + R), mul(&g, s));
+        assert_eq!(mul(&g, U256::MAX), mul(&g, U256::MAX % R));
+        assert_eq!(mul(&g, R), Some((U256::ZERO, U256::ZERO)));
     }
 }
@@ -0,0 +1,327 @@
+//! KZG polynomial commitment openings over BN254.
+//!
+//! Openings are checked against `[1]_2` and an imported `[x]_2` from the SRS the
+//! commitments were made with. A claim `p(z) = y` for commitment `C` with proof `W`
+//! holds iff `e(W, [x]_2) = e(C - y·[1]_1 + z·W, [1]_2)`.
+
+use alloc::vec::Vec;
+use stylus_sdk::{alloy_primitives::U256, crypto::keccak};
+
+use super::fr;
+use super::groth16::{ec_add, ec_mul, negate_g1, pairing_check, Q, R};
+use super::types::{G1Point, G2Point};
+
+/// Claim that the polynomial committed to in `commitment` evaluates to `evaluation` at
+/// `point`, with quotient commitment `proof`.
+#[derive(Clone, Copy)]
+pub struct KzgOpening {
+    pub commitment: G1Point,
+    pub point: U256,
+    pub evaluation: U256,
+    pub proof: G1Point,
+}
+
+pub struct KzgVerifier {
+    g2_x: G2Point,
+}
+
+impl KzgVerifier {
+    /// `g2_x` is `[x]_2` of the SRS, in `ecPairing` limb order.
+    pub fn new(g2_x: G2Point) -> Self {
+        Self { g2_x }
+    }
+
+    pub fn g2_x(&self) -> G2Point {
+        self.g2_x
+    }
+
+    /// Final check of an accumulated opening: `e(lhs, [x]_2) = e(rhs, [1]_2)`.
+    pub fn verify_accumulator(&self, lhs: &G1Point, rhs: &G1Point) -> bool {
+        pairing_check(&[*lhs, negate_g1(rhs)], &[self.g2_x, G2Point::GENERATOR]).unwrap_or(false)
+    }
+
+    /// Verifies a single opening.
+    pub fn verify_opening(&self, opening: &KzgOpening) -> bool {
+        if !Self::is_well_formed(opening) {
+            return false;
+        }
+        match Self::opening_rhs(opening) {
+            Some(rhs) => self.verify_accumulator(&opening.proof, &rhs),
+            None => false,
+        }
+    }
+
+    /// Verifies several polynomials opened at the same point with one proof, where the
+    /// prover batched them as `Σ γ^i p_i` with the protocol's challenge `gamma`.
+    pub fn verify_batch_opening(
+        &self,
+        commitments: &[G1Point],
+        point: U256,
+        evaluations: &[U256],
+        proof: &G1Point,
+        gamma: U256,
+    ) -> bool {
+        if commitments.is_empty() || commitments.len() != evaluations.len() || gamma >= R {
+            return false;
+        }
+
+        let mut power = U256::from(1u8);
+        let mut commitment = G1Point::ZERO;
+        let mut evaluation = U256::ZERO;
+        for (c, y) in commitments.iter().zip(evaluations) {
+            if *y >= R {
+                return false;
+            }
+            commitment = match ec_mul(c, power).and_then(|term| ec_add(&commitment, &term)) {
+                Some(sum) => sum,
+                None => return false,
+            };
+            evaluation = fr::add(evaluation, fr::mul(power, *y));
+            power = fr::mul(power, gamma);
+        }
+
+        self.verify_opening(&KzgOpening {
+            commitment,
+            point,
+            evaluation,
+            proof: *proof,
+        })
+    }
+
+    /// Verifies openings at arbitrary points, each with its own proof, using a single
+    /// pairing. The openings are combined with powers of a challenge derived from all of
+    /// them, so no randomness has to be supplied by the caller.
+    pub fn verify_multi_point(&self, openings: &[KzgOpening]) -> bool {
+        if openings.is_empty() || !openings.iter().all(Self::is_well_formed) {
+            return false;
+        }
+
+        let r = Self::batching_challenge(openings);
+        let mut power = U256::from(1u8);
+        let mut lhs = G1Point::ZERO;
+        let mut rhs = G1Point::ZERO;
+        for opening in openings {
+            let terms = Self::opening_rhs(opening).and_then(|opening_rhs| {
+                Some((
+                    ec_add(&lhs, &ec_mul(&opening.proof, power)?)?,
+                    ec_add(&rhs, &ec_mul(&opening_rhs, power)?)?,
+                ))
+            });
+            (lhs, rhs) = match terms {
+                Some(terms) => terms,
+                None => return false,
+            };
+            power = fr::mul(power, r);
+        }
+
+        self.verify_accumulator(&lhs, &rhs)
+    }
+
+    /// `C - y·[1]_1 + z·W`
+    fn opening_rhs(opening: &KzgOpening) -> Option<G1Point> {
+        let y = ec_mul(&G1Point::GENERATOR, opening.evaluation)?;
+        let zw = ec_mul(&opening.proof, opening.point)?;
+        ec_add(&ec_add(&opening.commitment, &negate_g1(&y))?, &zw)
+    }
+
+    fn is_well_formed(opening: &KzgOpening) -> bool {
+        opening.point < R
+            && opening.evaluation < R
+            && [opening.commitment, opening.proof]
+                .iter()
+                .all(|p| p.x < Q && p.y < Q)
+    }
+
+    fn batching_challenge(openings: &[KzgOpening]) -> U256 {
+        let mut buf = Vec::with_capacity(openings.len() * 192);
+        for opening in openings {
+            for word in [
+                opening.commitment.x,
+                opening.commitment.y,
+                opening.point,
+                opening.evaluation,
+                opening.proof.x,
+                opening.proof.y,
+            ] {
+                buf.extend_from_slice(&word.to_be_bytes::<32>());
+            }
+        }
+        fr::from_be_bytes_mod_order(keccak(&buf).as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use substrate_bn::{AffineG2, Fq, Fr, Group, G2};
+
+    /// SRS for a known `x`, large enough for the degree-3 polynomials below.
+    struct Srs {
+        g1: Vec<G1Point>,
+        g2_x: G2Point,
+    }
+
+    impl Srs {
+        fn new(x: U256) -> Self {
+            let g1 = (0..4)
+                .map(|i| ec_mul(&G1Point::GENERATOR, fr::pow(x, U256::from(i))).unwrap())
+                .collect();
+            let x = Fr::from_slice(&x.to_be_bytes::<32>()).unwrap();
+            let p = AffineG2::from_jacobian(G2::one() * x).unwrap();
+            let limb = |v: Fq| {
+                let mut bytes = [0u8; 32];
+                v.to_big_endian(&mut bytes).unwrap();
+                U256::from_be_bytes(bytes)
+            };
+            let g2_x = G2Point {
+                x: [limb(p.x().imaginary()), limb(p.x().real())],
+                y: [limb(p.y().imaginary()), limb(p.y().real())],
+            };
+            Self { g1, g2_x }
+        }
+
+        fn commit(&self, coeffs: &[U256]) -> G1Point {
+            coeffs
+                .iter()
+                .zip(&self.g1)
+                .fold(G1Point::ZERO, |acc, (c, g)| {
+                    ec_add(&acc, &ec_mul(g, *c).unwrap()).unwrap()
+                })
+        }
+
+        /// Opening of `coeffs` at `z`, with the quotient `(p(X) - p(z)) / (X - z)`
+        /// computed by synthetic division.
+        fn open(&self, coeffs: &[U256], z: U256) -> KzgOpening {
+            let mut quotient = vec![U256::ZERO; coeffs.len() - 1];
+            let mut acc = U256::ZERO;
+            for i in (0..coeffs.len()).rev() {
+                acc = fr::add(fr::mul(acc, z), coeffs[i]);
+                if i > 0 {
+                    quotient[i - 1] = acc;
+                }
+            }
+            KzgOpening {
+                commitment: self.commit(coeffs),
+                point: z,
+                evaluation: acc,
+                proof: self.commit(&quotient),
+            }
+        }
+    }
+
+    fn poly(coeffs: [u64; 4]) -> Vec<U256> {
+        coeffs.iter().map(|c| U256::from(*c)).collect()
+    }
+
+    fn srs() -> Srs {
+        Srs::new(U256::from(0x1234_5678_9abc_def0u64))
+    }
+
+    #[test]
+    fn srs_of_one_is_the_generator() {
+        let g2 = Srs::new(U256::from(1)).g2_x;
+        assert_eq!((g2.x, g2.y), (G2Point::GENERATOR.x, G2Point::GENERATOR.y));
+    }
+
+    #[test]
+    fn verifies_single_point_openings() {
+        let srs = srs();
+        let kzg = KzgVerifier::new(srs.g2_x);
+        let p = poly([7, 0, 3, 5]);
+        let opening = srs.open(&p, U256::from(10));
+        // 7 + 3·10² + 5·10³
+        assert_eq!(opening.evaluation, U256::from(5307));
+        assert!(kzg.verify_opening(&opening));
+
+        for wrong in [
+            KzgOpening {
+                evaluation: U256::from(5308),
+                ..opening
+            },
+            KzgOpening {
+                point: U256::from(11),
+                ..opening
+            },
+            KzgOpening {
+                commitment: srs.commit(&poly([8, 0, 3, 5])),
+                ..opening
+            },
+            KzgOpening {
+                proof: srs.open(&p, U256::from(11)).proof,
+                ..opening
+            },
+        ] {
+            assert!(!kzg.verify_opening(&wrong));
+        }
+
+        // Non-canonical scalars are rejected rather than reduced.
+        assert!(!kzg.verify_opening(&KzgOpening {
+            evaluation: opening.evaluation + R,
+            ..opening
+        }));
+        // An opening is only valid against the SRS it was made with.
+        assert!(!KzgVerifier::new(Srs::new(U256::from(2)).g2_x).verify_opening(&opening));
+    }
+
+    #[test]
+    fn verifies_batched_openings() {
+        let srs = srs();
+        let kzg = KzgVerifier::new(srs.g2_x);
+        let z = U256::from(3);
+        let gamma = U256::from(0xabcdu64);
+        let polys = [poly([1, 2, 3, 4]), poly([5, 6, 7, 8]), poly([9, 0, 0, 1])];
+
+        let commitments: Vec<_> = polys.iter().map(|p| srs.commit(p)).collect();
+        let evaluations: Vec<_> = polys.iter().map(|p| srs.open(p, z).evaluation).collect();
+        let mut batched = vec![U256::ZERO; 4];
+        let mut power = U256::from(1);
+        for p in &polys {
+            for (b, c) in batched.iter_mut().zip(p) {
+                *b = fr::add(*b, fr::mul(power, *c));
+            }
+            power = fr::mul(power, gamma);
+        }
+        let proof = srs.open(&batched, z).proof;
+
+        assert!(kzg.verify_batch_opening(&commitments, z, &evaluations, &proof, gamma));
+
+        let mut wrong = evaluations.clone();
+        wrong[2] = fr::add(wrong[2], U256::from(1));
+        assert!(!kzg.verify_batch_opening(&commitments, z, &wrong, &proof, gamma));
+        assert!(!kzg.verify_batch_opening(
+            &commitments,
+            z,
+            &evaluations,
+            &proof,
+            gamma + U256::from(1)
+        ));
+        assert!(!kzg.verify_batch_opening(&commitments[..2], z, &evaluations[..2], &proof, gamma));
+        assert!(!kzg.verify_batch_opening(&commitments, z, &evaluations[..2], &proof, gamma));
+        assert!(!kzg.verify_batch_opening(&[], z, &[], &proof, gamma));
+    }
+
+    #[test]
+    fn verifies_multi_point_openings() {
+        let srs = srs();
+        let kzg = KzgVerifier::new(srs.g2_x);
+        let openings = [
+            srs.open(&poly([1, 2, 3, 4]), U256::from(2)),
+            srs.open(&poly([5, 6, 7, 8]), U256::from(9)),
+            srs.open(&poly([9, 0, 0, 1]), R - U256::from(1)),
+        ];
+        assert!(openings.iter().all(|o| kzg.verify_opening(o)));
+        assert!(kzg.verify_multi_point(&openings));
+
+        for i in 0..openings.len() {
+            let mut wrong = openings;
+            wrong[i].evaluation = fr::add(wrong[i].evaluation, U256::from(1));
+            assert!(!kzg.verify_multi_point(&wrong));
+        }
+        // Swapping proofs between openings breaks both.
+        let mut swapped = openings;
+        (swapped[0].proof, swapped[1].proof) = (openings[1].proof, openings[0].proof);
+        assert!(!kzg.verify_multi_point(&swapped));
+        assert!(!kzg.verify_multi_point(&[]));
+    }
+}
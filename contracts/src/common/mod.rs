@@ -1,7 +1,14 @@
 pub mod errors;
+pub mod fr;
 pub mod groth16;
+pub mod kzg;
+pub mod ownable;
+pub mod resumable;
+pub mod sha256;
+pub mod stark;
 pub mod types;
 
 pub use errors::*;
-pub use groth16::*; 
-pub use types::*;
\ No newline at end of file
+pub use groth16::*;
+pub use kzg::*;
+pub use types::*;
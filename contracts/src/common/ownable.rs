@@ -0,0 +1,227 @@
+//! Single-owner access control with two-step transfer, after OpenZeppelin's
+//! `Ownable2Step`.
+//!
+//! The owner is set once, from the deploying contract's constructor, so that no one can
+//! claim an undeployed-then-uninitialized contract. A transfer only takes effect once the
+//! new owner accepts it.
+
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::Address,
+    alloy_sol_types::{sol, SolError},
+    prelude::*,
+    stylus_core::log,
+};
+
+sol! {
+    event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+    error OwnableUnauthorizedAccount(address account);
+    error OwnableInvalidOwner(address owner);
+}
+
+#[derive(Debug)]
+pub enum OwnableError {
+    UnauthorizedAccount { account: Address },
+    InvalidOwner { owner: Address },
+}
+
+impl OwnableError {
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self {
+            OwnableError::UnauthorizedAccount { account } => {
+                OwnableUnauthorizedAccount { account: *account }.abi_encode()
+            }
+            OwnableError::InvalidOwner { owner } => {
+                OwnableInvalidOwner { owner: *owner }.abi_encode()
+            }
+        }
+    }
+}
+
+pub trait IOwnable {
+    type Error;
+
+    fn owner(&self) -> Address;
+    fn pending_owner(&self) -> Address;
+
+    /// Starts a transfer to `new_owner`, replacing any pending one. The zero address
+    /// cancels the pending transfer.
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error>;
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error>;
+}
+
+sol_storage! {
+    pub struct Ownable {
+        address owner;
+        address pending_owner;
+    }
+}
+
+impl Ownable {
+    /// Sets the first owner; meant for the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), OwnableError> {
+        if owner == Address::ZERO || self.owner.get() != Address::ZERO {
+            return Err(OwnableError::InvalidOwner { owner });
+        }
+        self.set_owner(owner);
+        Ok(())
+    }
+
+    pub fn only_owner(&self) -> Result<(), OwnableError> {
+        let account = self.vm().msg_sender();
+        if account != self.owner.get() {
+            return Err(OwnableError::UnauthorizedAccount { account });
+        }
+        Ok(())
+    }
+
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    pub fn pending_owner(&self) -> Address {
+        self.pending_owner.get()
+    }
+
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), OwnableError> {
+        self.only_owner()?;
+        self.pending_owner.set(new_owner);
+        log(
+            self.vm(),
+            OwnershipTransferStarted {
+                previousOwner: self.owner.get(),
+                newOwner: new_owner,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn accept_ownership(&mut self) -> Result<(), OwnableError> {
+        let account = self.vm().msg_sender();
+        if account != self.pending_owner.get() || account == Address::ZERO {
+            return Err(OwnableError::UnauthorizedAccount { account });
+        }
+        self.set_owner(account);
+        Ok(())
+    }
+
+    fn set_owner(&mut self, owner: Address) {
+        let previous_owner = self.owner.get();
+        self.owner.set(owner);
+        self.pending_owner.set(Address::ZERO);
+        log(
+            self.vm(),
+            OwnershipTransferred {
+                previousOwner: previous_owner,
+                newOwner: owner,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::{alloy_sol_types::SolEvent, testing::*};
+
+    const OWNER: Address = Address::repeat_byte(1);
+    const NEW_OWNER: Address = Address::repeat_byte(2);
+    const OTHER: Address = Address::repeat_byte(3);
+
+    fn deploy(vm: &TestVM) -> Ownable {
+        let mut ownable = Ownable::from(vm);
+        ownable.set_initial_owner(OWNER).unwrap();
+        ownable
+    }
+
+    #[test]
+    fn initial_owner_is_set_once() {
+        let vm = TestVM::default();
+        let mut ownable = Ownable::from(&vm);
+        assert!(matches!(
+            ownable.set_initial_owner(Address::ZERO),
+            Err(OwnableError::InvalidOwner { .. })
+        ));
+        ownable.set_initial_owner(OWNER).unwrap();
+        assert_eq!(ownable.owner(), OWNER);
+        assert!(matches!(
+            ownable.set_initial_owner(OTHER),
+            Err(OwnableError::InvalidOwner { owner }) if owner == OTHER
+        ));
+        assert_eq!(ownable.owner(), OWNER);
+    }
+
+    #[test]
+    fn only_owner_checks_the_sender() {
+        let vm = TestVM::default();
+        let ownable = deploy(&vm);
+
+        vm.set_sender(OWNER);
+        assert!(ownable.only_owner().is_ok());
+        vm.set_sender(OTHER);
+        assert!(matches!(
+            ownable.only_owner(),
+            Err(OwnableError::UnauthorizedAccount { account }) if account == OTHER
+        ));
+    }
+
+    #[test]
+    fn transfer_takes_effect_once_accepted() {
+        let vm = TestVM::default();
+        let mut ownable = deploy(&vm);
+
+        vm.set_sender(OTHER);
+        assert!(ownable.transfer_ownership(OTHER).is_err());
+
+        vm.set_sender(OWNER);
+        ownable.transfer_ownership(NEW_OWNER).unwrap();
+        assert_eq!(ownable.owner(), OWNER);
+        assert_eq!(ownable.pending_owner(), NEW_OWNER);
+
+        vm.set_sender(OTHER);
+        assert!(matches!(
+            ownable.accept_ownership(),
+            Err(OwnableError::UnauthorizedAccount { account }) if account == OTHER
+        ));
+
+        vm.set_sender(NEW_OWNER);
+        ownable.accept_ownership().unwrap();
+        assert_eq!(ownable.owner(), NEW_OWNER);
+        assert_eq!(ownable.pending_owner(), Address::ZERO);
+
+        // The previous owner has lost its rights, and the acceptance cannot be replayed.
+        vm.set_sender(OWNER);
+        assert!(ownable.only_owner().is_err());
+        assert!(ownable.accept_ownership().is_err());
+
+        let logs = vm.get_emitted_logs();
+        let started = OwnershipTransferStarted::decode_raw_log(&logs[1].0, &logs[1].1, true);
+        assert_eq!(started.unwrap().newOwner, NEW_OWNER);
+        let transferred =
+            OwnershipTransferred::decode_raw_log(&logs[2].0, &logs[2].1, true).unwrap();
+        assert_eq!(
+            (transferred.previousOwner, transferred.newOwner),
+            (OWNER, NEW_OWNER)
+        );
+    }
+
+    #[test]
+    fn zero_address_cancels_a_pending_transfer() {
+        let vm = TestVM::default();
+        let mut ownable = deploy(&vm);
+
+        vm.set_sender(OWNER);
+        ownable.transfer_ownership(NEW_OWNER).unwrap();
+        ownable.transfer_ownership(Address::ZERO).unwrap();
+        assert_eq!(ownable.pending_owner(), Address::ZERO);
+
+        vm.set_sender(NEW_OWNER);
+        assert!(ownable.accept_ownership().is_err());
+        vm.set_sender(Address::ZERO);
+        assert!(ownable.accept_ownership().is_err());
+        assert_eq!(ownable.owner(), OWNER);
+    }
+}
@@ -0,0 +1,444 @@
+//! Verification spread over several transactions, for proofs whose calldata or gas does
+//! not fit in one call.
+//!
+//! The prover opens a session for a claim, uploads the proof in chunks, then calls
+//! `advance` until the verifier reports the proof done. Between calls the verifier's
+//! intermediate state (transcript, partial checks) is kept in the session as opaque bytes.
+//! The call that completes verification marks the claim verified and clears the session.
+//! Sessions expire `SESSION_TTL` seconds after opening, after which anyone may clear them.
+
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{Address, B256, U256, U64, U8},
+    alloy_sol_types::{sol, SolError},
+    crypto::keccak,
+    prelude::*,
+};
+
+use super::errors::VerificationError;
+
+/// Lifetime of a session, in seconds.
+pub const SESSION_TTL: u64 = 24 * 60 * 60;
+
+/// Upper bound on the chunks of one session, so that loading a proof stays bounded.
+pub const MAX_CHUNKS: usize = 64;
+
+const STATUS_NONE: u8 = 0;
+const STATUS_UPLOADING: u8 = 1;
+const STATUS_VERIFYING: u8 = 2;
+
+sol! {
+    error SessionNotFound();
+    error SessionExpired();
+    error SessionNotExpired();
+    error NotSessionOwner();
+    error SessionAlreadyVerifying();
+    error TooManyChunks();
+}
+
+#[derive(Debug)]
+pub enum ResumableError {
+    Common(VerificationError),
+    SessionNotFound,
+    SessionExpired,
+    SessionNotExpired,
+    NotSessionOwner,
+    SessionAlreadyVerifying,
+    TooManyChunks,
+}
+
+impl ResumableError {
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self {
+            ResumableError::Common(e) => e.abi_encode(),
+            ResumableError::SessionNotFound => SessionNotFound {}.abi_encode(),
+            ResumableError::SessionExpired => SessionExpired {}.abi_encode(),
+            ResumableError::SessionNotExpired => SessionNotExpired {}.abi_encode(),
+            ResumableError::NotSessionOwner => NotSessionOwner {}.abi_encode(),
+            ResumableError::SessionAlreadyVerifying => SessionAlreadyVerifying {}.abi_encode(),
+            ResumableError::TooManyChunks => TooManyChunks {}.abi_encode(),
+        }
+    }
+}
+
+impl From<VerificationError> for ResumableError {
+    fn from(error: VerificationError) -> Self {
+        ResumableError::Common(error)
+    }
+}
+
+/// Outcome of one verification step.
+pub enum Step {
+    /// More work remains; the state to resume from.
+    Continue(Vec<u8>),
+    Done,
+}
+
+/// A verifier whose work is split into steps that each fit in one call.
+pub trait StepVerifier {
+    /// Advances the verification of `proof` for `claim` from `state`, which is empty on the
+    /// first step. Errors reject the proof.
+    fn step(&self, claim: B256, proof: &[u8], state: &[u8]) -> Result<Step, VerificationError>;
+}
+
+/// A verifier of whole proofs takes a single step, for proofs whose calldata rather than
+/// gas exceeds one call.
+impl<F: Fn(B256, &[u8]) -> Result<(), VerificationError>> StepVerifier for F {
+    fn step(&self, claim: B256, proof: &[u8], _state: &[u8]) -> Result<Step, VerificationError> {
+        self(claim, proof)?;
+        Ok(Step::Done)
+    }
+}
+
+pub trait IResumableVerifier {
+    type Error;
+
+    /// Opens a session for `claim` and returns its id.
+    fn open_session(&mut self, claim: B256) -> Result<B256, Self::Error>;
+
+    fn upload_chunk(&mut self, session_id: B256, chunk: Vec<u8>) -> Result<(), Self::Error>;
+
+    /// Runs one verification step; returns whether the claim is now verified.
+    fn advance(&mut self, session_id: B256) -> Result<bool, Self::Error>;
+
+    fn close_session(&mut self, session_id: B256) -> Result<(), Self::Error>;
+
+    fn cleanup_session(&mut self, session_id: B256) -> Result<(), Self::Error>;
+
+    fn is_claim_verified(&self, claim: B256) -> bool;
+}
+
+sol_storage! {
+    pub struct Session {
+        address owner;
+        bytes32 claim;
+        uint64 expires_at;
+        uint8 status;
+        bytes[] chunks;
+        bytes state;
+    }
+
+    pub struct ResumableVerification {
+        mapping(bytes32 => Session) sessions;
+        mapping(bytes32 => bool) verified;
+        uint256 nonce;
+    }
+}
+
+impl Session {
+    fn clear(&mut self) {
+        self.owner.set(Address::ZERO);
+        self.claim.set(B256::ZERO);
+        self.expires_at.set(U64::ZERO);
+        self.status.set(U8::from(STATUS_NONE));
+        self.chunks.erase();
+        self.state.erase();
+    }
+
+    fn proof(&self) -> Vec<u8> {
+        let mut proof = Vec::new();
+        for i in 0..self.chunks.len() {
+            if let Some(chunk) = self.chunks.getter(i) {
+                proof.extend(chunk.get_bytes());
+            }
+        }
+        proof
+    }
+}
+
+impl ResumableVerification {
+    /// Opens a session owned by `owner`; its id is `keccak(owner || claim || nonce)`.
+    pub fn open(&mut self, owner: Address, claim: B256, now: u64) -> B256 {
+        let nonce = self.nonce.get();
+        self.nonce.set(nonce + U256::from(1));
+        let id = keccak(
+            [
+                owner.as_slice(),
+                claim.as_slice(),
+                &nonce.to_be_bytes::<32>(),
+            ]
+            .concat(),
+        );
+        let mut session = self.sessions.setter(id);
+        session.owner.set(owner);
+        session.claim.set(claim);
+        session
+            .expires_at
+            .set(U64::from(now.saturating_add(SESSION_TTL)));
+        session.status.set(U8::from(STATUS_UPLOADING));
+        id
+    }
+
+    /// Checks that the session exists, is owned by `owner` and has not expired.
+    fn check_live(&self, id: B256, owner: Address, now: u64) -> Result<(), ResumableError> {
+        let session = self.sessions.getter(id);
+        if session.status.get() == U8::from(STATUS_NONE) {
+            return Err(ResumableError::SessionNotFound);
+        }
+        if session.owner.get() != owner {
+            return Err(ResumableError::NotSessionOwner);
+        }
+        if now >= session.expires_at.get().to::<u64>() {
+            return Err(ResumableError::SessionExpired);
+        }
+        Ok(())
+    }
+
+    pub fn upload(
+        &mut self,
+        id: B256,
+        owner: Address,
+        chunk: &[u8],
+        now: u64,
+    ) -> Result<(), ResumableError> {
+        self.check_live(id, owner, now)?;
+        let mut session = self.sessions.setter(id);
+        if session.status.get() != U8::from(STATUS_UPLOADING) {
+            return Err(ResumableError::SessionAlreadyVerifying);
+        }
+        if session.chunks.len() >= MAX_CHUNKS {
+            return Err(ResumableError::TooManyChunks);
+        }
+        session.chunks.grow().set_bytes(chunk);
+        Ok(())
+    }
+
+    /// Runs one step of `verifier`; uploads are closed from the first step on. Returns
+    /// `true` once the claim is verified, at which point the session is cleared.
+    pub fn advance<V: StepVerifier>(
+        &mut self,
+        id: B256,
+        owner: Address,
+        verifier: &V,
+        now: u64,
+    ) -> Result<bool, ResumableError> {
+        self.check_live(id, owner, now)?;
+        let mut session = self.sessions.setter(id);
+        let claim = session.claim.get();
+        let proof = session.proof();
+        let state = session.state.get_bytes();
+        session.status.set(U8::from(STATUS_VERIFYING));
+
+        match verifier.step(claim, &proof, &state)? {
+            Step::Continue(state) => {
+                session.state.set_bytes(state);
+                Ok(false)
+            }
+            Step::Done => {
+                session.clear();
+                self.verified.insert(claim, true);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Abandons a live session.
+    pub fn close(&mut self, id: B256, owner: Address, now: u64) -> Result<(), ResumableError> {
+        self.check_live(id, owner, now)?;
+        self.sessions.setter(id).clear();
+        Ok(())
+    }
+
+    /// Clears an expired session; callable by anyone.
+    pub fn cleanup(&mut self, id: B256, now: u64) -> Result<(), ResumableError> {
+        let mut session = self.sessions.setter(id);
+        if session.status.get() == U8::from(STATUS_NONE) {
+            return Err(ResumableError::SessionNotFound);
+        }
+        if now < session.expires_at.get().to::<u64>() {
+            return Err(ResumableError::SessionNotExpired);
+        }
+        session.clear();
+        Ok(())
+    }
+
+    pub fn is_verified(&self, claim: B256) -> bool {
+        self.verified.get(claim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::testing::*;
+
+    const OWNER: Address = Address::repeat_byte(1);
+    const OTHER: Address = Address::repeat_byte(2);
+    const CLAIM: B256 = B256::repeat_byte(0xcc);
+    const NOW: u64 = 1_000;
+
+    /// Takes `steps` steps over the proof `"proof"`, counting them in the state.
+    struct Counter {
+        steps: u8,
+    }
+
+    impl StepVerifier for Counter {
+        fn step(&self, claim: B256, proof: &[u8], state: &[u8]) -> Result<Step, VerificationError> {
+            if claim != CLAIM || proof != b"proof" {
+                return Err(VerificationError::VerificationFailed);
+            }
+            let done = state.first().copied().unwrap_or(0) + 1;
+            if done == self.steps {
+                return Ok(Step::Done);
+            }
+            Ok(Step::Continue(vec![done]))
+        }
+    }
+
+    fn encoded<T>(result: Result<T, ResumableError>) -> Result<T, Vec<u8>> {
+        result.map_err(|e| e.abi_encode())
+    }
+
+    fn error<T: core::fmt::Debug>(result: Result<T, ResumableError>) -> Vec<u8> {
+        result.unwrap_err().abi_encode()
+    }
+
+    fn uploaded(sessions: &mut ResumableVerification) -> B256 {
+        let id = sessions.open(OWNER, CLAIM, NOW);
+        sessions.upload(id, OWNER, b"pro", NOW).unwrap();
+        sessions.upload(id, OWNER, b"of", NOW).unwrap();
+        id
+    }
+
+    #[test]
+    fn advances_until_the_verifier_is_done() {
+        let vm = TestVM::default();
+        let mut sessions = ResumableVerification::from(&vm);
+        let id = uploaded(&mut sessions);
+        let verifier = Counter { steps: 3 };
+
+        for step in 1..3 {
+            assert_eq!(
+                encoded(sessions.advance(id, OWNER, &verifier, NOW)),
+                Ok(false)
+            );
+            assert_eq!(sessions.sessions.getter(id).state.get_bytes(), [step]);
+            assert!(!sessions.is_verified(CLAIM));
+        }
+        assert_eq!(
+            encoded(sessions.advance(id, OWNER, &verifier, NOW)),
+            Ok(true)
+        );
+        assert!(sessions.is_verified(CLAIM));
+
+        // Completing clears the session.
+        let session = sessions.sessions.getter(id);
+        assert_eq!(session.owner.get(), Address::ZERO);
+        assert_eq!(session.chunks.len(), 0);
+        assert!(session.state.get_bytes().is_empty());
+        assert_eq!(
+            encoded(sessions.advance(id, OWNER, &verifier, NOW)),
+            Err(ResumableError::SessionNotFound.abi_encode())
+        );
+    }
+
+    #[test]
+    fn rejected_steps_leave_the_session_unverified() {
+        let vm = TestVM::default();
+        let mut sessions = ResumableVerification::from(&vm);
+        let id = sessions.open(OWNER, CLAIM, NOW);
+        sessions.upload(id, OWNER, b"forged", NOW).unwrap();
+
+        assert_eq!(
+            encoded(sessions.advance(id, OWNER, &Counter { steps: 1 }, NOW)),
+            Err(VerificationError::VerificationFailed.abi_encode())
+        );
+        assert!(!sessions.is_verified(CLAIM));
+        sessions.close(id, OWNER, NOW).unwrap();
+    }
+
+    #[test]
+    fn only_the_session_owner_drives_it() {
+        let vm = TestVM::default();
+        let mut sessions = ResumableVerification::from(&vm);
+        let id = uploaded(&mut sessions);
+        let verifier = Counter { steps: 2 };
+        let not_owner = ResumableError::NotSessionOwner.abi_encode();
+
+        assert_eq!(error(sessions.upload(id, OTHER, b"x", NOW)), not_owner);
+        assert_eq!(
+            error(sessions.advance(id, OTHER, &verifier, NOW)),
+            not_owner
+        );
+        assert_eq!(error(sessions.close(id, OTHER, NOW)), not_owner);
+
+        assert_eq!(
+            encoded(sessions.advance(id, OWNER, &verifier, NOW)),
+            Ok(false)
+        );
+        assert_eq!(
+            encoded(sessions.upload(id, OWNER, b"x", NOW)),
+            Err(ResumableError::SessionAlreadyVerifying.abi_encode())
+        );
+        sessions.close(id, OWNER, NOW).unwrap();
+        assert_eq!(
+            encoded(sessions.close(id, OWNER, NOW)),
+            Err(ResumableError::SessionNotFound.abi_encode())
+        );
+    }
+
+    #[test]
+    fn sessions_have_distinct_ids() {
+        let vm = TestVM::default();
+        let mut sessions = ResumableVerification::from(&vm);
+        let first = sessions.open(OWNER, CLAIM, NOW);
+        let second = sessions.open(OWNER, CLAIM, NOW);
+        assert_ne!(first, second);
+        assert_ne!(sessions.open(OTHER, CLAIM, NOW), second);
+
+        sessions.upload(first, OWNER, b"proof", NOW).unwrap();
+        assert_eq!(sessions.sessions.getter(second).chunks.len(), 0);
+    }
+
+    #[test]
+    fn bounds_the_chunks() {
+        let vm = TestVM::default();
+        let mut sessions = ResumableVerification::from(&vm);
+        let id = sessions.open(OWNER, CLAIM, NOW);
+        for _ in 0..MAX_CHUNKS {
+            sessions.upload(id, OWNER, b"x", NOW).unwrap();
+        }
+        assert_eq!(
+            encoded(sessions.upload(id, OWNER, b"x", NOW)),
+            Err(ResumableError::TooManyChunks.abi_encode())
+        );
+    }
+
+    #[test]
+    fn expired_sessions_can_only_be_cleaned_up() {
+        let vm = TestVM::default();
+        let mut sessions = ResumableVerification::from(&vm);
+        let id = uploaded(&mut sessions);
+        let verifier = Counter { steps: 2 };
+        let expiry = NOW + SESSION_TTL;
+
+        assert_eq!(
+            encoded(sessions.cleanup(id, expiry - 1)),
+            Err(ResumableError::SessionNotExpired.abi_encode())
+        );
+        assert_eq!(
+            encoded(sessions.advance(id, OWNER, &verifier, expiry - 1)),
+            Ok(false)
+        );
+
+        let expired = ResumableError::SessionExpired.abi_encode();
+        assert_eq!(
+            error(sessions.advance(id, OWNER, &verifier, expiry)),
+            expired
+        );
+        assert_eq!(error(sessions.upload(id, OWNER, b"x", expiry)), expired);
+        assert_eq!(error(sessions.close(id, OWNER, expiry)), expired);
+        assert!(!sessions.is_verified(CLAIM));
+
+        sessions.cleanup(id, expiry).unwrap();
+        assert_eq!(sessions.sessions.getter(id).chunks.len(), 0);
+        assert_eq!(
+            encoded(sessions.cleanup(id, expiry)),
+            Err(ResumableError::SessionNotFound.abi_encode())
+        );
+        assert_eq!(
+            encoded(sessions.advance(id, OWNER, &verifier, expiry)),
+            Err(ResumableError::SessionNotFound.abi_encode())
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! BabyBear `p = 15·2^27 + 1` and its Plonky3 quartic extension `F_p[X]/(X^4 - 11)`.
+
+use crate::common::stark::{
+    extension::{BinomialExtension, BinomialParams},
+    monty31::{Monty31, MontyParams},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BabyBearParams;
+
+impl MontyParams for BabyBearParams {
+    const P: u32 = 15 * (1 << 27) + 1;
+    const P_INV: u32 = 0x88000001;
+    const R2: u32 = 1172168163;
+    const TWO_ADICITY: usize = 27;
+    const GENERATOR: u32 = 31;
+    const TWO_ADIC_GENERATOR: u32 = 0x1a427a41;
+}
+
+pub type BabyBear = Monty31<BabyBearParams>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BabyBearExt4Params;
+
+impl BinomialParams<4> for BabyBearExt4Params {
+    type Base = BabyBear;
+    const W: BabyBear = BabyBear::new(11);
+    const FROBENIUS: BabyBear = BabyBear::new(1728404513);
+}
+
+pub type BabyBearExt4 = BinomialExtension<BabyBearExt4Params, 4>;
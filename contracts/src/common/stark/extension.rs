@@ -0,0 +1,163 @@
+//! Binomial extensions `F[X]/(X^D - W)`, inverted through the Frobenius norm so that a
+//! single base-field inversion is needed.
+
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::common::stark::field::Field;
+
+pub trait BinomialParams<const D: usize>: Copy + Debug + Default + Eq + 'static {
+    type Base: Field;
+    /// Non-residue with `X^D = W`.
+    const W: Self::Base;
+    /// `W^((q - 1) / D)`, so that the Frobenius map sends `X` to `FROBENIUS · X`.
+    const FROBENIUS: Self::Base;
+}
+
+/// A field containing `F` as a subfield.
+pub trait ExtensionField<F: Field>: Field {
+    const DEGREE: usize;
+
+    fn from_base(x: F) -> Self;
+
+    fn mul_base(self, x: F) -> Self;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BinomialExtension<P: BinomialParams<D>, const D: usize>(pub [P::Base; D]);
+
+impl<P: BinomialParams<D>, const D: usize> BinomialExtension<P, D> {
+    pub const ZERO: Self = Self([P::Base::ZERO; D]);
+    pub const ONE: Self = Self::monomial(0);
+
+    pub const fn new(coeffs: [P::Base; D]) -> Self {
+        Self(coeffs)
+    }
+
+    pub const fn from_base(x: P::Base) -> Self {
+        let mut out = [P::Base::ZERO; D];
+        out[0] = x;
+        Self(out)
+    }
+
+    /// The basis element `X^i`.
+    pub const fn monomial(i: usize) -> Self {
+        let mut out = [P::Base::ZERO; D];
+        out[i] = P::Base::ONE;
+        Self(out)
+    }
+
+    /// Applies the Frobenius map `k` times.
+    fn frobenius(self, k: usize) -> Self {
+        let gamma = P::FROBENIUS.pow(k as u64);
+        let mut factor = P::Base::ONE;
+        let mut out = self.0;
+        for x in out.iter_mut() {
+            *x *= factor;
+            factor *= gamma;
+        }
+        Self(out)
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> Default for BinomialExtension<P, D> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> Field for BinomialExtension<P, D> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn inv(self) -> Self {
+        // The product of the conjugates times `self` is the norm, which lies in the base.
+        let conjugates = (1..D).fold(Self::ONE, |acc, k| acc * self.frobenius(k));
+        let norm = (self * conjugates).0[0];
+        conjugates.mul_base(norm.inv())
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> ExtensionField<P::Base> for BinomialExtension<P, D> {
+    const DEGREE: usize = D;
+
+    fn from_base(x: P::Base) -> Self {
+        Self::from_base(x)
+    }
+
+    fn mul_base(self, x: P::Base) -> Self {
+        Self(self.0.map(|y| y * x))
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> Add for BinomialExtension<P, D> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut out = self;
+        for (x, y) in out.0.iter_mut().zip(rhs.0) {
+            *x += y;
+        }
+        out
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> Sub for BinomialExtension<P, D> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let mut out = self;
+        for (x, y) in out.0.iter_mut().zip(rhs.0) {
+            *x -= y;
+        }
+        out
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> Mul for BinomialExtension<P, D> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut low = [P::Base::ZERO; D];
+        let mut high = [P::Base::ZERO; D];
+        for (i, a) in self.0.iter().enumerate() {
+            for (j, b) in rhs.0.iter().enumerate() {
+                if i + j < D {
+                    low[i + j] += *a * *b;
+                } else {
+                    high[i + j - D] += *a * *b;
+                }
+            }
+        }
+        for (x, y) in low.iter_mut().zip(high) {
+            *x += P::W * y;
+        }
+        Self(low)
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> Neg for BinomialExtension<P, D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|x| -x))
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> AddAssign for BinomialExtension<P, D> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> SubAssign for BinomialExtension<P, D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<P: BinomialParams<D>, const D: usize> MulAssign for BinomialExtension<P, D> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
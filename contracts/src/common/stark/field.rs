@@ -0,0 +1,87 @@
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub trait Field:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Multiplicative inverse, with `0⁻¹ = 0`.
+    fn inv(self) -> Self;
+
+    fn square(self) -> Self {
+        self * self
+    }
+
+    fn double(self) -> Self {
+        self + self
+    }
+
+    fn pow(self, mut n: u64) -> Self {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while n > 0 {
+            if n & 1 == 1 {
+                acc *= base;
+            }
+            base = base.square();
+            n >>= 1;
+        }
+        acc
+    }
+}
+
+/// A prime field whose elements fit in a `u64`.
+pub trait PrimeField: Field {
+    const ORDER: u64;
+
+    /// Reads a canonical value, rejecting values not below `ORDER`.
+    fn from_canonical_u64(x: u64) -> Option<Self>;
+
+    fn as_canonical_u64(&self) -> u64;
+
+    /// Reduces an arbitrary `u64`.
+    fn from_u64(x: u64) -> Self;
+}
+
+/// A field with a multiplicative subgroup of order `2^TWO_ADICITY`.
+pub trait TwoAdicField: Field {
+    const TWO_ADICITY: usize;
+    /// Generator of the full multiplicative group, used as the default coset shift.
+    const GENERATOR: Self;
+
+    /// Generator of the subgroup of order `2^bits`, as chosen by Plonky3.
+    fn two_adic_generator(bits: usize) -> Self;
+}
+
+/// Multiplies every element by its batch-mates' product so that `n` inversions cost one
+/// inversion and `3(n - 1)` multiplications. Zeros are left as zero.
+pub fn batch_inverse<F: Field>(values: &mut [F]) {
+    let mut acc = F::ONE;
+    let mut prefix = alloc::vec::Vec::with_capacity(values.len());
+    for value in values.iter() {
+        prefix.push(acc);
+        if *value != F::ZERO {
+            acc *= *value;
+        }
+    }
+    let mut inv = acc.inv();
+    for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+        if *value != F::ZERO {
+            let next = inv * *value;
+            *value = inv * prefix;
+            inv = next;
+        }
+    }
+}
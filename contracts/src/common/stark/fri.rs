@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+
+use crate::common::stark::{extension::ExtensionField, field::TwoAdicField, poly};
+
+/// How evaluations of one folding coset are laid out in a committed codeword of size `n`
+/// folded by `k`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CosetOrder {
+    /// Natural order: coset `c` holds positions `c + j · n/k` (RISC Zero).
+    Strided,
+    /// Bit-reversed order: coset `c` holds positions `c · k + j` (Plonky3).
+    BitReversed,
+}
+
+/// Folds one FRI query through the commit-phase rounds.
+///
+/// Round `r` folds a codeword of size `2^(log_height - r · log_arity)` by `2^log_arity`
+/// with challenge `betas[r]`. `root(bits)` returns the generator of order `2^bits` the
+/// codewords are evaluated over. `open(round, coset, position, value)` must read the
+/// coset's evaluations, check them against the round commitment and check that the one
+/// at `position` matches `value`, then return them in natural coset order.
+///
+/// Returns the final index and folded value, to be checked against the final polynomial.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_query<F, E, R, O>(
+    order: CosetOrder,
+    log_arity: usize,
+    mut index: usize,
+    mut log_height: usize,
+    betas: &[E],
+    mut value: E,
+    root: R,
+    mut open: O,
+) -> Option<(usize, E)>
+where
+    F: TwoAdicField,
+    E: ExtensionField<F>,
+    R: Fn(usize) -> F,
+    O: FnMut(usize, usize, usize, E) -> Option<Vec<E>>,
+{
+    let arity = 1 << log_arity;
+    let omega = root(log_arity);
+    for (round, beta) in betas.iter().enumerate() {
+        if log_height < log_arity || index >> log_height != 0 {
+            return None;
+        }
+        let log_folded = log_height - log_arity;
+        // The coset is `shift · <omega>`; `position` is the index of `index` within it.
+        let (coset, position, exponent) = match order {
+            CosetOrder::Strided => {
+                let coset = index & ((1 << log_folded) - 1);
+                (coset, index >> log_folded, coset)
+            }
+            CosetOrder::BitReversed => {
+                let coset = index >> log_arity;
+                let position = reverse_bits(index & (arity - 1), log_arity);
+                (coset, position, reverse_bits(coset, log_folded))
+            }
+        };
+        let shift = root(log_height).pow(exponent as u64);
+        let evals = open(round, coset, position, value)?;
+        if evals.len() != arity {
+            return None;
+        }
+        value = poly::interpolate_coset(&evals, shift, omega, *beta);
+        index = coset;
+        log_height = log_folded;
+    }
+    Some((index, value))
+}
+
+/// Reverses the low `bits` bits of `x`.
+pub fn reverse_bits(x: usize, bits: usize) -> usize {
+    if bits == 0 {
+        return 0;
+    }
+    (x as u32).reverse_bits() as usize >> (32 - bits)
+}
@@ -0,0 +1,242 @@
+//! Goldilocks `p = 2^64 - 2^32 + 1` and its quadratic extension `F_p[X]/(X^2 - 7)`.
+//!
+//! Elements are kept canonical; reduction uses `2^64 ≡ 2^32 - 1` and `2^96 ≡ -1`.
+
+use core::fmt::{self, Debug};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::common::stark::{
+    extension::{BinomialExtension, BinomialParams, ExtensionField},
+    field::{Field, PrimeField, TwoAdicField},
+};
+
+const P: u64 = 0xffff_ffff_0000_0001;
+/// `2^64 mod p`.
+const EPSILON: u64 = 0xffff_ffff;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Goldilocks(u64);
+
+impl Goldilocks {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1);
+
+    pub const fn new(x: u64) -> Self {
+        Self(if x >= P { x - P } else { x })
+    }
+
+    pub const fn as_canonical_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Debug for Goldilocks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+fn reduce128(x: u128) -> u64 {
+    let lo = x as u64;
+    let hi = (x >> 64) as u64;
+    let (mut t0, borrow) = lo.overflowing_sub(hi >> 32);
+    if borrow {
+        t0 = t0.wrapping_sub(EPSILON);
+    }
+    let t1 = (hi & EPSILON) * EPSILON;
+    let (res, carry) = t0.overflowing_add(t1);
+    let res = if carry {
+        res.wrapping_add(EPSILON)
+    } else {
+        res
+    };
+    if res >= P {
+        res - P
+    } else {
+        res
+    }
+}
+
+impl Field for Goldilocks {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn inv(self) -> Self {
+        self.pow(P - 2)
+    }
+}
+
+impl PrimeField for Goldilocks {
+    const ORDER: u64 = P;
+
+    fn from_canonical_u64(x: u64) -> Option<Self> {
+        (x < P).then_some(Self(x))
+    }
+
+    fn as_canonical_u64(&self) -> u64 {
+        self.0
+    }
+
+    fn from_u64(x: u64) -> Self {
+        Self::new(x)
+    }
+}
+
+impl TwoAdicField for Goldilocks {
+    const TWO_ADICITY: usize = 32;
+    const GENERATOR: Self = Self(7);
+
+    fn two_adic_generator(bits: usize) -> Self {
+        let mut g = Self(1753635133440165772);
+        for _ in bits..Self::TWO_ADICITY {
+            g = g.square();
+        }
+        g
+    }
+}
+
+impl Add for Goldilocks {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (sum, carry) = self.0.overflowing_add(rhs.0);
+        let sum = if carry {
+            sum.wrapping_add(EPSILON)
+        } else {
+            sum
+        };
+        Self::new(sum)
+    }
+}
+
+impl Sub for Goldilocks {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (diff, borrow) = self.0.overflowing_sub(rhs.0);
+        Self(if borrow {
+            diff.wrapping_sub(EPSILON)
+        } else {
+            diff
+        })
+    }
+}
+
+impl Mul for Goldilocks {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(reduce128(self.0 as u128 * rhs.0 as u128))
+    }
+}
+
+impl Neg for Goldilocks {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl AddAssign for Goldilocks {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Goldilocks {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Goldilocks {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Scales an extension element by a base element.
+impl<P: BinomialParams<D, Base = Goldilocks>, const D: usize> Mul<Goldilocks>
+    for BinomialExtension<P, D>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Goldilocks) -> Self {
+        self.mul_base(rhs)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GoldilocksExt2Params;
+
+impl BinomialParams<2> for GoldilocksExt2Params {
+    type Base = Goldilocks;
+    const W: Goldilocks = Goldilocks(7);
+    const FROBENIUS: Goldilocks = Goldilocks(P - 1);
+}
+
+pub type GoldilocksExt2 = BinomialExtension<GoldilocksExt2Params, 2>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: [u64; 10] = [
+        0,
+        1,
+        2,
+        EPSILON,
+        EPSILON + 1,
+        1 << 63,
+        P - 2,
+        P - 1,
+        0x1234_5678_9abc_def0,
+        0xfedc_ba98_7654_3210 % P,
+    ];
+
+    #[test]
+    fn arithmetic_matches_u128() {
+        let p = P as u128;
+        for &a in &SAMPLES {
+            for &b in &SAMPLES {
+                let (x, y) = (Goldilocks::new(a), Goldilocks::new(b));
+                let (a, b) = (a as u128, b as u128);
+                assert_eq!((x + y).as_canonical_u64() as u128, (a + b) % p);
+                assert_eq!((x - y).as_canonical_u64() as u128, (a + p - b) % p);
+                assert_eq!((x * y).as_canonical_u64() as u128, a * b % p);
+            }
+        }
+    }
+
+    #[test]
+    fn reduces_non_canonical_values() {
+        assert_eq!(Goldilocks::new(P), Goldilocks::ZERO);
+        assert_eq!(Goldilocks::new(u64::MAX).as_canonical_u64(), EPSILON - 1);
+        assert_eq!(Goldilocks::from_canonical_u64(P), None);
+        assert_eq!(reduce128(u128::MAX), ((u128::MAX % P as u128) as u64));
+    }
+
+    #[test]
+    fn inverts() {
+        for &a in &SAMPLES[1..] {
+            let x = Goldilocks::new(a);
+            assert_eq!(x * x.inv(), Goldilocks::ONE);
+        }
+        assert_eq!(Goldilocks::ZERO.inv(), Goldilocks::ZERO);
+
+        let x = GoldilocksExt2::new([Goldilocks::new(3), Goldilocks::new(P - 5)]);
+        assert_eq!(x * x.inv(), GoldilocksExt2::ONE);
+    }
+
+    #[test]
+    fn two_adic_generators_have_exact_order() {
+        for bits in [1, 5, Goldilocks::TWO_ADICITY] {
+            let g = Goldilocks::two_adic_generator(bits);
+            assert_eq!(g.pow(1 << (bits - 1)), -Goldilocks::ONE);
+            assert_eq!(g.pow(1 << bits), Goldilocks::ONE);
+        }
+        // A generator of the whole group is a quadratic non-residue.
+        assert_eq!(Goldilocks::GENERATOR.pow((P - 1) / 2), -Goldilocks::ONE);
+    }
+}
@@ -0,0 +1,31 @@
+//! KoalaBear `p = 2^31 - 2^24 + 1` and its quartic extension `F_p[X]/(X^4 - 3)`.
+
+use crate::common::stark::{
+    extension::{BinomialExtension, BinomialParams},
+    monty31::{Monty31, MontyParams},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KoalaBearParams;
+
+impl MontyParams for KoalaBearParams {
+    const P: u32 = (1 << 31) - (1 << 24) + 1;
+    const P_INV: u32 = 0x81000001;
+    const R2: u32 = 402124772;
+    const TWO_ADICITY: usize = 24;
+    const GENERATOR: u32 = 3;
+    const TWO_ADIC_GENERATOR: u32 = 0x6ac49f88;
+}
+
+pub type KoalaBear = Monty31<KoalaBearParams>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KoalaBearExt4Params;
+
+impl BinomialParams<4> for KoalaBearExt4Params {
+    type Base = KoalaBear;
+    const W: KoalaBear = KoalaBear::new(3);
+    const FROBENIUS: KoalaBear = KoalaBear::new(2113994754);
+}
+
+pub type KoalaBearExt4 = BinomialExtension<KoalaBearExt4Params, 4>;
@@ -0,0 +1,48 @@
+/// A two-to-one hash over Merkle tree nodes.
+pub trait Compress {
+    type Digest: Copy + Eq;
+
+    fn compress(&self, left: &Self::Digest, right: &Self::Digest) -> Self::Digest;
+}
+
+/// Hashes `node` at `index` with its sibling, ordering the pair by the low index bit.
+pub fn hash_with_sibling<C: Compress>(
+    hasher: &C,
+    node: &C::Digest,
+    sibling: &C::Digest,
+    index: usize,
+) -> C::Digest {
+    if index & 1 == 0 {
+        hasher.compress(node, sibling)
+    } else {
+        hasher.compress(sibling, node)
+    }
+}
+
+/// Walks from `leaf` at `index` through `siblings`, bottom layer first, and returns the
+/// node reached.
+pub fn fold_path<C: Compress, I: IntoIterator<Item = C::Digest>>(
+    hasher: &C,
+    leaf: C::Digest,
+    mut index: usize,
+    siblings: I,
+) -> C::Digest {
+    let mut node = leaf;
+    for sibling in siblings {
+        node = hash_with_sibling(hasher, &node, &sibling, index);
+        index >>= 1;
+    }
+    node
+}
+
+/// Checks that `leaf` sits at `index` under `root`, with one sibling per layer.
+pub fn verify_path<C: Compress>(
+    hasher: &C,
+    root: &C::Digest,
+    leaf: C::Digest,
+    index: usize,
+    siblings: &[C::Digest],
+) -> bool {
+    index >> siblings.len() == 0
+        && fold_path(hasher, leaf, index, siblings.iter().copied()) == *root
+}
@@ -0,0 +1,162 @@
+//! Mersenne-31 `p = 2^31 - 1`, the complex extension `CM31 = F_p[i]/(i^2 + 1)` and the
+//! secure extension `QM31 = CM31[u]/(u^2 - 2 - i)` used by circle STARKs.
+//!
+//! `p - 1` has two-adicity one, so M31 has no FFT-friendly multiplicative subgroups;
+//! circle STARKs work on the circle group over CM31 instead.
+
+use core::fmt::{self, Debug};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::common::stark::{
+    extension::{BinomialExtension, BinomialParams, ExtensionField},
+    field::{Field, PrimeField},
+};
+
+const P: u32 = (1 << 31) - 1;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Mersenne31(u32);
+
+impl Mersenne31 {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1);
+
+    pub const fn new(x: u32) -> Self {
+        Self::reduce(x as u64)
+    }
+
+    pub fn from_canonical_u32(x: u32) -> Option<Self> {
+        (x < P).then_some(Self(x))
+    }
+
+    pub const fn as_canonical_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Reduces using `2^31 ≡ 1`.
+    const fn reduce(x: u64) -> Self {
+        let x = (x & P as u64) + (x >> 31);
+        let x = (x & P as u64) + (x >> 31);
+        Self(if x >= P as u64 {
+            x as u32 - P
+        } else {
+            x as u32
+        })
+    }
+}
+
+impl Debug for Mersenne31 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Field for Mersenne31 {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn inv(self) -> Self {
+        self.pow((P - 2) as u64)
+    }
+}
+
+impl PrimeField for Mersenne31 {
+    const ORDER: u64 = P as u64;
+
+    fn from_canonical_u64(x: u64) -> Option<Self> {
+        (x < P as u64).then_some(Self(x as u32))
+    }
+
+    fn as_canonical_u64(&self) -> u64 {
+        self.0 as u64
+    }
+
+    fn from_u64(x: u64) -> Self {
+        Self::reduce(x)
+    }
+}
+
+impl Add for Mersenne31 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let x = self.0 + rhs.0;
+        Self(if x >= P { x - P } else { x })
+    }
+}
+
+impl Sub for Mersenne31 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (x, borrow) = self.0.overflowing_sub(rhs.0);
+        Self(if borrow { x.wrapping_add(P) } else { x })
+    }
+}
+
+impl Mul for Mersenne31 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::reduce(self.0 as u64 * rhs.0 as u64)
+    }
+}
+
+impl Neg for Mersenne31 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl AddAssign for Mersenne31 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Mersenne31 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Mersenne31 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Scales an extension element by a base element.
+impl<P: BinomialParams<D, Base = Mersenne31>, const D: usize> Mul<Mersenne31>
+    for BinomialExtension<P, D>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Mersenne31) -> Self {
+        self.mul_base(rhs)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Cm31Params;
+
+impl BinomialParams<2> for Cm31Params {
+    type Base = Mersenne31;
+    const W: Mersenne31 = Mersenne31(P - 1);
+    const FROBENIUS: Mersenne31 = Mersenne31(P - 1);
+}
+
+pub type Cm31 = BinomialExtension<Cm31Params, 2>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Qm31Params;
+
+impl BinomialParams<2> for Qm31Params {
+    type Base = Cm31;
+    const W: Cm31 = BinomialExtension([Mersenne31(2), Mersenne31(1)]);
+    const FROBENIUS: Cm31 = BinomialExtension([Mersenne31(P - 1), Mersenne31(0)]);
+}
+
+pub type Qm31 = BinomialExtension<Qm31Params, 2>;
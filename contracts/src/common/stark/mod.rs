@@ -0,0 +1,25 @@
+//! Small-field arithmetic and FRI building blocks for STARK verifiers.
+//!
+//! 31-bit fields use Montgomery form with 32-bit limbs; extension inverses go through the
+//! Frobenius norm and FRI folding interpolates cosets directly, so nothing here needs an
+//! NTT.
+
+pub mod baby_bear;
+pub mod extension;
+pub mod field;
+pub mod fri;
+pub mod goldilocks;
+pub mod koala_bear;
+pub mod merkle;
+pub mod mersenne31;
+pub mod monty31;
+pub mod poly;
+
+pub use baby_bear::{BabyBear, BabyBearExt4};
+pub use extension::{BinomialExtension, BinomialParams, ExtensionField};
+pub use field::{batch_inverse, Field, PrimeField, TwoAdicField};
+pub use goldilocks::{Goldilocks, GoldilocksExt2};
+pub use koala_bear::{KoalaBear, KoalaBearExt4};
+pub use merkle::Compress;
+pub use mersenne31::{Cm31, Mersenne31, Qm31};
+pub use monty31::{Monty31, MontyParams};
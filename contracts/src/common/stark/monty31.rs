@@ -0,0 +1,264 @@
+//! 31-bit prime fields in Montgomery form with `R = 2^32`, so that products fit a single
+//! 64-bit multiply and reduction on wasm32.
+
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::common::stark::{
+    extension::{BinomialExtension, BinomialParams, ExtensionField},
+    field::{Field, PrimeField, TwoAdicField},
+};
+
+pub trait MontyParams: Copy + Debug + Default + Eq + 'static {
+    const P: u32;
+    /// `P^-1 mod 2^32`.
+    const P_INV: u32;
+    /// `2^64 mod P`.
+    const R2: u32;
+    const TWO_ADICITY: usize;
+    const GENERATOR: u32;
+    /// Generator of the subgroup of order `2^TWO_ADICITY`.
+    const TWO_ADIC_GENERATOR: u32;
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Monty31<MP: MontyParams>(u32, PhantomData<MP>);
+
+const fn monty_mul<MP: MontyParams>(lhs: u32, rhs: u32) -> u32 {
+    let mut o64 = (lhs as u64).wrapping_mul(rhs as u64);
+    let low = 0u32.wrapping_sub(o64 as u32);
+    let red = MP::P_INV.wrapping_mul(low);
+    o64 += (red as u64).wrapping_mul(MP::P as u64);
+    let ret = (o64 >> 32) as u32;
+    if ret >= MP::P {
+        ret - MP::P
+    } else {
+        ret
+    }
+}
+
+impl<MP: MontyParams> Monty31<MP> {
+    pub const ZERO: Self = Self(0, PhantomData);
+    pub const ONE: Self = Self::new(1);
+
+    /// Reduces `x` and converts it into Montgomery form.
+    pub const fn new(x: u32) -> Self {
+        Self(monty_mul::<MP>(MP::R2, x % MP::P), PhantomData)
+    }
+
+    /// Converts canonical values at compile time.
+    pub const fn new_array<const N: usize>(values: [u32; N]) -> [Self; N] {
+        let mut out = [Self::ZERO; N];
+        let mut i = 0;
+        while i < N {
+            out[i] = Self::new(values[i]);
+            i += 1;
+        }
+        out
+    }
+
+    /// Reads a canonical value, rejecting unreduced words.
+    pub fn from_canonical_u32(x: u32) -> Option<Self> {
+        (x < MP::P).then(|| Self::new(x))
+    }
+
+    /// Reads a word that is already in Montgomery form, rejecting unreduced values.
+    pub fn from_monty(x: u32) -> Option<Self> {
+        (x < MP::P).then_some(Self(x, PhantomData))
+    }
+
+    pub const fn as_canonical_u32(&self) -> u32 {
+        monty_mul::<MP>(1, self.0)
+    }
+
+    pub const fn as_monty(&self) -> u32 {
+        self.0
+    }
+}
+
+impl<MP: MontyParams> Debug for Monty31<MP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.as_canonical_u32(), f)
+    }
+}
+
+impl<MP: MontyParams> Field for Monty31<MP> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn inv(self) -> Self {
+        self.pow((MP::P - 2) as u64)
+    }
+}
+
+impl<MP: MontyParams> PrimeField for Monty31<MP> {
+    const ORDER: u64 = MP::P as u64;
+
+    fn from_canonical_u64(x: u64) -> Option<Self> {
+        (x < MP::P as u64).then(|| Self::new(x as u32))
+    }
+
+    fn as_canonical_u64(&self) -> u64 {
+        self.as_canonical_u32() as u64
+    }
+
+    fn from_u64(x: u64) -> Self {
+        Self::new((x % MP::P as u64) as u32)
+    }
+}
+
+impl<MP: MontyParams> TwoAdicField for Monty31<MP> {
+    const TWO_ADICITY: usize = MP::TWO_ADICITY;
+    const GENERATOR: Self = Self::new(MP::GENERATOR);
+
+    fn two_adic_generator(bits: usize) -> Self {
+        let mut g = Self::new(MP::TWO_ADIC_GENERATOR);
+        for _ in bits..MP::TWO_ADICITY {
+            g = g.square();
+        }
+        g
+    }
+}
+
+impl<MP: MontyParams> Add for Monty31<MP> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let x = self.0 + rhs.0;
+        Self(if x >= MP::P { x - MP::P } else { x }, PhantomData)
+    }
+}
+
+impl<MP: MontyParams> Sub for Monty31<MP> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (x, borrow) = self.0.overflowing_sub(rhs.0);
+        Self(if borrow { x.wrapping_add(MP::P) } else { x }, PhantomData)
+    }
+}
+
+impl<MP: MontyParams> Mul for Monty31<MP> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(monty_mul::<MP>(self.0, rhs.0), PhantomData)
+    }
+}
+
+impl<MP: MontyParams> Neg for Monty31<MP> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl<MP: MontyParams> AddAssign for Monty31<MP> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<MP: MontyParams> SubAssign for Monty31<MP> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<MP: MontyParams> MulAssign for Monty31<MP> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Scales an extension element by a base element.
+impl<MP: MontyParams, P: BinomialParams<D, Base = Monty31<MP>>, const D: usize> Mul<Monty31<MP>>
+    for BinomialExtension<P, D>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Monty31<MP>) -> Self {
+        self.mul_base(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::stark::{
+        baby_bear::{BabyBearExt4Params, BabyBearParams},
+        koala_bear::{KoalaBearExt4Params, KoalaBearParams},
+    };
+
+    fn samples<MP: MontyParams>() -> [u32; 8] {
+        [
+            0,
+            1,
+            2,
+            0x7fff,
+            0x1234_5678 % MP::P,
+            1 << 30,
+            MP::P - 2,
+            MP::P - 1,
+        ]
+    }
+
+    fn check_arithmetic<MP: MontyParams>() {
+        let p = MP::P as u64;
+        for a in samples::<MP>() {
+            let x = Monty31::<MP>::new(a);
+            assert_eq!(x.as_canonical_u32(), a);
+            for b in samples::<MP>() {
+                let y = Monty31::<MP>::new(b);
+                let (a, b) = (a as u64, b as u64);
+                assert_eq!((x + y).as_canonical_u64(), (a + b) % p);
+                assert_eq!((x - y).as_canonical_u64(), (a + p - b) % p);
+                assert_eq!((x * y).as_canonical_u64(), a * b % p);
+            }
+            if a != 0 {
+                assert_eq!(x * x.inv(), Monty31::ONE);
+            }
+        }
+        assert_eq!(Monty31::<MP>::new(MP::P), Monty31::ZERO);
+        assert_eq!(Monty31::<MP>::from_canonical_u32(MP::P), None);
+        assert_eq!(Monty31::<MP>::from_monty(MP::P), None);
+    }
+
+    fn check_two_adicity<MP: MontyParams>() {
+        let g = Monty31::<MP>::two_adic_generator(MP::TWO_ADICITY);
+        assert_eq!(g.pow(1 << (MP::TWO_ADICITY - 1)), -Monty31::ONE);
+        assert_eq!(g.pow(1 << MP::TWO_ADICITY), Monty31::ONE);
+        let generator = Monty31::<MP>::GENERATOR;
+        assert_eq!(generator.pow((MP::P as u64 - 1) / 2), -Monty31::ONE);
+    }
+
+    fn check_extension<P: BinomialParams<4, Base = Monty31<MP>>, MP: MontyParams>() {
+        let q = MP::P as u64;
+        assert_eq!(P::FROBENIUS, P::W.pow((q - 1) / 4));
+        // `X^4 - W` is irreducible only if `W` is not a square.
+        assert_eq!(P::W.pow((q - 1) / 2), -Monty31::ONE);
+
+        let x = BinomialExtension::<P, 4>::new(Monty31::new_array([1, 2, 3, MP::P - 4]));
+        assert_eq!(x * x.inv(), BinomialExtension::ONE);
+        assert_eq!(
+            BinomialExtension::<P, 4>::monomial(1).pow(4),
+            BinomialExtension::from_base(P::W)
+        );
+    }
+
+    #[test]
+    fn baby_bear() {
+        check_arithmetic::<BabyBearParams>();
+        check_two_adicity::<BabyBearParams>();
+        check_extension::<BabyBearExt4Params, BabyBearParams>();
+    }
+
+    #[test]
+    fn koala_bear() {
+        check_arithmetic::<KoalaBearParams>();
+        check_two_adicity::<KoalaBearParams>();
+        check_extension::<KoalaBearExt4Params, KoalaBearParams>();
+    }
+}
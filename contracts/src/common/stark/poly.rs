@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+
+use crate::common::stark::{
+    extension::ExtensionField,
+    field::{batch_inverse, Field},
+};
+
+/// Evaluates `Σ coeffs[i] · x^i` by Horner's rule.
+pub fn eval<E: Field>(coeffs: &[E], x: E) -> E {
+    coeffs
+        .iter()
+        .rev()
+        .fold(E::ZERO, |acc, coeff| acc * x + *coeff)
+}
+
+/// Evaluates at `z` the polynomial of degree below `n = evals.len()` that takes
+/// `evals[j]` at `shift · omega^j`, where `omega` has order `n`.
+///
+/// Uses the barycentric form `((z/s)^n - 1) / n · Σ evals[j] · ω^j / (z/s - ω^j)`,
+/// so no NTT is needed.
+pub fn interpolate_coset<F: Field, E: ExtensionField<F>>(
+    evals: &[E],
+    shift: F,
+    omega: F,
+    z: E,
+) -> E {
+    let y = z.mul_base(shift.inv());
+    let mut points = Vec::with_capacity(evals.len());
+    let mut w = F::ONE;
+    for _ in evals {
+        points.push(w);
+        w *= omega;
+    }
+    let mut denominators: Vec<E> = points.iter().map(|w| y - E::from_base(*w)).collect();
+    if let Some(j) = denominators.iter().position(|d| *d == E::ZERO) {
+        return evals[j];
+    }
+    batch_inverse(&mut denominators);
+
+    let sum = evals
+        .iter()
+        .zip(&points)
+        .zip(denominators)
+        .fold(E::ZERO, |acc, ((v, w), d)| acc + (*v * d).mul_base(*w));
+    let mut n = F::ZERO;
+    for _ in evals {
+        n += F::ONE;
+    }
+    ((y.pow(evals.len() as u64) - E::ONE) * sum).mul_base(n.inv())
+}
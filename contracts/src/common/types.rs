@@ -1,4 +1,4 @@
-use stylus_sdk::alloy_primitives::U256;
+use stylus_sdk::alloy_primitives::{uint, U256};
 
 #[derive(Clone, Copy)]
 pub struct G1Point {
@@ -14,13 +14,42 @@ pub struct G2Point {
     pub y: [U256; 2],
 }
 
-pub struct VerificationKey {
+impl G1Point {
+    pub const ZERO: G1Point = G1Point {
+        x: U256::ZERO,
+        y: U256::ZERO,
+    };
+
+    pub const GENERATOR: G1Point = G1Point {
+        x: uint!(1_U256),
+        y: uint!(2_U256),
+    };
+}
+
+impl G2Point {
+    /// Generator in the `ecPairing` precompile limb order.
+    pub const GENERATOR: G2Point = G2Point {
+        x: [
+            uint!(0x198E9393920D483A7260BFB731FB5D25F1AA493335A9E71297E485B7AEF312C2_U256),
+            uint!(0x1800DEEF121F1E76426A00665E5C4479674322D4F75EDADD46DEBD5CD992F6ED_U256),
+        ],
+        y: [
+            uint!(0x90689D0585FF075EC9E99AD690C3395BC4B313370B38EF355ACDADCD122975B_U256),
+            uint!(0x12C85EA5DB8C6DEB4AAB71808DCB408FE3D1E7690C43D37B4CE6CC0166FA7DAA_U256),
+        ],
+    };
+}
+
+pub struct VerificationKey<'a> {
     pub alpha1: G1Point,
     pub beta2: G2Point,
     pub gamma2: G2Point,
     pub delta2: G2Point,
-    pub ic: &'static [G1Point],
+    pub ic: &'a [G1Point],
 }
 
 #[derive(Clone, Copy)]
-pub enum VMType { Risc0, Sp1 }
\ No newline at end of file
+pub enum VMType {
+    Risc0,
+    Sp1,
+}
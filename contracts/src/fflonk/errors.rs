@@ -0,0 +1,51 @@
+use stylus_sdk::{
+    alloy_primitives::U256,
+    alloy_sol_types::{sol, SolError},
+};
+
+use crate::common::VerificationError;
+
+sol! {
+    error InvalidVerificationKey();
+    error PublicInputsLengthMismatch(uint256 received, uint256 expected);
+}
+
+#[derive(Debug)]
+pub enum FflonkError {
+    Common(VerificationError),
+    InvalidVerificationKey,
+    PublicInputsLengthMismatch { received: U256, expected: U256 },
+}
+
+impl FflonkError {
+    pub fn abi_encode(&self) -> alloc::vec::Vec<u8> {
+        match self {
+            FflonkError::Common(e) => e.abi_encode(),
+            FflonkError::InvalidVerificationKey => InvalidVerificationKey {}.abi_encode(),
+            FflonkError::PublicInputsLengthMismatch { received, expected } => {
+                PublicInputsLengthMismatch {
+                    received: *received,
+                    expected: *expected,
+                }
+                .abi_encode()
+            }
+        }
+    }
+}
+
+impl From<VerificationError> for FflonkError {
+    fn from(error: VerificationError) -> Self {
+        FflonkError::Common(error)
+    }
+}
+
+impl FflonkError {
+    pub const VERIFICATION_FAILED: FflonkError =
+        FflonkError::Common(VerificationError::VerificationFailed);
+    pub const INVALID_INITIALIZATION: FflonkError =
+        FflonkError::Common(VerificationError::InvalidInitialization);
+    pub const ALREADY_INITIALIZED: FflonkError =
+        FflonkError::Common(VerificationError::AlreadyInitialized);
+    pub const INVALID_PROOF_DATA: FflonkError =
+        FflonkError::Common(VerificationError::InvalidProofData);
+}
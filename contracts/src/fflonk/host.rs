@@ -0,0 +1,279 @@
+//! Host-side import of snarkjs fflonk artifacts.
+//!
+//! Reads the `verification_key.json`, `proof.json` and `public.json` written by
+//! `snarkjs fflonk setup` and `snarkjs fflonk prove`, and encodes them as the arguments of
+//! `initialize` and `verify_proof`.
+
+use std::{fmt, string::String, vec::Vec};
+
+use serde::Deserialize;
+use stylus_sdk::{alloy_primitives::U256, alloy_sol_types::SolValue};
+
+use crate::common::{G1Point, G2Point};
+use crate::fflonk::types::{FflonkProof, FflonkVerificationKey};
+
+#[derive(Debug)]
+pub enum HostError {
+    Json(serde_json::Error),
+    /// A value is not a decimal integer below `2^256`.
+    InvalidNumber(String),
+    /// The file is not for fflonk over bn128.
+    UnsupportedProtocol {
+        protocol: String,
+        curve: String,
+    },
+    /// A point is not in affine form, with `z = 1`.
+    NonAffinePoint,
+    /// The key is rejected by [`FflonkVerificationKey::from_bytes`].
+    InvalidVerificationKey,
+}
+
+impl fmt::Display for HostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostError::Json(e) => write!(f, "invalid snarkjs JSON: {e}"),
+            HostError::InvalidNumber(value) => write!(f, "invalid field element {value:?}"),
+            HostError::UnsupportedProtocol { protocol, curve } => {
+                write!(f, "unsupported protocol {protocol} over {curve}")
+            }
+            HostError::NonAffinePoint => write!(f, "point is not in affine form"),
+            HostError::InvalidVerificationKey => write!(f, "invalid verification key"),
+        }
+    }
+}
+
+impl std::error::Error for HostError {}
+
+impl From<serde_json::Error> for HostError {
+    fn from(e: serde_json::Error) -> Self {
+        HostError::Json(e)
+    }
+}
+
+impl FflonkVerificationKey {
+    /// Reads a snarkjs `verification_key.json`.
+    pub fn from_json(json: &str) -> Result<Self, HostError> {
+        let vk: serde_repr::VerificationKey = serde_json::from_str(json)?;
+        check_protocol(&vk.protocol, &vk.curve)?;
+        let key = FflonkVerificationKey {
+            n_public: vk.n_public as usize,
+            power: vk.power,
+            k1: number(&vk.k1)?,
+            k2: number(&vk.k2)?,
+            w: number(&vk.w)?,
+            w3: number(&vk.w3)?,
+            w4: number(&vk.w4)?,
+            w8: number(&vk.w8)?,
+            wr: number(&vk.wr)?,
+            x_2: g2_point(&vk.x_2)?,
+            c0: g1_point(&vk.c0)?,
+        };
+        // Applies the contract's own checks.
+        Self::from_bytes(&key.to_bytes()).ok_or(HostError::InvalidVerificationKey)
+    }
+}
+
+/// Encodes a snarkjs `proof.json` as the `proof_bytes` argument of `verify_proof`.
+pub fn encode_proof(json: &str) -> Result<Vec<u8>, HostError> {
+    let proof: serde_repr::Proof = serde_json::from_str(json)?;
+    check_protocol(&proof.protocol, &proof.curve)?;
+
+    let polynomials = &proof.polynomials;
+    let evaluations = &proof.evaluations;
+    let mut words = Vec::with_capacity(24);
+    for point in [
+        &polynomials.c1,
+        &polynomials.c2,
+        &polynomials.w1,
+        &polynomials.w2,
+    ] {
+        let point = g1_point(point)?;
+        words.extend([point.x, point.y]);
+    }
+    for evaluation in [
+        &evaluations.ql,
+        &evaluations.qr,
+        &evaluations.qm,
+        &evaluations.qo,
+        &evaluations.qc,
+        &evaluations.s1,
+        &evaluations.s2,
+        &evaluations.s3,
+        &evaluations.a,
+        &evaluations.b,
+        &evaluations.c,
+        &evaluations.z,
+        &evaluations.zw,
+        &evaluations.t1w,
+        &evaluations.t2w,
+        &evaluations.inv,
+    ] {
+        words.push(number(evaluation)?);
+    }
+
+    let proof = FflonkProof {
+        proof: words.try_into().expect("24 proof words"),
+    };
+    Ok(proof.abi_encode())
+}
+
+/// Reads a snarkjs `public.json`.
+pub fn parse_public_signals(json: &str) -> Result<Vec<U256>, HostError> {
+    let signals: Vec<String> = serde_json::from_str(json)?;
+    signals.iter().map(|signal| number(signal)).collect()
+}
+
+fn check_protocol(protocol: &str, curve: &str) -> Result<(), HostError> {
+    if protocol != "fflonk" || curve != "bn128" {
+        return Err(HostError::UnsupportedProtocol {
+            protocol: protocol.into(),
+            curve: curve.into(),
+        });
+    }
+    Ok(())
+}
+
+fn number(value: &str) -> Result<U256, HostError> {
+    U256::from_str_radix(value, 10).map_err(|_| HostError::InvalidNumber(value.into()))
+}
+
+fn g1_point(point: &[String; 3]) -> Result<G1Point, HostError> {
+    if point[2] != "1" {
+        return Err(HostError::NonAffinePoint);
+    }
+    Ok(G1Point {
+        x: number(&point[0])?,
+        y: number(&point[1])?,
+    })
+}
+
+/// snarkjs writes `[[x_c0, x_c1], [y_c0, y_c1], ["1", "0"]]`; the precompile takes the
+/// imaginary limb first.
+fn g2_point(point: &[[String; 2]; 3]) -> Result<G2Point, HostError> {
+    if point[2] != ["1", "0"] {
+        return Err(HostError::NonAffinePoint);
+    }
+    Ok(G2Point {
+        x: [number(&point[0][1])?, number(&point[0][0])?],
+        y: [number(&point[1][1])?, number(&point[1][0])?],
+    })
+}
+
+/// Mirror of the snarkjs JSON layouts.
+mod serde_repr {
+    use super::*;
+
+    #[derive(Deserialize)]
+    pub struct VerificationKey {
+        pub protocol: String,
+        pub curve: String,
+        #[serde(rename = "nPublic")]
+        pub n_public: u32,
+        pub power: u32,
+        pub k1: String,
+        pub k2: String,
+        pub w: String,
+        pub w3: String,
+        pub w4: String,
+        pub w8: String,
+        pub wr: String,
+        #[serde(rename = "X_2")]
+        pub x_2: [[String; 2]; 3],
+        #[serde(rename = "C0")]
+        pub c0: [String; 3],
+    }
+
+    #[derive(Deserialize)]
+    pub struct Proof {
+        pub polynomials: Polynomials,
+        pub evaluations: Evaluations,
+        pub protocol: String,
+        pub curve: String,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct Polynomials {
+        pub c1: [String; 3],
+        pub c2: [String; 3],
+        pub w1: [String; 3],
+        pub w2: [String; 3],
+    }
+
+    #[derive(Deserialize)]
+    pub struct Evaluations {
+        pub ql: String,
+        pub qr: String,
+        pub qm: String,
+        pub qo: String,
+        pub qc: String,
+        pub s1: String,
+        pub s2: String,
+        pub s3: String,
+        pub a: String,
+        pub b: String,
+        pub c: String,
+        pub z: String,
+        pub zw: String,
+        pub t1w: String,
+        pub t2w: String,
+        pub inv: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::VerificationError;
+    use crate::fflonk::{verifier::verify_proof_with_key, FflonkError};
+
+    // A one-output circuit proving `x^3 + x + 5 = 35` over a domain of size 8.
+    const VERIFICATION_KEY: &str = include_str!("../../testdata/fflonk/verification_key.json");
+    const PROOF: &str = include_str!("../../testdata/fflonk/proof.json");
+    const PUBLIC: &str = include_str!("../../testdata/fflonk/public.json");
+
+    #[test]
+    fn verifies_fixture_proof() {
+        let vk = FflonkVerificationKey::from_json(VERIFICATION_KEY).unwrap();
+        let encoded = vk.to_bytes();
+        assert_eq!(encoded.len(), FflonkVerificationKey::ENCODED_SIZE);
+        let vk = FflonkVerificationKey::from_bytes(&encoded).unwrap();
+
+        let proof = encode_proof(PROOF).unwrap();
+        let public_signals = parse_public_signals(PUBLIC).unwrap();
+        assert_eq!(public_signals, [U256::from(35)]);
+        verify_proof_with_key(&vk, &proof, &public_signals).unwrap();
+    }
+
+    #[test]
+    fn rejects_altered_statements() {
+        let vk = FflonkVerificationKey::from_json(VERIFICATION_KEY).unwrap();
+        let proof = encode_proof(PROOF).unwrap();
+
+        assert!(matches!(
+            verify_proof_with_key(&vk, &proof, &[U256::from(36)]),
+            Err(FflonkError::Common(VerificationError::VerificationFailed))
+        ));
+        assert!(matches!(
+            verify_proof_with_key(&vk, &proof, &[]),
+            Err(FflonkError::PublicInputsLengthMismatch { .. })
+        ));
+
+        // Flip the low bit of the `a` evaluation.
+        let mut altered = proof.clone();
+        altered[(8 + 8) * 32 + 31] ^= 1;
+        assert!(matches!(
+            verify_proof_with_key(&vk, &altered, &[U256::from(35)]),
+            Err(FflonkError::Common(VerificationError::VerificationFailed))
+        ));
+    }
+
+    #[test]
+    fn rejects_other_protocols() {
+        let plonk = VERIFICATION_KEY.replace("\"fflonk\"", "\"plonk\"");
+        assert!(matches!(
+            FflonkVerificationKey::from_json(&plonk),
+            Err(HostError::UnsupportedProtocol { .. })
+        ));
+    }
+}
@@ -0,0 +1,10 @@
+pub mod errors;
+#[cfg(feature = "fflonk-host")]
+pub mod host;
+pub mod transcript;
+pub mod types;
+pub mod verifier;
+
+pub use errors::FflonkError;
+pub use types::{FflonkProof, FflonkVerificationKey};
+pub use verifier::{verify_proof_with_key, FflonkVerifier, IFflonkVerifier};
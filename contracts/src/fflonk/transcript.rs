@@ -0,0 +1,38 @@
+use alloc::vec::Vec;
+use stylus_sdk::{alloy_primitives::U256, crypto::keccak};
+
+use crate::common::{fr, G1Point};
+
+/// Keccak-256 transcript matching snarkjs' `Keccak256Transcript`: scalars are
+/// appended as 32-byte big-endian words, points as uncompressed `x || y`.
+pub struct Keccak256Transcript {
+    buffer: Vec<u8>,
+}
+
+impl Keccak256Transcript {
+    pub fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+
+    pub fn add_scalar(&mut self, scalar: U256) {
+        self.buffer.extend_from_slice(&scalar.to_be_bytes::<32>());
+    }
+
+    pub fn add_commitment(&mut self, point: &G1Point) {
+        self.buffer.extend_from_slice(&point.x.to_be_bytes::<32>());
+        self.buffer.extend_from_slice(&point.y.to_be_bytes::<32>());
+    }
+
+    /// Hashes the absorbed data into a scalar and resets the transcript.
+    pub fn get_challenge(&mut self) -> U256 {
+        let hash = keccak(&self.buffer);
+        self.buffer.clear();
+        fr::from_be_bytes_mod_order(hash.as_slice())
+    }
+}
+
+impl Default for Keccak256Transcript {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+use stylus_sdk::{alloy_primitives::U256, alloy_sol_types::sol};
+
+use crate::common::{
+    groth16::{Q, R},
+    G1Point, G2Point,
+};
+
+/// Two-adicity of the BN254 scalar field, bounding the domain size.
+const MAX_POWER: u32 = 28;
+
+sol! {
+    /// snarkjs fflonk calldata layout: `C1, C2, W1, W2` followed by the 16 evaluations
+    /// `ql, qr, qm, qo, qc, s1, s2, s3, a, b, c, z, zw, t1w, t2w, inv`.
+    struct FflonkProof {
+        uint256[24] proof;
+    }
+}
+
+/// fflonk verification key, imported from a snarkjs `verification_key.json`.
+///
+/// Field names follow the JSON keys. `X_2` goes into `x_2` in the limb order the
+/// `ecPairing` precompile expects, i.e. `[[X_2[0][1], X_2[0][0]], [X_2[1][1], X_2[1][0]]]`.
+///
+/// The contract stores it encoded as
+///
+/// ```text
+/// u32 nPublic | u32 power | 7 × 32  k1, k2, w, w3, w4, w8, wr
+/// 4 × 32  x_2 in ecPairing order | 32 x || 32 y  C0
+/// ```
+pub struct FflonkVerificationKey {
+    /// `nPublic`
+    pub n_public: usize,
+    /// `power`, the domain size is `2^power`
+    pub power: u32,
+    pub k1: U256,
+    pub k2: U256,
+    pub w: U256,
+    pub w3: U256,
+    pub w4: U256,
+    pub w8: U256,
+    pub wr: U256,
+    /// `X_2`, `[x]_2` from the SRS
+    pub x_2: G2Point,
+    /// `C0`, commitment to the preprocessed polynomials
+    pub c0: G1Point,
+}
+
+impl FflonkVerificationKey {
+    pub const ENCODED_SIZE: usize = 2 * 4 + 13 * 32;
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::ENCODED_SIZE {
+            return None;
+        }
+
+        let n_public = u32::from_be_bytes(bytes[0..4].try_into().ok()?) as usize;
+        let power = u32::from_be_bytes(bytes[4..8].try_into().ok()?);
+        if power == 0 || power > MAX_POWER || n_public > 1 << power {
+            return None;
+        }
+
+        let word = |i: usize| U256::from_be_slice(&bytes[8 + i * 32..8 + (i + 1) * 32]);
+        let scalars: [U256; 7] = core::array::from_fn(word);
+        let coordinates: [U256; 6] = core::array::from_fn(|i| word(7 + i));
+        if scalars.iter().any(|x| *x >= R) || coordinates.iter().any(|x| *x >= Q) {
+            return None;
+        }
+
+        let [k1, k2, w, w3, w4, w8, wr] = scalars;
+        let [x_2_x0, x_2_x1, x_2_y0, x_2_y1, c0_x, c0_y] = coordinates;
+        Some(Self {
+            n_public,
+            power,
+            k1,
+            k2,
+            w,
+            w3,
+            w4,
+            w8,
+            wr,
+            x_2: G2Point {
+                x: [x_2_x0, x_2_x1],
+                y: [x_2_y0, x_2_y1],
+            },
+            c0: G1Point { x: c0_x, y: c0_y },
+        })
+    }
+
+    /// Inverse of [`Self::from_bytes`], the argument of `initialize`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::ENCODED_SIZE);
+        bytes.extend_from_slice(&(self.n_public as u32).to_be_bytes());
+        bytes.extend_from_slice(&self.power.to_be_bytes());
+        for word in [
+            self.k1,
+            self.k2,
+            self.w,
+            self.w3,
+            self.w4,
+            self.w8,
+            self.wr,
+            self.x_2.x[0],
+            self.x_2.x[1],
+            self.x_2.y[0],
+            self.x_2.y[1],
+            self.c0.x,
+            self.c0.y,
+        ] {
+            bytes.extend_from_slice(&word.to_be_bytes::<32>());
+        }
+        bytes
+    }
+}
+
+pub struct Polynomials {
+    pub c1: G1Point,
+    pub c2: G1Point,
+    pub w1: G1Point,
+    pub w2: G1Point,
+}
+
+pub struct Evaluations {
+    pub ql: U256,
+    pub qr: U256,
+    pub qm: U256,
+    pub qo: U256,
+    pub qc: U256,
+    pub s1: U256,
+    pub s2: U256,
+    pub s3: U256,
+    pub a: U256,
+    pub b: U256,
+    pub c: U256,
+    pub z: U256,
+    pub zw: U256,
+    pub t1w: U256,
+    pub t2w: U256,
+    /// Batched inverse hint used by the Solidity verifier; inverses are computed
+    /// directly here, so it is only range-checked.
+    pub inv: U256,
+}
+
+pub struct FflonkProofData {
+    pub polynomials: Polynomials,
+    pub evaluations: Evaluations,
+}
+
+impl FflonkProofData {
+    pub fn from_words(words: &[U256; 24]) -> Self {
+        let point = |i: usize| G1Point {
+            x: words[i],
+            y: words[i + 1],
+        };
+
+        Self {
+            polynomials: Polynomials {
+                c1: point(0),
+                c2: point(2),
+                w1: point(4),
+                w2: point(6),
+            },
+            evaluations: Evaluations {
+                ql: words[8],
+                qr: words[9],
+                qm: words[10],
+                qo: words[11],
+                qc: words[12],
+                s1: words[13],
+                s2: words[14],
+                s3: words[15],
+                a: words[16],
+                b: words[17],
+                c: words[18],
+                z: words[19],
+                zw: words[20],
+                t1w: words[21],
+                t2w: words[22],
+                inv: words[23],
+            },
+        }
+    }
+}
@@ -0,0 +1,418 @@
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{Address, B256, U256},
+    alloy_sol_types::SolType,
+    crypto::keccak,
+    prelude::*,
+};
+
+use crate::common::{
+    fr,
+    groth16::{ec_add, ec_mul, ec_sub, Q, R},
+    ownable::{IOwnable, Ownable},
+    G1Point, KzgVerifier,
+};
+use crate::fflonk::{
+    errors::FflonkError,
+    transcript::Keccak256Transcript,
+    types::{FflonkProof, FflonkProofData, FflonkVerificationKey},
+};
+
+pub trait IFflonkVerifier {
+    type Error;
+
+    /// Imports the encoded verification key (see [`FflonkVerificationKey`]); owner-only and
+    /// callable once.
+    fn initialize(&mut self, verification_key: Vec<u8>) -> Result<(), Self::Error>;
+
+    fn verify_proof(
+        &self,
+        proof_bytes: Vec<u8>,
+        public_signals: Vec<U256>,
+    ) -> Result<(), Self::Error>;
+
+    fn get_verification_key_hash(&self) -> B256;
+    fn is_initialized(&self) -> bool;
+}
+
+sol_storage! {
+    /// Verifier for snarkjs fflonk proofs over BN254, following `fflonk_verify.js`.
+    pub struct FflonkVerifier {
+        bytes verification_key;
+        bytes32 verification_key_hash;
+        bool initialized;
+        Ownable ownable;
+    }
+}
+
+#[public]
+impl IFflonkVerifier for FflonkVerifier {
+    type Error = Vec<u8>;
+
+    fn initialize(&mut self, verification_key: Vec<u8>) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        if self.initialized.get() {
+            return Err(FflonkError::ALREADY_INITIALIZED.abi_encode());
+        }
+        if FflonkVerificationKey::from_bytes(&verification_key).is_none() {
+            return Err(FflonkError::InvalidVerificationKey.abi_encode());
+        }
+
+        self.verification_key_hash.set(keccak(&verification_key));
+        self.verification_key.set_bytes(&verification_key);
+        self.initialized.set(true);
+
+        Ok(())
+    }
+
+    fn verify_proof(
+        &self,
+        proof_bytes: Vec<u8>,
+        public_signals: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        if !self.initialized.get() {
+            return Err(FflonkError::INVALID_INITIALIZATION.abi_encode());
+        }
+
+        let vk = FflonkVerificationKey::from_bytes(&self.verification_key.get_bytes())
+            .ok_or_else(|| FflonkError::InvalidVerificationKey.abi_encode())?;
+        verify_proof_with_key(&vk, &proof_bytes, &public_signals).map_err(|e| e.abi_encode())
+    }
+
+    fn get_verification_key_hash(&self) -> B256 {
+        self.verification_key_hash.get()
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.initialized.get()
+    }
+}
+
+#[public]
+impl IOwnable for FflonkVerifier {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.ownable.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.ownable
+            .transfer_ownership(new_owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.ownable.accept_ownership().map_err(|e| e.abi_encode())
+    }
+}
+
+impl FflonkVerifier {
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable
+            .set_initial_owner(owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn only_owner(&self) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner().map_err(|e| e.abi_encode())
+    }
+}
+
+struct Challenges {
+    beta: U256,
+    gamma: U256,
+    xi: U256,
+    xi_n: U256,
+    alpha: U256,
+    y: U256,
+}
+
+/// Opening points `h0·w8^i`, `h1·w4^i`, `h2·w3^i` and `h3·w3^i` derived from `xiSeed`.
+struct Roots {
+    h0w8: [U256; 8],
+    h1w4: [U256; 4],
+    h2w3: [U256; 3],
+    h3w3: [U256; 3],
+}
+
+/// Verifies a snarkjs fflonk proof, ABI-encoded as [`FflonkProof`], against `vk`.
+pub fn verify_proof_with_key(
+    vk: &FflonkVerificationKey,
+    proof_bytes: &[u8],
+    public_signals: &[U256],
+) -> Result<(), FflonkError> {
+    if public_signals.len() != vk.n_public {
+        return Err(FflonkError::PublicInputsLengthMismatch {
+            received: U256::from(public_signals.len()),
+            expected: U256::from(vk.n_public),
+        });
+    }
+
+    let decoded = match <FflonkProof as SolType>::abi_decode(proof_bytes, true) {
+        Ok(proof) => proof,
+        Err(_) => return Err(FflonkError::INVALID_PROOF_DATA),
+    };
+
+    // Commitment coordinates must be in Fq, evaluations and public inputs in Fr.
+    if decoded.proof[..8].iter().any(|&x| x >= Q)
+        || decoded.proof[8..].iter().any(|&x| x >= R)
+        || public_signals.iter().any(|&x| x >= R)
+    {
+        return Err(FflonkError::INVALID_PROOF_DATA);
+    }
+
+    let proof = FflonkProofData::from_words(&decoded.proof);
+    let (challenges, roots) = compute_challenges(vk, &proof, public_signals);
+
+    let verified =
+        verify_with_challenges(vk, &proof, public_signals, &challenges, &roots).unwrap_or(false);
+
+    if !verified {
+        return Err(FflonkError::VERIFICATION_FAILED);
+    }
+
+    Ok(())
+}
+
+fn compute_challenges(
+    vk: &FflonkVerificationKey,
+    proof: &FflonkProofData,
+    public_signals: &[U256],
+) -> (Challenges, Roots) {
+    let evals = &proof.evaluations;
+    let mut transcript = Keccak256Transcript::new();
+
+    transcript.add_commitment(&vk.c0);
+    for signal in public_signals {
+        transcript.add_scalar(*signal);
+    }
+    transcript.add_commitment(&proof.polynomials.c1);
+    let beta = transcript.get_challenge();
+
+    transcript.add_scalar(beta);
+    let gamma = transcript.get_challenge();
+
+    transcript.add_scalar(gamma);
+    transcript.add_commitment(&proof.polynomials.c2);
+    let xi_seed = transcript.get_challenge();
+    let xi_seed2 = fr::square(xi_seed);
+
+    // h0 = xiSeed^3
+    let mut h0w8 = [U256::ZERO; 8];
+    h0w8[0] = fr::mul(xi_seed2, xi_seed);
+    for i in 1..8 {
+        h0w8[i] = fr::mul(h0w8[i - 1], vk.w8);
+    }
+
+    // h1 = xiSeed^6
+    let mut h1w4 = [U256::ZERO; 4];
+    h1w4[0] = fr::square(h0w8[0]);
+    for i in 1..4 {
+        h1w4[i] = fr::mul(h1w4[i - 1], vk.w4);
+    }
+
+    // h2 = xiSeed^8, h3 = h2·wr so that h3^3 = xi·ω
+    let w3_2 = fr::square(vk.w3);
+    let h2 = fr::mul(h1w4[0], xi_seed2);
+    let h3 = fr::mul(h2, vk.wr);
+    let h2w3 = [h2, fr::mul(h2, vk.w3), fr::mul(h2, w3_2)];
+    let h3w3 = [h3, fr::mul(h3, vk.w3), fr::mul(h3, w3_2)];
+
+    // xi = xiSeed^24
+    let xi = fr::mul(fr::square(h2), h2);
+    let mut xi_n = xi;
+    for _ in 0..vk.power {
+        xi_n = fr::square(xi_n);
+    }
+
+    transcript.add_scalar(xi_seed);
+    for eval in [
+        evals.ql, evals.qr, evals.qm, evals.qo, evals.qc, evals.s1, evals.s2, evals.s3, evals.a,
+        evals.b, evals.c, evals.z, evals.zw, evals.t1w, evals.t2w,
+    ] {
+        transcript.add_scalar(eval);
+    }
+    let alpha = transcript.get_challenge();
+
+    transcript.add_scalar(alpha);
+    transcript.add_commitment(&proof.polynomials.w1);
+    let y = transcript.get_challenge();
+
+    (
+        Challenges {
+            beta,
+            gamma,
+            xi,
+            xi_n,
+            alpha,
+            y,
+        },
+        Roots {
+            h0w8,
+            h1w4,
+            h2w3,
+            h3w3,
+        },
+    )
+}
+
+fn verify_with_challenges(
+    vk: &FflonkVerificationKey,
+    proof: &FflonkProofData,
+    public_signals: &[U256],
+    ch: &Challenges,
+    roots: &Roots,
+) -> Option<bool> {
+    let evals = &proof.evaluations;
+    let one = U256::from(1u8);
+
+    // Z_H(xi) = xi^n - 1
+    let zh = fr::sub(ch.xi_n, one);
+    let inv_zh = fr::inv(zh)?;
+
+    let lagrange = compute_lagrange_evaluations(vk, ch.xi, zh)?;
+
+    // PI(xi) = -Σ w_i·L_i(xi)
+    let pi = public_signals
+        .iter()
+        .zip(&lagrange)
+        .fold(U256::ZERO, |acc, (w, l)| fr::sub(acc, fr::mul(*w, *l)));
+
+    // r0(y): C0 = ql + X·qr + X^2·qo + X^3·qm + X^4·qc + X^5·s1 + X^6·s2 + X^7·s3
+    let c0_coeffs = [
+        evals.ql, evals.qr, evals.qo, evals.qm, evals.qc, evals.s1, evals.s2, evals.s3,
+    ];
+    let r0 = interpolate_at(&roots.h0w8, |h| eval_poly(&c0_coeffs, h), ch.y)?;
+
+    // r1(y): C1 = a + X·b + X^2·c + X^3·T0
+    let mut t0 = fr::mul(evals.ql, evals.a);
+    t0 = fr::add(t0, fr::mul(evals.qr, evals.b));
+    t0 = fr::add(t0, fr::mul(evals.qm, fr::mul(evals.a, evals.b)));
+    t0 = fr::add(t0, fr::mul(evals.qo, evals.c));
+    t0 = fr::add(t0, evals.qc);
+    t0 = fr::add(t0, pi);
+    t0 = fr::mul(t0, inv_zh);
+    let c1_coeffs = [evals.a, evals.b, evals.c, t0];
+    let r1 = interpolate_at(&roots.h1w4, |h| eval_poly(&c1_coeffs, h), ch.y)?;
+
+    // r2(y): C2 = z + X·T1 + X^2·T2, opened at xi (h2w3) and xi·ω (h3w3)
+    let t1 = fr::mul(fr::mul(fr::sub(evals.z, one), lagrange[0]), inv_zh);
+
+    let beta_xi = fr::mul(ch.beta, ch.xi);
+    let t211 = fr::add(evals.a, fr::add(beta_xi, ch.gamma));
+    let t212 = fr::add(evals.b, fr::add(fr::mul(beta_xi, vk.k1), ch.gamma));
+    let t213 = fr::add(evals.c, fr::add(fr::mul(beta_xi, vk.k2), ch.gamma));
+    let t21 = fr::mul(t211, fr::mul(t212, fr::mul(t213, evals.z)));
+
+    let t221 = fr::add(evals.a, fr::add(fr::mul(ch.beta, evals.s1), ch.gamma));
+    let t222 = fr::add(evals.b, fr::add(fr::mul(ch.beta, evals.s2), ch.gamma));
+    let t223 = fr::add(evals.c, fr::add(fr::mul(ch.beta, evals.s3), ch.gamma));
+    let t22 = fr::mul(t221, fr::mul(t222, fr::mul(t223, evals.zw)));
+
+    let t2 = fr::mul(fr::sub(t21, t22), inv_zh);
+
+    let s2_points = [
+        roots.h2w3[0],
+        roots.h2w3[1],
+        roots.h2w3[2],
+        roots.h3w3[0],
+        roots.h3w3[1],
+        roots.h3w3[2],
+    ];
+    let c2_xi = [evals.z, t1, t2];
+    let c2_xiw = [evals.zw, evals.t1w, evals.t2w];
+    let s2_values: Vec<U256> = s2_points
+        .iter()
+        .enumerate()
+        .map(|(i, h)| eval_poly(if i < 3 { &c2_xi } else { &c2_xiw }, *h))
+        .collect();
+    let s2_basis = fr::lagrange_basis(&s2_points, ch.y)?;
+    let r2 = dot(&s2_values, &s2_basis);
+
+    // Vanishing polynomials of each opening set at y
+    let mul_h0 = vanishing_at(&roots.h0w8, ch.y);
+    let mul_h1 = vanishing_at(&roots.h1w4, ch.y);
+    let mul_h2 = vanishing_at(&s2_points, ch.y);
+
+    let quotient1 = fr::mul(ch.alpha, fr::mul(mul_h0, fr::inv(mul_h1)?));
+    let quotient2 = fr::mul(fr::square(ch.alpha), fr::mul(mul_h0, fr::inv(mul_h2)?));
+
+    // F = C0 + quotient1·C1 + quotient2·C2
+    let f2 = ec_mul(&proof.polynomials.c1, quotient1)?;
+    let f3 = ec_mul(&proof.polynomials.c2, quotient2)?;
+    let f = ec_add(&vk.c0, &ec_add(&f2, &f3)?)?;
+
+    // E = [r0 + quotient1·r1 + quotient2·r2]_1
+    let e_scalar = fr::add(r0, fr::add(fr::mul(r1, quotient1), fr::mul(r2, quotient2)));
+    let e = ec_mul(&G1Point::GENERATOR, e_scalar)?;
+
+    // J = Z_S0(y)·W1
+    let j = ec_mul(&proof.polynomials.w1, mul_h0)?;
+
+    // e(W2, [x]_2) == e(F - E - J + y·W2, [1]_2)
+    let a1 = ec_sub(&ec_sub(&f, &e)?, &j)?;
+    let a1 = ec_add(&a1, &ec_mul(&proof.polynomials.w2, ch.y)?)?;
+
+    Some(KzgVerifier::new(vk.x_2).verify_accumulator(&proof.polynomials.w2, &a1))
+}
+
+/// `L_i(xi) = ω^{i-1}·(xi^n - 1) / (n·(xi - ω^{i-1}))` for `i = 1..=max(1, nPublic)`.
+fn compute_lagrange_evaluations(
+    vk: &FflonkVerificationKey,
+    xi: U256,
+    zh: U256,
+) -> Option<Vec<U256>> {
+    let size = vk.n_public.max(1);
+    let n = U256::from(1u64 << vk.power);
+
+    let mut numerators = Vec::with_capacity(size);
+    let mut denominators = Vec::with_capacity(size);
+    let mut w = U256::from(1u8);
+    for _ in 0..size {
+        numerators.push(fr::mul(w, zh));
+        denominators.push(fr::mul(n, fr::sub(xi, w)));
+        w = fr::mul(w, vk.w);
+    }
+
+    let inverses = fr::batch_inverse(&denominators)?;
+    Some(
+        numerators
+            .into_iter()
+            .zip(inverses)
+            .map(|(num, inv)| fr::mul(num, inv))
+            .collect(),
+    )
+}
+
+/// Evaluates at `y` the polynomial interpolating `f` over `points`.
+fn interpolate_at(points: &[U256], f: impl Fn(U256) -> U256, y: U256) -> Option<U256> {
+    let values: Vec<U256> = points.iter().map(|h| f(*h)).collect();
+    let basis = fr::lagrange_basis(points, y)?;
+    Some(dot(&values, &basis))
+}
+
+/// Horner evaluation of `Σ coeffs[i]·x^i`.
+fn eval_poly(coeffs: &[U256], x: U256) -> U256 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(U256::ZERO, |acc, c| fr::add(fr::mul(acc, x), *c))
+}
+
+fn vanishing_at(points: &[U256], y: U256) -> U256 {
+    points
+        .iter()
+        .fold(U256::from(1u8), |acc, p| fr::mul(acc, fr::sub(y, *p)))
+}
+
+fn dot(a: &[U256], b: &[U256]) -> U256 {
+    a.iter()
+        .zip(b)
+        .fold(U256::ZERO, |acc, (x, y)| fr::add(acc, fr::mul(*x, *y)))
+}
@@ -0,0 +1,49 @@
+use stylus_sdk::{
+    alloy_primitives::U256,
+    alloy_sol_types::{sol, SolError},
+};
+
+use crate::common::VerificationError;
+
+sol! {
+    error InvalidProtocol();
+    error InstancesLengthMismatch(uint256 received, uint256 expected);
+}
+
+#[derive(Debug)]
+pub enum Halo2Error {
+    Common(VerificationError),
+    InvalidProtocol,
+    InstancesLengthMismatch { received: U256, expected: U256 },
+}
+
+impl Halo2Error {
+    pub fn abi_encode(&self) -> alloc::vec::Vec<u8> {
+        match self {
+            Halo2Error::Common(e) => e.abi_encode(),
+            Halo2Error::InvalidProtocol => InvalidProtocol {}.abi_encode(),
+            Halo2Error::InstancesLengthMismatch { received, expected } => InstancesLengthMismatch {
+                received: *received,
+                expected: *expected,
+            }
+            .abi_encode(),
+        }
+    }
+}
+
+impl From<VerificationError> for Halo2Error {
+    fn from(error: VerificationError) -> Self {
+        Halo2Error::Common(error)
+    }
+}
+
+impl Halo2Error {
+    pub const VERIFICATION_FAILED: Halo2Error =
+        Halo2Error::Common(VerificationError::VerificationFailed);
+    pub const INVALID_INITIALIZATION: Halo2Error =
+        Halo2Error::Common(VerificationError::InvalidInitialization);
+    pub const ALREADY_INITIALIZED: Halo2Error =
+        Halo2Error::Common(VerificationError::AlreadyInitialized);
+    pub const INVALID_PROOF_DATA: Halo2Error =
+        Halo2Error::Common(VerificationError::InvalidProofData);
+}
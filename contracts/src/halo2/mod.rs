@@ -0,0 +1,10 @@
+pub mod errors;
+pub mod multiopen;
+pub mod poseidon;
+pub mod protocol;
+pub mod transcript;
+pub mod verifier;
+
+pub use errors::Halo2Error;
+pub use protocol::{MultiOpenScheme, Protocol, TranscriptKind};
+pub use verifier::{verify_proof_with_protocol, Halo2Verifier, IHalo2Verifier};
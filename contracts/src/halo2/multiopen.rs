@@ -0,0 +1,249 @@
+//! KZG multi-opening verifiers (GWC19 and BDFG21/SHPLONK), following halo2's
+//! `poly::kzg::multiopen`. Both reduce the queries to a pair of MSMs `(left, right)` that
+//! satisfy `e(left, [s]_2) = e(right, [1]_2)`.
+
+use alloc::{vec, vec::Vec};
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::common::{
+    fr,
+    groth16::{ec_add, ec_mul},
+    G1Point,
+};
+use crate::halo2::transcript::Transcript;
+
+/// A multi-scalar multiplication, evaluated lazily through the EC precompiles.
+#[derive(Clone, Default)]
+pub struct Msm {
+    terms: Vec<(U256, G1Point)>,
+}
+
+impl Msm {
+    pub fn new() -> Self {
+        Self { terms: Vec::new() }
+    }
+
+    pub fn from_point(point: G1Point) -> Self {
+        Self {
+            terms: vec![(U256::from(1u8), point)],
+        }
+    }
+
+    pub fn append_term(&mut self, scalar: U256, point: G1Point) {
+        self.terms.push((scalar, point));
+    }
+
+    pub fn add_msm(&mut self, other: &Msm) {
+        self.terms.extend_from_slice(&other.terms);
+    }
+
+    pub fn scale(&mut self, factor: U256) {
+        for (scalar, _) in self.terms.iter_mut() {
+            *scalar = fr::mul(*scalar, factor);
+        }
+    }
+
+    pub fn evaluate(&self) -> Option<G1Point> {
+        self.terms
+            .iter()
+            .try_fold(G1Point::ZERO, |acc, (scalar, point)| {
+                ec_add(&acc, &ec_mul(point, *scalar)?)
+            })
+    }
+}
+
+/// Claim that the commitment at index `commitment` opens to `eval` at `point`.
+#[derive(Clone, Copy)]
+pub struct Query {
+    pub commitment: usize,
+    pub point: U256,
+    pub eval: U256,
+}
+
+pub fn verify_gwc(
+    transcript: &mut Transcript,
+    commitments: &[Msm],
+    queries: &[Query],
+) -> Option<(Msm, Msm)> {
+    let v = transcript.squeeze_challenge();
+
+    // Group queries by point, in order of first appearance.
+    let mut sets: Vec<(U256, Vec<Query>)> = Vec::new();
+    for query in queries {
+        match sets.iter_mut().find(|(point, _)| *point == query.point) {
+            Some((_, set)) => set.push(*query),
+            None => sets.push((query.point, vec![*query])),
+        }
+    }
+
+    let mut witnesses = Vec::with_capacity(sets.len());
+    for _ in 0..sets.len() {
+        witnesses.push(transcript.read_point()?);
+    }
+    let u = transcript.squeeze_challenge();
+
+    let mut commitment_multi = Msm::new();
+    let mut eval_multi = U256::ZERO;
+    let mut witness = Msm::new();
+    let mut witness_with_aux = Msm::new();
+
+    for ((point, set), w) in sets.iter().zip(witnesses) {
+        let mut commitment_batch = Msm::new();
+        let mut eval_batch = U256::ZERO;
+        let mut power_of_v = U256::from(1u8);
+        for query in set {
+            let mut msm = commitments[query.commitment].clone();
+            msm.scale(power_of_v);
+            commitment_batch.add_msm(&msm);
+            eval_batch = fr::add(eval_batch, fr::mul(power_of_v, query.eval));
+            power_of_v = fr::mul(power_of_v, v);
+        }
+
+        commitment_multi.scale(u);
+        commitment_multi.add_msm(&commitment_batch);
+        eval_multi = fr::add(fr::mul(eval_multi, u), eval_batch);
+
+        witness_with_aux.scale(u);
+        witness_with_aux.append_term(*point, w);
+        witness.scale(u);
+        witness.append_term(U256::from(1u8), w);
+    }
+
+    commitment_multi.append_term(fr::neg(eval_multi), G1Point::GENERATOR);
+    witness_with_aux.add_msm(&commitment_multi);
+
+    Some((witness, witness_with_aux))
+}
+
+/// Queries on one commitment, and the commitments sharing the same set of points.
+struct CommitmentData {
+    commitment: usize,
+    points: Vec<U256>,
+    evals: Vec<U256>,
+}
+
+struct RotationSet {
+    points: Vec<U256>,
+    commitments: Vec<CommitmentData>,
+}
+
+pub fn verify_shplonk(
+    transcript: &mut Transcript,
+    commitments: &[Msm],
+    queries: &[Query],
+) -> Option<(Msm, Msm)> {
+    let (rotation_sets, super_point_set) = construct_intermediate_sets(queries);
+
+    let y = transcript.squeeze_challenge();
+    let v = transcript.squeeze_challenge();
+    let h1 = transcript.read_point()?;
+    let u = transcript.squeeze_challenge();
+    let h2 = transcript.read_point()?;
+
+    let mut z_0 = U256::ZERO;
+    let mut z_0_diff_inverse = U256::ZERO;
+    let mut outer_msm = Msm::new();
+    let mut r_outer_acc = U256::ZERO;
+    let mut power_of_v = U256::from(1u8);
+
+    for (i, rotation_set) in rotation_sets.iter().enumerate() {
+        let diffs: Vec<U256> = super_point_set
+            .iter()
+            .filter(|point| !rotation_set.points.contains(point))
+            .copied()
+            .collect();
+        let mut z_diff_i = vanishing_at(&diffs, u);
+
+        // Normalise by the coefficient of the first set so that `h1` is scaled by `z_0`.
+        if i == 0 {
+            z_0 = vanishing_at(&rotation_set.points, u);
+            z_0_diff_inverse = fr::inv(z_diff_i)?;
+            z_diff_i = U256::from(1u8);
+        } else {
+            z_diff_i = fr::mul(z_diff_i, z_0_diff_inverse);
+        }
+
+        let mut inner_msm = Msm::new();
+        let mut r_inner_acc = U256::ZERO;
+        let mut power_of_y = U256::from(1u8);
+        for data in &rotation_set.commitments {
+            let basis = fr::lagrange_basis(&data.points, u)?;
+            let r_eval = data
+                .evals
+                .iter()
+                .zip(basis)
+                .fold(U256::ZERO, |acc, (e, l)| fr::add(acc, fr::mul(*e, l)));
+            r_inner_acc = fr::add(r_inner_acc, fr::mul(power_of_y, r_eval));
+
+            let mut msm = commitments[data.commitment].clone();
+            msm.scale(power_of_y);
+            inner_msm.add_msm(&msm);
+            power_of_y = fr::mul(power_of_y, y);
+        }
+
+        let factor = fr::mul(power_of_v, z_diff_i);
+        inner_msm.scale(factor);
+        outer_msm.add_msm(&inner_msm);
+        r_outer_acc = fr::add(r_outer_acc, fr::mul(factor, r_inner_acc));
+        power_of_v = fr::mul(power_of_v, v);
+    }
+
+    outer_msm.append_term(fr::neg(r_outer_acc), G1Point::GENERATOR);
+    outer_msm.append_term(fr::neg(z_0), h1);
+    outer_msm.append_term(u, h2);
+
+    Some((Msm::from_point(h2), outer_msm))
+}
+
+/// Groups queries by commitment, then commitments by their set of points; both in order of
+/// first appearance. Also returns the union of all points.
+fn construct_intermediate_sets(queries: &[Query]) -> (Vec<RotationSet>, Vec<U256>) {
+    let mut by_commitment: Vec<CommitmentData> = Vec::new();
+    let mut super_point_set: Vec<U256> = Vec::new();
+
+    for query in queries {
+        if !super_point_set.contains(&query.point) {
+            super_point_set.push(query.point);
+        }
+        match by_commitment
+            .iter_mut()
+            .find(|data| data.commitment == query.commitment)
+        {
+            Some(data) => {
+                if !data.points.contains(&query.point) {
+                    data.points.push(query.point);
+                    data.evals.push(query.eval);
+                }
+            }
+            None => by_commitment.push(CommitmentData {
+                commitment: query.commitment,
+                points: vec![query.point],
+                evals: vec![query.eval],
+            }),
+        }
+    }
+
+    let mut rotation_sets: Vec<RotationSet> = Vec::new();
+    for data in by_commitment {
+        let same_points = |set: &&mut RotationSet| {
+            set.points.len() == data.points.len()
+                && data.points.iter().all(|p| set.points.contains(p))
+        };
+        match rotation_sets.iter_mut().find(same_points) {
+            Some(set) => set.commitments.push(data),
+            None => rotation_sets.push(RotationSet {
+                points: data.points.clone(),
+                commitments: vec![data],
+            }),
+        }
+    }
+
+    (rotation_sets, super_point_set)
+}
+
+/// `∏ (x - p)` over `points`.
+fn vanishing_at(points: &[U256], x: U256) -> U256 {
+    points
+        .iter()
+        .fold(U256::from(1u8), |acc, p| fr::mul(acc, fr::sub(x, *p)))
+}
@@ -0,0 +1,92 @@
+//! Poseidon sponge over the BN254 scalar field with the absorb/squeeze semantics of the
+//! `poseidon` crate used by snark-verifier's `PoseidonTranscript`.
+
+use alloc::{vec, vec::Vec};
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::common::fr;
+use crate::halo2::protocol::PoseidonSpec;
+
+pub struct Poseidon<'a> {
+    spec: &'a PoseidonSpec,
+    state: Vec<U256>,
+    absorbing: Vec<U256>,
+}
+
+impl<'a> Poseidon<'a> {
+    pub fn new(spec: &'a PoseidonSpec) -> Self {
+        let mut state = vec![U256::ZERO; spec.t];
+        // Capacity element initialised to 2^64.
+        state[0] = U256::from(1u128 << 64);
+        Self {
+            spec,
+            state,
+            absorbing: Vec::new(),
+        }
+    }
+
+    fn rate(&self) -> usize {
+        self.spec.t - 1
+    }
+
+    pub fn update(&mut self, elements: &[U256]) {
+        self.absorbing.extend_from_slice(elements);
+        let rate = self.rate();
+        while self.absorbing.len() >= rate {
+            let chunk: Vec<U256> = self.absorbing.drain(..rate).collect();
+            self.absorb(&chunk);
+            self.permute();
+        }
+    }
+
+    pub fn squeeze(&mut self) -> U256 {
+        let mut last_chunk = core::mem::take(&mut self.absorbing);
+        // Variable-length padding, applied even when nothing is pending.
+        last_chunk.push(U256::from(1u8));
+        self.absorb(&last_chunk);
+        self.permute();
+        self.state[1]
+    }
+
+    fn absorb(&mut self, chunk: &[U256]) {
+        for (state, input) in self.state.iter_mut().skip(1).zip(chunk) {
+            *state = fr::add(*state, *input);
+        }
+    }
+
+    fn permute(&mut self) {
+        let t = self.spec.t;
+        let half_full = self.spec.r_f / 2;
+        let rounds = self.spec.r_f + self.spec.r_p;
+
+        for round in 0..rounds {
+            let constants = &self.spec.round_constants[round * t..(round + 1) * t];
+            for (s, c) in self.state.iter_mut().zip(constants) {
+                *s = fr::add(*s, *c);
+            }
+
+            if round < half_full || round >= half_full + self.spec.r_p {
+                for s in self.state.iter_mut() {
+                    *s = sbox(*s);
+                }
+            } else {
+                self.state[0] = sbox(self.state[0]);
+            }
+
+            let mut next = vec![U256::ZERO; t];
+            for (i, out) in next.iter_mut().enumerate() {
+                let row = &self.spec.mds[i * t..(i + 1) * t];
+                *out = row
+                    .iter()
+                    .zip(&self.state)
+                    .fold(U256::ZERO, |acc, (m, s)| fr::add(acc, fr::mul(*m, *s)));
+            }
+            self.state = next;
+        }
+    }
+}
+
+fn sbox(x: U256) -> U256 {
+    let x2 = fr::square(x);
+    fr::mul(fr::square(x2), x)
+}
@@ -0,0 +1,433 @@
+//! Serialized description of a halo2 circuit over BN254 with KZG commitments.
+//!
+//! halo2's `VerifyingKey` does not carry the constraint system, so the verifier consumes a
+//! protocol blob exported on the host from `vk.cs()` together with the fixed and permutation
+//! commitments. All integers are big-endian, points are uncompressed `x || y`, and `[s]_2` is
+//! in `ecPairing` limb order.
+//!
+//! ```text
+//! u8  version (= 1) | u8 k | u8 transcript | u8 multiopen
+//! 32  transcript_repr | 128 s_g2
+//! u16 blinding_factors | u16 quotient_chunks | u16 cs_degree
+//! u16 num_instance_columns
+//! u16 n, n × (u16 column, i32 rotation)            instance queries
+//! u16 n, n × u8 phase                              advice columns
+//! u16 n, n × u8 phase                              challenges
+//! u16 n, n × (u16 column, i32 rotation)            advice queries
+//! u16 n, n × 64 commitment                         fixed columns
+//! u16 n, n × (u16 column, i32 rotation)            fixed queries
+//! u16 n, n × (u8 kind, u16 query, 64 commitment)   permutation columns
+//! u16 n, n × expression                            gate polynomials
+//! u16 n, n × (u16 m, m × expression, u16 m, m × expression)   lookups (input, table)
+//! poseidon spec                                    only when transcript = Poseidon
+//! ```
+//!
+//! Expressions are postfix node lists prefixed by a `u16` node count, see [`Expression`].
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::common::{groth16::R, G1Point, G2Point};
+
+const PROTOCOL_VERSION: u8 = 1;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptKind {
+    /// Keccak-256 transcript used by EVM verifiers (halo2-solidity-verifier, snark-verifier).
+    Keccak256,
+    /// Poseidon sponge transcript used by snark-verifier for recursion-friendly proofs.
+    Poseidon,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MultiOpenScheme {
+    Gwc,
+    Shplonk,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    Advice,
+    Fixed,
+    Instance,
+}
+
+#[derive(Clone, Copy)]
+pub struct ColumnQuery {
+    pub column: usize,
+    pub rotation: i32,
+}
+
+/// A permutation column, referencing its `Rotation::cur()` query.
+#[derive(Clone, Copy)]
+pub struct PermutationColumn {
+    pub kind: ColumnKind,
+    pub query_index: usize,
+}
+
+#[derive(Clone, Copy)]
+pub enum ExpressionNode {
+    Constant(U256),
+    Fixed(usize),
+    Advice(usize),
+    Instance(usize),
+    Challenge(usize),
+    Negated,
+    Sum,
+    Product,
+    Scaled(U256),
+}
+
+/// A halo2 `Expression` in postfix form, with queries resolved to query indices.
+#[derive(Clone)]
+pub struct Expression {
+    pub nodes: Vec<ExpressionNode>,
+}
+
+pub struct Lookup {
+    pub input_expressions: Vec<Expression>,
+    pub table_expressions: Vec<Expression>,
+}
+
+/// Poseidon parameters of the sponge transcript (width `t`, rate `t - 1`), with the
+/// non-optimized round constants and MDS matrix. Points are absorbed as `limbs` limbs of
+/// `bits` bits per coordinate; `limbs = 1` absorbs the coordinate itself, reduced into `Fr`.
+pub struct PoseidonSpec {
+    pub t: usize,
+    pub r_f: usize,
+    pub r_p: usize,
+    pub limbs: usize,
+    pub bits: usize,
+    pub round_constants: Vec<U256>,
+    pub mds: Vec<U256>,
+}
+
+pub struct Protocol {
+    pub k: u32,
+    pub transcript: TranscriptKind,
+    pub multiopen: MultiOpenScheme,
+    pub transcript_repr: U256,
+    pub s_g2: G2Point,
+    pub blinding_factors: usize,
+    pub quotient_chunks: usize,
+    pub cs_degree: usize,
+    pub num_instance_columns: usize,
+    pub instance_queries: Vec<ColumnQuery>,
+    pub advice_phases: Vec<u8>,
+    pub challenge_phases: Vec<u8>,
+    pub advice_queries: Vec<ColumnQuery>,
+    pub fixed_commitments: Vec<G1Point>,
+    pub fixed_queries: Vec<ColumnQuery>,
+    pub permutation_columns: Vec<PermutationColumn>,
+    pub permutation_commitments: Vec<G1Point>,
+    pub gates: Vec<Expression>,
+    pub lookups: Vec<Lookup>,
+    pub poseidon: Option<PoseidonSpec>,
+}
+
+impl Protocol {
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(bytes);
+
+        if r.u8()? != PROTOCOL_VERSION {
+            return None;
+        }
+        let k = r.u8()? as u32;
+        if k == 0 || k > 28 {
+            return None;
+        }
+        let transcript = match r.u8()? {
+            0 => TranscriptKind::Keccak256,
+            1 => TranscriptKind::Poseidon,
+            _ => return None,
+        };
+        let multiopen = match r.u8()? {
+            0 => MultiOpenScheme::Gwc,
+            1 => MultiOpenScheme::Shplonk,
+            _ => return None,
+        };
+        let transcript_repr = r.scalar()?;
+        let s_g2 = r.g2()?;
+        let blinding_factors = r.u16()? as usize;
+        let quotient_chunks = r.u16()? as usize;
+        let cs_degree = r.u16()? as usize;
+        // The blinding rows and the last row must leave at least one usable row.
+        if cs_degree < 3 || blinding_factors + 1 >= 1 << k {
+            return None;
+        }
+
+        let num_instance_columns = r.u16()? as usize;
+        let instance_queries = r.queries()?;
+        let advice_phases = r.list(|r| r.u8())?;
+        let challenge_phases = r.list(|r| r.u8())?;
+        let advice_queries = r.queries()?;
+        let fixed_commitments = r.list(|r| r.g1())?;
+        let fixed_queries = r.queries()?;
+
+        let n = r.u16()? as usize;
+        let mut permutation_columns = Vec::with_capacity(n);
+        let mut permutation_commitments = Vec::with_capacity(n);
+        for _ in 0..n {
+            let kind = match r.u8()? {
+                0 => ColumnKind::Advice,
+                1 => ColumnKind::Fixed,
+                2 => ColumnKind::Instance,
+                _ => return None,
+            };
+            let query_index = r.u16()? as usize;
+            permutation_columns.push(PermutationColumn { kind, query_index });
+            permutation_commitments.push(r.g1()?);
+        }
+
+        let gates = r.list(|r| r.expression())?;
+        let lookups = r.list(|r| {
+            Some(Lookup {
+                input_expressions: r.list(|r| r.expression())?,
+                table_expressions: r.list(|r| r.expression())?,
+            })
+        })?;
+
+        let poseidon = match transcript {
+            TranscriptKind::Poseidon => Some(r.poseidon_spec()?),
+            TranscriptKind::Keccak256 => None,
+        };
+
+        if !r.is_empty() {
+            return None;
+        }
+
+        let protocol = Protocol {
+            k,
+            transcript,
+            multiopen,
+            transcript_repr,
+            s_g2,
+            blinding_factors,
+            quotient_chunks,
+            cs_degree,
+            num_instance_columns,
+            instance_queries,
+            advice_phases,
+            challenge_phases,
+            advice_queries,
+            fixed_commitments,
+            fixed_queries,
+            permutation_columns,
+            permutation_commitments,
+            gates,
+            lookups,
+            poseidon,
+        };
+
+        protocol.is_consistent().then_some(protocol)
+    }
+
+    /// Checks that every column and query reference is in range.
+    fn is_consistent(&self) -> bool {
+        let columns_ok =
+            |queries: &[ColumnQuery], columns: usize| queries.iter().all(|q| q.column < columns);
+        let expression_ok = |e: &Expression| {
+            e.nodes.iter().all(|node| match *node {
+                ExpressionNode::Fixed(i) => i < self.fixed_queries.len(),
+                ExpressionNode::Advice(i) => i < self.advice_queries.len(),
+                ExpressionNode::Instance(i) => i < self.instance_queries.len(),
+                ExpressionNode::Challenge(i) => i < self.challenge_phases.len(),
+                _ => true,
+            })
+        };
+
+        columns_ok(&self.instance_queries, self.num_instance_columns)
+            && columns_ok(&self.advice_queries, self.advice_phases.len())
+            && columns_ok(&self.fixed_queries, self.fixed_commitments.len())
+            && self.permutation_columns.iter().all(|c| match c.kind {
+                ColumnKind::Advice => c.query_index < self.advice_queries.len(),
+                ColumnKind::Fixed => c.query_index < self.fixed_queries.len(),
+                ColumnKind::Instance => c.query_index < self.instance_queries.len(),
+            })
+            && self.gates.iter().all(expression_ok)
+            && self.lookups.iter().all(|l| {
+                l.input_expressions.len() == l.table_expressions.len()
+                    && l.input_expressions.iter().all(expression_ok)
+                    && l.table_expressions.iter().all(expression_ok)
+            })
+    }
+
+    pub fn num_phases(&self) -> u8 {
+        self.advice_phases
+            .iter()
+            .chain(&self.challenge_phases)
+            .copied()
+            .max()
+            .map_or(0, |max| max + 1)
+    }
+
+    /// Number of permutation product polynomials, one per `cs_degree - 2` columns.
+    pub fn permutation_chunk_len(&self) -> usize {
+        self.cs_degree - 2
+    }
+
+    pub fn num_permutation_sets(&self) -> usize {
+        self.permutation_columns
+            .len()
+            .div_ceil(self.permutation_chunk_len())
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let b = self.take(2)?;
+        Some(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        let b = self.take(4)?;
+        Some(i32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn u256(&mut self) -> Option<U256> {
+        Some(U256::from_be_slice(self.take(32)?))
+    }
+
+    fn scalar(&mut self) -> Option<U256> {
+        self.u256().filter(|x| *x < R)
+    }
+
+    fn g1(&mut self) -> Option<G1Point> {
+        Some(G1Point {
+            x: self.u256()?,
+            y: self.u256()?,
+        })
+    }
+
+    fn g2(&mut self) -> Option<G2Point> {
+        Some(G2Point {
+            x: [self.u256()?, self.u256()?],
+            y: [self.u256()?, self.u256()?],
+        })
+    }
+
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Option<T>) -> Option<Vec<T>> {
+        let n = self.u16()? as usize;
+        let mut out = Vec::with_capacity(n);
+        for _ in 0..n {
+            out.push(item(self)?);
+        }
+        Some(out)
+    }
+
+    fn queries(&mut self) -> Option<Vec<ColumnQuery>> {
+        self.list(|r| {
+            Some(ColumnQuery {
+                column: r.u16()? as usize,
+                rotation: r.i32()?,
+            })
+        })
+    }
+
+    fn expression(&mut self) -> Option<Expression> {
+        let n = self.u16()? as usize;
+        let mut nodes = Vec::with_capacity(n);
+        let mut depth = 0usize;
+        for _ in 0..n {
+            let node = match self.u8()? {
+                0 => ExpressionNode::Constant(self.scalar()?),
+                1 => ExpressionNode::Fixed(self.u16()? as usize),
+                2 => ExpressionNode::Advice(self.u16()? as usize),
+                3 => ExpressionNode::Instance(self.u16()? as usize),
+                4 => ExpressionNode::Challenge(self.u16()? as usize),
+                5 => ExpressionNode::Negated,
+                6 => ExpressionNode::Sum,
+                7 => ExpressionNode::Product,
+                8 => ExpressionNode::Scaled(self.scalar()?),
+                _ => return None,
+            };
+            // Reject programs that would underflow the evaluation stack.
+            depth = match node {
+                ExpressionNode::Negated | ExpressionNode::Scaled(_) => depth.checked_sub(1)? + 1,
+                ExpressionNode::Sum | ExpressionNode::Product => depth.checked_sub(2)? + 1,
+                _ => depth + 1,
+            };
+            nodes.push(node);
+        }
+        (depth == 1).then_some(Expression { nodes })
+    }
+
+    fn poseidon_spec(&mut self) -> Option<PoseidonSpec> {
+        let t = self.u8()? as usize;
+        let r_f = self.u8()? as usize;
+        let r_p = self.u8()? as usize;
+        let limbs = self.u8()? as usize;
+        let bits = self.u8()? as usize;
+        if t < 2 || r_f % 2 == 1 || limbs == 0 || bits == 0 || limbs * bits < 254 {
+            return None;
+        }
+
+        let mut round_constants = Vec::with_capacity((r_f + r_p) * t);
+        for _ in 0..(r_f + r_p) * t {
+            round_constants.push(self.scalar()?);
+        }
+        let mut mds = Vec::with_capacity(t * t);
+        for _ in 0..t * t {
+            mds.push(self.scalar()?);
+        }
+
+        Some(PoseidonSpec {
+            t,
+            r_f,
+            r_p,
+            limbs,
+            bits,
+            round_constants,
+            mds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Keccak/GWC protocol with no columns.
+    fn empty_protocol(k: u8, blinding_factors: u16) -> Vec<u8> {
+        let mut bytes = vec![PROTOCOL_VERSION, k, 0, 0];
+        bytes.extend([0u8; 32 + 128]);
+        for word in [blinding_factors, 1, 3, 0] {
+            bytes.extend(word.to_be_bytes());
+        }
+        // Instance queries through lookups, all empty.
+        bytes.extend([0u8; 9 * 2]);
+        bytes
+    }
+
+    #[test]
+    fn rejects_blinding_factors_covering_the_domain() {
+        assert!(Protocol::from_bytes(&empty_protocol(4, 14)).is_some());
+        assert!(Protocol::from_bytes(&empty_protocol(4, 15)).is_none());
+        assert!(Protocol::from_bytes(&empty_protocol(4, u16::MAX)).is_none());
+    }
+}
@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+use stylus_sdk::{alloy_primitives::U256, crypto::keccak};
+
+use crate::common::{
+    fr,
+    groth16::{Q, R},
+    G1Point,
+};
+use crate::halo2::{
+    poseidon::Poseidon,
+    protocol::{PoseidonSpec, Protocol, TranscriptKind},
+};
+
+enum State<'a> {
+    /// EVM transcript: big-endian `x || y` points and scalars; a squeeze hashes the buffer
+    /// (plus a `0x01` byte when only the previous challenge is pending) and keeps the hash.
+    Keccak { buf: Vec<u8> },
+    /// snark-verifier Poseidon transcript: compressed little-endian points, points absorbed
+    /// as base-field limbs. A single limb absorbs each coordinate reduced into `Fr`, as
+    /// axiom's snark-verifier does.
+    Poseidon {
+        sponge: Poseidon<'a>,
+        spec: &'a PoseidonSpec,
+    },
+}
+
+pub struct Transcript<'a> {
+    proof: &'a [u8],
+    state: State<'a>,
+}
+
+impl<'a> Transcript<'a> {
+    pub fn new(protocol: &'a Protocol, proof: &'a [u8]) -> Option<Self> {
+        let state = match protocol.transcript {
+            TranscriptKind::Keccak256 => State::Keccak { buf: Vec::new() },
+            TranscriptKind::Poseidon => {
+                let spec = protocol.poseidon.as_ref()?;
+                State::Poseidon {
+                    sponge: Poseidon::new(spec),
+                    spec,
+                }
+            }
+        };
+        Some(Self { proof, state })
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.proof.is_empty()
+    }
+
+    pub fn common_scalar(&mut self, scalar: U256) {
+        match &mut self.state {
+            State::Keccak { buf } => buf.extend_from_slice(&scalar.to_be_bytes::<32>()),
+            State::Poseidon { sponge, .. } => sponge.update(&[scalar]),
+        }
+    }
+
+    pub fn common_point(&mut self, point: &G1Point) {
+        match &mut self.state {
+            State::Keccak { buf } => {
+                buf.extend_from_slice(&point.x.to_be_bytes::<32>());
+                buf.extend_from_slice(&point.y.to_be_bytes::<32>());
+            }
+            State::Poseidon { sponge, spec } => {
+                let mut limbs = to_limbs(point.x, spec.limbs, spec.bits);
+                limbs.extend(to_limbs(point.y, spec.limbs, spec.bits));
+                sponge.update(&limbs);
+            }
+        }
+    }
+
+    pub fn squeeze_challenge(&mut self) -> U256 {
+        match &mut self.state {
+            State::Keccak { buf } => {
+                if buf.len() == 0x20 {
+                    buf.push(1);
+                }
+                let hash = keccak(&buf);
+                buf.clear();
+                buf.extend_from_slice(hash.as_slice());
+                fr::from_be_bytes_mod_order(hash.as_slice())
+            }
+            State::Poseidon { sponge, .. } => sponge.squeeze(),
+        }
+    }
+
+    pub fn read_scalar(&mut self) -> Option<U256> {
+        let bytes = self.take(32)?;
+        let scalar = match self.state {
+            State::Keccak { .. } => U256::from_be_slice(bytes),
+            State::Poseidon { .. } => U256::from_le_slice(bytes),
+        };
+        if scalar >= R {
+            return None;
+        }
+        self.common_scalar(scalar);
+        Some(scalar)
+    }
+
+    pub fn read_point(&mut self) -> Option<G1Point> {
+        let point = match self.state {
+            State::Keccak { .. } => {
+                let bytes = self.take(64)?;
+                let point = G1Point {
+                    x: U256::from_be_slice(&bytes[..32]),
+                    y: U256::from_be_slice(&bytes[32..]),
+                };
+                if point.x >= Q || point.y >= Q {
+                    return None;
+                }
+                point
+            }
+            State::Poseidon { .. } => decompress(self.take(32)?)?,
+        };
+        self.common_point(&point);
+        Some(point)
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.proof.len() < n {
+            return None;
+        }
+        let (head, tail) = self.proof.split_at(n);
+        self.proof = tail;
+        Some(head)
+    }
+}
+
+/// Splits a base-field coordinate into `limbs` little-endian limbs of `bits` bits.
+fn to_limbs(value: U256, limbs: usize, bits: usize) -> Vec<U256> {
+    let mask = (U256::from(1u8) << bits) - U256::from(1u8);
+    (0..limbs).map(|i| (value >> (i * bits)) & mask).collect()
+}
+
+/// Decodes a halo2curves compressed G1 point: little-endian `x` with the parity of `y` in
+/// the top bit, or from halo2curves 0.7 on in bit 254 below an identity flag. The two
+/// layouts cannot be confused as `x < 2^254`. The identity is rejected as it cannot be
+/// absorbed, and no other point has `x = 0`.
+fn decompress(bytes: &[u8]) -> Option<G1Point> {
+    let mut repr = [0u8; 32];
+    repr.copy_from_slice(bytes);
+    let sign = match repr[31] >> 6 {
+        0b00 => 0,
+        0b01 | 0b10 => 1,
+        _ => return None,
+    };
+    repr[31] &= 0x3f;
+
+    let x = U256::from_le_bytes(repr);
+    if x >= Q || x.is_zero() {
+        return None;
+    }
+
+    // y^2 = x^3 + 3, and q ≡ 3 (mod 4) so y = (y^2)^((q + 1) / 4).
+    let y2 = x.mul_mod(x, Q).mul_mod(x, Q).add_mod(U256::from(3u8), Q);
+    let mut y = y2.pow_mod((Q + U256::from(1u8)) >> 2, Q);
+    if y.mul_mod(y, Q) != y2 {
+        return None;
+    }
+    if (y.bit(0) as u8) != sign {
+        y = Q - y;
+    }
+
+    Some(G1Point { x, y })
+}
@@ -0,0 +1,906 @@
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{uint, Address, B256, U256},
+    crypto::keccak,
+    prelude::*,
+};
+
+use crate::common::{
+    fr,
+    groth16::R,
+    ownable::{IOwnable, Ownable},
+    G1Point, KzgVerifier,
+};
+use crate::halo2::{
+    errors::Halo2Error,
+    multiopen::{verify_gwc, verify_shplonk, Msm, Query},
+    protocol::{ColumnKind, Expression, ExpressionNode, MultiOpenScheme, Protocol},
+    transcript::Transcript,
+};
+
+/// Generator of the `2^28` roots of unity of BN254 `Fr`, as in halo2curves.
+const ROOT_OF_UNITY: U256 =
+    uint!(0x03ddb9f5166d18b798865ea93dd31f743215cf6dd39329c8d34f1ed960c37c9c_U256);
+const S: u32 = 28;
+/// Generator of the `2^S`-th roots of unity coset used by the permutation argument.
+const DELTA: U256 = uint!(0x09226b6e22c6f0ca64ec26aad4c86e715b5f898e5e963f25870e56bbe533e9a2_U256);
+
+pub trait IHalo2Verifier {
+    type Error;
+
+    /// Imports the circuit's protocol blob (see [`Protocol`]); owner-only and callable
+    /// once.
+    fn initialize(&mut self, protocol: Vec<u8>) -> Result<(), Self::Error>;
+
+    fn verify_proof(&self, proof: Vec<u8>, instances: Vec<Vec<U256>>) -> Result<(), Self::Error>;
+
+    fn get_protocol_hash(&self) -> B256;
+    fn is_initialized(&self) -> bool;
+}
+
+sol_storage! {
+    /// Verifier for halo2 (PSE fork) proofs over BN254 with KZG commitments, supporting the
+    /// GWC and SHPLONK multi-open schemes and Keccak or Poseidon transcripts.
+    ///
+    /// Single-proof verification only; circuits using shuffle arguments are not supported.
+    pub struct Halo2Verifier {
+        bytes protocol;
+        bytes32 protocol_hash;
+        bool initialized;
+        Ownable ownable;
+    }
+}
+
+#[public]
+impl IHalo2Verifier for Halo2Verifier {
+    type Error = Vec<u8>;
+
+    fn initialize(&mut self, protocol: Vec<u8>) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        if self.initialized.get() {
+            return Err(Halo2Error::ALREADY_INITIALIZED.abi_encode());
+        }
+        if Protocol::from_bytes(&protocol).is_none() {
+            return Err(Halo2Error::InvalidProtocol.abi_encode());
+        }
+
+        self.protocol_hash.set(keccak(&protocol));
+        self.protocol.set_bytes(&protocol);
+        self.initialized.set(true);
+
+        Ok(())
+    }
+
+    fn verify_proof(&self, proof: Vec<u8>, instances: Vec<Vec<U256>>) -> Result<(), Self::Error> {
+        if !self.initialized.get() {
+            return Err(Halo2Error::INVALID_INITIALIZATION.abi_encode());
+        }
+
+        let protocol = Protocol::from_bytes(&self.protocol.get_bytes())
+            .ok_or_else(|| Halo2Error::InvalidProtocol.abi_encode())?;
+        verify_proof_with_protocol(&protocol, &proof, &instances).map_err(|e| e.abi_encode())
+    }
+
+    fn get_protocol_hash(&self) -> B256 {
+        self.protocol_hash.get()
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.initialized.get()
+    }
+}
+
+#[public]
+impl IOwnable for Halo2Verifier {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.ownable.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.ownable
+            .transfer_ownership(new_owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.ownable.accept_ownership().map_err(|e| e.abi_encode())
+    }
+}
+
+impl Halo2Verifier {
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable
+            .set_initial_owner(owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn only_owner(&self) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner().map_err(|e| e.abi_encode())
+    }
+}
+
+struct Challenges {
+    theta: U256,
+    beta: U256,
+    gamma: U256,
+    y: U256,
+    x: U256,
+    phase: Vec<U256>,
+}
+
+struct PermutationSet {
+    commitment: G1Point,
+    eval: U256,
+    next_eval: U256,
+    last_eval: Option<U256>,
+}
+
+struct LookupData {
+    permuted_input: G1Point,
+    permuted_table: G1Point,
+    product: G1Point,
+    product_eval: U256,
+    product_next_eval: U256,
+    permuted_input_eval: U256,
+    permuted_input_inv_eval: U256,
+    permuted_table_eval: U256,
+}
+
+/// Evaluations of the circuit's queries at `x`.
+struct Evaluations<'a> {
+    advice: &'a [U256],
+    fixed: &'a [U256],
+    instance: &'a [U256],
+    challenges: &'a [U256],
+}
+
+impl Evaluations<'_> {
+    fn evaluate(&self, expression: &Expression) -> U256 {
+        let mut stack: Vec<U256> = Vec::with_capacity(expression.nodes.len());
+        for node in &expression.nodes {
+            let value = match *node {
+                ExpressionNode::Constant(c) => c,
+                ExpressionNode::Fixed(i) => self.fixed[i],
+                ExpressionNode::Advice(i) => self.advice[i],
+                ExpressionNode::Instance(i) => self.instance[i],
+                ExpressionNode::Challenge(i) => self.challenges[i],
+                // Stack depth is validated when the protocol is decoded.
+                ExpressionNode::Negated => fr::neg(stack.pop().unwrap_or_default()),
+                ExpressionNode::Scaled(c) => fr::mul(stack.pop().unwrap_or_default(), c),
+                ExpressionNode::Sum | ExpressionNode::Product => {
+                    let b = stack.pop().unwrap_or_default();
+                    let a = stack.pop().unwrap_or_default();
+                    if matches!(node, ExpressionNode::Sum) {
+                        fr::add(a, b)
+                    } else {
+                        fr::mul(a, b)
+                    }
+                }
+            };
+            stack.push(value);
+        }
+        stack.pop().unwrap_or_default()
+    }
+
+    fn column(&self, kind: ColumnKind, query_index: usize) -> U256 {
+        match kind {
+            ColumnKind::Advice => self.advice[query_index],
+            ColumnKind::Fixed => self.fixed[query_index],
+            ColumnKind::Instance => self.instance[query_index],
+        }
+    }
+}
+
+/// Evaluation domain of size `n = 2^k`.
+struct Domain {
+    n: u64,
+    omega: U256,
+    omega_inv: U256,
+}
+
+impl Domain {
+    fn new(k: u32) -> Option<Self> {
+        let omega = fr::pow(ROOT_OF_UNITY, U256::from(1u64 << (S - k)));
+        Some(Self {
+            n: 1 << k,
+            omega,
+            omega_inv: fr::inv(omega)?,
+        })
+    }
+
+    /// `ω^rotation · x`.
+    fn rotate(&self, x: U256, rotation: i32) -> U256 {
+        let base = if rotation < 0 {
+            self.omega_inv
+        } else {
+            self.omega
+        };
+        fr::mul(x, fr::pow(base, U256::from(rotation.unsigned_abs())))
+    }
+
+    /// `L_i(x) = ω^i (x^n - 1) / (n (x - ω^i))` for each `i` in `indices`.
+    fn lagrange_evals(&self, x: U256, xn: U256, indices: &[i32]) -> Option<Vec<U256>> {
+        let points: Vec<U256> = indices
+            .iter()
+            .map(|&i| self.rotate(U256::from(1u8), i))
+            .collect();
+        let denominators: Vec<U256> = points
+            .iter()
+            .map(|p| fr::mul(U256::from(self.n), fr::sub(x, *p)))
+            .collect();
+        let inverses = fr::batch_inverse(&denominators)?;
+        let common = fr::sub(xn, U256::from(1u8));
+        Some(
+            points
+                .iter()
+                .zip(inverses)
+                .map(|(p, d)| fr::mul(fr::mul(*p, common), d))
+                .collect(),
+        )
+    }
+}
+
+/// Verifies a halo2 proof against `protocol`, with one vector per instance column.
+pub fn verify_proof_with_protocol(
+    protocol: &Protocol,
+    proof: &[u8],
+    instances: &[Vec<U256>],
+) -> Result<(), Halo2Error> {
+    if instances.len() != protocol.num_instance_columns {
+        return Err(Halo2Error::InstancesLengthMismatch {
+            received: U256::from(instances.len()),
+            expected: U256::from(protocol.num_instance_columns),
+        });
+    }
+
+    let domain = Domain::new(protocol.k).ok_or(Halo2Error::InvalidProtocol)?;
+    let usable_rows = domain.n as usize - (protocol.blinding_factors + 1);
+    if instances
+        .iter()
+        .any(|column| column.len() > usable_rows || column.iter().any(|v| *v >= R))
+    {
+        return Err(Halo2Error::INVALID_PROOF_DATA);
+    }
+
+    let mut transcript = Transcript::new(protocol, proof).ok_or(Halo2Error::InvalidProtocol)?;
+
+    let verified = verify_with_transcript(protocol, &domain, &mut transcript, instances)
+        .ok_or(Halo2Error::INVALID_PROOF_DATA)?;
+
+    if !transcript.is_exhausted() {
+        return Err(Halo2Error::INVALID_PROOF_DATA);
+    }
+    if !verified {
+        return Err(Halo2Error::VERIFICATION_FAILED);
+    }
+
+    Ok(())
+}
+
+/// Replays the prover's transcript and checks the final pairing. `None` if the proof is
+/// malformed or a precompile call fails.
+fn verify_with_transcript(
+    protocol: &Protocol,
+    domain: &Domain,
+    transcript: &mut Transcript,
+    instances: &[Vec<U256>],
+) -> Option<bool> {
+    transcript.common_scalar(protocol.transcript_repr);
+    for column in instances {
+        for value in column {
+            transcript.common_scalar(*value);
+        }
+    }
+
+    // Advice commitments and challenges, phase by phase.
+    let mut advice_commitments = vec![G1Point::ZERO; protocol.advice_phases.len()];
+    let mut phase_challenges = vec![U256::ZERO; protocol.challenge_phases.len()];
+    for phase in 0..protocol.num_phases() {
+        for (column_phase, commitment) in protocol
+            .advice_phases
+            .iter()
+            .zip(advice_commitments.iter_mut())
+        {
+            if *column_phase == phase {
+                *commitment = transcript.read_point()?;
+            }
+        }
+        for (challenge_phase, challenge) in protocol
+            .challenge_phases
+            .iter()
+            .zip(phase_challenges.iter_mut())
+        {
+            if *challenge_phase == phase {
+                *challenge = transcript.squeeze_challenge();
+            }
+        }
+    }
+
+    let theta = transcript.squeeze_challenge();
+
+    let mut lookups = Vec::with_capacity(protocol.lookups.len());
+    for _ in &protocol.lookups {
+        lookups.push(LookupData {
+            permuted_input: transcript.read_point()?,
+            permuted_table: transcript.read_point()?,
+            product: G1Point::ZERO,
+            product_eval: U256::ZERO,
+            product_next_eval: U256::ZERO,
+            permuted_input_eval: U256::ZERO,
+            permuted_input_inv_eval: U256::ZERO,
+            permuted_table_eval: U256::ZERO,
+        });
+    }
+
+    let beta = transcript.squeeze_challenge();
+    let gamma = transcript.squeeze_challenge();
+
+    let mut permutation_sets = Vec::with_capacity(protocol.num_permutation_sets());
+    for _ in 0..protocol.num_permutation_sets() {
+        permutation_sets.push(PermutationSet {
+            commitment: transcript.read_point()?,
+            eval: U256::ZERO,
+            next_eval: U256::ZERO,
+            last_eval: None,
+        });
+    }
+
+    for lookup in lookups.iter_mut() {
+        lookup.product = transcript.read_point()?;
+    }
+
+    let random_commitment = transcript.read_point()?;
+    let y = transcript.squeeze_challenge();
+
+    let mut h_commitments = Vec::with_capacity(protocol.quotient_chunks);
+    for _ in 0..protocol.quotient_chunks {
+        h_commitments.push(transcript.read_point()?);
+    }
+
+    let x = transcript.squeeze_challenge();
+    let challenges = Challenges {
+        theta,
+        beta,
+        gamma,
+        y,
+        x,
+        phase: phase_challenges,
+    };
+
+    let xn = fr::pow(x, U256::from(domain.n));
+    let instance_evals = instance_evals(protocol, domain, &challenges, xn, instances)?;
+
+    let advice_evals = read_scalars(transcript, protocol.advice_queries.len())?;
+    let fixed_evals = read_scalars(transcript, protocol.fixed_queries.len())?;
+    let random_eval = transcript.read_scalar()?;
+    let permutation_common_evals = read_scalars(transcript, protocol.permutation_columns.len())?;
+
+    let num_sets = permutation_sets.len();
+    for (i, set) in permutation_sets.iter_mut().enumerate() {
+        set.eval = transcript.read_scalar()?;
+        set.next_eval = transcript.read_scalar()?;
+        if i + 1 < num_sets {
+            set.last_eval = Some(transcript.read_scalar()?);
+        }
+    }
+
+    for lookup in lookups.iter_mut() {
+        lookup.product_eval = transcript.read_scalar()?;
+        lookup.product_next_eval = transcript.read_scalar()?;
+        lookup.permuted_input_eval = transcript.read_scalar()?;
+        lookup.permuted_input_inv_eval = transcript.read_scalar()?;
+        lookup.permuted_table_eval = transcript.read_scalar()?;
+    }
+
+    let evals = Evaluations {
+        advice: &advice_evals,
+        fixed: &fixed_evals,
+        instance: &instance_evals,
+        challenges: &challenges.phase,
+    };
+
+    // Vanishing argument: fold every constraint with `y` and divide by `x^n - 1`.
+    let bf = protocol.blinding_factors as i32;
+    let indices: Vec<i32> = (-(bf + 1)..=0).collect();
+    let l_evals = domain.lagrange_evals(x, xn, &indices)?;
+    let l_last = l_evals[0];
+    let l_blind = l_evals[1..=protocol.blinding_factors]
+        .iter()
+        .fold(U256::ZERO, |acc, l| fr::add(acc, *l));
+    let l_0 = l_evals[protocol.blinding_factors + 1];
+
+    let mut constraints: Vec<U256> = protocol.gates.iter().map(|g| evals.evaluate(g)).collect();
+    permutation_constraints(
+        protocol,
+        &evals,
+        &challenges,
+        &permutation_sets,
+        &permutation_common_evals,
+        [l_0, l_last, l_blind],
+        &mut constraints,
+    );
+    lookup_constraints(
+        protocol,
+        &evals,
+        &challenges,
+        &lookups,
+        [l_0, l_last, l_blind],
+        &mut constraints,
+    );
+
+    let expected_h_eval = fr::mul(
+        constraints
+            .iter()
+            .fold(U256::ZERO, |acc, c| fr::add(fr::mul(acc, challenges.y), *c)),
+        fr::inv(fr::sub(xn, U256::from(1u8)))?,
+    );
+    let h_commitment = h_commitments.iter().rev().fold(Msm::new(), |mut acc, h| {
+        acc.scale(xn);
+        acc.append_term(U256::from(1u8), *h);
+        acc
+    });
+
+    // Collect the opening claims in the order of halo2's verifier.
+    let x_next = domain.rotate(x, 1);
+    let x_prev = domain.rotate(x, -1);
+    let x_last = domain.rotate(x, -(protocol.blinding_factors as i32 + 1));
+    let mut claims = OpeningClaims::default();
+
+    let advice: Vec<usize> = advice_commitments
+        .iter()
+        .map(|c| claims.commit(*c))
+        .collect();
+    for (query, eval) in protocol.advice_queries.iter().zip(&advice_evals) {
+        claims.open(
+            advice[query.column],
+            domain.rotate(x, query.rotation),
+            *eval,
+        );
+    }
+
+    let sets: Vec<usize> = permutation_sets
+        .iter()
+        .map(|s| claims.commit(s.commitment))
+        .collect();
+    for (set, index) in permutation_sets.iter().zip(&sets) {
+        claims.open(*index, x, set.eval);
+        claims.open(*index, x_next, set.next_eval);
+    }
+    for (set, index) in permutation_sets.iter().zip(&sets).rev().skip(1) {
+        claims.open(*index, x_last, set.last_eval?);
+    }
+
+    for lookup in &lookups {
+        let product = claims.commit(lookup.product);
+        let input = claims.commit(lookup.permuted_input);
+        let table = claims.commit(lookup.permuted_table);
+        claims.open(product, x, lookup.product_eval);
+        claims.open(input, x, lookup.permuted_input_eval);
+        claims.open(table, x, lookup.permuted_table_eval);
+        claims.open(input, x_prev, lookup.permuted_input_inv_eval);
+        claims.open(product, x_next, lookup.product_next_eval);
+    }
+
+    let fixed: Vec<usize> = protocol
+        .fixed_commitments
+        .iter()
+        .map(|c| claims.commit(*c))
+        .collect();
+    for (query, eval) in protocol.fixed_queries.iter().zip(&fixed_evals) {
+        claims.open(fixed[query.column], domain.rotate(x, query.rotation), *eval);
+    }
+
+    for (commitment, eval) in protocol
+        .permutation_commitments
+        .iter()
+        .zip(&permutation_common_evals)
+    {
+        let index = claims.commit(*commitment);
+        claims.open(index, x, *eval);
+    }
+
+    let h = claims.commit_msm(h_commitment);
+    claims.open(h, x, expected_h_eval);
+    let random = claims.commit(random_commitment);
+    claims.open(random, x, random_eval);
+
+    let (left, right) = match protocol.multiopen {
+        MultiOpenScheme::Gwc => verify_gwc(transcript, &claims.commitments, &claims.queries)?,
+        MultiOpenScheme::Shplonk => {
+            verify_shplonk(transcript, &claims.commitments, &claims.queries)?
+        }
+    };
+
+    let (left, right) = (left.evaluate()?, right.evaluate()?);
+    Some(KzgVerifier::new(protocol.s_g2).verify_accumulator(&left, &right))
+}
+
+/// Instance column evaluations at the rotated points, `Σ_j v_j · L_{j - rotation}(x)`.
+fn instance_evals(
+    protocol: &Protocol,
+    domain: &Domain,
+    challenges: &Challenges,
+    xn: U256,
+    instances: &[Vec<U256>],
+) -> Option<Vec<U256>> {
+    let mut evals = Vec::with_capacity(protocol.instance_queries.len());
+    for query in &protocol.instance_queries {
+        let column = &instances[query.column];
+        let indices: Vec<i32> = (0..column.len() as i32)
+            .map(|j| j - query.rotation)
+            .collect();
+        let basis = domain.lagrange_evals(challenges.x, xn, &indices)?;
+        evals.push(
+            column
+                .iter()
+                .zip(basis)
+                .fold(U256::ZERO, |acc, (v, l)| fr::add(acc, fr::mul(*v, l))),
+        );
+    }
+    Some(evals)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn permutation_constraints(
+    protocol: &Protocol,
+    evals: &Evaluations,
+    challenges: &Challenges,
+    sets: &[PermutationSet],
+    common_evals: &[U256],
+    [l_0, l_last, l_blind]: [U256; 3],
+    out: &mut Vec<U256>,
+) {
+    let one = U256::from(1u8);
+    let (Some(first), Some(last)) = (sets.first(), sets.last()) else {
+        return;
+    };
+
+    // l_0(X) · (1 - z_0(X))
+    out.push(fr::mul(l_0, fr::sub(one, first.eval)));
+    // l_last(X) · (z_l(X)^2 - z_l(X))
+    out.push(fr::mul(fr::sub(fr::square(last.eval), last.eval), l_last));
+    // l_0(X) · (z_i(X) - z_{i-1}(ω^last X))
+    for (set, previous) in sets.iter().skip(1).zip(sets) {
+        let previous_last = previous.last_eval.unwrap_or_default();
+        out.push(fr::mul(fr::sub(set.eval, previous_last), l_0));
+    }
+
+    // (1 - (l_last + l_blind)) · (z_i(ωX) ∏ (p + β s + γ) - z_i(X) ∏ (p + δ^j β X + γ))
+    let active_rows = fr::sub(one, fr::add(l_last, l_blind));
+    let chunk_len = protocol.permutation_chunk_len();
+    for (chunk_index, ((set, columns), permutation_evals)) in sets
+        .iter()
+        .zip(protocol.permutation_columns.chunks(chunk_len))
+        .zip(common_evals.chunks(chunk_len))
+        .enumerate()
+    {
+        let mut left = set.next_eval;
+        let mut right = set.eval;
+        let mut current_delta = fr::mul(
+            fr::mul(challenges.beta, challenges.x),
+            fr::pow(DELTA, U256::from(chunk_index * chunk_len)),
+        );
+        for (column, permutation_eval) in columns.iter().zip(permutation_evals) {
+            let eval = evals.column(column.kind, column.query_index);
+            left = fr::mul(
+                left,
+                fr::add(
+                    fr::add(eval, fr::mul(challenges.beta, *permutation_eval)),
+                    challenges.gamma,
+                ),
+            );
+            right = fr::mul(
+                right,
+                fr::add(fr::add(eval, current_delta), challenges.gamma),
+            );
+            current_delta = fr::mul(current_delta, DELTA);
+        }
+        out.push(fr::mul(fr::sub(left, right), active_rows));
+    }
+}
+
+fn lookup_constraints(
+    protocol: &Protocol,
+    evals: &Evaluations,
+    challenges: &Challenges,
+    lookups: &[LookupData],
+    [l_0, l_last, l_blind]: [U256; 3],
+    out: &mut Vec<U256>,
+) {
+    let one = U256::from(1u8);
+    let active_rows = fr::sub(one, fr::add(l_last, l_blind));
+    let compress = |expressions: &[Expression]| {
+        expressions.iter().fold(U256::ZERO, |acc, e| {
+            fr::add(fr::mul(acc, challenges.theta), evals.evaluate(e))
+        })
+    };
+
+    for (lookup, argument) in lookups.iter().zip(&protocol.lookups) {
+        let input_minus_table = fr::sub(lookup.permuted_input_eval, lookup.permuted_table_eval);
+
+        // l_0(X) · (1 - z(X))
+        out.push(fr::mul(l_0, fr::sub(one, lookup.product_eval)));
+        // l_last(X) · (z(X)^2 - z(X))
+        out.push(fr::mul(
+            l_last,
+            fr::sub(fr::square(lookup.product_eval), lookup.product_eval),
+        ));
+        // (1 - (l_last + l_blind)) · (z(ωX)(a' + β)(s' + γ) - z(X)(A + β)(S + γ))
+        let left = fr::mul(
+            fr::mul(
+                lookup.product_next_eval,
+                fr::add(lookup.permuted_input_eval, challenges.beta),
+            ),
+            fr::add(lookup.permuted_table_eval, challenges.gamma),
+        );
+        let right = fr::mul(
+            fr::mul(
+                lookup.product_eval,
+                fr::add(compress(&argument.input_expressions), challenges.beta),
+            ),
+            fr::add(compress(&argument.table_expressions), challenges.gamma),
+        );
+        out.push(fr::mul(fr::sub(left, right), active_rows));
+        // l_0(X) · (a'(X) - s'(X))
+        out.push(fr::mul(l_0, input_minus_table));
+        // (1 - (l_last + l_blind)) · (a'(X) - s'(X)) · (a'(X) - a'(ω^-1 X))
+        out.push(fr::mul(
+            fr::mul(
+                input_minus_table,
+                fr::sub(lookup.permuted_input_eval, lookup.permuted_input_inv_eval),
+            ),
+            active_rows,
+        ));
+    }
+}
+
+/// Commitments and the points they are opened at, referenced by index.
+#[derive(Default)]
+struct OpeningClaims {
+    commitments: Vec<Msm>,
+    queries: Vec<Query>,
+}
+
+impl OpeningClaims {
+    fn commit(&mut self, point: G1Point) -> usize {
+        self.commit_msm(Msm::from_point(point))
+    }
+
+    fn commit_msm(&mut self, msm: Msm) -> usize {
+        self.commitments.push(msm);
+        self.commitments.len() - 1
+    }
+
+    fn open(&mut self, commitment: usize, point: U256, eval: U256) {
+        self.queries.push(Query {
+            commitment,
+            point,
+            eval,
+        });
+    }
+}
+
+fn read_scalars(transcript: &mut Transcript, n: usize) -> Option<Vec<U256>> {
+    let mut out = Vec::with_capacity(n);
+    for _ in 0..n {
+        out.push(transcript.read_scalar()?);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::VerificationError;
+    use crate::halo2::protocol::TranscriptKind;
+
+    // `c = a · b`, `a' = b + c` over four rows with `b` range-checked by a lookup, a
+    // second-phase column `d = a + α · b`, a constant and two instances copied in and out.
+    // Proven once per transcript and opening scheme by `testdata/halo2/generate`.
+    const KECCAK_GWC: [&[u8]; 2] = [
+        include_bytes!("../../testdata/halo2/keccak_gwc/protocol.bin"),
+        include_bytes!("../../testdata/halo2/keccak_gwc/proof.bin"),
+    ];
+    const KECCAK_SHPLONK: [&[u8]; 2] = [
+        include_bytes!("../../testdata/halo2/keccak_shplonk/protocol.bin"),
+        include_bytes!("../../testdata/halo2/keccak_shplonk/proof.bin"),
+    ];
+    const POSEIDON_GWC: [&[u8]; 2] = [
+        include_bytes!("../../testdata/halo2/poseidon_gwc/protocol.bin"),
+        include_bytes!("../../testdata/halo2/poseidon_gwc/proof.bin"),
+    ];
+    const POSEIDON_SHPLONK: [&[u8]; 2] = [
+        include_bytes!("../../testdata/halo2/poseidon_shplonk/protocol.bin"),
+        include_bytes!("../../testdata/halo2/poseidon_shplonk/proof.bin"),
+    ];
+    const FIXTURES: [[&[u8]; 2]; 4] = [KECCAK_GWC, KECCAK_SHPLONK, POSEIDON_GWC, POSEIDON_SHPLONK];
+
+    fn instances() -> Vec<Vec<U256>> {
+        let words = include_bytes!("../../testdata/halo2/instances.bin");
+        vec![words.chunks(32).map(U256::from_be_slice).collect()]
+    }
+
+    fn verify(protocol: &[u8], proof: &[u8], instances: &[Vec<U256>]) -> Result<(), Halo2Error> {
+        verify_proof_with_protocol(&Protocol::from_bytes(protocol).unwrap(), proof, instances)
+    }
+
+    fn point_size(protocol: &Protocol) -> usize {
+        match protocol.transcript {
+            TranscriptKind::Keccak256 => 64,
+            TranscriptKind::Poseidon => 32,
+        }
+    }
+
+    /// Where the evaluations start, after every commitment up to the quotient chunks.
+    fn evaluations_offset(protocol: &Protocol) -> usize {
+        let points = protocol.advice_phases.len()
+            + 3 * protocol.lookups.len()
+            + protocol.num_permutation_sets()
+            + 1
+            + protocol.quotient_chunks;
+        points * point_size(protocol)
+    }
+
+    /// Flips the lowest bit of the scalar at `offset`, keeping it below `R`.
+    fn flip_scalar(protocol: &Protocol, proof: &mut [u8], offset: usize) {
+        match protocol.transcript {
+            TranscriptKind::Keccak256 => proof[offset + 31] ^= 1,
+            TranscriptKind::Poseidon => proof[offset] ^= 1,
+        }
+    }
+
+    fn is_verification_failure(result: Result<(), Halo2Error>) -> bool {
+        matches!(
+            result,
+            Err(Halo2Error::Common(VerificationError::VerificationFailed))
+        )
+    }
+
+    fn is_invalid_proof(result: Result<(), Halo2Error>) -> bool {
+        matches!(
+            result,
+            Err(Halo2Error::Common(VerificationError::InvalidProofData))
+        )
+    }
+
+    #[test]
+    fn verifies_fixture_proofs() {
+        for [protocol, proof] in FIXTURES {
+            verify(protocol, proof, &instances()).unwrap();
+        }
+    }
+
+    #[test]
+    fn rejects_altered_commitments() {
+        let instances = instances();
+        for [bytes, proof] in FIXTURES {
+            let protocol = Protocol::from_bytes(bytes).unwrap();
+            let size = point_size(&protocol);
+            // Swap the commitments to `a` and `b`, both valid points.
+            let mut altered = proof.to_vec();
+            let (a, b) = altered.split_at_mut(size);
+            a.swap_with_slice(&mut b[..size]);
+            assert!(is_verification_failure(verify(bytes, &altered, &instances)));
+        }
+    }
+
+    #[test]
+    fn rejects_altered_evaluations() {
+        let instances = instances();
+        for [bytes, proof] in FIXTURES {
+            let protocol = Protocol::from_bytes(bytes).unwrap();
+            let evaluations = evaluations_offset(&protocol);
+            // The last permutation set has no evaluation at the last usable row.
+            let n_evaluations = protocol.advice_queries.len()
+                + protocol.fixed_queries.len()
+                + 1
+                + protocol.permutation_columns.len()
+                + (3 * protocol.num_permutation_sets() - 1)
+                + 5 * protocol.lookups.len();
+            for i in [0, n_evaluations / 2, n_evaluations - 1] {
+                let mut altered = proof.to_vec();
+                flip_scalar(&protocol, &mut altered, evaluations + 32 * i);
+                assert!(is_verification_failure(verify(bytes, &altered, &instances)));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_altered_opening_proofs() {
+        let instances = instances();
+        for [bytes, proof] in FIXTURES {
+            let protocol = Protocol::from_bytes(bytes).unwrap();
+            let size = point_size(&protocol);
+            // Swap the last two points of the multi-opening argument.
+            let mut altered = proof.to_vec();
+            let (head, last) = altered.split_at_mut(proof.len() - size);
+            let n = head.len();
+            head[n - size..].swap_with_slice(last);
+            assert!(is_verification_failure(verify(bytes, &altered, &instances)));
+        }
+    }
+
+    #[test]
+    fn rejects_other_instances() {
+        for [protocol, proof] in FIXTURES {
+            let mut instances = instances();
+            instances[0][1] += U256::from(1u8);
+            assert!(is_verification_failure(verify(protocol, proof, &instances)));
+
+            instances[0][1] = R;
+            assert!(is_invalid_proof(verify(protocol, proof, &instances)));
+
+            let parsed = Protocol::from_bytes(protocol).unwrap();
+            let usable_rows = (1 << parsed.k) - (parsed.blinding_factors + 1);
+            let column = vec![U256::ZERO; usable_rows + 1];
+            assert!(is_invalid_proof(verify(protocol, proof, &[column])));
+
+            assert!(matches!(
+                verify(protocol, proof, &[]),
+                Err(Halo2Error::InstancesLengthMismatch { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_other_protocols() {
+        let instances = instances();
+        for [protocol, proof] in FIXTURES {
+            // Another verifying key.
+            let mut altered = protocol.to_vec();
+            altered[4 + 31] ^= 1;
+            assert!(is_verification_failure(verify(&altered, proof, &instances)));
+
+            // The other opening scheme.
+            altered = protocol.to_vec();
+            altered[3] ^= 1;
+            assert!(verify(&altered, proof, &instances).is_err());
+        }
+
+        // The other transcript.
+        assert!(verify(KECCAK_GWC[0], POSEIDON_GWC[1], &instances).is_err());
+        assert!(verify(POSEIDON_SHPLONK[0], KECCAK_SHPLONK[1], &instances).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_proofs() {
+        let instances = instances();
+        for [protocol, proof] in FIXTURES {
+            for len in [0, 32, proof.len() / 2, proof.len() - 1] {
+                assert!(is_invalid_proof(verify(
+                    protocol,
+                    &proof[..len],
+                    &instances
+                )));
+            }
+            let mut extended = proof.to_vec();
+            extended.push(0);
+            assert!(is_invalid_proof(verify(protocol, &extended, &instances)));
+        }
+
+        // Both compression flags set on the first Poseidon commitment.
+        let mut altered = POSEIDON_GWC[1].to_vec();
+        altered[31] |= 0xc0;
+        assert!(is_invalid_proof(verify(
+            POSEIDON_GWC[0],
+            &altered,
+            &instances
+        )));
+
+        // A Keccak coordinate outside the base field.
+        altered = KECCAK_GWC[1].to_vec();
+        altered[..32].fill(0xff);
+        assert!(is_invalid_proof(verify(
+            KECCAK_GWC[0],
+            &altered,
+            &instances
+        )));
+    }
+}
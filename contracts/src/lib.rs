@@ -9,6 +9,12 @@ This library offers verifiers for multiple ZKP systems.
 
 - **RISC Zero**: Verify RISC Zero proofs using Groth16
 - **SP1**: Verify SP1 proofs using Groth16/PLONK
+- **fflonk**: Verify snarkjs fflonk proofs against an imported verification key
+- **halo2**: Verify halo2 KZG proofs (SHPLONK or GWC) with Keccak or Poseidon transcripts
+- **UltraHonk**: Verify Noir/Barretenberg UltraHonk proofs
+- **Cairo**: Verify Stone prover proofs of Cairo programs
+- **Stwo**: Verify Stwo circle-STARK proofs of Cairo programs
+- **Winterfell**: Verify Winterfell STARKs against a Rust-described AIR, with a Miden binding
 - More verifiers coming soon...
 
 ## Usage
@@ -41,12 +47,37 @@ impl IRiscZeroVerifier for MyContract {
 ## Features
 
 - `risc0`: Enable RISC Zero verifier support
+- `risc0-succinct`: Enable RISC Zero succinct STARK verifier support
+- `risc0-router`: Enable the RISC Zero verifier router for several releases at once
+- `risc0-estop`: Enable the RISC Zero emergency stop wrapper
+- `risc0-host`: Enable the std-only RISC Zero receipt parser and seal encoder for off-chain clients
+- `risc0-v1_2`, `risc0-v2_0`, `risc0-v2_1`, `risc0-v2_2` (2.2 and 2.3), `risc0-v3_x`: Select the RISC Zero release whose parameters are compiled in; the newest enabled one wins (v2.0 by default)
 - `sp1`: Enable SP1 verifier support
+- `sp1-stark`: Enable SP1 compressed STARK verifier support
+- `fflonk`: Enable snarkjs fflonk verifier support
+- `fflonk-host`: Enable the std-only snarkjs verification key and proof importer for off-chain clients
+- `halo2`: Enable halo2 KZG verifier support
+- `ultrahonk`: Enable Noir/Barretenberg UltraHonk verifier support
+- `cairo`: Enable Cairo/Stone STARK verifier support
+- `cairo-host`: Enable the std-only Stone proof importer for off-chain clients
+- `stwo`: Enable Stwo circle-STARK verifier support
+- `winterfell`: Enable Winterfell/Miden STARK verifier support
 */
 
-#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+#![cfg_attr(
+    not(any(
+        test,
+        feature = "export-abi",
+        feature = "risc0-host",
+        feature = "fflonk-host",
+        feature = "cairo-host"
+    )),
+    no_std
+)]
 #![allow(clippy::module_name_repetitions)]
 extern crate alloc;
+// Lets derived code name this crate by path, here as in dependents.
+extern crate self as stylus_zkvm_verifiers;
 
 pub mod common;
 
@@ -56,5 +87,23 @@ pub mod risc0;
 #[cfg(feature = "sp1")]
 pub mod sp1;
 
+#[cfg(feature = "fflonk")]
+pub mod fflonk;
+
+#[cfg(feature = "halo2")]
+pub mod halo2;
+
+#[cfg(feature = "ultrahonk")]
+pub mod ultrahonk;
+
+#[cfg(feature = "cairo")]
+pub mod cairo;
+
+#[cfg(feature = "stwo")]
+pub mod stwo;
+
+#[cfg(feature = "winterfell")]
+pub mod winterfell;
+
 // Re-export commonly used types
-pub use common::*; 
\ No newline at end of file
+pub use common::*;
@@ -1,11 +1,13 @@
 use stylus_sdk::alloy_primitives::B256;
 
+/// RISC Zero release whose Groth16 key is compiled in.
+pub const VERSION: &str = crate::risc0::crypto::vk::VERSION;
+
 /// System state zero digest used for successful execution claims
 /// Reference: https://github.com/risc0/risc0-ethereum/blob/ab2fdafac60327e310121ada9e65bce8a439fba2/contracts/src/IRiscZeroVerifier.sol#L63
 pub const SYSTEM_STATE_ZERO_DIGEST: [u8; 32] = [
-    0xa3, 0xac, 0xc2, 0x71, 0x17, 0x41, 0x89, 0x96, 0x34, 0x0b, 0x84, 0xe5, 0xa9, 0x0f,
-    0x3e, 0xf4, 0xc4, 0x9d, 0x22, 0xc7, 0x9e, 0x44, 0xaa, 0xd8, 0x22, 0xec, 0x9c, 0x31,
-    0x3e, 0x1e, 0xb8, 0xe2,
+    0xa3, 0xac, 0xc2, 0x71, 0x17, 0x41, 0x89, 0x96, 0x34, 0x0b, 0x84, 0xe5, 0xa9, 0x0f, 0x3e, 0xf4,
+    0xc4, 0x9d, 0x22, 0xc7, 0x9e, 0x44, 0xaa, 0xd8, 0x22, 0xec, 0x9c, 0x31, 0x3e, 0x1e, 0xb8, 0xe2,
 ];
 
 /// Get the system state zero digest as a B256
@@ -13,20 +15,51 @@ pub fn system_state_zero_digest() -> B256 {
     B256::from(SYSTEM_STATE_ZERO_DIGEST)
 }
 
+/// Keccak-256 of the recursion circuit description (`recursion_circuit.bin`) accepted by
+/// the succinct verifier, for risc0-circuit-recursion 3.0 (`RECURSION:rev1v1`).
+#[cfg(feature = "risc0-succinct")]
+pub const RECURSION_CIRCUIT_DIGEST: B256 = B256::new([
+    0xf9, 0xbb, 0x29, 0x0c, 0x1c, 0xbc, 0x84, 0x1f, 0x4b, 0xd4, 0xcd, 0x72, 0xe7, 0xcd, 0x05, 0xeb,
+    0x70, 0x96, 0x87, 0xe2, 0xd7, 0x74, 0xaf, 0x7b, 0xf5, 0xb4, 0xe2, 0xcd, 0x13, 0x3b, 0x01, 0x71,
+]);
+
+/// Delay between proposing new verifier parameters and being able to accept them.
+pub const PARAMETER_ROTATION_DELAY: u64 = 2 * 24 * 60 * 60;
+
 /// Tag constants for digest computation
 pub mod tags {
+    use crate::risc0::crypto::digest_utils::tag_digest;
+    use stylus_sdk::alloy_primitives::B256;
+
     /// Tag for Groth16 receipt verifier parameters digest computation
-    pub const GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG: &[u8] = b"risc0.Groth16ReceiptVerifierParameters";
+    pub const GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG: &[u8] =
+        b"risc0.Groth16ReceiptVerifierParameters";
 
     /// Tag for ReceiptClaim digest computation
     pub const RECEIPT_CLAIM_TAG: &[u8] = b"risc0.ReceiptClaim";
-    
+
     /// Tag for Output digest computation
     pub const OUTPUT_TAG: &[u8] = b"risc0.Output";
-    
+
+    /// Tag for Assumption digest computation
+    pub const ASSUMPTION_TAG: &[u8] = b"risc0.Assumption";
+
+    /// Tag for the assumptions list digest computation
+    pub const ASSUMPTIONS_TAG: &[u8] = b"risc0.Assumptions";
+
     /// Tag for verifying key IC list
     pub const VK_IC_TAG: &[u8] = b"risc0_groth16.VerifyingKey.IC";
-    
+
     /// Tag for verifying key digest
     pub const VK_TAG: &[u8] = b"risc0_groth16.VerifyingKey";
-} 
\ No newline at end of file
+
+    /// SHA-256 digests of the tags above, computed at compile time.
+    pub const GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG_DIGEST: B256 =
+        tag_digest(GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG);
+    pub const RECEIPT_CLAIM_TAG_DIGEST: B256 = tag_digest(RECEIPT_CLAIM_TAG);
+    pub const OUTPUT_TAG_DIGEST: B256 = tag_digest(OUTPUT_TAG);
+    pub const ASSUMPTION_TAG_DIGEST: B256 = tag_digest(ASSUMPTION_TAG);
+    pub const ASSUMPTIONS_TAG_DIGEST: B256 = tag_digest(ASSUMPTIONS_TAG);
+    pub const VK_IC_TAG_DIGEST: B256 = tag_digest(VK_IC_TAG);
+    pub const VK_TAG_DIGEST: B256 = tag_digest(VK_TAG);
+}
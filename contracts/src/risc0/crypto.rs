@@ -1,14 +1,10 @@
-use alloc::{vec, vec::Vec};
-use sha2::{Digest, Sha256};
-use stylus_sdk::{
-    alloy_primitives::B256,
-    alloy_sol_types::SolValue,
-};
+use stylus_sdk::alloy_primitives::{b256, FixedBytes, B256};
 
 use crate::common::VerificationKey;
-use crate::risc0::config::tags;
+use crate::risc0::{config::tags, verifier};
 
-pub mod vk {
+/// Key from RISC Zero's Groth16 trusted-setup ceremony.
+pub mod ceremony {
     use super::*;
     use crate::common::{G1Point, G2Point};
     use stylus_sdk::alloy_primitives::uint;
@@ -78,7 +74,7 @@ pub mod vk {
         },
     ];
 
-    pub fn get_verification_key() -> VerificationKey {
+    pub const fn get_verification_key() -> VerificationKey<'static> {
         VerificationKey {
             alpha1: ALPHA1,
             beta2: BETA2,
@@ -89,108 +85,484 @@ pub mod vk {
     }
 }
 
+/// Verifier parameters per RISC Zero release. The releases below all share the ceremony
+/// key and differ in the recursion control root, the `ALLOWED_CONTROL_ROOT` of the
+/// risc0-circuit-recursion version the release depends on; one that ships a new key gets
+/// its own key constants in its module.
+///
+/// risc0-zkvm 1.2, on risc0-circuit-recursion 1.2.
+pub mod v1_2 {
+    pub use super::ceremony::*;
+    use super::*;
+
+    pub const VERSION: &str = "v1.2";
+    pub const CONTROL_ROOT: B256 =
+        b256!("8cdad9242664be3112aba377c5425a4df735eb1c6966472b561d2855932c0469");
+    pub const BN254_CONTROL_ID: B256 = BN254_IDENTITY_CONTROL_ID;
+
+    pub const fn compute_verifier_key_digest() -> B256 {
+        digest_utils::verifier_key_digest(&get_verification_key())
+    }
+
+    pub const fn calculate_selector() -> FixedBytes<4> {
+        verifier::calculate_selector(
+            CONTROL_ROOT,
+            BN254_CONTROL_ID,
+            compute_verifier_key_digest(),
+        )
+    }
+}
+
+/// risc0-zkvm 2.0, on risc0-circuit-recursion 2.0.1.
+pub mod v2_0 {
+    pub use super::ceremony::*;
+    use super::*;
+
+    pub const VERSION: &str = "v2.0";
+    pub const CONTROL_ROOT: B256 =
+        b256!("539032186827b06719244873b17b2d4c122e2d02cfb1994fe958b2523b844576");
+    pub const BN254_CONTROL_ID: B256 = BN254_IDENTITY_CONTROL_ID;
+
+    pub const fn compute_verifier_key_digest() -> B256 {
+        digest_utils::verifier_key_digest(&get_verification_key())
+    }
+
+    pub const fn calculate_selector() -> FixedBytes<4> {
+        verifier::calculate_selector(
+            CONTROL_ROOT,
+            BN254_CONTROL_ID,
+            compute_verifier_key_digest(),
+        )
+    }
+}
+
+/// risc0-zkvm 2.1, on risc0-circuit-recursion 2.0.2.
+pub mod v2_1 {
+    pub use super::ceremony::*;
+    use super::*;
+
+    pub const VERSION: &str = "v2.1";
+    pub const CONTROL_ROOT: B256 =
+        b256!("884389273e128b32475b334dec75ee619b77cb33d41c332021fe7e44c746ee60");
+    pub const BN254_CONTROL_ID: B256 = BN254_IDENTITY_CONTROL_ID;
+
+    pub const fn compute_verifier_key_digest() -> B256 {
+        digest_utils::verifier_key_digest(&get_verification_key())
+    }
+
+    pub const fn calculate_selector() -> FixedBytes<4> {
+        verifier::calculate_selector(
+            CONTROL_ROOT,
+            BN254_CONTROL_ID,
+            compute_verifier_key_digest(),
+        )
+    }
+}
+
+/// risc0-zkvm 2.2 and 2.3, on risc0-circuit-recursion 3.0.
+pub mod v2_2 {
+    pub use super::ceremony::*;
+    use super::*;
+
+    pub const VERSION: &str = "v2.2";
+    pub const CONTROL_ROOT: B256 =
+        b256!("ce52bf56033842021af3cf6db8a50d1b7535c125a34f1a22c6fdcf002c5a1529");
+    pub const BN254_CONTROL_ID: B256 = BN254_IDENTITY_CONTROL_ID;
+
+    pub const fn compute_verifier_key_digest() -> B256 {
+        digest_utils::verifier_key_digest(&get_verification_key())
+    }
+
+    pub const fn calculate_selector() -> FixedBytes<4> {
+        verifier::calculate_selector(
+            CONTROL_ROOT,
+            BN254_CONTROL_ID,
+            compute_verifier_key_digest(),
+        )
+    }
+}
+
+/// risc0-zkvm 3.0, on risc0-circuit-recursion 4.0.
+pub mod v3_x {
+    pub use super::ceremony::*;
+    use super::*;
+
+    pub const VERSION: &str = "v3.x";
+    pub const CONTROL_ROOT: B256 =
+        b256!("a54dc85ac99f851c92d7c96d7318af41dbe7c0194edfcc37eb4d422a998c1f56");
+    pub const BN254_CONTROL_ID: B256 = BN254_IDENTITY_CONTROL_ID;
+
+    pub const fn compute_verifier_key_digest() -> B256 {
+        digest_utils::verifier_key_digest(&get_verification_key())
+    }
+
+    pub const fn calculate_selector() -> FixedBytes<4> {
+        verifier::calculate_selector(
+            CONTROL_ROOT,
+            BN254_CONTROL_ID,
+            compute_verifier_key_digest(),
+        )
+    }
+}
+
+/// Control ID of the identity recursion program over BN254, unchanged across releases.
+const BN254_IDENTITY_CONTROL_ID: B256 =
+    b256!("04446e66d300eb7fb45c9726bb53c793dda407a62e9601618bb43c5c14657ac0");
+
+/// Release selected by the `risc0-v*` features, v2.0 by default. When several are enabled
+/// the newest one wins, so that the features stay additive.
+#[cfg(all(
+    feature = "risc0-v1_2",
+    not(feature = "risc0-v2_0"),
+    not(feature = "risc0-v2_1"),
+    not(feature = "risc0-v2_2"),
+    not(feature = "risc0-v3_x")
+))]
+pub use v1_2 as vk;
+#[cfg(all(
+    any(feature = "risc0-v2_0", not(feature = "risc0-v1_2")),
+    not(feature = "risc0-v2_1"),
+    not(feature = "risc0-v2_2"),
+    not(feature = "risc0-v3_x")
+))]
+pub use v2_0 as vk;
+#[cfg(all(
+    feature = "risc0-v2_1",
+    not(feature = "risc0-v2_2"),
+    not(feature = "risc0-v3_x")
+))]
+pub use v2_1 as vk;
+#[cfg(all(feature = "risc0-v2_2", not(feature = "risc0-v3_x")))]
+pub use v2_2 as vk;
+#[cfg(feature = "risc0-v3_x")]
+pub use v3_x as vk;
+
+/// Digest machinery as `const fn`, so that digests of constant data become constants.
 pub mod digest_utils {
     use super::*;
+    use crate::common::{sha256, G1Point, G2Point};
+    use stylus_sdk::alloy_primitives::U256;
 
-    pub fn reverse_byte_order_uint256(value: B256) -> B256 {
+    pub use stylus_zkvm_verifiers_derive::TaggedDigest;
+
+    /// Digest of the compiled-in Groth16 key.
+    pub const VERIFIER_KEY_DIGEST: B256 = vk::compute_verifier_key_digest();
+
+    pub const fn tag_digest(tag: &[u8]) -> B256 {
+        B256::new(sha256::digest(tag))
+    }
+
+    pub const fn reverse_byte_order_uint256(value: B256) -> B256 {
         let mut reversed = [0u8; 32];
-        reversed.iter_mut()
-            .zip(value.as_slice().iter().rev())
-            .for_each(|(dst, src)| *dst = *src);
-        B256::from(reversed)
+        let mut i = 0;
+        while i < 32 {
+            reversed[i] = value.0[31 - i];
+            i += 1;
+        }
+        B256::new(reversed)
     }
 
-    pub fn split_digest(d: B256) -> ([u8; 16], [u8; 16]) {
+    pub const fn split_digest(d: B256) -> ([u8; 16], [u8; 16]) {
         let rev = reverse_byte_order_uint256(d);
         let mut low = [0u8; 16];
         let mut high = [0u8; 16];
-        low.copy_from_slice(&rev[16..]);
-        high.copy_from_slice(&rev[..16]);
+        let mut i = 0;
+        while i < 16 {
+            low[i] = rev.0[16 + i];
+            high[i] = rev.0[i];
+            i += 1;
+        }
         (low, high)
     }
 
-    pub fn tagged_struct(tag_digest: B256, down: Vec<B256>) -> B256 {
-        let mut buf = Vec::with_capacity(32 + 32 * down.len() + 2);
-
-        buf.extend_from_slice(tag_digest.as_slice());
-        for d in &down {
-            buf.extend_from_slice(d.as_slice())
+    /// Inverse of `split_digest`.
+    pub const fn join_digest(low: [u8; 16], high: [u8; 16]) -> B256 {
+        let mut rev = [0u8; 32];
+        let mut i = 0;
+        while i < 16 {
+            rev[i] = high[i];
+            rev[16 + i] = low[i];
+            i += 1;
         }
-        buf.extend_from_slice(&((down.len() as u16) << 8).to_be_bytes());
+        reverse_byte_order_uint256(B256::new(rev))
+    }
 
-        B256::from_slice(&Sha256::digest(&buf))
+    pub const fn tagged_struct(tag_digest: B256, down: &[B256]) -> B256 {
+        tagged_struct_with_data(tag_digest, down, &[])
     }
 
-    pub fn tagged_list_cons(tag_digest: B256, head: B256, tail: B256) -> B256 {
-        tagged_struct(tag_digest, vec![head, tail])
+    /// Hashes the tag, the `down` digests, the `data` words little-endian and the number
+    /// of digests as a little-endian `u16`.
+    pub const fn tagged_struct_with_data(tag_digest: B256, down: &[B256], data: &[u32]) -> B256 {
+        let mut hasher = sha256::Sha256::new();
+        hasher.update(&tag_digest.0);
+        let mut i = 0;
+        while i < down.len() {
+            hasher.update(&down[i].0);
+            i += 1;
+        }
+        i = 0;
+        while i < data.len() {
+            hasher.update(&data[i].to_le_bytes());
+            i += 1;
+        }
+        hasher.update(&(down.len() as u16).to_le_bytes());
+        B256::new(hasher.finalize())
     }
 
-    pub fn tagged_list(tag_digest: B256, list: Vec<B256>) -> B256 {
+    pub const fn tagged_list_cons(tag_digest: B256, head: B256, tail: B256) -> B256 {
+        tagged_struct(tag_digest, &[head, tail])
+    }
+
+    pub const fn tagged_list(tag_digest: B256, list: &[B256]) -> B256 {
         let mut curr = B256::ZERO;
-        for element in list.into_iter().rev() {
-            curr = tagged_list_cons(tag_digest, element, curr);
+        let mut i = list.len();
+        while i > 0 {
+            i -= 1;
+            curr = tagged_list_cons(tag_digest, list[i], curr);
         }
         curr
     }
 
-    pub fn compute_verifier_key_digest() -> B256 {
-        let mut ic_digests = Vec::with_capacity(6);
-        for pt in &vk::IC {
-            let encoded = (pt.x, pt.y).abi_encode_packed();
-            ic_digests.push(B256::from_slice(&Sha256::digest(&encoded)));
+    /// A struct hashed with [`tagged_struct_with_data`]; derive it with
+    /// `#[derive(TaggedDigest)]`.
+    pub trait TaggedDigest {
+        const TAG_DIGEST: B256;
+
+        fn tagged_digest(&self) -> B256;
+    }
+
+    /// A field hashed as a digest in a [`TaggedDigest`] struct.
+    pub trait DownDigest {
+        fn down_digest(&self) -> B256;
+    }
+
+    impl DownDigest for B256 {
+        fn down_digest(&self) -> B256 {
+            *self
+        }
+    }
+
+    impl<T: TaggedDigest> DownDigest for T {
+        fn down_digest(&self) -> B256 {
+            self.tagged_digest()
+        }
+    }
+
+    /// A field hashed as `WORDS` 32-bit words in a [`TaggedDigest`] struct.
+    pub trait TaggedData {
+        const WORDS: usize;
+
+        fn write_words(&self, words: &mut [u32]);
+    }
+
+    impl TaggedData for u32 {
+        const WORDS: usize = 1;
+
+        fn write_words(&self, words: &mut [u32]) {
+            words[0] = *self;
+        }
+    }
+
+    pub const fn compute_verifier_key_digest() -> B256 {
+        VERIFIER_KEY_DIGEST
+    }
+
+    /// Digest of `key` as committed in the selector, following `Groth16ReceiptVerifier`.
+    pub const fn verifier_key_digest(key: &VerificationKey) -> B256 {
+        let ic_tag = tags::VK_IC_TAG_DIGEST;
+        let mut ic_list_digest = B256::ZERO;
+        let mut i = key.ic.len();
+        while i > 0 {
+            i -= 1;
+            ic_list_digest = tagged_list_cons(ic_tag, g1_digest(&key.ic[i]), ic_list_digest);
         }
 
-        let alpha_digest = {
-            let e = (vk::ALPHA1.x, vk::ALPHA1.y).abi_encode_packed();
-            B256::from_slice(&Sha256::digest(&e))
-        };
-        let beta_digest = {
-            let e = (
-                vk::BETA2.x[0],
-                vk::BETA2.x[1],
-                vk::BETA2.y[0],
-                vk::BETA2.y[1],
-            )
-                .abi_encode_packed();
-            B256::from_slice(&Sha256::digest(&e))
-        };
-        let gamma_digest = {
-            let e = (
-                vk::GAMMA2.x[0],
-                vk::GAMMA2.x[1],
-                vk::GAMMA2.y[0],
-                vk::GAMMA2.y[1],
-            )
-                .abi_encode_packed();
-            B256::from_slice(&Sha256::digest(&e))
-        };
-        let delta_digest = {
-            let e = (
-                vk::DELTA2.x[0],
-                vk::DELTA2.x[1],
-                vk::DELTA2.y[0],
-                vk::DELTA2.y[1],
-            )
-                .abi_encode_packed();
-            B256::from_slice(&Sha256::digest(&e))
-        };
-
-        let ic_tag = B256::from_slice(&Sha256::digest(tags::VK_IC_TAG));
-        let ic_list_digest = tagged_list(ic_tag, ic_digests);
-
-        let vk_tag = B256::from_slice(&Sha256::digest(tags::VK_TAG));
-
-        let encoded = (
-            vk_tag,
-            alpha_digest,
-            beta_digest,
-            gamma_digest,
-            delta_digest,
-            ic_list_digest,
-            (5u16) << 8,
+        tagged_struct(
+            tags::VK_TAG_DIGEST,
+            &[
+                g1_digest(&key.alpha1),
+                g2_digest(&key.beta2),
+                g2_digest(&key.gamma2),
+                g2_digest(&key.delta2),
+                ic_list_digest,
+            ],
         )
-            .abi_encode_packed();
+    }
+
+    const fn g1_digest(p: &G1Point) -> B256 {
+        let mut hasher = sha256::Sha256::new();
+        hasher.update(&u256_be(&p.x));
+        hasher.update(&u256_be(&p.y));
+        B256::new(hasher.finalize())
+    }
+
+    const fn g2_digest(p: &G2Point) -> B256 {
+        let mut hasher = sha256::Sha256::new();
+        hasher.update(&u256_be(&p.x[0]));
+        hasher.update(&u256_be(&p.x[1]));
+        hasher.update(&u256_be(&p.y[0]));
+        hasher.update(&u256_be(&p.y[1]));
+        B256::new(hasher.finalize())
+    }
+
+    const fn u256_be(x: &U256) -> [u8; 32] {
+        let limbs = x.as_limbs();
+        let mut out = [0u8; 32];
+        let mut i = 0;
+        while i < 4 {
+            let bytes = limbs[3 - i].to_be_bytes();
+            let mut j = 0;
+            while j < 8 {
+                out[8 * i + j] = bytes[j];
+                j += 1;
+            }
+            i += 1;
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risc0::types::ReceiptClaim;
+    use sha2::{Digest, Sha256};
+    use stylus_sdk::alloy_primitives::{fixed_bytes, hex};
+
+    #[test]
+    fn system_state_zero_digest_matches_risc0() {
+        let digest = digest_utils::tagged_struct_with_data(
+            digest_utils::tag_digest(b"risc0.SystemState"),
+            &[B256::ZERO],
+            &[0],
+        );
+        assert_eq!(digest.0, crate::risc0::config::SYSTEM_STATE_ZERO_DIGEST);
+    }
 
-        B256::from_slice(&Sha256::digest(&encoded))
+    #[test]
+    fn split_digest_round_trips() {
+        let digest = b256!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let (low, high) = digest_utils::split_digest(digest);
+        // The halves of the byte-reversed digest, as the Groth16 circuit reads them.
+        assert_eq!(low, hex!("0f0e0d0c0b0a09080706050403020100"));
+        assert_eq!(high, hex!("1f1e1d1c1b1a19181716151413121110"));
+        assert_eq!(digest_utils::join_digest(low, high), digest);
+
+        for digest in [B256::ZERO, B256::repeat_byte(0xff), v2_0::CONTROL_ROOT] {
+            let (low, high) = digest_utils::split_digest(digest);
+            assert_eq!(digest_utils::join_digest(low, high), digest);
+        }
+    }
+
+    #[test]
+    fn verifier_parameters_digest_matches_risc0() {
+        // `Groth16ReceiptVerifierParameters::default().digest()` in risc0-zkvm 2.3.2.
+        let digest = digest_utils::tagged_struct(
+            tags::GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG_DIGEST,
+            &[
+                v2_2::CONTROL_ROOT,
+                digest_utils::reverse_byte_order_uint256(BN254_IDENTITY_CONTROL_ID),
+                digest_utils::VERIFIER_KEY_DIGEST,
+            ],
+        );
+        assert_eq!(
+            digest,
+            b256!("bb001d444841d70e8bc0c7d034b349044bf3cf0117afb702b2f1e898b7dd13cc")
+        );
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn selectors_match_deployed_verifiers() {
+        assert_eq!(v1_2::calculate_selector(), fixed_bytes!("c101b42b"));
+        assert_eq!(v2_0::calculate_selector(), fixed_bytes!("9f39696c"));
+        assert_eq!(v2_2::calculate_selector(), fixed_bytes!("bb001d44"));
+        assert_eq!(v2_1::calculate_selector(), fixed_bytes!("f536085a"));
+        assert_eq!(v3_x::calculate_selector(), fixed_bytes!("73c457ba"));
+    }
+
+    /// Groth16 test receipts of risc0-ethereum-contracts 3.0.1 (`test/TestReceiptV*.sol`),
+    /// one per release, all for the journal `b"just a simple receipt"`.
+    const RECEIPTS: [(&str, [u8; 260], B256); 4] = [
+        (
+            "v2.0",
+            hex!(
+                "9f39696c2c1cd42efd2ea081de900b08eb97ff95c2df709622928fdc93976baa4730a7502018b1654a232c0614a591ea"
+                "42d8c210d63aa49c19a2009016b08255175d413712932a948d9569c41e39a7618fe801263768e32aa1780bff62c39432"
+                "f208d66c272ea7ff083bceb60d70b317bbc6ae9656883ee1f3db197ffa4df9f581e7b8022acac6495e3dc193edf57762"
+                "d43b460d767cb2f6bde5fb5f89097a7921622c0117c3c6f84a0d6e5cc251a9025a6812155c50212ed17c2dfab5c0805b"
+                "5fa522cf08318d8bf05cba911dc6305dcd87ed1e90a0c27c80caaa05c0560871c8b6273c0333dfa7f0ca652760a1da86"
+                "6a2a9e222a87a422d988d5b75917be1ae4fbec67"
+            ),
+            b256!("192f3eb31ce4f578a52abb305490d63e4700f24fd460aa4cd4aa801e9672c869"),
+        ),
+        (
+            "v2.1",
+            hex!(
+                "f536085a1d323acff28d9c5ed9dc0cb8cf1b01f4596515aad4483ab8610b7c284f043ed81d74715567d7225580413fea"
+                "b5c59e32b0268bb290629957d06e1437bf2fb13f11b4d40f00a2e9a7462ff787fd9b8ebedbc22f25bc2a7be3a89394c0"
+                "0ed7018f02065b43f3e6ecec419f48bdd3f42f292503ccbca34a379b424af0a53cfe53660d693cbd13ee5ea7f38282d4"
+                "d4be2a7bbdff3ba24cb460caa5b024bdb1f8003406b7d223e2110dab31b4194d5667e16264650924e1b5c00a1375b553"
+                "18ed15732af940c906657fbc94daaad6da027941e0c01119bfe579ecd0431bf32065a1062d4d5e0f91c308a0ef80ed43"
+                "fd14d8e84921a28214700ef8d6a8f02bea7e72a2"
+            ),
+            b256!("e462bc1dc34b9dfe4af9243a5fc56f0e5423a88ace7e575fa051364109a8bfa0"),
+        ),
+        (
+            "v2.2",
+            hex!(
+                "bb001d441cb19c0439e5cb54ab7dd4d4e0cd8c4809abe8e19ba602b4ce8728dc9e2eafbe27eac14be5e561f6cd697429"
+                "d0567a4f46f62291b58f541c46fbf653dff6725424ade3fdf1cc1014e062fa9e6c2e8e3a785fb2f84af10a44adfe7614"
+                "83b1036a00bea38909a90b4f557b520cc77f01a33000444dd2c5c572d590e03c55c646392b8f7d3f65ab4b05ce2a339f"
+                "86210a1762d8cae53bafdeaa105949019e5fb72a17506d1c6db5cfbfee339cd9e20abc9871d2d72e6c0e66b9e8f6f5dd"
+                "ce73181022cc8347b8ac33a5ee18bb0cecf4d47225112e90e1c4d0b896b7ab77701a8f4c1391b544bc1d7ba20e6287ff"
+                "63fded2de08e02db62b0093be54044819071cd65"
+            ),
+            b256!("39b8aec425bb4e7eb994a0e4b6e9dbeceba907cf70f463cba7dc9786fe2dfb86"),
+        ),
+        (
+            "v3.x",
+            hex!(
+                "73c457ba2ccb718fd9092cc11546eeded62a44d3ed274076dd3ec154fae8739f3432050b2005be2c5dbe6c08bfd04b30"
+                "601a462540962bc26a2f38c5cfc0a4d76d8f1b8015e690a1b230081234867edeedb2f98bcdf33d0471c2aa5e8db63b72"
+                "333f871527eb5d1fcf0a7af50fb8f42e8699e2c4eda3cd93f4e2a930096ae78e38bea4020c5c3d963dc453b4b302170e"
+                "47c0cf53382255143c8fcef474d8b6eaaa8daaaf092c2f650809a3afbd122ef128cb882c2de7a6ccddd2e544b645fa3f"
+                "edf6bcc92e09be04876a07778231fd5b93305d35fd8af23f040a11682a8c64130370804f28f07a76fa538755276e42c0"
+                "4b5f7eb97b04b68b65fa50e3181a0452069a3667"
+            ),
+            b256!("11d264ed8dfdee222b820f0278e4d7f55d4b69a5472253a471c102265a91ea1a"),
+        ),
+    ];
+
+    #[test]
+    fn releases_verify_risc0_ethereum_receipts() {
+        let journal_digest = B256::from_slice(&Sha256::digest(b"just a simple receipt"));
+        let releases = [
+            (v2_0::CONTROL_ROOT, v2_0::calculate_selector()),
+            (v2_1::CONTROL_ROOT, v2_1::calculate_selector()),
+            (v2_2::CONTROL_ROOT, v2_2::calculate_selector()),
+            (v3_x::CONTROL_ROOT, v3_x::calculate_selector()),
+        ];
+
+        for ((version, seal, image_id), (control_root, selector)) in RECEIPTS.iter().zip(releases) {
+            assert_eq!(seal[..4], selector[..], "{version}");
+            let verify = |control_root, claim_digest| {
+                verifier::verify_seal(
+                    &seal[4..],
+                    control_root,
+                    BN254_IDENTITY_CONTROL_ID,
+                    &ceremony::get_verification_key(),
+                    claim_digest,
+                )
+            };
+            let claim_digest = ReceiptClaim::ok(*image_id, journal_digest).digest();
+            assert!(verify(control_root, claim_digest).is_ok(), "{version}");
+            // Each seal is bound to the control root of its release.
+            for (other, _) in releases.iter().filter(|(root, _)| *root != control_root) {
+                assert!(verify(*other, claim_digest).is_err(), "{version}");
+            }
+            let other_claim = ReceiptClaim::ok(*image_id, B256::ZERO).digest();
+            assert!(verify(control_root, other_claim).is_err(), "{version}");
+        }
+    }
+}
@@ -1,12 +1,30 @@
 use stylus_sdk::{
-    alloy_primitives::FixedBytes,
+    alloy_primitives::{Address, FixedBytes, B256},
     alloy_sol_types::{sol, SolError},
 };
 
-use crate::common::VerificationError;
+use crate::common::{ownable::OwnableError, VerificationError};
 
 sol! {
     error SelectorMismatch(bytes4 received, bytes4 expected);
+    error ControlIdNotAllowed(bytes32 controlId);
+    error ControlRootMismatch(bytes32 received, bytes32 expected);
+    error ClaimDigestMismatch(bytes32 received, bytes32 expected);
+    error InvalidRecursionCircuit();
+    error UnsupportedExitCode(uint8 system);
+    error AssumptionNotVerified(bytes32 claim);
+    error SelectorUnknown(bytes4 selector);
+    error SelectorRemoved(bytes4 selector);
+    error SelectorInUse(bytes4 selector);
+    error InvalidProofOfExploit();
+    error EmergencyStopped();
+    error InvalidParameters();
+    error NoPendingParameters();
+    error TimelockNotExpired(uint64 eta);
+    error ImageNotRegistered(bytes32 imageId);
+    error ImageNotActive(bytes32 imageId);
+    error CallerNotAllowed(bytes32 imageId, address caller);
+    error InvalidValidityWindow();
 }
 
 #[derive(Debug)]
@@ -16,6 +34,52 @@ pub enum RiscZeroError {
         received: FixedBytes<4>,
         expected: FixedBytes<4>,
     },
+    ControlIdNotAllowed {
+        control_id: B256,
+    },
+    ControlRootMismatch {
+        received: B256,
+        expected: B256,
+    },
+    ClaimDigestMismatch {
+        received: B256,
+        expected: B256,
+    },
+    InvalidRecursionCircuit,
+    UnsupportedExitCode {
+        system: u8,
+    },
+    AssumptionNotVerified {
+        claim: B256,
+    },
+    SelectorUnknown {
+        selector: FixedBytes<4>,
+    },
+    SelectorRemoved {
+        selector: FixedBytes<4>,
+    },
+    SelectorInUse {
+        selector: FixedBytes<4>,
+    },
+    InvalidProofOfExploit,
+    EmergencyStopped,
+    InvalidParameters,
+    NoPendingParameters,
+    TimelockNotExpired {
+        eta: u64,
+    },
+    Ownable(OwnableError),
+    ImageNotRegistered {
+        image_id: B256,
+    },
+    ImageNotActive {
+        image_id: B256,
+    },
+    CallerNotAllowed {
+        image_id: B256,
+        caller: Address,
+    },
+    InvalidValidityWindow,
 }
 
 impl RiscZeroError {
@@ -27,6 +91,59 @@ impl RiscZeroError {
                 expected: *expected,
             }
             .abi_encode(),
+            RiscZeroError::ControlIdNotAllowed { control_id } => ControlIdNotAllowed {
+                controlId: *control_id,
+            }
+            .abi_encode(),
+            RiscZeroError::ControlRootMismatch { received, expected } => ControlRootMismatch {
+                received: *received,
+                expected: *expected,
+            }
+            .abi_encode(),
+            RiscZeroError::ClaimDigestMismatch { received, expected } => ClaimDigestMismatch {
+                received: *received,
+                expected: *expected,
+            }
+            .abi_encode(),
+            RiscZeroError::InvalidRecursionCircuit => InvalidRecursionCircuit {}.abi_encode(),
+            RiscZeroError::UnsupportedExitCode { system } => {
+                UnsupportedExitCode { system: *system }.abi_encode()
+            }
+            RiscZeroError::AssumptionNotVerified { claim } => {
+                AssumptionNotVerified { claim: *claim }.abi_encode()
+            }
+            RiscZeroError::SelectorUnknown { selector } => SelectorUnknown {
+                selector: *selector,
+            }
+            .abi_encode(),
+            RiscZeroError::SelectorRemoved { selector } => SelectorRemoved {
+                selector: *selector,
+            }
+            .abi_encode(),
+            RiscZeroError::SelectorInUse { selector } => SelectorInUse {
+                selector: *selector,
+            }
+            .abi_encode(),
+            RiscZeroError::InvalidProofOfExploit => InvalidProofOfExploit {}.abi_encode(),
+            RiscZeroError::EmergencyStopped => EmergencyStopped {}.abi_encode(),
+            RiscZeroError::InvalidParameters => InvalidParameters {}.abi_encode(),
+            RiscZeroError::NoPendingParameters => NoPendingParameters {}.abi_encode(),
+            RiscZeroError::TimelockNotExpired { eta } => {
+                TimelockNotExpired { eta: *eta }.abi_encode()
+            }
+            RiscZeroError::Ownable(e) => e.abi_encode(),
+            RiscZeroError::ImageNotRegistered { image_id } => {
+                ImageNotRegistered { imageId: *image_id }.abi_encode()
+            }
+            RiscZeroError::ImageNotActive { image_id } => {
+                ImageNotActive { imageId: *image_id }.abi_encode()
+            }
+            RiscZeroError::CallerNotAllowed { image_id, caller } => CallerNotAllowed {
+                imageId: *image_id,
+                caller: *caller,
+            }
+            .abi_encode(),
+            RiscZeroError::InvalidValidityWindow => InvalidValidityWindow {}.abi_encode(),
         }
     }
 }
@@ -37,9 +154,19 @@ impl From<VerificationError> for RiscZeroError {
     }
 }
 
+impl From<OwnableError> for RiscZeroError {
+    fn from(error: OwnableError) -> Self {
+        RiscZeroError::Ownable(error)
+    }
+}
+
 impl RiscZeroError {
-    pub const VERIFICATION_FAILED: RiscZeroError = RiscZeroError::Common(VerificationError::VerificationFailed);
-    pub const INVALID_INITIALIZATION: RiscZeroError = RiscZeroError::Common(VerificationError::InvalidInitialization);
-    pub const ALREADY_INITIALIZED: RiscZeroError = RiscZeroError::Common(VerificationError::AlreadyInitialized);
-    pub const INVALID_PROOF_DATA: RiscZeroError = RiscZeroError::Common(VerificationError::InvalidProofData);
-} 
\ No newline at end of file
+    pub const VERIFICATION_FAILED: RiscZeroError =
+        RiscZeroError::Common(VerificationError::VerificationFailed);
+    pub const INVALID_INITIALIZATION: RiscZeroError =
+        RiscZeroError::Common(VerificationError::InvalidInitialization);
+    pub const ALREADY_INITIALIZED: RiscZeroError =
+        RiscZeroError::Common(VerificationError::AlreadyInitialized);
+    pub const INVALID_PROOF_DATA: RiscZeroError =
+        RiscZeroError::Common(VerificationError::InvalidProofData);
+}
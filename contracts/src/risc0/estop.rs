@@ -0,0 +1,315 @@
+//! Emergency stop around [`RiscZeroVerifier`], after risc0-ethereum's
+//! `RiscZeroVerifierEmergencyStop`.
+//!
+//! The owner of the wrapped verifier may stop it at any time. Anyone may stop it by proving
+//! the exploit claim: a receipt whose claim digest is zero, which is the digest of no claim
+//! and so can only be proven through a soundness bug. Stopping is permanent; every
+//! verification reverts with `EmergencyStopped` afterwards.
+
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{Address, B256},
+    alloy_sol_types::sol,
+    prelude::*,
+    stylus_core::log,
+};
+
+use crate::common::ownable::IOwnable;
+use crate::risc0::{
+    errors::RiscZeroError,
+    types::abi,
+    verifier::{IRiscZeroVerifier, RiscZeroVerifier},
+};
+
+sol! {
+    event Stopped(address caller);
+}
+
+/// Digest of the claim whose proof shows the verifier unsound.
+pub const EXPLOIT_CLAIM_DIGEST: B256 = B256::ZERO;
+
+pub trait IRiscZeroVerifierEmergencyStop {
+    type Error;
+
+    fn initialize(&mut self, control_root: B256, bn254_control_id: B256)
+        -> Result<(), Self::Error>;
+
+    /// Stops the verifier; owner only.
+    fn estop(&mut self) -> Result<(), Self::Error>;
+
+    /// Stops the verifier given a valid receipt for the exploit claim; callable by anyone.
+    fn estop_with_proof(&mut self, seal: Vec<u8>, claim_digest: B256) -> Result<(), Self::Error>;
+
+    fn verify(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error>;
+
+    fn verify_claim(&self, seal: Vec<u8>, claim: abi::ReceiptClaim) -> Result<bool, Self::Error>;
+
+    fn verify_integrity(
+        &self,
+        receipt_seal: Vec<u8>,
+        receipt_claim_digest: B256,
+    ) -> Result<bool, Self::Error>;
+
+    fn is_stopped(&self) -> bool;
+}
+
+sol_storage! {
+    pub struct RiscZeroVerifierEmergencyStop {
+        RiscZeroVerifier verifier;
+        bool stopped;
+    }
+}
+
+#[public]
+impl IRiscZeroVerifierEmergencyStop for RiscZeroVerifierEmergencyStop {
+    type Error = Vec<u8>;
+
+    fn initialize(
+        &mut self,
+        control_root: B256,
+        bn254_control_id: B256,
+    ) -> Result<(), Self::Error> {
+        self.verifier.initialize(control_root, bn254_control_id)
+    }
+
+    fn estop(&mut self) -> Result<(), Self::Error> {
+        self.verifier.only_owner()?;
+        self.stop(self.vm().msg_sender())
+    }
+
+    fn estop_with_proof(&mut self, seal: Vec<u8>, claim_digest: B256) -> Result<(), Self::Error> {
+        if claim_digest != EXPLOIT_CLAIM_DIGEST {
+            return Err(RiscZeroError::InvalidProofOfExploit.abi_encode());
+        }
+        self.verify_integrity(seal, claim_digest)?;
+
+        self.stop(self.vm().msg_sender())
+    }
+
+    fn verify(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        self.when_not_stopped()?;
+        self.verifier.verify(seal, image_id, journal_digest)
+    }
+
+    fn verify_claim(&self, seal: Vec<u8>, claim: abi::ReceiptClaim) -> Result<bool, Self::Error> {
+        self.when_not_stopped()?;
+        self.verifier.verify_claim(seal, claim)
+    }
+
+    fn verify_integrity(
+        &self,
+        receipt_seal: Vec<u8>,
+        receipt_claim_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        self.when_not_stopped()?;
+        self.verifier
+            .verify_integrity(receipt_seal, receipt_claim_digest)
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.stopped.get()
+    }
+}
+
+#[public]
+impl IOwnable for RiscZeroVerifierEmergencyStop {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.verifier.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.verifier.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.verifier.transfer_ownership(new_owner)
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.verifier.accept_ownership()
+    }
+}
+
+impl RiscZeroVerifierEmergencyStop {
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.verifier.set_initial_owner(owner)
+    }
+
+    fn when_not_stopped(&self) -> Result<(), Vec<u8>> {
+        if self.stopped.get() {
+            return Err(RiscZeroError::EmergencyStopped.abi_encode());
+        }
+        Ok(())
+    }
+
+    fn stop(&mut self, caller: Address) -> Result<(), Vec<u8>> {
+        self.when_not_stopped()?;
+        self.stopped.set(true);
+        log(self.vm(), Stopped { caller });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risc0::{
+        crypto::v2_0,
+        testing::{self, IMAGE_ID, JOURNAL_DIGEST, SEAL},
+        types::ReceiptClaim,
+    };
+    use stylus_sdk::{alloy_sol_types::SolEvent, testing::*};
+
+    const OWNER: Address = Address::repeat_byte(1);
+    const ANYONE: Address = Address::repeat_byte(2);
+
+    fn deploy(vm: &TestVM) -> RiscZeroVerifierEmergencyStop {
+        let mut estop = RiscZeroVerifierEmergencyStop::from(vm);
+        estop.set_initial_owner(OWNER).unwrap();
+        vm.set_sender(OWNER);
+        estop
+            .initialize(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID)
+            .unwrap();
+        estop
+    }
+
+    fn claim() -> abi::ReceiptClaim {
+        abi::ReceiptClaim {
+            preStateDigest: IMAGE_ID,
+            postStateDigest: B256::repeat_byte(3),
+            exitCode: abi::ExitCode {
+                system: abi::SystemExitCode::Halted,
+                user: 0,
+            },
+            input: B256::ZERO,
+            output: abi::Output {
+                journalDigest: JOURNAL_DIGEST,
+                assumptionsDigest: B256::ZERO,
+            },
+        }
+    }
+
+    fn assert_stopped_by(vm: &TestVM, caller: Address) {
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = logs.last().unwrap();
+        assert_eq!(
+            Stopped::decode_raw_log(topics, data, true).unwrap().caller,
+            caller
+        );
+    }
+
+    fn assert_blocked(vm: &TestVM, estop: &mut RiscZeroVerifierEmergencyStop, seal: Vec<u8>) {
+        let stopped = RiscZeroError::EmergencyStopped.abi_encode();
+        assert!(estop.is_stopped());
+        assert_eq!(
+            estop.verify(seal.clone(), IMAGE_ID, JOURNAL_DIGEST),
+            Err(stopped.clone())
+        );
+        assert_eq!(
+            estop.verify_claim(seal.clone(), claim()),
+            Err(stopped.clone())
+        );
+        assert_eq!(
+            estop.verify_integrity(seal.clone(), B256::ZERO),
+            Err(stopped.clone())
+        );
+        vm.set_sender(OWNER);
+        assert_eq!(estop.estop(), Err(stopped.clone()));
+        assert_eq!(
+            estop.estop_with_proof(seal, EXPLOIT_CLAIM_DIGEST),
+            Err(stopped)
+        );
+    }
+
+    #[test]
+    fn owner_stops_verification() {
+        let vm = TestVM::default();
+        let mut estop = deploy(&vm);
+        assert!(!estop.is_stopped());
+        assert_eq!(
+            estop.verify(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Ok(true)
+        );
+
+        vm.set_sender(ANYONE);
+        assert!(estop.estop().is_err());
+        assert!(!estop.is_stopped());
+
+        vm.set_sender(OWNER);
+        estop.estop().unwrap();
+        assert_stopped_by(&vm, OWNER);
+        assert_blocked(&vm, &mut estop, SEAL.to_vec());
+    }
+
+    #[test]
+    fn anyone_stops_verification_with_a_proof_of_exploit() {
+        let vm = TestVM::default();
+        let mut estop = deploy(&vm);
+        let key = testing::use_trapdoor_key();
+        let selector = v2_0::calculate_selector();
+        let seal = |claim_digest| {
+            key.seal(
+                selector,
+                v2_0::CONTROL_ROOT,
+                v2_0::BN254_CONTROL_ID,
+                claim_digest,
+            )
+        };
+
+        // The verifier is unsound under this key: it accepts seals for any claim.
+        let claim_digest = ReceiptClaim::try_from(claim()).unwrap().digest();
+        assert_eq!(estop.verify_claim(seal(claim_digest), claim()), Ok(true));
+
+        vm.set_sender(ANYONE);
+        assert_eq!(
+            estop.estop_with_proof(seal(claim_digest), claim_digest),
+            Err(RiscZeroError::InvalidProofOfExploit.abi_encode())
+        );
+        assert!(estop
+            .estop_with_proof(seal(claim_digest), EXPLOIT_CLAIM_DIGEST)
+            .is_err());
+        assert!(!estop.is_stopped());
+
+        estop
+            .estop_with_proof(seal(EXPLOIT_CLAIM_DIGEST), EXPLOIT_CLAIM_DIGEST)
+            .unwrap();
+        assert_stopped_by(&vm, ANYONE);
+        assert_blocked(&vm, &mut estop, seal(claim_digest));
+    }
+
+    #[test]
+    fn rejects_a_real_receipt_as_proof_of_exploit() {
+        let vm = TestVM::default();
+        let mut estop = deploy(&vm);
+        let claim_digest = ReceiptClaim::ok(IMAGE_ID, JOURNAL_DIGEST).digest();
+        let logs = vm.get_emitted_logs().len();
+
+        vm.set_sender(ANYONE);
+        assert_eq!(
+            estop.verify_integrity(SEAL.to_vec(), claim_digest),
+            Ok(true)
+        );
+        assert_eq!(
+            estop.estop_with_proof(SEAL.to_vec(), claim_digest),
+            Err(RiscZeroError::InvalidProofOfExploit.abi_encode())
+        );
+        assert!(estop
+            .estop_with_proof(SEAL.to_vec(), EXPLOIT_CLAIM_DIGEST)
+            .is_err());
+        assert!(!estop.is_stopped());
+        assert_eq!(vm.get_emitted_logs().len(), logs);
+    }
+}
@@ -0,0 +1,417 @@
+//! Host-side encoding of RISC Zero Groth16 receipts into verifier calldata.
+//!
+//! Parses a `risc0_zkvm::Receipt` serialized with serde, as JSON or bincode, without
+//! depending on the zkVM itself. Only Groth16 receipts are accepted; the seal they carry
+//! is the 256-byte encoding of [`Seal`], and the contract expects it prefixed with the
+//! selector, which is the head of the receipt's verifier parameters digest.
+
+use std::{boxed::Box, fmt, vec::Vec};
+
+use serde::{de::Error as _, Deserialize, Deserializer};
+use sha2::{Digest as _, Sha256};
+use stylus_sdk::{
+    alloy_primitives::{FixedBytes, B256},
+    alloy_sol_types::SolValue,
+};
+
+use crate::risc0::{
+    config::tags,
+    crypto::digest_utils::{self, DownDigest, TaggedDigest},
+    types::{self, Assumption, ExitCode, ReceiptClaim, Seal, SystemExitCode},
+    verifier::calculate_selector,
+};
+
+const SEAL_SIZE: usize = 256;
+const SYSTEM_STATE_TAG: &[u8] = b"risc0.SystemState";
+
+#[derive(Debug)]
+pub enum HostError {
+    Json(serde_json::Error),
+    Bincode(bincode::Error),
+    InvalidSealLength {
+        len: usize,
+    },
+    UnsupportedExitCode {
+        system: u32,
+        user: u32,
+    },
+    /// The claim is pruned, so its image ID and journal are unknown.
+    PrunedClaim,
+    /// The claim is not that of a successful run with this journal.
+    NotOk,
+    /// The receipt is not a Groth16 receipt.
+    UnsupportedReceipt,
+}
+
+impl fmt::Display for HostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostError::Json(e) => write!(f, "invalid receipt JSON: {e}"),
+            HostError::Bincode(e) => write!(f, "invalid receipt bincode: {e}"),
+            HostError::InvalidSealLength { len } => {
+                write!(f, "seal is {len} bytes, expected {SEAL_SIZE}")
+            }
+            HostError::UnsupportedExitCode { system, user } => {
+                write!(f, "unsupported exit code ({system}, {user})")
+            }
+            HostError::PrunedClaim => write!(f, "receipt claim is pruned"),
+            HostError::NotOk => write!(f, "receipt is not of a successful run"),
+            HostError::UnsupportedReceipt => write!(f, "receipt is not a Groth16 receipt"),
+        }
+    }
+}
+
+impl std::error::Error for HostError {}
+
+impl From<serde_json::Error> for HostError {
+    fn from(e: serde_json::Error) -> Self {
+        HostError::Json(e)
+    }
+}
+
+impl From<bincode::Error> for HostError {
+    fn from(e: bincode::Error) -> Self {
+        HostError::Bincode(e)
+    }
+}
+
+/// A Groth16 receipt, reduced to what the contract checks.
+#[derive(Clone)]
+pub struct Groth16Receipt {
+    /// Groth16 seal, without selector.
+    pub seal: [u8; SEAL_SIZE],
+    pub claim_digest: B256,
+    /// The claim, unless the receipt only carries its digest.
+    pub claim: Option<ReceiptClaim>,
+    pub journal: Vec<u8>,
+    pub verifier_parameters: B256,
+}
+
+impl Groth16Receipt {
+    pub fn from_json(json: &str) -> Result<Self, HostError> {
+        serde_json::from_str::<serde_repr::Receipt>(json)?.try_into()
+    }
+
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, HostError> {
+        bincode::deserialize::<serde_repr::Receipt>(bytes)?.try_into()
+    }
+
+    pub fn selector(&self) -> FixedBytes<4> {
+        FixedBytes::from_slice(&self.verifier_parameters[..4])
+    }
+
+    /// `seal` argument of the verify methods: selector followed by the seal.
+    pub fn encoded_seal(&self) -> Vec<u8> {
+        [self.selector().as_slice(), &self.seal].concat()
+    }
+
+    pub fn journal_digest(&self) -> B256 {
+        B256::from_slice(&Sha256::digest(&self.journal))
+    }
+
+    /// Arguments of `verify`: seal, image ID and journal digest. Fails unless the receipt
+    /// is of a run that halted with exit code 0 and committed this journal.
+    pub fn verify_calldata(&self) -> Result<(Vec<u8>, B256, B256), HostError> {
+        let claim = self.claim.as_ref().ok_or(HostError::PrunedClaim)?;
+        let image_id = claim.pre_state_digest;
+        let journal_digest = self.journal_digest();
+        if ReceiptClaim::ok(image_id, journal_digest).digest() != self.claim_digest {
+            return Err(HostError::NotOk);
+        }
+        Ok((self.encoded_seal(), image_id, journal_digest))
+    }
+
+    /// Arguments of `verify_integrity`: seal and claim digest.
+    pub fn verify_integrity_calldata(&self) -> (Vec<u8>, B256) {
+        (self.encoded_seal(), self.claim_digest)
+    }
+}
+
+/// Encodes a seal for the verifier with the given selector.
+pub fn encode_seal(selector: FixedBytes<4>, seal: &Seal) -> Vec<u8> {
+    [selector.as_slice(), &seal.abi_encode()].concat()
+}
+
+/// Selector under which a verifier initialized with these parameters accepts seals.
+pub fn predict_selector(control_root: B256, bn254_control_id: B256) -> FixedBytes<4> {
+    calculate_selector(
+        control_root,
+        bn254_control_id,
+        digest_utils::compute_verifier_key_digest(),
+    )
+}
+
+impl TryFrom<serde_repr::Receipt> for Groth16Receipt {
+    type Error = HostError;
+
+    fn try_from(receipt: serde_repr::Receipt) -> Result<Self, HostError> {
+        let serde_repr::InnerReceipt::Groth16(inner) = receipt.inner else {
+            return Err(HostError::UnsupportedReceipt);
+        };
+        let seal = inner
+            .seal
+            .as_slice()
+            .try_into()
+            .map_err(|_| HostError::InvalidSealLength {
+                len: inner.seal.len(),
+            })?;
+        let (claim_digest, claim) = match inner.claim {
+            serde_repr::MaybePruned::Value(claim) => {
+                let claim = claim.into_claim()?;
+                (claim.digest(), Some(claim))
+            }
+            serde_repr::MaybePruned::Pruned(digest) => (digest.into(), None),
+        };
+        Ok(Groth16Receipt {
+            seal,
+            claim_digest,
+            claim,
+            journal: receipt.journal.bytes,
+            verifier_parameters: inner.verifier_parameters.into(),
+        })
+    }
+}
+
+/// Mirror of the serde layout of the `risc0_zkvm` receipt types.
+mod serde_repr {
+    use super::*;
+
+    /// Digest as eight little-endian words.
+    #[derive(Clone, Copy, Deserialize)]
+    pub struct Digest([u32; 8]);
+
+    impl DownDigest for Digest {
+        fn down_digest(&self) -> B256 {
+            B256::from(*self)
+        }
+    }
+
+    impl From<Digest> for B256 {
+        fn from(digest: Digest) -> Self {
+            let mut bytes = [0u8; 32];
+            for (chunk, word) in bytes.chunks_exact_mut(4).zip(digest.0) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+            }
+            B256::new(bytes)
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub enum MaybePruned<T> {
+        Value(T),
+        Pruned(Digest),
+    }
+
+    impl<T> MaybePruned<T> {
+        fn digest(self, f: impl FnOnce(T) -> B256) -> B256 {
+            match self {
+                MaybePruned::Value(value) => f(value),
+                MaybePruned::Pruned(digest) => digest.into(),
+            }
+        }
+    }
+
+    /// Stands in for variants this module does not decode.
+    pub struct Unsupported;
+
+    impl<'de> Deserialize<'de> for Unsupported {
+        fn deserialize<D: Deserializer<'de>>(_: D) -> Result<Self, D::Error> {
+            Err(D::Error::custom("only Groth16 receipts are supported"))
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub struct Receipt {
+        pub inner: InnerReceipt,
+        pub journal: Journal,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Journal {
+        pub bytes: Vec<u8>,
+    }
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    pub enum InnerReceipt {
+        Composite(Unsupported),
+        Succinct(Unsupported),
+        Groth16(Box<Groth16Receipt>),
+        Fake(Unsupported),
+    }
+
+    #[derive(Deserialize)]
+    pub struct Groth16Receipt {
+        pub seal: Vec<u8>,
+        pub claim: MaybePruned<ReceiptClaim>,
+        pub verifier_parameters: Digest,
+    }
+
+    #[derive(Deserialize)]
+    pub struct ReceiptClaim {
+        pre: MaybePruned<SystemState>,
+        post: MaybePruned<SystemState>,
+        exit_code: ExitCode,
+        input: MaybePruned<Option<Unsupported>>,
+        output: MaybePruned<Option<Output>>,
+    }
+
+    impl ReceiptClaim {
+        pub fn into_claim(self) -> Result<super::ReceiptClaim, HostError> {
+            Ok(super::ReceiptClaim {
+                pre_state_digest: self.pre.digest(|state| state.tagged_digest()),
+                post_state_digest: self.post.digest(|state| state.tagged_digest()),
+                exit_code: self.exit_code.try_into()?,
+                input: self.input.digest(|_| B256::ZERO),
+                output: self.output.digest(|output| match output {
+                    Some(output) => output.digest(),
+                    None => B256::ZERO,
+                }),
+            })
+        }
+    }
+
+    #[derive(Deserialize, TaggedDigest)]
+    #[tagged(tag = SYSTEM_STATE_TAG)]
+    pub struct SystemState {
+        #[tagged(data)]
+        pc: u32,
+        #[tagged(down)]
+        merkle_root: Digest,
+    }
+
+    #[derive(Deserialize)]
+    pub enum ExitCode {
+        Halted(u32),
+        Paused(u32),
+        SystemSplit,
+        SessionLimit,
+    }
+
+    impl TryFrom<ExitCode> for super::ExitCode {
+        type Error = HostError;
+
+        fn try_from(exit_code: ExitCode) -> Result<Self, HostError> {
+            let (system, user) = match exit_code {
+                ExitCode::Halted(user) => (SystemExitCode::Halted, user),
+                ExitCode::Paused(user) => (SystemExitCode::Paused, user),
+                ExitCode::SystemSplit => (SystemExitCode::SystemSplit, 0),
+                ExitCode::SessionLimit => (SystemExitCode::SystemSplit, 2),
+            };
+            let user = u8::try_from(user).map_err(|_| HostError::UnsupportedExitCode {
+                system: system as u32,
+                user,
+            })?;
+            Ok(super::ExitCode { system, user })
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub struct Output {
+        journal: MaybePruned<Vec<u8>>,
+        assumptions: MaybePruned<Assumptions>,
+    }
+
+    impl Output {
+        fn digest(self) -> B256 {
+            types::Output {
+                journal_digest: self
+                    .journal
+                    .digest(|journal| B256::from_slice(&Sha256::digest(journal))),
+                assumptions_digest: self.assumptions.digest(Assumptions::digest),
+            }
+            .digest()
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub struct Assumptions(Vec<MaybePruned<AssumptionRepr>>);
+
+    impl Assumptions {
+        fn digest(self) -> B256 {
+            let digests: Vec<B256> = self
+                .0
+                .into_iter()
+                .map(|a| a.digest(|a| Assumption::from(a).digest()))
+                .collect();
+            digest_utils::tagged_list(tags::ASSUMPTIONS_TAG_DIGEST, &digests)
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub struct AssumptionRepr {
+        claim: Digest,
+        control_root: Digest,
+    }
+
+    impl From<AssumptionRepr> for Assumption {
+        fn from(a: AssumptionRepr) -> Self {
+            Assumption {
+                claim: a.claim.into(),
+                control_root: a.control_root.into(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risc0::testing::{IMAGE_ID, JOURNAL, JOURNAL_DIGEST, SEAL};
+
+    /// `risc0_zkvm::Receipt` of `testing::SEAL`, written by `testdata/risc0/generate`.
+    const RECEIPT_JSON: &str = include_str!("../../testdata/risc0/receipt.json");
+    const RECEIPT_BINCODE: &[u8] = include_bytes!("../../testdata/risc0/receipt.bin");
+
+    fn check_receipt(receipt: &Groth16Receipt) {
+        let (seal, image_id, journal_digest) = receipt.verify_calldata().unwrap();
+        assert_eq!(seal, SEAL);
+        assert_eq!(image_id, IMAGE_ID);
+        assert_eq!(journal_digest, JOURNAL_DIGEST);
+
+        assert_eq!(receipt.encoded_seal(), SEAL);
+        assert_eq!(receipt.journal, JOURNAL);
+        assert_eq!(
+            receipt.verify_integrity_calldata(),
+            (
+                SEAL.to_vec(),
+                ReceiptClaim::ok(IMAGE_ID, JOURNAL_DIGEST).digest()
+            )
+        );
+    }
+
+    #[test]
+    fn parses_json_receipt() {
+        check_receipt(&Groth16Receipt::from_json(RECEIPT_JSON).unwrap());
+    }
+
+    #[test]
+    fn parses_bincode_receipt() {
+        check_receipt(&Groth16Receipt::from_bincode(RECEIPT_BINCODE).unwrap());
+    }
+
+    #[test]
+    fn rejects_receipt_of_another_journal() {
+        let mut receipt = Groth16Receipt::from_json(RECEIPT_JSON).unwrap();
+        receipt.journal.push(0);
+        assert!(matches!(receipt.verify_calldata(), Err(HostError::NotOk)));
+    }
+
+    #[test]
+    fn rejects_other_receipt_kinds() {
+        let json = r#"{"inner":{"Succinct":{}},"journal":{"bytes":[]}}"#;
+        assert!(matches!(
+            Groth16Receipt::from_json(json),
+            Err(HostError::Json(_))
+        ));
+
+        let receipt = serde_repr::Receipt {
+            inner: serde_repr::InnerReceipt::Fake(serde_repr::Unsupported),
+            journal: serde_repr::Journal { bytes: Vec::new() },
+        };
+        assert!(matches!(
+            Groth16Receipt::try_from(receipt),
+            Err(HostError::UnsupportedReceipt)
+        ));
+    }
+}
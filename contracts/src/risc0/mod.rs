@@ -1,9 +1,27 @@
 pub mod config;
 pub mod crypto;
 pub mod errors;
+#[cfg(feature = "risc0-estop")]
+pub mod estop;
+#[cfg(feature = "risc0-host")]
+pub mod host;
+pub mod registry;
+#[cfg(feature = "risc0-router")]
+pub mod router;
+#[cfg(feature = "risc0-succinct")]
+pub mod succinct;
+#[cfg(test)]
+pub mod testing;
 pub mod types;
 pub mod verifier;
 
 pub use errors::RiscZeroError;
-pub use types::{ReceiptClaim, Seal};
-pub use verifier::{RiscZeroVerifier, IRiscZeroVerifier}; 
\ No newline at end of file
+#[cfg(feature = "risc0-estop")]
+pub use estop::{IRiscZeroVerifierEmergencyStop, RiscZeroVerifierEmergencyStop};
+pub use registry::IRiscZeroImageRegistry;
+#[cfg(feature = "risc0-router")]
+pub use router::{IRiscZeroVerifierRouter, RiscZeroVerifierRouter};
+#[cfg(feature = "risc0-succinct")]
+pub use succinct::IRiscZeroSuccinctVerifier;
+pub use types::{Assumption, ExitCode, ReceiptClaim, Seal, SystemExitCode};
+pub use verifier::{IRiscZeroVerifier, IRiscZeroVerifierGovernance, RiscZeroVerifier};
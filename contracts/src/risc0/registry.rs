@@ -0,0 +1,293 @@
+//! Allowlist of guest programs for [`RiscZeroVerifier`](super::RiscZeroVerifier).
+//!
+//! Each registered image ID carries a label, a validity window and the callers allowed to
+//! verify against it. The registry only restricts `verify_registered`; the other verify
+//! methods accept any image ID.
+
+use alloc::{string::String, vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{Address, B256, U64},
+    alloy_sol_types::sol,
+    prelude::*,
+    stylus_core::log,
+};
+
+use crate::risc0::errors::RiscZeroError;
+
+sol! {
+    event ImageRegistered(
+        bytes32 indexed imageId,
+        string label,
+        uint64 validFrom,
+        uint64 validUntil,
+        address[] callers
+    );
+    event ImageRemoved(bytes32 indexed imageId);
+}
+
+pub trait IRiscZeroImageRegistry {
+    type Error;
+
+    /// Registers `image_id`, replacing any previous entry; owner only. The image is valid
+    /// from `valid_from` up to but excluding `valid_until`, where zero means no end. An
+    /// empty `callers` lets anyone verify against it.
+    fn register_image(
+        &mut self,
+        image_id: B256,
+        label: String,
+        valid_from: u64,
+        valid_until: u64,
+        callers: Vec<Address>,
+    ) -> Result<(), Self::Error>;
+
+    fn remove_image(&mut self, image_id: B256) -> Result<(), Self::Error>;
+
+    /// Label, validity window and allowed callers of `image_id`.
+    fn get_image(&self, image_id: B256) -> Result<(String, u64, u64, Vec<Address>), Self::Error>;
+
+    /// Like `verify`, but only for registered images within their validity window, called
+    /// by an allowed caller.
+    fn verify_registered(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error>;
+}
+
+sol_storage! {
+    pub struct ImageEntry {
+        bool registered;
+        string label;
+        uint64 valid_from;
+        uint64 valid_until;
+        address[] callers;
+    }
+
+    pub struct ImageRegistry {
+        mapping(bytes32 => ImageEntry) images;
+    }
+}
+
+impl ImageRegistry {
+    pub fn register(
+        &mut self,
+        image_id: B256,
+        label: String,
+        valid_from: u64,
+        valid_until: u64,
+        callers: Vec<Address>,
+    ) -> Result<(), RiscZeroError> {
+        if valid_until != 0 && valid_until <= valid_from {
+            return Err(RiscZeroError::InvalidValidityWindow);
+        }
+
+        let mut entry = self.images.setter(image_id);
+        entry.registered.set(true);
+        entry.label.set_str(&label);
+        entry.valid_from.set(U64::from(valid_from));
+        entry.valid_until.set(U64::from(valid_until));
+        entry.callers.erase();
+        for caller in &callers {
+            entry.callers.push(*caller);
+        }
+
+        log(
+            self.vm(),
+            ImageRegistered {
+                imageId: image_id,
+                label,
+                validFrom: valid_from,
+                validUntil: valid_until,
+                callers,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn remove(&mut self, image_id: B256) -> Result<(), RiscZeroError> {
+        self.check_registered(image_id)?;
+
+        let mut entry = self.images.setter(image_id);
+        entry.registered.set(false);
+        entry.label.erase();
+        entry.valid_from.set(U64::ZERO);
+        entry.valid_until.set(U64::ZERO);
+        entry.callers.erase();
+
+        log(self.vm(), ImageRemoved { imageId: image_id });
+        Ok(())
+    }
+
+    pub fn get(&self, image_id: B256) -> Result<(String, u64, u64, Vec<Address>), RiscZeroError> {
+        self.check_registered(image_id)?;
+
+        let entry = self.images.getter(image_id);
+        let callers = (0..entry.callers.len())
+            .filter_map(|i| entry.callers.get(i))
+            .collect();
+        Ok((
+            entry.label.get_string(),
+            entry.valid_from.get().to::<u64>(),
+            entry.valid_until.get().to::<u64>(),
+            callers,
+        ))
+    }
+
+    /// Checks that the current caller may verify against `image_id` at this time.
+    pub fn check(&self, image_id: B256) -> Result<(), RiscZeroError> {
+        self.check_registered(image_id)?;
+
+        let entry = self.images.getter(image_id);
+        let now = self.vm().block_timestamp();
+        let valid_from = entry.valid_from.get().to::<u64>();
+        let valid_until = entry.valid_until.get().to::<u64>();
+        if now < valid_from || (valid_until != 0 && now >= valid_until) {
+            return Err(RiscZeroError::ImageNotActive { image_id });
+        }
+
+        let caller = self.vm().msg_sender();
+        let allowed = entry.callers.is_empty()
+            || (0..entry.callers.len()).any(|i| entry.callers.get(i) == Some(caller));
+        if !allowed {
+            return Err(RiscZeroError::CallerNotAllowed { image_id, caller });
+        }
+        Ok(())
+    }
+
+    fn check_registered(&self, image_id: B256) -> Result<(), RiscZeroError> {
+        if !self.images.getter(image_id).registered.get() {
+            return Err(RiscZeroError::ImageNotRegistered { image_id });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::{alloy_sol_types::SolEvent, testing::*};
+
+    const IMAGE: B256 = B256::repeat_byte(0xaa);
+    const ALICE: Address = Address::repeat_byte(1);
+    const BOB: Address = Address::repeat_byte(2);
+
+    fn encoded<T>(result: Result<T, RiscZeroError>) -> Result<T, Vec<u8>> {
+        result.map_err(|e| e.abi_encode())
+    }
+
+    fn not_registered() -> Vec<u8> {
+        RiscZeroError::ImageNotRegistered { image_id: IMAGE }.abi_encode()
+    }
+
+    fn not_active() -> Vec<u8> {
+        RiscZeroError::ImageNotActive { image_id: IMAGE }.abi_encode()
+    }
+
+    #[test]
+    fn registers_and_removes_images() {
+        let vm = TestVM::default();
+        let mut registry = ImageRegistry::from(&vm);
+        assert_eq!(encoded(registry.get(IMAGE)), Err(not_registered()));
+
+        registry
+            .register(IMAGE, "guest".into(), 10, 20, vec![ALICE, BOB])
+            .unwrap();
+        assert_eq!(
+            encoded(registry.get(IMAGE)),
+            Ok(("guest".into(), 10, 20, vec![ALICE, BOB]))
+        );
+        let logs = vm.get_emitted_logs();
+        let registered = ImageRegistered::decode_raw_log(&logs[0].0, &logs[0].1, true).unwrap();
+        assert_eq!(registered.imageId, IMAGE);
+        assert_eq!(registered.label, "guest");
+        assert_eq!((registered.validFrom, registered.validUntil), (10, 20));
+        assert_eq!(registered.callers, vec![ALICE, BOB]);
+
+        // Registering again replaces the entry, callers included.
+        registry
+            .register(IMAGE, "guest v2".into(), 0, 0, vec![BOB])
+            .unwrap();
+        assert_eq!(
+            encoded(registry.get(IMAGE)),
+            Ok(("guest v2".into(), 0, 0, vec![BOB]))
+        );
+
+        registry.remove(IMAGE).unwrap();
+        let logs = vm.get_emitted_logs();
+        let removed = ImageRemoved::decode_raw_log(&logs[2].0, &logs[2].1, true).unwrap();
+        assert_eq!(removed.imageId, IMAGE);
+        assert_eq!(encoded(registry.get(IMAGE)), Err(not_registered()));
+        assert_eq!(encoded(registry.check(IMAGE)), Err(not_registered()));
+        assert_eq!(encoded(registry.remove(IMAGE)), Err(not_registered()));
+    }
+
+    #[test]
+    fn rejects_empty_validity_windows() {
+        let vm = TestVM::default();
+        let mut registry = ImageRegistry::from(&vm);
+
+        for (from, until) in [(10, 10), (10, 9)] {
+            assert_eq!(
+                encoded(registry.register(IMAGE, "guest".into(), from, until, Vec::new())),
+                Err(RiscZeroError::InvalidValidityWindow.abi_encode())
+            );
+        }
+        assert!(registry.get(IMAGE).is_err());
+        assert!(vm.get_emitted_logs().is_empty());
+    }
+
+    #[test]
+    fn images_are_active_within_their_window() {
+        let vm = TestVM::default();
+        let mut registry = ImageRegistry::from(&vm);
+        registry
+            .register(IMAGE, "guest".into(), 100, 200, Vec::new())
+            .unwrap();
+
+        for (now, expected) in [
+            (99, Err(not_active())),
+            (100, Ok(())),
+            (199, Ok(())),
+            (200, Err(not_active())),
+        ] {
+            vm.set_block_timestamp(now);
+            assert_eq!(encoded(registry.check(IMAGE)), expected, "at {now}");
+        }
+
+        // A zero end leaves the window open.
+        registry
+            .register(IMAGE, "guest".into(), 100, 0, Vec::new())
+            .unwrap();
+        vm.set_block_timestamp(u64::MAX);
+        assert_eq!(encoded(registry.check(IMAGE)), Ok(()));
+        vm.set_block_timestamp(99);
+        assert_eq!(encoded(registry.check(IMAGE)), Err(not_active()));
+    }
+
+    #[test]
+    fn only_listed_callers_may_verify() {
+        let vm = TestVM::default();
+        let mut registry = ImageRegistry::from(&vm);
+        registry
+            .register(IMAGE, "guest".into(), 0, 0, vec![ALICE])
+            .unwrap();
+
+        vm.set_sender(ALICE);
+        assert_eq!(encoded(registry.check(IMAGE)), Ok(()));
+        vm.set_sender(BOB);
+        assert_eq!(
+            encoded(registry.check(IMAGE)),
+            Err(RiscZeroError::CallerNotAllowed {
+                image_id: IMAGE,
+                caller: BOB
+            }
+            .abi_encode())
+        );
+
+        registry
+            .register(IMAGE, "guest".into(), 0, 0, Vec::new())
+            .unwrap();
+        assert_eq!(encoded(registry.check(IMAGE)), Ok(()));
+    }
+}
@@ -0,0 +1,500 @@
+//! Routing of Groth16 seals to the verifier parameters of their RISC Zero release, after
+//! risc0-ethereum's `RiscZeroVerifierRouter`.
+//!
+//! Each release is registered under the selector its seals start with, derived from its
+//! control root, BN254 control id and verification key. A removed selector can never be
+//! registered again.
+
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{Address, FixedBytes, B256, U256, U8},
+    alloy_sol_types::sol,
+    prelude::*,
+    stylus_core::log,
+};
+
+use crate::common::{
+    ownable::{IOwnable, Ownable},
+    G1Point, G2Point, VerificationKey, Q,
+};
+use crate::risc0::{
+    crypto::{digest_utils, vk},
+    errors::RiscZeroError,
+    types::{abi, ReceiptClaim},
+    verifier::{calculate_selector, validate_parameters, verify_seal},
+};
+
+const STATUS_NONE: u8 = 0;
+const STATUS_ACTIVE: u8 = 1;
+const STATUS_REMOVED: u8 = 2;
+
+/// Number of public signals of the RISC Zero Groth16 circuit.
+const NUM_PUBLIC_SIGNALS: usize = 5;
+
+sol! {
+    event VerifierAdded(
+        bytes4 indexed selector,
+        bytes32 controlRoot,
+        bytes32 bn254ControlId,
+        bytes32 verifierKeyDigest
+    );
+    event VerifierRemoved(bytes4 indexed selector);
+}
+
+/// A Groth16 verification key read from storage.
+///
+/// Encoded as 32-byte big-endian words: `alpha1` (x, y), then `beta2`, `gamma2` and
+/// `delta2` (x_c0, x_c1, y_c0, y_c1), then the six `IC` points (x, y).
+pub struct RouterKey {
+    pub alpha1: G1Point,
+    pub beta2: G2Point,
+    pub gamma2: G2Point,
+    pub delta2: G2Point,
+    pub ic: Vec<G1Point>,
+}
+
+impl RouterKey {
+    pub const ENCODED_SIZE: usize = (2 + 3 * 4 + 2 * (NUM_PUBLIC_SIGNALS + 1)) * 32;
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::ENCODED_SIZE {
+            return None;
+        }
+        let words: Vec<U256> = bytes.chunks_exact(32).map(U256::from_be_slice).collect();
+        if words.iter().any(|w| *w >= Q) {
+            return None;
+        }
+
+        let g1 = |i: usize| G1Point {
+            x: words[i],
+            y: words[i + 1],
+        };
+        let g2 = |i: usize| G2Point {
+            x: [words[i], words[i + 1]],
+            y: [words[i + 2], words[i + 3]],
+        };
+        Some(Self {
+            alpha1: g1(0),
+            beta2: g2(2),
+            gamma2: g2(6),
+            delta2: g2(10),
+            ic: (0..=NUM_PUBLIC_SIGNALS).map(|i| g1(14 + 2 * i)).collect(),
+        })
+    }
+
+    pub fn as_key(&self) -> VerificationKey<'_> {
+        VerificationKey {
+            alpha1: self.alpha1,
+            beta2: self.beta2,
+            gamma2: self.gamma2,
+            delta2: self.delta2,
+            ic: &self.ic,
+        }
+    }
+}
+
+pub trait IRiscZeroVerifierRouter {
+    type Error;
+
+    /// Registers a release and returns its selector. An empty `verification_key` selects
+    /// the key built into this crate.
+    fn add_verifier(
+        &mut self,
+        control_root: B256,
+        bn254_control_id: B256,
+        verification_key: Vec<u8>,
+    ) -> Result<FixedBytes<4>, Self::Error>;
+
+    fn remove_verifier(&mut self, selector: FixedBytes<4>) -> Result<(), Self::Error>;
+
+    /// Control root, BN254 control id and verifier key digest registered under `selector`.
+    fn get_verifier(&self, selector: FixedBytes<4>) -> Result<(B256, B256, B256), Self::Error>;
+
+    fn verify(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error>;
+
+    fn verify_claim(&self, seal: Vec<u8>, claim: abi::ReceiptClaim) -> Result<bool, Self::Error>;
+
+    fn verify_integrity(
+        &self,
+        receipt_seal: Vec<u8>,
+        receipt_claim_digest: B256,
+    ) -> Result<bool, Self::Error>;
+}
+
+sol_storage! {
+    pub struct RouterEntry {
+        uint8 status;
+        bytes32 control_root;
+        bytes32 bn254_control_id;
+        bytes verification_key;
+    }
+
+    pub struct RiscZeroVerifierRouter {
+        Ownable ownable;
+        mapping(bytes4 => RouterEntry) verifiers;
+    }
+}
+
+#[public]
+impl IRiscZeroVerifierRouter for RiscZeroVerifierRouter {
+    type Error = Vec<u8>;
+
+    fn add_verifier(
+        &mut self,
+        control_root: B256,
+        bn254_control_id: B256,
+        verification_key: Vec<u8>,
+    ) -> Result<FixedBytes<4>, Self::Error> {
+        self.only_owner()?;
+        validate_parameters(control_root, bn254_control_id).map_err(|e| e.abi_encode())?;
+
+        let key_digest = if verification_key.is_empty() {
+            digest_utils::compute_verifier_key_digest()
+        } else {
+            let key = RouterKey::from_bytes(&verification_key)
+                .ok_or_else(|| RiscZeroError::INVALID_PROOF_DATA.abi_encode())?;
+            digest_utils::verifier_key_digest(&key.as_key())
+        };
+        let selector = calculate_selector(control_root, bn254_control_id, key_digest);
+
+        let mut entry = self.verifiers.setter(selector);
+        if entry.status.get() != U8::from(STATUS_NONE) {
+            return Err(RiscZeroError::SelectorInUse { selector }.abi_encode());
+        }
+        entry.status.set(U8::from(STATUS_ACTIVE));
+        entry.control_root.set(control_root);
+        entry.bn254_control_id.set(bn254_control_id);
+        entry.verification_key.set_bytes(&verification_key);
+
+        log(
+            self.vm(),
+            VerifierAdded {
+                selector,
+                controlRoot: control_root,
+                bn254ControlId: bn254_control_id,
+                verifierKeyDigest: key_digest,
+            },
+        );
+        Ok(selector)
+    }
+
+    fn remove_verifier(&mut self, selector: FixedBytes<4>) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        self.check_active(selector)?;
+
+        let mut entry = self.verifiers.setter(selector);
+        entry.status.set(U8::from(STATUS_REMOVED));
+        entry.control_root.set(B256::ZERO);
+        entry.bn254_control_id.set(B256::ZERO);
+        entry.verification_key.erase();
+
+        log(self.vm(), VerifierRemoved { selector });
+        Ok(())
+    }
+
+    fn get_verifier(&self, selector: FixedBytes<4>) -> Result<(B256, B256, B256), Self::Error> {
+        self.check_active(selector)?;
+
+        let entry = self.verifiers.getter(selector);
+        let key_digest = match self.key(selector)? {
+            Some(key) => digest_utils::verifier_key_digest(&key.as_key()),
+            None => digest_utils::compute_verifier_key_digest(),
+        };
+        Ok((
+            entry.control_root.get(),
+            entry.bn254_control_id.get(),
+            key_digest,
+        ))
+    }
+
+    fn verify(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        let claim = ReceiptClaim::ok(image_id, journal_digest);
+        self.verify_integrity(seal, claim.digest())
+    }
+
+    fn verify_claim(&self, seal: Vec<u8>, claim: abi::ReceiptClaim) -> Result<bool, Self::Error> {
+        let claim = ReceiptClaim::try_from(claim)
+            .map_err(|_| RiscZeroError::INVALID_PROOF_DATA.abi_encode())?;
+        self.verify_integrity(seal, claim.digest())
+    }
+
+    fn verify_integrity(
+        &self,
+        receipt_seal: Vec<u8>,
+        receipt_claim_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        if receipt_seal.len() < 4 {
+            return Err(RiscZeroError::INVALID_PROOF_DATA.abi_encode());
+        }
+        let selector = FixedBytes::<4>::from_slice(&receipt_seal[..4]);
+        self.check_active(selector)?;
+
+        let entry = self.verifiers.getter(selector);
+        let key = self.key(selector)?;
+        let verification_key = match &key {
+            Some(key) => key.as_key(),
+            None => vk::get_verification_key(),
+        };
+        verify_seal(
+            &receipt_seal[4..],
+            entry.control_root.get(),
+            entry.bn254_control_id.get(),
+            &verification_key,
+            receipt_claim_digest,
+        )
+        .map_err(|e| e.abi_encode())?;
+
+        Ok(true)
+    }
+}
+
+#[public]
+impl IOwnable for RiscZeroVerifierRouter {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.ownable.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.ownable
+            .transfer_ownership(new_owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.ownable.accept_ownership().map_err(|e| e.abi_encode())
+    }
+}
+
+impl RiscZeroVerifierRouter {
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable
+            .set_initial_owner(owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn only_owner(&self) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner().map_err(|e| e.abi_encode())
+    }
+
+    fn check_active(&self, selector: FixedBytes<4>) -> Result<(), Vec<u8>> {
+        match self.verifiers.getter(selector).status.get().to::<u8>() {
+            STATUS_ACTIVE => Ok(()),
+            STATUS_REMOVED => Err(RiscZeroError::SelectorRemoved { selector }.abi_encode()),
+            _ => Err(RiscZeroError::SelectorUnknown { selector }.abi_encode()),
+        }
+    }
+
+    /// Key registered under `selector`, or `None` for the built-in key.
+    fn key(&self, selector: FixedBytes<4>) -> Result<Option<RouterKey>, Vec<u8>> {
+        let bytes = self.verifiers.getter(selector).verification_key.get_bytes();
+        if bytes.is_empty() {
+            return Ok(None);
+        }
+        RouterKey::from_bytes(&bytes)
+            .map(Some)
+            .ok_or_else(|| RiscZeroError::INVALID_PROOF_DATA.abi_encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::R;
+    use crate::risc0::{
+        crypto::{v1_2, v2_0},
+        testing::{TrapdoorKey, IMAGE_ID, JOURNAL_DIGEST, SEAL},
+    };
+    use stylus_sdk::{alloy_sol_types::SolEvent, testing::*};
+
+    const OWNER: Address = Address::repeat_byte(1);
+    const OTHER: Address = Address::repeat_byte(2);
+
+    fn deploy(vm: &TestVM) -> RiscZeroVerifierRouter {
+        let mut router = RiscZeroVerifierRouter::from(vm);
+        router.set_initial_owner(OWNER).unwrap();
+        vm.set_sender(OWNER);
+        router
+    }
+
+    fn with_selector(selector: FixedBytes<4>, seal: &[u8]) -> Vec<u8> {
+        [selector.as_slice(), &seal[4..]].concat()
+    }
+
+    #[test]
+    fn dispatches_seals_by_selector() {
+        let vm = TestVM::default();
+        let mut router = deploy(&vm);
+
+        let v1 = router
+            .add_verifier(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID, Vec::new())
+            .unwrap();
+        let v2 = router
+            .add_verifier(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID, Vec::new())
+            .unwrap();
+        assert_eq!(v1, v1_2::calculate_selector());
+        assert_eq!(v2, v2_0::calculate_selector());
+        assert_eq!(
+            router.get_verifier(v2).unwrap(),
+            (
+                v2_0::CONTROL_ROOT,
+                v2_0::BN254_CONTROL_ID,
+                digest_utils::compute_verifier_key_digest()
+            )
+        );
+
+        let logs = vm.get_emitted_logs();
+        let added = VerifierAdded::decode_raw_log(&logs[2].0, &logs[2].1, true).unwrap();
+        assert_eq!(
+            (added.selector, added.controlRoot),
+            (v2, v2_0::CONTROL_ROOT)
+        );
+
+        assert_eq!(
+            router.verify(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Ok(true)
+        );
+        assert!(router
+            .verify(SEAL.to_vec(), IMAGE_ID, B256::repeat_byte(1))
+            .is_err());
+        // The same proof under the v1.2 selector is checked against the v1.2 control root.
+        assert_eq!(
+            router.verify(with_selector(v1, &SEAL), IMAGE_ID, JOURNAL_DIGEST),
+            Err(RiscZeroError::VERIFICATION_FAILED.abi_encode())
+        );
+    }
+
+    #[test]
+    fn dispatches_to_a_registered_key() {
+        let vm = TestVM::default();
+        let mut router = deploy(&vm);
+        let key = TrapdoorKey::default();
+        let control_root = B256::repeat_byte(7);
+
+        let selector = router
+            .add_verifier(control_root, v2_0::BN254_CONTROL_ID, key.to_bytes())
+            .unwrap();
+        let key_digest = digest_utils::verifier_key_digest(&key.as_key());
+        assert_eq!(
+            selector,
+            calculate_selector(control_root, v2_0::BN254_CONTROL_ID, key_digest)
+        );
+        assert_eq!(router.get_verifier(selector).unwrap().2, key_digest);
+
+        let claim = ReceiptClaim::ok(IMAGE_ID, JOURNAL_DIGEST).digest();
+        let seal = key.seal(selector, control_root, v2_0::BN254_CONTROL_ID, claim);
+        assert_eq!(router.verify_integrity(seal.clone(), claim), Ok(true));
+        assert!(router.verify_integrity(seal, B256::repeat_byte(1)).is_err());
+
+        let mut bad_key = key.to_bytes();
+        bad_key.pop();
+        assert!(router
+            .add_verifier(B256::repeat_byte(8), v2_0::BN254_CONTROL_ID, bad_key)
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_and_removed_selectors() {
+        let vm = TestVM::default();
+        let mut router = deploy(&vm);
+        let selector = v2_0::calculate_selector();
+
+        let unknown = RiscZeroError::SelectorUnknown { selector }.abi_encode();
+        assert_eq!(
+            router.verify(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Err(unknown.clone())
+        );
+        assert_eq!(router.get_verifier(selector), Err(unknown.clone()));
+        assert_eq!(router.remove_verifier(selector), Err(unknown));
+        assert_eq!(
+            router.verify_integrity(SEAL[..3].to_vec(), B256::ZERO),
+            Err(RiscZeroError::INVALID_PROOF_DATA.abi_encode())
+        );
+
+        router
+            .add_verifier(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID, Vec::new())
+            .unwrap();
+        assert_eq!(
+            router.add_verifier(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID, Vec::new()),
+            Err(RiscZeroError::SelectorInUse { selector }.abi_encode())
+        );
+        router.remove_verifier(selector).unwrap();
+        let logs = vm.get_emitted_logs();
+        let removed = logs.last().unwrap();
+        assert_eq!(
+            VerifierRemoved::decode_raw_log(&removed.0, &removed.1, true)
+                .unwrap()
+                .selector,
+            selector
+        );
+
+        let removed = RiscZeroError::SelectorRemoved { selector }.abi_encode();
+        assert_eq!(
+            router.verify(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Err(removed.clone())
+        );
+        assert_eq!(router.remove_verifier(selector), Err(removed));
+        assert_eq!(
+            router.add_verifier(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID, Vec::new()),
+            Err(RiscZeroError::SelectorInUse { selector }.abi_encode())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let vm = TestVM::default();
+        let mut router = deploy(&vm);
+        let invalid = Err(RiscZeroError::InvalidParameters.abi_encode());
+
+        assert_eq!(
+            router.add_verifier(B256::ZERO, v2_0::BN254_CONTROL_ID, Vec::new()),
+            invalid
+        );
+        assert_eq!(
+            router.add_verifier(v2_0::CONTROL_ROOT, B256::from(R), Vec::new()),
+            invalid
+        );
+        assert_eq!(
+            router.add_verifier(v2_0::CONTROL_ROOT, B256::repeat_byte(0xff), Vec::new()),
+            invalid
+        );
+    }
+
+    #[test]
+    fn only_the_owner_manages_releases() {
+        let vm = TestVM::default();
+        let mut router = deploy(&vm);
+        let selector = router
+            .add_verifier(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID, Vec::new())
+            .unwrap();
+
+        vm.set_sender(OTHER);
+        assert!(router
+            .add_verifier(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID, Vec::new())
+            .is_err());
+        assert!(router.remove_verifier(selector).is_err());
+        assert!(router.get_verifier(selector).is_ok());
+
+        vm.set_sender(OWNER);
+        router.transfer_ownership(OTHER).unwrap();
+        vm.set_sender(OTHER);
+        router.accept_ownership().unwrap();
+        router.remove_verifier(selector).unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+//! BabyBear and the RISC Zero quartic extension `F_p[X]/(X^4 + 11)`.
+//!
+//! Seals and Poseidon2 digests carry raw Montgomery words.
+
+use crate::common::stark::{
+    baby_bear::BabyBearParams, BabyBear, BinomialExtension, BinomialParams, MontyParams,
+};
+
+pub type Elem = BabyBear;
+
+/// Generators of the power-of-two subgroups: `ROU_FWD[i]` has order `2^i`.
+pub const ROU_FWD: [Elem; 28] = Elem::new_array([
+    1, 2013265920, 284861408, 1801542727, 567209306, 740045640, 918899846, 1881002012, 1453957774,
+    65325759, 1538055801, 515192888, 483885487, 157393079, 1695124103, 2005211659, 1540072241,
+    88064245, 1542985445, 1269900459, 1461624142, 825701067, 682402162, 1311873874, 1164520853,
+    352275361, 18769, 137,
+]);
+
+/// Inverses of `ROU_FWD`.
+pub const ROU_REV: [Elem; 28] = Elem::new_array([
+    1, 2013265920, 1728404513, 1592366214, 196396260, 1253260071, 72041623, 1091445674, 145223211,
+    1446820157, 1030796471, 2010749425, 1827366325, 1239938613, 246299276, 596347512, 1893145354,
+    246074437, 1525739923, 1194341128, 1463599021, 704606912, 95395244, 15672543, 647517488,
+    584175179, 137728885, 749463956,
+]);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExtParams;
+
+impl BinomialParams<4> for ExtParams {
+    type Base = Elem;
+    const W: Elem = Elem::new(BabyBearParams::P - 11);
+    const FROBENIUS: Elem = Elem::new(1728404513);
+}
+
+pub type ExtElem = BinomialExtension<ExtParams, 4>;
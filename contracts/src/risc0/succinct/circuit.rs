@@ -0,0 +1,253 @@
+//! Taps and constraint polynomial of the recursion circuit.
+//!
+//! The generated circuit is far larger than a Stylus program may be, so it is passed in
+//! as a blob and pinned by `RECURSION_CIRCUIT_DIGEST`. All integers are little-endian:
+//!
+//! ```text
+//! u16 n_taps      | n_taps × (u16 offset, u16 back, u8 group, u8 combo, u8 skip)
+//! u16 n           | n × u16 combo_taps
+//! u16 n           | n × u16 combo_begin
+//! 4 × u16 group_begin | u16 combos_count | u16 reg_count | u16 tot_combo_backs
+//! u16 n_steps     | u16 ret | n_steps × (u8 opcode, operands)
+//! ```
+//!
+//! Opcodes follow `PolyExtStep` of risc0-zkp: `0 Const(u32)`, `1 ConstExt(4 × u32)`,
+//! `2 Get(u16)`, `3 GetGlobal(u8, u16)`, `4 Add`, `5 Sub`, `6 Mul`, `7 True`,
+//! `8 AndEqz`, `9 AndCond`, with `u16` variable operands.
+
+use alloc::vec::Vec;
+
+use crate::risc0::succinct::baby_bear::{Elem, ExtElem};
+
+/// Register groups, in commitment order of the taps.
+pub const GROUP_ACCUM: usize = 0;
+pub const GROUP_CODE: usize = 1;
+pub const GROUP_DATA: usize = 2;
+const NUM_GROUPS: usize = 3;
+
+/// A column together with the rows (`backs`) it is queried at.
+pub struct Register {
+    pub group: usize,
+    pub offset: usize,
+    pub combo: usize,
+    /// Index of the register's first tap.
+    pub first_tap: usize,
+    pub backs: Vec<usize>,
+}
+
+enum Step {
+    Const(Elem),
+    ConstExt(ExtElem),
+    Get(usize),
+    GetGlobal(usize, usize),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    True,
+    AndEqz(usize, usize),
+    AndCond(usize, usize, usize),
+}
+
+#[derive(Clone, Copy)]
+struct MixState {
+    tot: ExtElem,
+    mul: ExtElem,
+}
+
+pub struct RecursionCircuit {
+    pub registers: Vec<Register>,
+    pub num_taps: usize,
+    group_sizes: [usize; NUM_GROUPS],
+    combo_taps: Vec<usize>,
+    pub combo_begin: Vec<usize>,
+    pub combos_count: usize,
+    pub tot_combo_backs: usize,
+    steps: Vec<Step>,
+}
+
+impl RecursionCircuit {
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut r = Reader { bytes };
+
+        let num_taps = r.u16()?;
+        let mut taps = Vec::with_capacity(num_taps);
+        for _ in 0..num_taps {
+            taps.push((r.u16()?, r.u16()?, r.u8()?, r.u8()?, r.u8()?));
+        }
+        let combo_taps = r.list()?;
+        let combo_begin = r.list()?;
+        let group_begin = [r.u16()?, r.u16()?, r.u16()?, r.u16()?];
+        let combos_count = r.u16()?;
+        let reg_count = r.u16()?;
+        let tot_combo_backs = r.u16()?;
+
+        if group_begin[0] != 0
+            || group_begin.windows(2).any(|w| w[0] >= w[1])
+            || group_begin[NUM_GROUPS] != num_taps
+            || combo_begin.len() != combos_count + 1
+            || combo_begin.windows(2).any(|w| w[0] > w[1])
+            || combo_begin[combos_count] != tot_combo_backs
+            || combo_taps.len() != tot_combo_backs
+        {
+            return None;
+        }
+
+        let mut group_sizes = [0; NUM_GROUPS];
+        for (size, end) in group_sizes.iter_mut().zip(&group_begin[1..]) {
+            *size = taps[end - 1].0 + 1;
+        }
+
+        let mut registers = Vec::with_capacity(reg_count);
+        let mut cursor = 0;
+        while cursor < num_taps {
+            let (offset, _, group, combo, skip) = taps[cursor];
+            if skip == 0
+                || cursor + skip > num_taps
+                || group >= NUM_GROUPS
+                || combo >= combos_count
+                || combo_begin[combo] + skip > combo_begin[combo + 1]
+            {
+                return None;
+            }
+            registers.push(Register {
+                group,
+                offset,
+                combo,
+                first_tap: cursor,
+                backs: taps[cursor..cursor + skip].iter().map(|t| t.1).collect(),
+            });
+            cursor += skip;
+        }
+        if registers.len() != reg_count {
+            return None;
+        }
+
+        let num_steps = r.u16()?;
+        let ret = r.u16()?;
+        let mut steps = Vec::with_capacity(num_steps);
+        let (mut fp_vars, mut mix_vars) = (0, 0);
+        for _ in 0..num_steps {
+            let fp = |x: usize| (x < fp_vars).then_some(x);
+            let mix = |x: usize| (x < mix_vars).then_some(x);
+            let step = match r.u8()? {
+                0 => Step::Const(Elem::new(r.u32()?)),
+                1 => Step::ConstExt(ExtElem::new([
+                    Elem::new(r.u32()?),
+                    Elem::new(r.u32()?),
+                    Elem::new(r.u32()?),
+                    Elem::new(r.u32()?),
+                ])),
+                2 => Step::Get(Some(r.u16()?).filter(|t| *t < num_taps)?),
+                3 => Step::GetGlobal(r.u8()?, r.u16()?),
+                4 => Step::Add(fp(r.u16()?)?, fp(r.u16()?)?),
+                5 => Step::Sub(fp(r.u16()?)?, fp(r.u16()?)?),
+                6 => Step::Mul(fp(r.u16()?)?, fp(r.u16()?)?),
+                7 => Step::True,
+                8 => Step::AndEqz(mix(r.u16()?)?, fp(r.u16()?)?),
+                9 => Step::AndCond(mix(r.u16()?)?, fp(r.u16()?)?, mix(r.u16()?)?),
+                _ => return None,
+            };
+            match step {
+                Step::True | Step::AndEqz(..) | Step::AndCond(..) => mix_vars += 1,
+                _ => fp_vars += 1,
+            }
+            steps.push(step);
+        }
+        if !r.bytes.is_empty() || mix_vars != ret + 1 {
+            return None;
+        }
+
+        Some(Self {
+            registers,
+            num_taps,
+            group_sizes,
+            combo_taps,
+            combo_begin,
+            combos_count,
+            tot_combo_backs,
+            steps,
+        })
+    }
+
+    pub fn group_size(&self, group: usize) -> usize {
+        self.group_sizes[group]
+    }
+
+    /// Row offsets of the taps sharing combo `id`.
+    pub fn combo(&self, id: usize) -> &[usize] {
+        &self.combo_taps[self.combo_begin[id]..self.combo_begin[id + 1]]
+    }
+
+    /// Mixes all constraints with powers of `mix`, given the tap evaluations `u` and
+    /// the circuit globals. Returns `None` if a global is out of range.
+    pub fn poly_ext(&self, mix: ExtElem, u: &[ExtElem], args: &[&[Elem]]) -> Option<ExtElem> {
+        let mut fp_vars: Vec<ExtElem> = Vec::with_capacity(self.steps.len());
+        let mut mix_vars: Vec<MixState> = Vec::new();
+        for step in &self.steps {
+            match *step {
+                Step::Const(x) => fp_vars.push(ExtElem::from_base(x)),
+                Step::ConstExt(x) => fp_vars.push(x),
+                Step::Get(tap) => fp_vars.push(u[tap]),
+                Step::GetGlobal(arg, offset) => {
+                    let x = *args.get(arg)?.get(offset)?;
+                    fp_vars.push(ExtElem::from_base(x));
+                }
+                Step::Add(a, b) => fp_vars.push(fp_vars[a] + fp_vars[b]),
+                Step::Sub(a, b) => fp_vars.push(fp_vars[a] - fp_vars[b]),
+                Step::Mul(a, b) => fp_vars.push(fp_vars[a] * fp_vars[b]),
+                Step::True => mix_vars.push(MixState {
+                    tot: ExtElem::ZERO,
+                    mul: ExtElem::ONE,
+                }),
+                Step::AndEqz(chain, inner) => {
+                    let chain = mix_vars[chain];
+                    mix_vars.push(MixState {
+                        tot: chain.tot + chain.mul * fp_vars[inner],
+                        mul: chain.mul * mix,
+                    });
+                }
+                Step::AndCond(chain, cond, inner) => {
+                    let chain = mix_vars[chain];
+                    let inner = mix_vars[inner];
+                    mix_vars.push(MixState {
+                        tot: chain.tot + fp_vars[cond] * inner.tot * chain.mul,
+                        mul: chain.mul * inner.mul,
+                    });
+                }
+            }
+        }
+        mix_vars.last().map(|m| m.tot)
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.bytes.len() < N {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(N);
+        self.bytes = tail;
+        head.try_into().ok()
+    }
+
+    fn u8(&mut self) -> Option<usize> {
+        Some(self.take::<1>()?[0] as usize)
+    }
+
+    fn u16(&mut self) -> Option<usize> {
+        Some(u16::from_le_bytes(self.take()?) as usize)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take()?))
+    }
+
+    fn list(&mut self) -> Option<Vec<usize>> {
+        let n = self.u16()?;
+        (0..n).map(|_| self.u16()).collect()
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+
+use crate::common::stark::{
+    fri::{verify_query, CosetOrder},
+    poly, Field,
+};
+use crate::risc0::succinct::{
+    baby_bear::{ExtElem, ROU_FWD},
+    iop::ReadIop,
+    merkle::MerkleTreeVerifier,
+    poseidon2::hash_elems,
+};
+
+pub const QUERIES: usize = 50;
+pub const INV_RATE: usize = 4;
+const FRI_FOLD: usize = 16;
+const FRI_MIN_DEGREE: usize = 256;
+
+/// FRI low-degree test of the DEEP polynomial, folding cosets of 16 per round. `inner`
+/// opens the committed trace at a query position and returns the DEEP evaluation.
+pub fn fri_verify<F>(iop: &mut ReadIop, mut degree: usize, mut inner: F) -> Option<()>
+where
+    F: FnMut(&mut ReadIop, usize) -> Option<ExtElem>,
+{
+    let orig_domain = INV_RATE * degree;
+    let mut domain = orig_domain;
+    let mut merkle = Vec::new();
+    let mut mixes = Vec::new();
+    while degree > FRI_MIN_DEGREE {
+        domain /= FRI_FOLD;
+        degree /= FRI_FOLD;
+        merkle.push(MerkleTreeVerifier::new(iop, domain, FRI_FOLD * 4, QUERIES)?);
+        mixes.push(iop.random_ext_elem());
+    }
+
+    let final_coeffs = iop.read_elems(4 * degree)?;
+    iop.commit(&hash_elems(final_coeffs.iter().copied()));
+    let final_poly: Vec<ExtElem> = (0..degree)
+        .map(|i| {
+            ExtElem::new([
+                final_coeffs[i],
+                final_coeffs[degree + i],
+                final_coeffs[2 * degree + i],
+                final_coeffs[3 * degree + i],
+            ])
+        })
+        .collect();
+    let gen = ROU_FWD[log2(domain)];
+
+    for _ in 0..QUERIES {
+        let pos = iop.random_bits(log2(orig_domain)) as usize;
+        let goal = inner(iop, pos)?;
+        let (pos, goal) = verify_query(
+            CosetOrder::Strided,
+            log2(FRI_FOLD),
+            pos,
+            log2(orig_domain),
+            &mixes,
+            goal,
+            |bits| ROU_FWD[bits],
+            |round, group, quot, goal| {
+                let data = merkle[round].verify(iop, group)?;
+                let folded: Vec<ExtElem> = (0..FRI_FOLD)
+                    .map(|i| {
+                        ExtElem::new([
+                            data[i],
+                            data[FRI_FOLD + i],
+                            data[2 * FRI_FOLD + i],
+                            data[3 * FRI_FOLD + i],
+                        ])
+                    })
+                    .collect();
+                (folded[quot] == goal).then_some(folded)
+            },
+        )?;
+        let x = ExtElem::from_base(gen.pow(pos as u64));
+        if poly::eval(&final_poly, x) != goal {
+            return None;
+        }
+    }
+    Some(())
+}
+
+pub fn log2(n: usize) -> usize {
+    n.trailing_zeros() as usize
+}
@@ -0,0 +1,83 @@
+use alloc::vec::Vec;
+
+use crate::risc0::succinct::{
+    baby_bear::{Elem, ExtElem},
+    poseidon2::{Digest, Poseidon2Rng},
+};
+
+/// Reader over the seal words, paired with the Fiat-Shamir sponge.
+pub struct ReadIop<'a> {
+    proof: &'a [u32],
+    rng: Poseidon2Rng,
+}
+
+impl<'a> ReadIop<'a> {
+    pub fn new(proof: &'a [u32]) -> Self {
+        Self {
+            proof,
+            rng: Poseidon2Rng::new(),
+        }
+    }
+
+    fn read_u32s(&mut self, n: usize) -> Option<&'a [u32]> {
+        if self.proof.len() < n {
+            return None;
+        }
+        let (head, tail) = self.proof.split_at(n);
+        self.proof = tail;
+        Some(head)
+    }
+
+    pub fn read_elems(&mut self, n: usize) -> Option<Vec<Elem>> {
+        self.read_u32s(n)?
+            .iter()
+            .map(|word| Elem::from_monty(*word))
+            .collect()
+    }
+
+    pub fn read_ext_elems(&mut self, n: usize) -> Option<Vec<ExtElem>> {
+        let elems = self.read_elems(4 * n)?;
+        Some(
+            elems
+                .chunks_exact(4)
+                .map(|c| ExtElem::new([c[0], c[1], c[2], c[3]]))
+                .collect(),
+        )
+    }
+
+    pub fn read_digests(&mut self, n: usize) -> Option<Vec<Digest>> {
+        let elems = self.read_elems(8 * n)?;
+        Some(
+            elems
+                .chunks_exact(8)
+                .map(|c| {
+                    let mut digest = [0u32; 8];
+                    for (word, elem) in digest.iter_mut().zip(c) {
+                        *word = elem.as_monty();
+                    }
+                    digest
+                })
+                .collect(),
+        )
+    }
+
+    pub fn commit(&mut self, digest: &Digest) {
+        self.rng.mix(digest);
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.proof.is_empty()
+    }
+
+    pub fn random_bits(&mut self, bits: usize) -> u32 {
+        self.rng.random_bits(bits)
+    }
+
+    pub fn random_elem(&mut self) -> Elem {
+        self.rng.random_elem()
+    }
+
+    pub fn random_ext_elem(&mut self) -> ExtElem {
+        self.rng.random_ext_elem()
+    }
+}
@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+use crate::risc0::succinct::{
+    baby_bear::Elem,
+    iop::ReadIop,
+    poseidon2::{hash_elems, hash_pair, Digest},
+};
+
+/// Merkle commitment to a `row_size × col_size` matrix, one leaf per row.
+///
+/// The prover sends the layer with at most `queries` nodes up front; query branches
+/// stop there. Node `i` has children `2i` and `2i + 1`, and the root is node 1.
+pub struct MerkleTreeVerifier {
+    row_size: usize,
+    col_size: usize,
+    top_size: usize,
+    /// Nodes `[1, 2 · top_size)`, offset by one.
+    nodes: Vec<Digest>,
+}
+
+impl MerkleTreeVerifier {
+    /// Reads the top layer, recomputes the root and commits it to the transcript.
+    pub fn new(
+        iop: &mut ReadIop,
+        row_size: usize,
+        col_size: usize,
+        queries: usize,
+    ) -> Option<Self> {
+        if !row_size.is_power_of_two() {
+            return None;
+        }
+        let layers = row_size.trailing_zeros() as usize;
+        let mut top_layer = 0;
+        for i in 1..layers {
+            if (1 << i) > queries {
+                break;
+            }
+            top_layer = i;
+        }
+        let top_size = 1 << top_layer;
+
+        let mut nodes = alloc::vec![[0u32; 8]; top_size - 1];
+        nodes.extend(iop.read_digests(top_size)?);
+        for i in (1..top_size).rev() {
+            nodes[i - 1] = hash_pair(&nodes[2 * i - 1], &nodes[2 * i])?;
+        }
+
+        let verifier = Self {
+            row_size,
+            col_size,
+            top_size,
+            nodes,
+        };
+        iop.commit(verifier.root());
+        Some(verifier)
+    }
+
+    pub fn root(&self) -> &Digest {
+        &self.nodes[0]
+    }
+
+    /// Reads row `idx` and its branch up to the top layer.
+    pub fn verify(&self, iop: &mut ReadIop, mut idx: usize) -> Option<Vec<Elem>> {
+        if idx >= self.row_size {
+            return None;
+        }
+        let row = iop.read_elems(self.col_size)?;
+        let mut cur = hash_elems(row.iter().copied());
+        idx += self.row_size;
+        while idx >= 2 * self.top_size {
+            let other = iop.read_digests(1)?[0];
+            cur = if idx % 2 == 1 {
+                hash_pair(&other, &cur)?
+            } else {
+                hash_pair(&cur, &other)?
+            };
+            idx /= 2;
+        }
+        (self.nodes[idx - 1] == cur).then_some(row)
+    }
+}
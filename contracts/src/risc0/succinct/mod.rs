@@ -0,0 +1,9 @@
+pub mod baby_bear;
+pub mod circuit;
+pub mod fri;
+pub mod iop;
+pub mod merkle;
+pub mod poseidon2;
+pub mod verifier;
+
+pub use verifier::{verify_succinct_seal, IRiscZeroSuccinctVerifier, SuccinctSeal};
@@ -0,0 +1,222 @@
+//! Poseidon2 over BabyBear with a 24-cell state, as used by the RISC Zero recursion
+//! circuit for Merkle trees and Fiat-Shamir.
+
+use crate::risc0::succinct::baby_bear::{Elem, ExtElem};
+
+pub const CELLS: usize = 24;
+const CELLS_RATE: usize = 16;
+const CELLS_OUT: usize = 8;
+const ROUNDS_HALF_FULL: usize = 4;
+const ROUNDS_PARTIAL: usize = 21;
+
+/// Eight field elements in Montgomery form, as stored in seals.
+pub type Digest = [u32; CELLS_OUT];
+
+const FULL_ROUND_CONSTANTS: [Elem; 2 * ROUNDS_HALF_FULL * CELLS] = Elem::new_array([
+    0x0FA20C37, 0x0795BB97, 0x12C60B9C, 0x0EABD88E, 0x096485CA, 0x07093527, 0x1B1D4E50, 0x30A01ACE,
+    0x3BD86F5A, 0x69AF7C28, 0x3F94775F, 0x731560E8, 0x465A0ECD, 0x574EF807, 0x62FD4870, 0x52CCFE44,
+    0x14772B14, 0x4DEDF371, 0x260ACD7C, 0x1F51DC58, 0x75125532, 0x686A4D7B, 0x54BAC179, 0x31947706,
+    0x29799D3B, 0x6E01AE90, 0x203A7A64, 0x4F7E25BE, 0x72503F77, 0x45BD3B69, 0x769BD6B4, 0x5A867F08,
+    0x4FDBA082, 0x251C4318, 0x28F06201, 0x6788C43A, 0x4C6D6A99, 0x357784A8, 0x2ABAF051, 0x770F7DE6,
+    0x1794B784, 0x4796C57A, 0x724B7A10, 0x449989A7, 0x64935CF1, 0x59E14AAC, 0x0E620BB8, 0x3AF5A33B,
+    0x4465CC0E, 0x019DF68F, 0x4AF8D068, 0x08784F82, 0x0CEFDEAE, 0x6337A467, 0x32FA7A16, 0x486F62D6,
+    0x386A7480, 0x20F17C4A, 0x54E50DA8, 0x2012CF03, 0x5FE52950, 0x09AFB6CD, 0x2523044E, 0x5C54D0EF,
+    0x71C01F3C, 0x60B2C4FB, 0x4050B379, 0x5E6A70A5, 0x418543F5, 0x71DEBE56, 0x1AAD2994, 0x3368A483,
+    0x07A86F3A, 0x5EA43FF1, 0x2443780E, 0x4CE444F7, 0x146F9882, 0x3132B089, 0x197EA856, 0x667030C3,
+    0x2317D5DC, 0x0C2C48A7, 0x56B2DF66, 0x67BD81E9, 0x4FCDFB19, 0x4BAAEF32, 0x0328D30A, 0x6235760D,
+    0x12432912, 0x0A49E258, 0x030E1B70, 0x48CAEB03, 0x49E4D9E9, 0x1051B5C6, 0x6A36DBBE, 0x4CFF27A5,
+    0x032959AD, 0x2B18AF6A, 0x55D3DC8C, 0x43BD26C8, 0x0C41595F, 0x7048D2E2, 0x00DB8983, 0x2AF563D7,
+    0x6E84758F, 0x611D64E1, 0x1F9977E2, 0x64163A0A, 0x5C5FC27B, 0x02E22561, 0x3A2D75DB, 0x1BA7B71A,
+    0x34343F64, 0x7406B35D, 0x19DF8299, 0x6FF4480A, 0x514A81C8, 0x57AB52CE, 0x6AD69F52, 0x3E0C0E0D,
+    0x48126114, 0x2A9D62CC, 0x17441F23, 0x485762BB, 0x2F218674, 0x06FDC64A, 0x0861B7F2, 0x3B36EEE6,
+    0x70A11040, 0x04B31737, 0x3722A872, 0x2A351C63, 0x623560DC, 0x62584AB2, 0x382C7C04, 0x3BF9EDC7,
+    0x0E38FE51, 0x376F3B10, 0x5381E178, 0x3AFC61C7, 0x5C1BCB4D, 0x6643CE1F, 0x2D0AF1C1, 0x08F583CC,
+    0x5D6FF60F, 0x6324C1E5, 0x74412FB7, 0x70C0192E, 0x0B72F141, 0x4067A111, 0x57388C4F, 0x351009EC,
+    0x0974C159, 0x539A58B3, 0x038C0CFF, 0x476C0392, 0x3F7BC15F, 0x4491DD2C, 0x4D1FEF55, 0x04936AE3,
+    0x58214DD4, 0x683C6AAD, 0x1B42F16B, 0x6DC79135, 0x2D4E71EC, 0x3E2946EA, 0x59DCE8DB, 0x6CEE892A,
+    0x47F07350, 0x7106CE93, 0x3BD4A7A9, 0x2BFE636A, 0x430011E9, 0x001CD66A, 0x307FAF5B, 0x0D9EF3FE,
+    0x6D40043A, 0x2E8F470C, 0x1B6865E8, 0x0C0E6C01, 0x4D41981F, 0x423B9D3D, 0x410408CC, 0x263F0884,
+    0x5311BBD0, 0x4DAE58D8, 0x30401CEA, 0x09AFA575, 0x4B3D5B42, 0x63AC0B37, 0x5FE5BB14, 0x5244E9D4,
+]);
+
+const PARTIAL_ROUND_CONSTANTS: [Elem; ROUNDS_PARTIAL] = Elem::new_array([
+    0x1DA78EC2, 0x730B0924, 0x3EB56CF3, 0x5BD93073, 0x37204C97, 0x51642D89, 0x66E943E8, 0x1A3E72DE,
+    0x70BEB1E9, 0x30FF3B3F, 0x4240D1C4, 0x12647B8D, 0x65D86965, 0x49EF4D7C, 0x47785697, 0x46B3969F,
+    0x5C7B7A0E, 0x7078FC60, 0x4F22D482, 0x482A9AEE, 0x6BEB839D,
+]);
+
+const M_INT_DIAG_HZN: [Elem; CELLS] = Elem::new_array([
+    0x409133F0, 0x1667A8A1, 0x06A6C7B6, 0x6F53160E, 0x273B11D1, 0x03176C5D, 0x72F9BBF9, 0x73CEBA91,
+    0x5CDEF81D, 0x01393285, 0x46DAEE06, 0x065D7BA6, 0x52D72D6F, 0x05DD05E0, 0x3BAB4B63, 0x6ADA3842,
+    0x2FC5FBEC, 0x770D61B0, 0x5715AAE9, 0x03EF0E90, 0x75B6C770, 0x242ADF5F, 0x00D0CA4C, 0x36C0E388,
+]);
+
+fn sbox(x: Elem) -> Elem {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    x4 * x2 * x
+}
+
+fn multiply_by_4x4_circulant(x: [Elem; 4]) -> [Elem; 4] {
+    let two = Elem::new(2);
+    let four = Elem::new(4);
+    let t0 = x[0] + x[1];
+    let t1 = x[2] + x[3];
+    let t2 = two * x[1] + t1;
+    let t3 = two * x[3] + t0;
+    let t4 = four * t1 + t3;
+    let t5 = four * t0 + t2;
+    [t3 + t5, t5, t2 + t4, t4]
+}
+
+fn multiply_by_m_ext(cells: &mut [Elem; CELLS]) {
+    let mut sums = [Elem::ZERO; 4];
+    for chunk in cells.chunks_exact_mut(4) {
+        let out = multiply_by_4x4_circulant([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        for ((cell, sum), value) in chunk.iter_mut().zip(sums.iter_mut()).zip(out) {
+            *cell = value;
+            *sum += value;
+        }
+    }
+    for (i, cell) in cells.iter_mut().enumerate() {
+        *cell += sums[i % 4];
+    }
+}
+
+fn multiply_by_m_int(cells: &mut [Elem; CELLS]) {
+    let sum = cells.iter().fold(Elem::ZERO, |acc, x| acc + *x);
+    for (cell, diag) in cells.iter_mut().zip(M_INT_DIAG_HZN) {
+        *cell = sum + diag * *cell;
+    }
+}
+
+fn full_round(cells: &mut [Elem; CELLS], round: usize) {
+    let constants = &FULL_ROUND_CONSTANTS[round * CELLS..(round + 1) * CELLS];
+    for (cell, constant) in cells.iter_mut().zip(constants) {
+        *cell = sbox(*cell + *constant);
+    }
+    multiply_by_m_ext(cells);
+}
+
+pub fn poseidon2_mix(cells: &mut [Elem; CELLS]) {
+    multiply_by_m_ext(cells);
+    for round in 0..ROUNDS_HALF_FULL {
+        full_round(cells, round);
+    }
+    for constant in PARTIAL_ROUND_CONSTANTS {
+        cells[0] = sbox(cells[0] + constant);
+        multiply_by_m_int(cells);
+    }
+    for round in ROUNDS_HALF_FULL..2 * ROUNDS_HALF_FULL {
+        full_round(cells, round);
+    }
+}
+
+/// Sponge hash without length padding: absorbs `CELLS_RATE` elements per permutation
+/// and zero-fills the last block.
+pub fn hash_elems<I: IntoIterator<Item = Elem>>(elems: I) -> Digest {
+    let mut cells = [Elem::ZERO; CELLS];
+    let mut count = 0;
+    let mut unmixed = 0;
+    for elem in elems {
+        cells[unmixed] = elem;
+        count += 1;
+        unmixed += 1;
+        if unmixed == CELLS_RATE {
+            poseidon2_mix(&mut cells);
+            unmixed = 0;
+        }
+    }
+    if unmixed != 0 || count == 0 {
+        for cell in &mut cells[unmixed..CELLS_RATE] {
+            *cell = Elem::ZERO;
+        }
+        poseidon2_mix(&mut cells);
+    }
+    to_digest(&cells)
+}
+
+pub fn hash_ext_elems(elems: &[ExtElem]) -> Digest {
+    hash_elems(elems.iter().flat_map(|e| e.0))
+}
+
+/// Parent of two Merkle nodes. Digest words are taken as Montgomery-form elements.
+pub fn hash_pair(a: &Digest, b: &Digest) -> Option<Digest> {
+    let mut words = [Elem::ZERO; 2 * CELLS_OUT];
+    for (elem, word) in words.iter_mut().zip(a.iter().chain(b)) {
+        *elem = Elem::from_monty(*word)?;
+    }
+    Some(hash_elems(words))
+}
+
+fn to_digest(cells: &[Elem; CELLS]) -> Digest {
+    let mut digest = [0u32; CELLS_OUT];
+    for (word, cell) in digest.iter_mut().zip(cells) {
+        *word = cell.as_monty();
+    }
+    digest
+}
+
+/// Fiat-Shamir sponge: digests are added into the rate and randomness is squeezed
+/// from the first `CELLS_RATE` cells.
+pub struct Poseidon2Rng {
+    cells: [Elem; CELLS],
+    pool_used: usize,
+}
+
+impl Poseidon2Rng {
+    pub fn new() -> Self {
+        Self {
+            cells: [Elem::ZERO; CELLS],
+            pool_used: 0,
+        }
+    }
+
+    pub fn mix(&mut self, digest: &Digest) {
+        if self.pool_used != 0 {
+            poseidon2_mix(&mut self.cells);
+            self.pool_used = 0;
+        }
+        for (cell, word) in self.cells.iter_mut().zip(digest) {
+            // Digests hashed by this module are always reduced.
+            *cell += Elem::from_monty(*word).unwrap_or_default();
+        }
+        poseidon2_mix(&mut self.cells);
+    }
+
+    pub fn random_elem(&mut self) -> Elem {
+        if self.pool_used == CELLS_RATE {
+            poseidon2_mix(&mut self.cells);
+            self.pool_used = 0;
+        }
+        let out = self.cells[self.pool_used];
+        self.pool_used += 1;
+        out
+    }
+
+    pub fn random_ext_elem(&mut self) -> ExtElem {
+        ExtElem::new([
+            self.random_elem(),
+            self.random_elem(),
+            self.random_elem(),
+            self.random_elem(),
+        ])
+    }
+
+    pub fn random_bits(&mut self, bits: usize) -> u32 {
+        let mut val = self.random_elem().as_canonical_u32();
+        for _ in 0..3 {
+            let new_val = self.random_elem().as_canonical_u32();
+            if val == 0 {
+                val = new_val;
+            }
+        }
+        ((1 << bits) - 1) & val
+    }
+}
+
+impl Default for Poseidon2Rng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,498 @@
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{alloy_primitives::B256, crypto::keccak, prelude::*};
+
+use crate::common::stark::{poly, Field};
+use crate::risc0::{
+    config::RECURSION_CIRCUIT_DIGEST,
+    errors::RiscZeroError,
+    succinct::{
+        baby_bear::{Elem, ExtElem, ROU_FWD, ROU_REV},
+        circuit::{RecursionCircuit, GROUP_ACCUM, GROUP_CODE, GROUP_DATA},
+        fri::{fri_verify, log2, INV_RATE, QUERIES},
+        iop::ReadIop,
+        merkle::MerkleTreeVerifier,
+        poseidon2::{hash_elems, hash_ext_elems, hash_pair, Digest},
+    },
+    types::ReceiptClaim,
+    verifier::{IRiscZeroVerifier, RiscZeroVerifier},
+};
+
+const PROOF_SYSTEM_INFO: &[u8; 16] = b"RISC0_STARK:v1__";
+const CIRCUIT_INFO: &[u8; 16] = b"RECURSION:rev1v1";
+const OUTPUT_SIZE: usize = 32;
+const MIX_SIZE: usize = 20;
+const CHECK_SIZE: usize = INV_RATE * 4;
+const MAX_CYCLES_PO2: usize = 24;
+
+/// Verification of succinct STARK receipts.
+///
+/// The recursion circuit is too large to deploy with the verifier, so every call passes
+/// it as `circuit` (the ~64 KiB `recursion_circuit.bin`) and it is checked against
+/// `RECURSION_CIRCUIT_DIGEST`. Callers pay its calldata and hashing on each call.
+pub trait IRiscZeroSuccinctVerifier {
+    type Error;
+
+    fn verify_succinct(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error>;
+
+    fn verify_succinct_integrity(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+        receipt_claim_digest: B256,
+    ) -> Result<bool, Self::Error>;
+
+    fn get_succinct_claim_digest(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+    ) -> Result<B256, Self::Error>;
+
+    fn get_recursion_circuit_digest(&self) -> B256;
+}
+
+#[public]
+impl IRiscZeroSuccinctVerifier for RiscZeroVerifier {
+    type Error = Vec<u8>;
+
+    fn verify_succinct(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        let claim_digest = ReceiptClaim::ok(image_id, journal_digest).digest();
+        self.verify_succinct_integrity(seal, circuit, claim_digest)
+    }
+
+    fn verify_succinct_integrity(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+        receipt_claim_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        let received = self.get_succinct_claim_digest(seal, circuit)?;
+        if received != receipt_claim_digest {
+            return Err(RiscZeroError::ClaimDigestMismatch {
+                received,
+                expected: receipt_claim_digest,
+            }
+            .abi_encode());
+        }
+        Ok(true)
+    }
+
+    fn get_succinct_claim_digest(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+    ) -> Result<B256, Self::Error> {
+        if !self.is_initialized() {
+            return Err(RiscZeroError::INVALID_INITIALIZATION.abi_encode());
+        }
+        if keccak(&circuit) != RECURSION_CIRCUIT_DIGEST {
+            return Err(RiscZeroError::InvalidRecursionCircuit.abi_encode());
+        }
+        let circuit = RecursionCircuit::from_bytes(&circuit)
+            .ok_or_else(|| RiscZeroError::InvalidRecursionCircuit.abi_encode())?;
+        let seal = SuccinctSeal::from_bytes(&seal)
+            .ok_or_else(|| RiscZeroError::INVALID_PROOF_DATA.abi_encode())?;
+
+        verify_succinct_seal(&circuit, &seal, self.control_root()).map_err(|e| e.abi_encode())
+    }
+
+    fn get_recursion_circuit_digest(&self) -> B256 {
+        RECURSION_CIRCUIT_DIGEST
+    }
+}
+
+/// Succinct receipt seal together with the inclusion proof of its recursion program.
+///
+/// ```text
+/// u32 control_index | u32 n | n × 8 words control_digests | seal words
+/// ```
+///
+/// All words are little-endian `u32`; digests and field elements are in the
+/// Montgomery form produced by the prover.
+pub struct SuccinctSeal {
+    pub control_index: u32,
+    pub control_digests: Vec<Digest>,
+    pub seal: Vec<u32>,
+}
+
+impl SuccinctSeal {
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let chunks = bytes.chunks_exact(4);
+        if !chunks.remainder().is_empty() {
+            return None;
+        }
+        let words: Vec<u32> = chunks
+            .map(|w| u32::from_le_bytes([w[0], w[1], w[2], w[3]]))
+            .collect();
+        let (&control_index, rest) = words.split_first()?;
+        let (&n, rest) = rest.split_first()?;
+        let n = n as usize;
+        if rest.len() < 8 * n {
+            return None;
+        }
+        let (digests, seal) = rest.split_at(8 * n);
+        Some(Self {
+            control_index,
+            control_digests: digests
+                .chunks_exact(8)
+                .map(|d| [d[0], d[1], d[2], d[3], d[4], d[5], d[6], d[7]])
+                .collect(),
+            seal: seal.to_vec(),
+        })
+    }
+}
+
+/// Verifies a recursion-circuit STARK whose program is in the tree with root
+/// `control_root`, and returns the `ReceiptClaim` digest it commits to.
+pub fn verify_succinct_seal(
+    circuit: &RecursionCircuit,
+    seal: &SuccinctSeal,
+    control_root: B256,
+) -> Result<B256, RiscZeroError> {
+    let control_root = to_digest(control_root);
+    let mut iop = ReadIop::new(&seal.seal);
+
+    iop.commit(&hash_elems(PROOF_SYSTEM_INFO.map(|b| Elem::new(b as u32))));
+    iop.commit(&hash_elems(CIRCUIT_INFO.map(|b| Elem::new(b as u32))));
+
+    let globals = iop
+        .read_elems(OUTPUT_SIZE + 1)
+        .ok_or(RiscZeroError::INVALID_PROOF_DATA)?;
+    iop.commit(&hash_elems(globals.iter().copied()));
+    let po2 = globals[OUTPUT_SIZE].as_canonical_u32() as usize;
+    if po2 > MAX_CYCLES_PO2 {
+        return Err(RiscZeroError::INVALID_PROOF_DATA);
+    }
+    let out = &globals[..OUTPUT_SIZE];
+
+    let stark = Stark {
+        circuit,
+        po2,
+        tot_cycles: 1 << po2,
+    };
+    let domain = INV_RATE * stark.tot_cycles;
+
+    let code = MerkleTreeVerifier::new(&mut iop, domain, circuit.group_size(GROUP_CODE), QUERIES)
+        .ok_or(RiscZeroError::VERIFICATION_FAILED)?;
+    let control_id = *code.root();
+    if control_proof_root(&control_id, seal)? != control_root {
+        return Err(RiscZeroError::ControlIdNotAllowed {
+            control_id: from_digest(&control_id),
+        });
+    }
+
+    let data = MerkleTreeVerifier::new(&mut iop, domain, circuit.group_size(GROUP_DATA), QUERIES)
+        .ok_or(RiscZeroError::VERIFICATION_FAILED)?;
+    let mix: Vec<Elem> = (0..MIX_SIZE).map(|_| iop.random_elem()).collect();
+    let accum = MerkleTreeVerifier::new(&mut iop, domain, circuit.group_size(GROUP_ACCUM), QUERIES)
+        .ok_or(RiscZeroError::VERIFICATION_FAILED)?;
+
+    // Queries open the groups in register group order.
+    stark
+        .verify_validity(&mut iop, &[accum, code, data], out, &mix)
+        .ok_or(RiscZeroError::VERIFICATION_FAILED)?;
+    if !iop.is_complete() {
+        return Err(RiscZeroError::INVALID_PROOF_DATA);
+    }
+
+    // Recursion programs output a Poseidon2 control root in 16 elements (8 words with
+    // padding) followed by the SHA-256 claim digest as 16 half-words.
+    let mut output_root = [0u32; 8];
+    for (word, elem) in output_root.iter_mut().zip(out.iter().step_by(2)) {
+        *word = elem.as_canonical_u32();
+    }
+    if output_root != control_root {
+        return Err(RiscZeroError::ControlRootMismatch {
+            received: from_digest(&output_root),
+            expected: from_digest(&control_root),
+        });
+    }
+
+    let mut claim_digest = [0u8; 32];
+    for (bytes, elem) in claim_digest.chunks_exact_mut(2).zip(&out[16..]) {
+        let half = u16::try_from(elem.as_canonical_u32())
+            .map_err(|_| RiscZeroError::INVALID_PROOF_DATA)?;
+        bytes.copy_from_slice(&half.to_le_bytes());
+    }
+    Ok(B256::from(claim_digest))
+}
+
+fn control_proof_root(control_id: &Digest, seal: &SuccinctSeal) -> Result<Digest, RiscZeroError> {
+    let mut cur = *control_id;
+    let mut index = seal.control_index;
+    for sibling in &seal.control_digests {
+        cur = if index & 1 == 0 {
+            hash_pair(&cur, sibling)
+        } else {
+            hash_pair(sibling, &cur)
+        }
+        .ok_or(RiscZeroError::INVALID_PROOF_DATA)?;
+        index >>= 1;
+    }
+    Ok(cur)
+}
+
+struct Stark<'a> {
+    circuit: &'a RecursionCircuit,
+    po2: usize,
+    tot_cycles: usize,
+}
+
+impl Stark<'_> {
+    /// DEEP-ALI check of the mixed constraints at `z`, followed by FRI on the DEEP
+    /// quotients of every tap.
+    fn verify_validity(
+        &self,
+        iop: &mut ReadIop,
+        groups: &[MerkleTreeVerifier; 3],
+        out: &[Elem],
+        mix: &[Elem],
+    ) -> Option<()> {
+        let circuit = self.circuit;
+        let poly_mix = iop.random_ext_elem();
+        let domain = INV_RATE * self.tot_cycles;
+        let check_merkle = MerkleTreeVerifier::new(iop, domain, CHECK_SIZE, QUERIES)?;
+        let z = iop.random_ext_elem();
+        let back_one = ROU_REV[self.po2];
+
+        let num_taps = circuit.num_taps;
+        let coeff_u = iop.read_ext_elems(num_taps + CHECK_SIZE)?;
+        iop.commit(&hash_ext_elems(&coeff_u));
+
+        let mut eval_u = Vec::with_capacity(num_taps);
+        for reg in &circuit.registers {
+            let coeffs = &coeff_u[reg.first_tap..reg.first_tap + reg.backs.len()];
+            for back in &reg.backs {
+                eval_u.push(poly::eval(coeffs, z * back_one.pow(*back as u64)));
+            }
+        }
+        let result = circuit.poly_ext(poly_mix, &eval_u, &[out, mix])?;
+
+        let mut check = ExtElem::ZERO;
+        let remap = [0, 2, 1, 3];
+        for (i, rmi) in remap.iter().enumerate() {
+            let zi = z.pow(i as u64);
+            for k in 0..4 {
+                let mut basis = ExtElem::ZERO;
+                basis.0[k] = Elem::ONE;
+                check += coeff_u[num_taps + rmi + 4 * k] * zi * basis;
+            }
+        }
+        let three = ExtElem::from_base(Elem::new(3));
+        check *= (three * z).pow(self.tot_cycles as u64) - ExtElem::ONE;
+        if check != result {
+            return None;
+        }
+
+        let fri_mix = iop.random_ext_elem();
+        let mut combo_u = vec![ExtElem::ZERO; circuit.tot_combo_backs + 1];
+        let mut cur_mix = ExtElem::ONE;
+        let mut tap_mix_pows = Vec::with_capacity(circuit.registers.len());
+        for reg in &circuit.registers {
+            let begin = circuit.combo_begin[reg.combo];
+            for i in 0..reg.backs.len() {
+                combo_u[begin + i] += cur_mix * coeff_u[reg.first_tap + i];
+            }
+            tap_mix_pows.push(cur_mix);
+            cur_mix *= fri_mix;
+        }
+        let mut check_mix_pows = Vec::with_capacity(CHECK_SIZE);
+        for coeff in &coeff_u[num_taps..] {
+            combo_u[circuit.tot_combo_backs] += cur_mix * *coeff;
+            check_mix_pows.push(cur_mix);
+            cur_mix *= fri_mix;
+        }
+
+        let gen = ROU_FWD[log2(domain)];
+        let deep = DeepQuotient {
+            circuit,
+            combo_u: &combo_u,
+            tap_mix_pows: &tap_mix_pows,
+            check_mix_pows: &check_mix_pows,
+            back_one,
+            z,
+        };
+        fri_verify(iop, self.tot_cycles, |iop, idx| {
+            let mut rows: Vec<Vec<Elem>> = Vec::with_capacity(groups.len());
+            for group in groups {
+                rows.push(group.verify(iop, idx)?);
+            }
+            let check_row = check_merkle.verify(iop, idx)?;
+            Some(deep.evaluate(gen.pow(idx as u64), &rows, &check_row))
+        })
+    }
+}
+
+struct DeepQuotient<'a> {
+    circuit: &'a RecursionCircuit,
+    combo_u: &'a [ExtElem],
+    tap_mix_pows: &'a [ExtElem],
+    check_mix_pows: &'a [ExtElem],
+    back_one: Elem,
+    z: ExtElem,
+}
+
+impl DeepQuotient<'_> {
+    /// `Σ (mixed column values at x - U(x)) / Π (x - z·ω^-back)` over the combos, plus
+    /// the check polynomial's quotient by `x - z^INV_RATE`.
+    fn evaluate(&self, x: Elem, rows: &[Vec<Elem>], check_row: &[Elem]) -> ExtElem {
+        let circuit = self.circuit;
+        let combos = circuit.combos_count;
+        let mut tot = vec![ExtElem::ZERO; combos + 1];
+        let x = ExtElem::from_base(x);
+
+        for (reg, cur) in circuit.registers.iter().zip(self.tap_mix_pows) {
+            tot[reg.combo] += *cur * rows[reg.group][reg.offset];
+        }
+        for (cur, value) in self.check_mix_pows.iter().zip(check_row) {
+            tot[combos] += *cur * *value;
+        }
+
+        let mut ret = ExtElem::ZERO;
+        for (i, total) in tot.iter().take(combos).enumerate() {
+            let begin = circuit.combo_begin[i];
+            let end = circuit.combo_begin[i + 1];
+            let num = *total - poly::eval(&self.combo_u[begin..end], x);
+            let mut divisor = ExtElem::ONE;
+            for back in circuit.combo(i) {
+                divisor *= x - self.z * self.back_one.pow(*back as u64);
+            }
+            ret += num * divisor.inv();
+        }
+        let check_num = tot[combos] - self.combo_u[circuit.tot_combo_backs];
+        let check_div = x - self.z.pow(INV_RATE as u64);
+        ret + check_num * check_div.inv()
+    }
+}
+
+/// Digest words are the little-endian words of its byte encoding.
+fn to_digest(bytes: B256) -> Digest {
+    let mut digest = [0u32; 8];
+    for (word, chunk) in digest.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    digest
+}
+
+fn from_digest(digest: &Digest) -> B256 {
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(digest) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    B256::from(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::VerificationError;
+
+    const CIRCUIT: &[u8] =
+        include_bytes!("../../../../examples/risc0-verifier/data/recursion_circuit.bin");
+
+    /// A seal whose globals are in Montgomery form, as written by the prover, and whose
+    /// commitments are all zero.
+    fn seal_with_po2(po2: u32) -> SuccinctSeal {
+        let mut words = vec![0u32; OUTPUT_SIZE];
+        words.push(Elem::new(po2).as_monty());
+        words.resize(1 << 16, 0);
+        SuccinctSeal {
+            control_index: 0,
+            control_digests: Vec::new(),
+            seal: words,
+        }
+    }
+
+    #[test]
+    fn circuit_matches_pinned_digest() {
+        assert_eq!(keccak(CIRCUIT), RECURSION_CIRCUIT_DIGEST);
+        assert!(RecursionCircuit::from_bytes(CIRCUIT).is_some());
+    }
+
+    #[test]
+    fn po2_is_read_in_canonical_form() {
+        let circuit = RecursionCircuit::from_bytes(CIRCUIT).unwrap();
+        let control_root = B256::repeat_byte(1);
+
+        // A po2 within bounds gets past the globals to the control ID check.
+        let err = verify_succinct_seal(&circuit, &seal_with_po2(16), control_root).unwrap_err();
+        assert!(
+            matches!(err, RiscZeroError::ControlIdNotAllowed { .. }),
+            "{err:?}"
+        );
+
+        let err = verify_succinct_seal(&circuit, &seal_with_po2(25), control_root).unwrap_err();
+        assert!(matches!(err, RiscZeroError::Common(_)), "{err:?}");
+    }
+
+    #[test]
+    fn checks_control_inclusion_proofs() {
+        let circuit = RecursionCircuit::from_bytes(CIRCUIT).unwrap();
+        let seal = seal_with_po2(16);
+        let control_id = match verify_succinct_seal(&circuit, &seal, B256::ZERO) {
+            Err(RiscZeroError::ControlIdNotAllowed { control_id }) => to_digest(control_id),
+            other => panic!("{other:?}"),
+        };
+
+        // The program is the third leaf of a four-leaf tree of allowed programs.
+        let siblings = [[Elem::new(1).as_monty(); 8], [Elem::new(2).as_monty(); 8]];
+        let parent = hash_pair(&control_id, &siblings[0]).unwrap();
+        let control_root = from_digest(&hash_pair(&siblings[1], &parent).unwrap());
+
+        let encode = |control_index: u32, digests: &[Digest], seal: &[u32]| {
+            let words = [control_index, digests.len() as u32]
+                .into_iter()
+                .chain(digests.iter().flatten().copied())
+                .chain(seal.iter().copied());
+            let bytes: Vec<u8> = words.flat_map(u32::to_le_bytes).collect();
+            SuccinctSeal::from_bytes(&bytes).unwrap()
+        };
+        let verify = |seal: &SuccinctSeal| verify_succinct_seal(&circuit, seal, control_root);
+        let is_not_allowed =
+            |result| matches!(result, Err(RiscZeroError::ControlIdNotAllowed { .. }));
+
+        // An included program gets past the control check to the STARK, which the zero
+        // seal fails.
+        let included = encode(2, &siblings, &seal.seal);
+        assert!(matches!(
+            verify(&included),
+            Err(RiscZeroError::Common(VerificationError::VerificationFailed))
+        ));
+
+        assert!(is_not_allowed(verify(&encode(3, &siblings, &seal.seal))));
+        assert!(is_not_allowed(verify(&encode(
+            2,
+            &siblings[..1],
+            &seal.seal
+        ))));
+        assert!(is_not_allowed(verify(&encode(
+            2,
+            &[siblings[1], siblings[0]],
+            &seal.seal
+        ))));
+
+        // Another program: the first node of the code tree's top layer.
+        let mut words = seal.seal.clone();
+        words[OUTPUT_SIZE + 1] = Elem::new(7).as_monty();
+        assert!(is_not_allowed(verify(&encode(2, &siblings, &words))));
+
+        // A sibling outside the field.
+        let mut digests = siblings;
+        digests[0][0] = u32::MAX;
+        assert!(matches!(
+            verify(&encode(2, &digests, &seal.seal)),
+            Err(RiscZeroError::Common(VerificationError::InvalidProofData))
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+//! Groth16 fixtures for the unit tests of the RISC Zero verifiers.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::cell::Cell;
+use stylus_sdk::{
+    alloy_primitives::{b256, hex, FixedBytes, B256, U256},
+    alloy_sol_types::SolType,
+};
+use substrate_bn::{AffineG1, AffineG2, Fq, Fr, Group, G1, G2};
+
+use crate::common::{G1Point, G2Point, VerificationKey, R};
+use crate::risc0::{
+    crypto::{digest_utils, vk},
+    types::Seal,
+};
+
+/// Seal of `testdata/risc0/receipt.json`, a receipt of the RISC Zero 2.0 prover for
+/// `IMAGE_ID` and the journal `JOURNAL`.
+pub const SEAL: [u8; 260] = hex!(
+    "9f39696c2c1cd42efd2ea081de900b08eb97ff95c2df709622928fdc93976baa4730a7502018b1654a232c0614a591ea"
+    "42d8c210d63aa49c19a2009016b08255175d413712932a948d9569c41e39a7618fe801263768e32aa1780bff62c39432"
+    "f208d66c272ea7ff083bceb60d70b317bbc6ae9656883ee1f3db197ffa4df9f581e7b8022acac6495e3dc193edf57762"
+    "d43b460d767cb2f6bde5fb5f89097a7921622c0117c3c6f84a0d6e5cc251a9025a6812155c50212ed17c2dfab5c0805b"
+    "5fa522cf08318d8bf05cba911dc6305dcd87ed1e90a0c27c80caaa05c0560871c8b6273c0333dfa7f0ca652760a1da86"
+    "6a2a9e222a87a422d988d5b75917be1ae4fbec67"
+);
+pub const IMAGE_ID: B256 =
+    b256!("192f3eb31ce4f578a52abb305490d63e4700f24fd460aa4cd4aa801e9672c869");
+pub const JOURNAL: &[u8] = b"just a simple receipt";
+/// SHA-256 of `JOURNAL`.
+pub const JOURNAL_DIGEST: B256 =
+    b256!("3b8839d29d6fc9286b8f95f9c676ff10c1add3c8bfe3b8d6153be90020ed91be");
+
+std::thread_local! {
+    static KEY: Cell<Option<&'static TrapdoorKey>> = const { Cell::new(None) };
+}
+
+/// Makes the verifiers on this thread check seals against a [`TrapdoorKey`] instead of the
+/// release key, and returns that key.
+pub fn use_trapdoor_key() -> &'static TrapdoorKey {
+    let key: &'static TrapdoorKey = Box::leak(Box::default());
+    KEY.with(|k| k.set(Some(key)));
+    key
+}
+
+/// The key the verifiers check seals against on this thread.
+pub fn verification_key() -> VerificationKey<'static> {
+    KEY.with(Cell::get)
+        .map_or_else(vk::get_verification_key, TrapdoorKey::as_key)
+}
+
+/// A Groth16 key built from known scalars, so that seals can be made for any claim.
+pub struct TrapdoorKey {
+    alpha: Fr,
+    beta: Fr,
+    gamma: Fr,
+    delta: Fr,
+    ic: [Fr; 6],
+    pub alpha1: G1Point,
+    pub beta2: G2Point,
+    pub gamma2: G2Point,
+    pub delta2: G2Point,
+    pub ic_points: Vec<G1Point>,
+}
+
+impl Default for TrapdoorKey {
+    fn default() -> Self {
+        let scalar = |x: u64| fr(U256::from(x));
+        let (alpha, beta, gamma, delta) = (scalar(2), scalar(3), scalar(5), scalar(7));
+        let ic = [11, 13, 17, 19, 23, 29].map(scalar);
+        Self {
+            alpha,
+            beta,
+            gamma,
+            delta,
+            ic,
+            alpha1: g1(G1::one() * alpha),
+            beta2: g2(G2::one() * beta),
+            gamma2: g2(G2::one() * gamma),
+            delta2: g2(G2::one() * delta),
+            ic_points: ic.iter().map(|s| g1(G1::one() * *s)).collect(),
+        }
+    }
+}
+
+impl TrapdoorKey {
+    pub fn as_key(&self) -> VerificationKey<'_> {
+        VerificationKey {
+            alpha1: self.alpha1,
+            beta2: self.beta2,
+            gamma2: self.gamma2,
+            delta2: self.delta2,
+            ic: &self.ic_points,
+        }
+    }
+
+    /// The key as 32-byte big-endian words, in the order of the router's key encoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut words = vec![self.alpha1.x, self.alpha1.y];
+        for p in [self.beta2, self.gamma2, self.delta2] {
+            words.extend([p.x[0], p.x[1], p.y[0], p.y[1]]);
+        }
+        for p in &self.ic_points {
+            words.extend([p.x, p.y]);
+        }
+        words.iter().flat_map(|w| w.to_be_bytes::<32>()).collect()
+    }
+
+    /// A seal of `claim_digest` under this key, prefixed with `selector`.
+    pub fn seal(
+        &self,
+        selector: FixedBytes<4>,
+        control_root: B256,
+        bn254_control_id: B256,
+        claim_digest: B256,
+    ) -> Vec<u8> {
+        let (ctrl_lo, ctrl_hi) = digest_utils::split_digest(control_root);
+        let (claim_lo, claim_hi) = digest_utils::split_digest(claim_digest);
+        let signals = [
+            U256::from_be_slice(&ctrl_lo),
+            U256::from_be_slice(&ctrl_hi),
+            U256::from_be_slice(&claim_lo),
+            U256::from_be_slice(&claim_hi),
+            U256::from_be_bytes(bn254_control_id.0),
+        ];
+        let x = signals
+            .iter()
+            .zip(&self.ic[1..])
+            .fold(self.ic[0], |acc, (s, ic)| acc + fr(*s) * *ic);
+
+        // e(A, B) = e(α, β)·e(vk_x, γ)·e(C, δ) holds in the exponent for these scalars.
+        let (a, b) = (fr(U256::from(31)), fr(U256::from(37)));
+        let c = (a * b - self.alpha * self.beta - x * self.gamma) * self.delta.inverse().unwrap();
+        let (a, b, c) = (g1(G1::one() * a), g2(G2::one() * b), g1(G1::one() * c));
+
+        let seal = Seal {
+            a: [a.x, a.y],
+            b: [b.x, b.y],
+            c: [c.x, c.y],
+        };
+        [selector.as_slice(), &<Seal as SolType>::abi_encode(&seal)].concat()
+    }
+}
+
+fn fr(x: U256) -> Fr {
+    Fr::from_slice(&(x % R).to_be_bytes::<32>()).unwrap()
+}
+
+fn fq(x: Fq) -> U256 {
+    let mut bytes = [0u8; 32];
+    x.to_big_endian(&mut bytes).unwrap();
+    U256::from_be_bytes(bytes)
+}
+
+fn g1(p: G1) -> G1Point {
+    let p = AffineG1::from_jacobian(p).unwrap();
+    G1Point {
+        x: fq(p.x()),
+        y: fq(p.y()),
+    }
+}
+
+/// Coordinates in the `ecPairing` precompile order, imaginary part first.
+fn g2(p: G2) -> G2Point {
+    let p = AffineG2::from_jacobian(p).unwrap();
+    G2Point {
+        x: [fq(p.x().imaginary()), fq(p.x().real())],
+        y: [fq(p.y().imaginary()), fq(p.y().real())],
+    }
+}
@@ -1,8 +1,10 @@
 use alloc::vec::Vec;
-use sha2::{Digest, Sha256};
 use stylus_sdk::{alloy_primitives::B256, alloy_sol_types::sol};
 
-use crate::risc0::config::{system_state_zero_digest, tags};
+use crate::risc0::{
+    config::{system_state_zero_digest, tags},
+    crypto::digest_utils::{self, TaggedData, TaggedDigest},
+};
 
 sol! {
     struct Seal {
@@ -12,36 +14,143 @@ sol! {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+/// ABI forms of the claim types, laid out as in `IRiscZeroVerifier.sol`, except that the
+/// claim carries its output unhashed.
+pub mod abi {
+    use stylus_sdk::{alloy_sol_types::sol, prelude::*};
+
+    sol! {
+        enum SystemExitCode {
+            Halted,
+            Paused,
+            SystemSplit
+        }
+
+        #[derive(AbiType)]
+        struct ExitCode {
+            SystemExitCode system;
+            uint8 user;
+        }
+
+        #[derive(AbiType)]
+        struct Output {
+            bytes32 journalDigest;
+            bytes32 assumptionsDigest;
+        }
+
+        #[derive(AbiType)]
+        struct ReceiptClaim {
+            bytes32 preStateDigest;
+            bytes32 postStateDigest;
+            ExitCode exitCode;
+            bytes32 input;
+            Output output;
+        }
+
+        #[derive(AbiType)]
+        struct Assumption {
+            bytes32 claim;
+            bytes32 controlRoot;
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ExitCode {
     pub system: SystemExitCode,
     pub user: u8,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SystemExitCode {
     Halted = 0,
     Paused = 1,
     SystemSplit = 2,
 }
 
-#[derive(Clone)]
+#[derive(Clone, TaggedDigest)]
+#[tagged(tag = tags::RECEIPT_CLAIM_TAG)]
 pub struct ReceiptClaim {
+    #[tagged(down)]
+    pub input: B256,
+    #[tagged(down)]
     pub pre_state_digest: B256,
+    #[tagged(down)]
     pub post_state_digest: B256,
-    pub exit_code: ExitCode,
-    pub input: B256,
+    #[tagged(down)]
     pub output: B256,
+    #[tagged(data)]
+    pub exit_code: ExitCode,
 }
 
-#[derive(Clone)]
+#[derive(Clone, TaggedDigest)]
+#[tagged(tag = tags::OUTPUT_TAG)]
 pub struct Output {
+    #[tagged(down)]
     pub journal_digest: B256,
+    #[tagged(down)]
     pub assumptions_digest: B256,
 }
 
+/// A receipt claim the guest relied on through composition, to be proven under
+/// `control_root`. A zero control root stands for the verifier's own.
+#[derive(Clone, TaggedDigest)]
+#[tagged(tag = tags::ASSUMPTION_TAG)]
+pub struct Assumption {
+    #[tagged(down)]
+    pub claim: B256,
+    #[tagged(down)]
+    pub control_root: B256,
+}
+
+impl TaggedData for ExitCode {
+    const WORDS: usize = 2;
+
+    fn write_words(&self, words: &mut [u32]) {
+        words[0] = self.system as u32;
+        words[1] = self.user as u32;
+    }
+}
+
 impl ReceiptClaim {
     pub fn ok(image_id: B256, journal_digest: B256) -> Self {
+        Self::halted(image_id, journal_digest, 0)
+    }
+
+    /// Claim of a run that halted with exit code `user`. A halted machine always ends in
+    /// the zero system state.
+    pub fn halted(image_id: B256, journal_digest: B256, user: u8) -> Self {
+        Self::unconditional(
+            image_id,
+            system_state_zero_digest(),
+            ExitCode {
+                system: SystemExitCode::Halted,
+                user,
+            },
+            journal_digest,
+        )
+    }
+
+    /// Claim of a run that paused with exit code `user`. The post state is the one the
+    /// continuation resumes from, so it is supplied by the caller.
+    pub fn paused(image_id: B256, post_state_digest: B256, journal_digest: B256, user: u8) -> Self {
+        Self::unconditional(
+            image_id,
+            post_state_digest,
+            ExitCode {
+                system: SystemExitCode::Paused,
+                user,
+            },
+            journal_digest,
+        )
+    }
+
+    fn unconditional(
+        image_id: B256,
+        post_state_digest: B256,
+        exit_code: ExitCode,
+        journal_digest: B256,
+    ) -> Self {
         let output = Output {
             journal_digest,
             assumptions_digest: B256::ZERO,
@@ -49,47 +158,175 @@ impl ReceiptClaim {
 
         ReceiptClaim {
             pre_state_digest: image_id,
-            post_state_digest: system_state_zero_digest(),
-            exit_code: ExitCode {
-                system: SystemExitCode::Halted,
-                user: 0,
-            },
+            post_state_digest,
+            exit_code,
             input: B256::ZERO,
             output: output.digest(),
         }
     }
 
     pub fn digest(&self) -> B256 {
-        let tag_digest = B256::from_slice(&Sha256::digest(tags::RECEIPT_CLAIM_TAG));
+        self.tagged_digest()
+    }
+}
 
-        let exit_system_be = ((self.exit_code.system as u32) << 24).to_be_bytes();
-        let exit_user_be   = ((self.exit_code.user   as u32) << 24).to_be_bytes(); 
+impl TryFrom<abi::ExitCode> for ExitCode {
+    type Error = ();
 
-        // 32×5  + 4 + 4 + 2 = 178 bytes
-        let mut buf = Vec::with_capacity(178);
-        buf.extend_from_slice(tag_digest.as_slice());
-        buf.extend_from_slice(self.input.as_slice());
-        buf.extend_from_slice(self.pre_state_digest.as_slice());
-        buf.extend_from_slice(self.post_state_digest.as_slice());
-        buf.extend_from_slice(self.output.as_slice());
-        buf.extend_from_slice(&exit_system_be);
-        buf.extend_from_slice(&exit_user_be);
-        buf.extend_from_slice(&(4u16 << 8).to_be_bytes());
+    fn try_from(exit_code: abi::ExitCode) -> Result<Self, ()> {
+        let system = match exit_code.system {
+            abi::SystemExitCode::Halted => SystemExitCode::Halted,
+            abi::SystemExitCode::Paused => SystemExitCode::Paused,
+            abi::SystemExitCode::SystemSplit => SystemExitCode::SystemSplit,
+            _ => return Err(()),
+        };
+        Ok(ExitCode {
+            system,
+            user: exit_code.user,
+        })
+    }
+}
 
-        B256::from_slice(&Sha256::digest(&buf))
+impl From<ExitCode> for abi::ExitCode {
+    fn from(exit_code: ExitCode) -> Self {
+        let system = match exit_code.system {
+            SystemExitCode::Halted => abi::SystemExitCode::Halted,
+            SystemExitCode::Paused => abi::SystemExitCode::Paused,
+            SystemExitCode::SystemSplit => abi::SystemExitCode::SystemSplit,
+        };
+        abi::ExitCode {
+            system,
+            user: exit_code.user,
+        }
+    }
+}
+
+impl From<abi::Output> for Output {
+    fn from(output: abi::Output) -> Self {
+        Output {
+            journal_digest: output.journalDigest,
+            assumptions_digest: output.assumptionsDigest,
+        }
+    }
+}
+
+impl TryFrom<abi::ReceiptClaim> for ReceiptClaim {
+    type Error = ();
+
+    /// Hashes the output; fails on an unknown system exit code.
+    fn try_from(claim: abi::ReceiptClaim) -> Result<Self, ()> {
+        Ok(ReceiptClaim {
+            pre_state_digest: claim.preStateDigest,
+            post_state_digest: claim.postStateDigest,
+            exit_code: claim.exitCode.try_into()?,
+            input: claim.input,
+            output: Output::from(claim.output).digest(),
+        })
     }
 }
 
 impl Output {
     pub fn digest(&self) -> B256 {
-        let tag_digest = B256::from_slice(&Sha256::digest(tags::OUTPUT_TAG));
+        self.tagged_digest()
+    }
+}
+
+impl From<abi::Assumption> for Assumption {
+    fn from(assumption: abi::Assumption) -> Self {
+        Assumption {
+            claim: assumption.claim,
+            control_root: assumption.controlRoot,
+        }
+    }
+}
+
+impl Assumption {
+    pub fn digest(&self) -> B256 {
+        self.tagged_digest()
+    }
+}
 
-        let mut buf = Vec::with_capacity(98);
-        buf.extend_from_slice(tag_digest.as_slice());
-        buf.extend_from_slice(self.journal_digest.as_slice());
-        buf.extend_from_slice(self.assumptions_digest.as_slice());
-        buf.extend_from_slice(&(2u16 << 8).to_be_bytes());  
+/// Digest of the assumptions list, as committed in [`Output::assumptions_digest`]. The
+/// empty list digests to zero.
+pub fn assumptions_digest(assumptions: &[Assumption]) -> B256 {
+    let digests: Vec<B256> = assumptions.iter().map(Assumption::digest).collect();
+    digest_utils::tagged_list(tags::ASSUMPTIONS_TAG_DIGEST, &digests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risc0::{crypto, verifier};
+    use stylus_sdk::alloy_primitives::{b256, hex};
+
+    #[test]
+    fn derived_digests_match_tagged_structs() {
+        let claim = ReceiptClaim::paused(
+            B256::repeat_byte(1),
+            B256::repeat_byte(2),
+            B256::repeat_byte(3),
+            7,
+        );
+        let output = digest_utils::tagged_struct(
+            tags::OUTPUT_TAG_DIGEST,
+            &[B256::repeat_byte(3), B256::ZERO],
+        );
+        assert_eq!(claim.output, output);
+        assert_eq!(
+            claim.digest(),
+            digest_utils::tagged_struct_with_data(
+                tags::RECEIPT_CLAIM_TAG_DIGEST,
+                &[
+                    B256::ZERO,
+                    B256::repeat_byte(1),
+                    B256::repeat_byte(2),
+                    output
+                ],
+                &[SystemExitCode::Paused as u32, 7],
+            )
+        );
+
+        let assumption = Assumption {
+            claim: B256::repeat_byte(4),
+            control_root: B256::repeat_byte(5),
+        };
+        assert_eq!(
+            assumption.digest(),
+            digest_utils::tagged_struct(
+                tags::ASSUMPTION_TAG_DIGEST,
+                &[B256::repeat_byte(4), B256::repeat_byte(5)],
+            )
+        );
+        assert_eq!(assumptions_digest(&[]), B256::ZERO);
+    }
 
-        B256::from_slice(&Sha256::digest(&buf))
+    #[test]
+    fn claim_digest_verifies_a_groth16_seal() {
+        // Seal produced by the RISC Zero 2.0 prover for this image id and journal.
+        const SEAL: [u8; 260] = hex!(
+            "9f39696c08b522a6c736627b0a445e8a7b01282742254793b97900972b1885f08aea3a6818cdb1e8a18a200c34b4dd2c"
+            "96b5cebc414becbd1ef304390de67acf17d777b12070f3c216c5236519405ff2b012d4c6cfc5df2882275f320c80453e"
+            "53a0e3240b7cdac29b24cd2d694d3ba6cdc85c0f6e08dc4f218998ba3ae169b13b0bb7fb0c1767d3a9b4cbfd6262af66"
+            "be7b3d11d18c323cda5db600e615110beb7d1e061ece06169517148a2ca2479fdf756f5f8dc7d555c4e4eb2b691487ac"
+            "2be1f8430fedf6c4d4bb7e42d79a6489ab3cee7d67efbd438fffbd626ca644aac725c15427df3b73288c2d37bba34f77"
+            "b6a586a859f259d6d524604a82d0c03b3158889f"
+        );
+        let image_id = b256!("886c206b82e4f2dbdc4220f32c3a278c357ddc31ea800574b850c93647ddb5ff");
+        let journal_digest =
+            b256!("d1ec675902ef1633427ca360b290b0b3045a0d9058ddb5e648b4c3c3224c5c68");
+
+        assert_eq!(SEAL[..4], crypto::v2_0::calculate_selector()[..]);
+        let verify = |claim: ReceiptClaim| {
+            verifier::verify_seal(
+                &SEAL[4..],
+                crypto::v2_0::CONTROL_ROOT,
+                crypto::v2_0::BN254_CONTROL_ID,
+                &crypto::v2_0::get_verification_key(),
+                claim.digest(),
+            )
+        };
+        assert!(verify(ReceiptClaim::ok(image_id, journal_digest)).is_ok());
+        assert!(verify(ReceiptClaim::halted(image_id, journal_digest, 1)).is_err());
+        assert!(verify(ReceiptClaim::ok(image_id, B256::ZERO)).is_err());
     }
 }
@@ -1,23 +1,42 @@
-use alloc::{vec, vec::Vec};
+use alloc::{string::String, vec, vec::Vec};
 use alloy_primitives::B128;
 use sha2::{Digest, Sha256};
 use stylus_sdk::{
-    alloy_primitives::{FixedBytes, B256, U256},
-    alloy_sol_types::{SolType, SolValue},
+    alloy_primitives::{Address, FixedBytes, B256, U256, U64},
+    alloy_sol_types::{sol, SolType},
     prelude::*,
+    stylus_core::log,
 };
 
-use crate::common::{Groth16Verifier, VMType};
+use crate::common::{
+    ownable::{IOwnable, Ownable},
+    Groth16Verifier, ProofVerified, VMType, VerificationKey, R,
+};
 use crate::risc0::{
-    config::tags,
-    crypto::{digest_utils, vk},
+    config::{self, tags, PARAMETER_ROTATION_DELAY},
+    crypto::digest_utils,
     errors::RiscZeroError,
-    types::{ReceiptClaim, Seal},
+    registry::{IRiscZeroImageRegistry, ImageRegistry},
+    types::{
+        abi, assumptions_digest, Assumption, ExitCode, Output, ReceiptClaim, Seal, SystemExitCode,
+    },
 };
 
+sol! {
+    event ParametersProposed(bytes32 controlRoot, bytes32 bn254ControlId, uint64 eta);
+    event ParametersProposalCancelled();
+    event ParametersRotated(
+        bytes4 indexed previousSelector,
+        bytes4 indexed selector,
+        bytes32 controlRoot,
+        bytes32 bn254ControlId
+    );
+}
+
 pub trait IRiscZeroVerifier {
     type Error;
 
+    /// Sets the verifier parameters; owner only.
     fn initialize(&mut self, control_root: B256, bn254_control_id: B256)
         -> Result<(), Self::Error>;
 
@@ -28,17 +47,112 @@ pub trait IRiscZeroVerifier {
         journal_digest: B256,
     ) -> Result<bool, Self::Error>;
 
+    /// Like `verify`, but emits `ProofVerified` for indexers.
+    fn verify_and_record(
+        &mut self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error>;
+
+    /// Like `verify`, taking the journal itself rather than its SHA-256 digest.
+    fn verify_journal(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal: Vec<u8>,
+    ) -> Result<bool, Self::Error>;
+
+    /// Like `verify`, for a run that halted with `exit_code`, which must be `Halted`. A
+    /// `Paused` claim also commits to the post state, so it goes through `verify_paused`.
+    /// `SystemSplit` only ends segments that a later segment continues, so no complete
+    /// receipt claims it; `verify_claim` still checks such a claim. Both are rejected with
+    /// `UnsupportedExitCode`.
+    fn verify_with_exit_code(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+        exit_code: abi::ExitCode,
+    ) -> Result<bool, Self::Error>;
+
+    /// Like `verify`, for a run that paused in `post_state_digest` with `user_exit_code`.
+    fn verify_paused(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        post_state_digest: B256,
+        journal_digest: B256,
+        user_exit_code: u8,
+    ) -> Result<bool, Self::Error>;
+
+    /// Verifies `seal` against an arbitrary claim, hashed here rather than by the caller.
+    fn verify_claim(&self, seal: Vec<u8>, claim: abi::ReceiptClaim) -> Result<bool, Self::Error>;
+
     fn verify_integrity(
         &self,
         receipt_seal: Vec<u8>,
         receipt_claim_digest: B256,
     ) -> Result<bool, Self::Error>;
 
+    /// Verifies `seal` against `claim` with its assumptions resolved. The claim's
+    /// assumptions digest is ignored and computed from `assumptions`. Each assumption is
+    /// proven by the seal at the same index, or, if that seal is empty, must have been
+    /// recorded with `record_verified_claim`.
+    fn verify_with_assumptions(
+        &self,
+        seal: Vec<u8>,
+        claim: abi::ReceiptClaim,
+        assumptions: Vec<abi::Assumption>,
+        assumption_seals: Vec<Vec<u8>>,
+    ) -> Result<bool, Self::Error>;
+
+    /// Verifies `seal` against `claim_digest` and records the claim as verified, for later
+    /// use as an assumption.
+    fn record_verified_claim(
+        &mut self,
+        seal: Vec<u8>,
+        claim_digest: B256,
+    ) -> Result<(), Self::Error>;
+
+    fn is_claim_verified(&self, claim_digest: B256) -> bool;
+
     fn get_selector(&self) -> FixedBytes<4>;
     fn get_control_root(&self) -> (B128, B128);
     fn get_bn254_control_id(&self) -> B256;
     fn get_verifier_key_digest(&self) -> B256;
     fn is_initialized(&self) -> bool;
+
+    /// RISC Zero release whose Groth16 key is compiled in.
+    fn version(&self) -> String;
+
+    /// Digest of `claim`, as checked against the seal. Fails on an unknown exit code.
+    fn compute_claim_digest(&self, claim: abi::ReceiptClaim) -> Result<B256, Self::Error>;
+
+    fn compute_output_digest(&self, output: abi::Output) -> B256;
+
+    /// Byte-reversed `digest` as two 128-bit public inputs, low half first.
+    fn split_digest(&self, digest: B256) -> (B128, B128);
+}
+
+/// Timelocked rotation of the verifier parameters, for moving to a new RISC Zero release.
+pub trait IRiscZeroVerifierGovernance {
+    type Error;
+
+    /// Announces new parameters, replacing any pending ones; they can be accepted after
+    /// `PARAMETER_ROTATION_DELAY` seconds. Returns that time.
+    fn propose_parameters(
+        &mut self,
+        control_root: B256,
+        bn254_control_id: B256,
+    ) -> Result<u64, Self::Error>;
+
+    fn accept_parameters(&mut self) -> Result<(), Self::Error>;
+
+    fn cancel_parameters(&mut self) -> Result<(), Self::Error>;
+
+    /// Pending control root, BN254 control id and the time they can be accepted from.
+    fn get_pending_parameters(&self) -> (B256, B256, u64);
 }
 
 sol_storage! {
@@ -48,6 +162,12 @@ sol_storage! {
         bytes32 bn254_control_id;
         bytes4 selector;
         bool initialized;
+        mapping(bytes32 => bool) verified_claims;
+        Ownable ownable;
+        bytes32 pending_control_root;
+        bytes32 pending_bn254_control_id;
+        uint64 pending_eta;
+        ImageRegistry images;
     }
 }
 
@@ -60,16 +180,13 @@ impl IRiscZeroVerifier for RiscZeroVerifier {
         control_root: B256,
         bn254_control_id: B256,
     ) -> Result<(), Self::Error> {
+        self.only_owner()?;
         if self.initialized.get() {
             return Err(RiscZeroError::ALREADY_INITIALIZED.abi_encode());
         }
+        validate_parameters(control_root, bn254_control_id).map_err(|e| e.abi_encode())?;
 
-        let (ctrl_lo, ctrl_hi) = digest_utils::split_digest(control_root);
-        self.control_root_0.set(B128::from(ctrl_lo));
-        self.control_root_1.set(B128::from(ctrl_hi));
-        self.bn254_control_id.set(bn254_control_id);
-        let selector = Self::calculate_selector(control_root, bn254_control_id);
-        self.selector.set(selector);
+        self.set_parameters(control_root, bn254_control_id);
         self.initialized.set(true);
 
         Ok(())
@@ -91,6 +208,85 @@ impl IRiscZeroVerifier for RiscZeroVerifier {
         self.verify_integrity_internal(seal, claim_digest)
     }
 
+    fn verify_and_record(
+        &mut self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        self.verify(seal, image_id, journal_digest)?;
+        log(
+            self.vm(),
+            ProofVerified {
+                system: VMType::Risc0 as u8,
+                programId: image_id,
+                publicInputsDigest: journal_digest,
+                caller: self.vm().msg_sender(),
+            },
+        );
+        Ok(true)
+    }
+
+    fn verify_journal(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal: Vec<u8>,
+    ) -> Result<bool, Self::Error> {
+        let journal_digest = B256::from_slice(&Sha256::digest(&journal));
+        self.verify(seal, image_id, journal_digest)
+    }
+
+    fn verify_with_exit_code(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+        exit_code: abi::ExitCode,
+    ) -> Result<bool, Self::Error> {
+        if !self.initialized.get() {
+            return Err(RiscZeroError::INVALID_INITIALIZATION.abi_encode());
+        }
+
+        let system = exit_code.system as u8;
+        let exit_code = ExitCode::try_from(exit_code)
+            .map_err(|_| RiscZeroError::INVALID_PROOF_DATA.abi_encode())?;
+        if exit_code.system != SystemExitCode::Halted {
+            return Err(RiscZeroError::UnsupportedExitCode { system }.abi_encode());
+        }
+
+        let claim = ReceiptClaim::halted(image_id, journal_digest, exit_code.user);
+        self.verify_integrity_internal(seal, claim.digest())
+    }
+
+    fn verify_paused(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        post_state_digest: B256,
+        journal_digest: B256,
+        user_exit_code: u8,
+    ) -> Result<bool, Self::Error> {
+        if !self.initialized.get() {
+            return Err(RiscZeroError::INVALID_INITIALIZATION.abi_encode());
+        }
+
+        let claim =
+            ReceiptClaim::paused(image_id, post_state_digest, journal_digest, user_exit_code);
+        self.verify_integrity_internal(seal, claim.digest())
+    }
+
+    fn verify_claim(&self, seal: Vec<u8>, claim: abi::ReceiptClaim) -> Result<bool, Self::Error> {
+        if !self.initialized.get() {
+            return Err(RiscZeroError::INVALID_INITIALIZATION.abi_encode());
+        }
+
+        let claim = ReceiptClaim::try_from(claim)
+            .map_err(|_| RiscZeroError::INVALID_PROOF_DATA.abi_encode())?;
+
+        self.verify_integrity_internal(seal, claim.digest())
+    }
+
     fn verify_integrity(
         &self,
         receipt_seal: Vec<u8>,
@@ -103,6 +299,68 @@ impl IRiscZeroVerifier for RiscZeroVerifier {
         self.verify_integrity_internal(receipt_seal, receipt_claim_digest)
     }
 
+    fn verify_with_assumptions(
+        &self,
+        seal: Vec<u8>,
+        claim: abi::ReceiptClaim,
+        assumptions: Vec<abi::Assumption>,
+        assumption_seals: Vec<Vec<u8>>,
+    ) -> Result<bool, Self::Error> {
+        if !self.initialized.get() {
+            return Err(RiscZeroError::INVALID_INITIALIZATION.abi_encode());
+        }
+        if assumptions.len() != assumption_seals.len() {
+            return Err(RiscZeroError::INVALID_PROOF_DATA.abi_encode());
+        }
+
+        let assumptions: Vec<Assumption> = assumptions.into_iter().map(Assumption::from).collect();
+        let control_root = self.control_root();
+        for (assumption, assumption_seal) in assumptions.iter().zip(assumption_seals) {
+            if assumption.control_root != B256::ZERO && assumption.control_root != control_root {
+                return Err(RiscZeroError::ControlRootMismatch {
+                    received: assumption.control_root,
+                    expected: control_root,
+                }
+                .abi_encode());
+            }
+            if assumption_seal.is_empty() {
+                if !self.verified_claims.get(assumption.claim) {
+                    return Err(RiscZeroError::AssumptionNotVerified {
+                        claim: assumption.claim,
+                    }
+                    .abi_encode());
+                }
+            } else {
+                self.verify_integrity_internal(assumption_seal, assumption.claim)?;
+            }
+        }
+
+        let mut claim = claim;
+        claim.output.assumptionsDigest = assumptions_digest(&assumptions);
+        let claim = ReceiptClaim::try_from(claim)
+            .map_err(|_| RiscZeroError::INVALID_PROOF_DATA.abi_encode())?;
+
+        self.verify_integrity_internal(seal, claim.digest())
+    }
+
+    fn record_verified_claim(
+        &mut self,
+        seal: Vec<u8>,
+        claim_digest: B256,
+    ) -> Result<(), Self::Error> {
+        if !self.initialized.get() {
+            return Err(RiscZeroError::INVALID_INITIALIZATION.abi_encode());
+        }
+
+        self.verify_integrity_internal(seal, claim_digest)?;
+        self.verified_claims.insert(claim_digest, true);
+        Ok(())
+    }
+
+    fn is_claim_verified(&self, claim_digest: B256) -> bool {
+        self.verified_claims.get(claim_digest)
+    }
+
     fn get_selector(&self) -> FixedBytes<4> {
         self.selector.get()
     }
@@ -122,25 +380,220 @@ impl IRiscZeroVerifier for RiscZeroVerifier {
     fn is_initialized(&self) -> bool {
         self.initialized.get()
     }
+
+    fn version(&self) -> String {
+        String::from(config::VERSION)
+    }
+
+    fn compute_claim_digest(&self, claim: abi::ReceiptClaim) -> Result<B256, Self::Error> {
+        let claim = ReceiptClaim::try_from(claim)
+            .map_err(|_| RiscZeroError::INVALID_PROOF_DATA.abi_encode())?;
+        Ok(claim.digest())
+    }
+
+    fn compute_output_digest(&self, output: abi::Output) -> B256 {
+        Output::from(output).digest()
+    }
+
+    fn split_digest(&self, digest: B256) -> (B128, B128) {
+        let (low, high) = digest_utils::split_digest(digest);
+        (B128::from(low), B128::from(high))
+    }
+}
+
+#[public]
+impl IRiscZeroVerifierGovernance for RiscZeroVerifier {
+    type Error = Vec<u8>;
+
+    fn propose_parameters(
+        &mut self,
+        control_root: B256,
+        bn254_control_id: B256,
+    ) -> Result<u64, Self::Error> {
+        self.only_owner()?;
+        if !self.initialized.get() {
+            return Err(RiscZeroError::INVALID_INITIALIZATION.abi_encode());
+        }
+        validate_parameters(control_root, bn254_control_id).map_err(|e| e.abi_encode())?;
+
+        let eta = self
+            .vm()
+            .block_timestamp()
+            .saturating_add(PARAMETER_ROTATION_DELAY);
+        self.pending_control_root.set(control_root);
+        self.pending_bn254_control_id.set(bn254_control_id);
+        self.pending_eta.set(U64::from(eta));
+
+        log(
+            self.vm(),
+            ParametersProposed {
+                controlRoot: control_root,
+                bn254ControlId: bn254_control_id,
+                eta,
+            },
+        );
+        Ok(eta)
+    }
+
+    fn accept_parameters(&mut self) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        let eta = self.pending_eta.get().to::<u64>();
+        if eta == 0 {
+            return Err(RiscZeroError::NoPendingParameters.abi_encode());
+        }
+        if self.vm().block_timestamp() < eta {
+            return Err(RiscZeroError::TimelockNotExpired { eta }.abi_encode());
+        }
+
+        let control_root = self.pending_control_root.get();
+        let bn254_control_id = self.pending_bn254_control_id.get();
+        let previous_selector = self.selector.get();
+        self.clear_pending_parameters();
+        self.set_parameters(control_root, bn254_control_id);
+
+        log(
+            self.vm(),
+            ParametersRotated {
+                previousSelector: previous_selector,
+                selector: self.selector.get(),
+                controlRoot: control_root,
+                bn254ControlId: bn254_control_id,
+            },
+        );
+        Ok(())
+    }
+
+    fn cancel_parameters(&mut self) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        if self.pending_eta.get() == U64::ZERO {
+            return Err(RiscZeroError::NoPendingParameters.abi_encode());
+        }
+
+        self.clear_pending_parameters();
+        log(self.vm(), ParametersProposalCancelled {});
+        Ok(())
+    }
+
+    fn get_pending_parameters(&self) -> (B256, B256, u64) {
+        (
+            self.pending_control_root.get(),
+            self.pending_bn254_control_id.get(),
+            self.pending_eta.get().to::<u64>(),
+        )
+    }
+}
+
+#[public]
+impl IOwnable for RiscZeroVerifier {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.ownable.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.ownable
+            .transfer_ownership(new_owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.ownable.accept_ownership().map_err(|e| e.abi_encode())
+    }
+}
+
+#[public]
+impl IRiscZeroImageRegistry for RiscZeroVerifier {
+    type Error = Vec<u8>;
+
+    fn register_image(
+        &mut self,
+        image_id: B256,
+        label: String,
+        valid_from: u64,
+        valid_until: u64,
+        callers: Vec<Address>,
+    ) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        self.images
+            .register(image_id, label, valid_from, valid_until, callers)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn remove_image(&mut self, image_id: B256) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        self.images.remove(image_id).map_err(|e| e.abi_encode())
+    }
+
+    fn get_image(&self, image_id: B256) -> Result<(String, u64, u64, Vec<Address>), Self::Error> {
+        self.images.get(image_id).map_err(|e| e.abi_encode())
+    }
+
+    fn verify_registered(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        self.images.check(image_id).map_err(|e| e.abi_encode())?;
+        self.verify(seal, image_id, journal_digest)
+    }
 }
 
 impl RiscZeroVerifier {
-    fn calculate_selector(control_root: B256, bn254_control_id: B256) -> FixedBytes<4> {
-        let tag_digest = B256::from_slice(&Sha256::digest(
-            tags::GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG,
-        ));
+    /// Verifies `seal` for `journal` and returns the journal ABI-decoded as `T`, for guests
+    /// that commit an ABI-encoded value.
+    pub fn verify_journal_decoded<T: SolType>(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal: &[u8],
+    ) -> Result<T::RustType, Vec<u8>> {
+        let journal_digest = B256::from_slice(&Sha256::digest(journal));
+        self.verify(seal, image_id, journal_digest)?;
+        T::abi_decode(journal, true).map_err(|_| RiscZeroError::INVALID_PROOF_DATA.abi_encode())
+    }
+
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable
+            .set_initial_owner(owner)
+            .map_err(|e| e.abi_encode())
+    }
 
-        let packed_data = (
-            tag_digest,
+    pub(crate) fn only_owner(&self) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner().map_err(|e| e.abi_encode())
+    }
+
+    fn set_parameters(&mut self, control_root: B256, bn254_control_id: B256) {
+        let (ctrl_lo, ctrl_hi) = digest_utils::split_digest(control_root);
+        self.control_root_0.set(B128::from(ctrl_lo));
+        self.control_root_1.set(B128::from(ctrl_hi));
+        self.bn254_control_id.set(bn254_control_id);
+        let selector = calculate_selector(
             control_root,
-            digest_utils::reverse_byte_order_uint256(bn254_control_id),
+            bn254_control_id,
             digest_utils::compute_verifier_key_digest(),
-            3u16 << 8,
-        )
-            .abi_encode_packed();
+        );
+        self.selector.set(selector);
+    }
+
+    fn clear_pending_parameters(&mut self) {
+        self.pending_control_root.set(B256::ZERO);
+        self.pending_bn254_control_id.set(B256::ZERO);
+        self.pending_eta.set(U64::ZERO);
+    }
 
-        let hash = Sha256::digest(&packed_data);
-        FixedBytes::<4>::from_slice(&hash[..4])
+    /// Control root currently in use.
+    pub(crate) fn control_root(&self) -> B256 {
+        digest_utils::join_digest(
+            self.control_root_0.get().into(),
+            self.control_root_1.get().into(),
+        )
     }
 
     fn verify_integrity_internal(
@@ -163,35 +616,771 @@ impl RiscZeroVerifier {
             .abi_encode());
         }
 
-        let proof_data = &seal[4..];
-        let decoded_seal = match <Seal as SolType>::abi_decode(proof_data, true) {
-            Ok(seal) => seal,
-            Err(_) => return Err(RiscZeroError::INVALID_PROOF_DATA.abi_encode()),
+        verify_seal(
+            &seal[4..],
+            self.control_root(),
+            self.bn254_control_id.get(),
+            &verification_key(),
+            claim_digest,
+        )
+        .map_err(|e| e.abi_encode())?;
+
+        Ok(true)
+    }
+}
+
+/// Key of the release selected by the `risc0-v*` features.
+#[cfg(not(test))]
+fn verification_key() -> VerificationKey<'static> {
+    crate::risc0::crypto::vk::get_verification_key()
+}
+
+/// Unit tests may swap in a key under which they can seal any claim.
+#[cfg(test)]
+fn verification_key() -> VerificationKey<'static> {
+    crate::risc0::testing::verification_key()
+}
+
+/// Rejects a zero control root and a BN254 control id outside the scalar field.
+pub(crate) fn validate_parameters(
+    control_root: B256,
+    bn254_control_id: B256,
+) -> Result<(), RiscZeroError> {
+    if control_root == B256::ZERO || U256::from_be_bytes(bn254_control_id.0) >= R {
+        return Err(RiscZeroError::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Selector of the Groth16 verifier for `control_root`, `bn254_control_id` and the key
+/// with digest `verifier_key_digest`, as in risc0-ethereum's `Groth16Verifier`.
+pub(crate) const fn calculate_selector(
+    control_root: B256,
+    bn254_control_id: B256,
+    verifier_key_digest: B256,
+) -> FixedBytes<4> {
+    let digest = digest_utils::tagged_struct(
+        tags::GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG_DIGEST,
+        &[
+            control_root,
+            digest_utils::reverse_byte_order_uint256(bn254_control_id),
+            verifier_key_digest,
+        ],
+    );
+    FixedBytes::new([digest.0[0], digest.0[1], digest.0[2], digest.0[3]])
+}
+
+/// Checks the ABI-encoded Groth16 `proof` (the seal without its selector) of `claim_digest`
+/// under the given parameters.
+pub(crate) fn verify_seal(
+    proof: &[u8],
+    control_root: B256,
+    bn254_control_id: B256,
+    verification_key: &VerificationKey,
+    claim_digest: B256,
+) -> Result<(), RiscZeroError> {
+    let decoded_seal = <Seal as SolType>::abi_decode(proof, true)
+        .map_err(|_| RiscZeroError::INVALID_PROOF_DATA)?;
+
+    let (ctrl_lo, ctrl_hi) = digest_utils::split_digest(control_root);
+    let (claim_lo, claim_hi) = digest_utils::split_digest(claim_digest);
+    let public_signals = [
+        U256::from_be_slice(&ctrl_lo),
+        U256::from_be_slice(&ctrl_hi),
+        U256::from_be_slice(&claim_lo),
+        U256::from_be_slice(&claim_hi),
+        U256::from_be_slice(bn254_control_id.as_slice()),
+    ];
+
+    let verified = Groth16Verifier::new().verify_proof_with_key(
+        VMType::Risc0,
+        verification_key,
+        decoded_seal.a,
+        decoded_seal.b,
+        decoded_seal.c,
+        &public_signals,
+    );
+
+    if !verified {
+        return Err(RiscZeroError::VERIFICATION_FAILED);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ownable::{OwnableUnauthorizedAccount, OwnershipTransferred};
+    use crate::risc0::{
+        crypto::{v1_2, v2_0},
+        testing::{self, TrapdoorKey, IMAGE_ID, JOURNAL_DIGEST, SEAL},
+    };
+    use stylus_sdk::{
+        alloy_sol_types::{sol_data, SolError, SolEvent},
+        testing::*,
+    };
+
+    const OWNER: Address = Address::repeat_byte(1);
+    const OTHER: Address = Address::repeat_byte(2);
+    const NOW: u64 = 1_700_000_000;
+
+    fn deploy(vm: &TestVM) -> RiscZeroVerifier {
+        let mut verifier = RiscZeroVerifier::from(vm);
+        verifier.set_initial_owner(OWNER).unwrap();
+        vm.set_sender(OWNER);
+        vm.set_block_timestamp(NOW);
+        verifier
+            .initialize(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID)
+            .unwrap();
+        verifier
+    }
+
+    fn unauthorized(account: Address) -> Vec<u8> {
+        OwnableUnauthorizedAccount { account }.abi_encode()
+    }
+
+    fn last_log<E: SolEvent>(vm: &TestVM) -> E {
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = logs.last().unwrap();
+        E::decode_raw_log(topics, data, true).unwrap()
+    }
+
+    #[test]
+    fn only_the_owner_initializes_once() {
+        let vm = TestVM::default();
+        let mut verifier = RiscZeroVerifier::from(&vm);
+        verifier.set_initial_owner(OWNER).unwrap();
+
+        vm.set_sender(OTHER);
+        assert_eq!(
+            verifier.initialize(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID),
+            Err(unauthorized(OTHER))
+        );
+        assert!(!verifier.is_initialized());
+
+        vm.set_sender(OWNER);
+        assert_eq!(
+            verifier.initialize(B256::ZERO, v2_0::BN254_CONTROL_ID),
+            Err(RiscZeroError::InvalidParameters.abi_encode())
+        );
+        verifier
+            .initialize(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID)
+            .unwrap();
+        assert_eq!(verifier.get_selector(), v2_0::calculate_selector());
+        assert_eq!(
+            verifier.initialize(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID),
+            Err(RiscZeroError::ALREADY_INITIALIZED.abi_encode())
+        );
+        assert_eq!(
+            verifier.verify(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn ownership_moves_in_two_steps() {
+        let vm = TestVM::default();
+        let mut verifier = deploy(&vm);
+
+        verifier.transfer_ownership(OTHER).unwrap();
+        assert_eq!((verifier.owner(), verifier.pending_owner()), (OWNER, OTHER));
+        assert_eq!(
+            verifier.propose_parameters(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID),
+            Ok(NOW + PARAMETER_ROTATION_DELAY)
+        );
+
+        vm.set_sender(OTHER);
+        assert_eq!(verifier.cancel_parameters(), Err(unauthorized(OTHER)));
+        verifier.accept_ownership().unwrap();
+        let transferred = last_log::<OwnershipTransferred>(&vm);
+        assert_eq!(
+            (transferred.previousOwner, transferred.newOwner),
+            (OWNER, OTHER)
+        );
+        assert_eq!(
+            (verifier.owner(), verifier.pending_owner()),
+            (OTHER, Address::ZERO)
+        );
+        verifier.cancel_parameters().unwrap();
+
+        vm.set_sender(OWNER);
+        assert_eq!(
+            verifier.propose_parameters(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID),
+            Err(unauthorized(OWNER))
+        );
+        assert_eq!(verifier.transfer_ownership(OWNER), Err(unauthorized(OWNER)));
+    }
+
+    #[test]
+    fn rotation_waits_for_the_delay() {
+        let vm = TestVM::default();
+        let mut verifier = deploy(&vm);
+        let eta = NOW + PARAMETER_ROTATION_DELAY;
+
+        assert_eq!(
+            verifier.accept_parameters(),
+            Err(RiscZeroError::NoPendingParameters.abi_encode())
+        );
+        assert_eq!(
+            verifier.propose_parameters(v1_2::CONTROL_ROOT, B256::from(R)),
+            Err(RiscZeroError::InvalidParameters.abi_encode())
+        );
+        assert_eq!(
+            verifier.propose_parameters(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID),
+            Ok(eta)
+        );
+        let proposed = last_log::<ParametersProposed>(&vm);
+        assert_eq!(
+            (proposed.controlRoot, proposed.bn254ControlId, proposed.eta),
+            (v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID, eta)
+        );
+        assert_eq!(
+            verifier.get_pending_parameters(),
+            (v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID, eta)
+        );
+
+        vm.set_block_timestamp(eta - 1);
+        assert_eq!(
+            verifier.accept_parameters(),
+            Err(RiscZeroError::TimelockNotExpired { eta }.abi_encode())
+        );
+        assert_eq!(verifier.get_selector(), v2_0::calculate_selector());
+        assert_eq!(
+            verifier.verify(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Ok(true)
+        );
+
+        vm.set_sender(OTHER);
+        vm.set_block_timestamp(eta);
+        assert_eq!(verifier.accept_parameters(), Err(unauthorized(OTHER)));
+
+        vm.set_sender(OWNER);
+        verifier.accept_parameters().unwrap();
+        let rotated = last_log::<ParametersRotated>(&vm);
+        assert_eq!(
+            (rotated.previousSelector, rotated.selector),
+            (v2_0::calculate_selector(), v1_2::calculate_selector())
+        );
+        assert_eq!(
+            (rotated.controlRoot, rotated.bn254ControlId),
+            (v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID)
+        );
+        assert_eq!(verifier.get_selector(), v1_2::calculate_selector());
+        assert_eq!(verifier.get_bn254_control_id(), v1_2::BN254_CONTROL_ID);
+        assert_eq!(
+            verifier.get_pending_parameters(),
+            (B256::ZERO, B256::ZERO, 0)
+        );
+        assert_eq!(
+            verifier.accept_parameters(),
+            Err(RiscZeroError::NoPendingParameters.abi_encode())
+        );
+
+        // Seals for the previous release no longer match the selector.
+        assert_eq!(
+            verifier.verify(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Err(RiscZeroError::SelectorMismatch {
+                received: v2_0::calculate_selector(),
+                expected: v1_2::calculate_selector(),
+            }
+            .abi_encode())
+        );
+    }
+
+    #[test]
+    fn cancelled_proposals_cannot_be_accepted() {
+        let vm = TestVM::default();
+        let mut verifier = deploy(&vm);
+
+        assert_eq!(
+            verifier.cancel_parameters(),
+            Err(RiscZeroError::NoPendingParameters.abi_encode())
+        );
+        verifier
+            .propose_parameters(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID)
+            .unwrap();
+        vm.set_sender(OTHER);
+        assert_eq!(verifier.cancel_parameters(), Err(unauthorized(OTHER)));
+        assert_eq!(
+            verifier.propose_parameters(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID),
+            Err(unauthorized(OTHER))
+        );
+
+        vm.set_sender(OWNER);
+        verifier.cancel_parameters().unwrap();
+        last_log::<ParametersProposalCancelled>(&vm);
+        assert_eq!(
+            verifier.get_pending_parameters(),
+            (B256::ZERO, B256::ZERO, 0)
+        );
+
+        vm.set_block_timestamp(NOW + PARAMETER_ROTATION_DELAY);
+        assert_eq!(
+            verifier.accept_parameters(),
+            Err(RiscZeroError::NoPendingParameters.abi_encode())
+        );
+        assert_eq!(verifier.get_selector(), v2_0::calculate_selector());
+    }
+
+    #[test]
+    fn a_new_proposal_restarts_the_delay() {
+        let vm = TestVM::default();
+        let mut verifier = deploy(&vm);
+
+        verifier
+            .propose_parameters(v1_2::CONTROL_ROOT, v1_2::BN254_CONTROL_ID)
+            .unwrap();
+        vm.set_block_timestamp(NOW + 1);
+        let eta = verifier
+            .propose_parameters(v2_0::CONTROL_ROOT, v2_0::BN254_CONTROL_ID)
+            .unwrap();
+        assert_eq!(eta, NOW + 1 + PARAMETER_ROTATION_DELAY);
+
+        vm.set_block_timestamp(NOW + PARAMETER_ROTATION_DELAY);
+        assert_eq!(
+            verifier.accept_parameters(),
+            Err(RiscZeroError::TimelockNotExpired { eta }.abi_encode())
+        );
+        vm.set_block_timestamp(eta);
+        verifier.accept_parameters().unwrap();
+        assert_eq!(verifier.get_selector(), v2_0::calculate_selector());
+    }
+
+    #[test]
+    fn verify_registered_checks_the_registry() {
+        let vm = TestVM::default();
+        let mut verifier = deploy(&vm);
+        let verify = |verifier: &RiscZeroVerifier| {
+            verifier.verify_registered(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST)
         };
 
-        let (claim_lo, claim_hi) = digest_utils::split_digest(claim_digest);
-        let public_signals = [
-            U256::from_be_slice(self.control_root_0.get().as_slice()),
-            U256::from_be_slice(self.control_root_1.get().as_slice()),
-            U256::from_be_slice(&claim_lo),
-            U256::from_be_slice(&claim_hi),
-            U256::from_be_slice(self.bn254_control_id.get().as_slice()),
-        ];
+        assert_eq!(
+            verify(&verifier),
+            Err(RiscZeroError::ImageNotRegistered { image_id: IMAGE_ID }.abi_encode())
+        );
+        vm.set_sender(OTHER);
+        assert_eq!(
+            verifier.register_image(IMAGE_ID, "guest".into(), 0, 0, Vec::new()),
+            Err(unauthorized(OTHER))
+        );
 
-        let verification_key = vk::get_verification_key();
-        let verified = Groth16Verifier::new().verify_proof_with_key(
-            VMType::Risc0,
-            &verification_key,
-            decoded_seal.a,
-            decoded_seal.b,
-            decoded_seal.c,
-            &public_signals,
+        vm.set_sender(OWNER);
+        verifier
+            .register_image(IMAGE_ID, "guest".into(), NOW, NOW + 10, vec![OTHER])
+            .unwrap();
+        assert_eq!(
+            verifier.get_image(IMAGE_ID),
+            Ok(("guest".into(), NOW, NOW + 10, vec![OTHER]))
+        );
+        assert_eq!(
+            verify(&verifier),
+            Err(RiscZeroError::CallerNotAllowed {
+                image_id: IMAGE_ID,
+                caller: OWNER
+            }
+            .abi_encode())
         );
+        vm.set_sender(OTHER);
+        assert_eq!(verify(&verifier), Ok(true));
+        assert!(verifier
+            .verify_registered(SEAL.to_vec(), IMAGE_ID, B256::repeat_byte(1))
+            .is_err());
+
+        vm.set_block_timestamp(NOW + 10);
+        assert_eq!(
+            verify(&verifier),
+            Err(RiscZeroError::ImageNotActive { image_id: IMAGE_ID }.abi_encode())
+        );
+        // The registry only restricts `verify_registered`.
+        assert_eq!(
+            verifier.verify(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Ok(true)
+        );
+
+        assert_eq!(verifier.remove_image(IMAGE_ID), Err(unauthorized(OTHER)));
+        vm.set_sender(OWNER);
+        verifier.remove_image(IMAGE_ID).unwrap();
+        assert_eq!(
+            verifier.get_image(IMAGE_ID),
+            Err(RiscZeroError::ImageNotRegistered { image_id: IMAGE_ID }.abi_encode())
+        );
+    }
+
+    #[test]
+    fn verify_and_record_emits_proof_verified() {
+        let vm = TestVM::default();
+        let mut verifier = deploy(&vm);
+        vm.set_sender(OTHER);
+        let logs = vm.get_emitted_logs().len();
+
+        assert!(verifier
+            .verify_and_record(SEAL.to_vec(), IMAGE_ID, B256::repeat_byte(1))
+            .is_err());
+        assert_eq!(vm.get_emitted_logs().len(), logs);
+
+        assert_eq!(
+            verifier.verify_and_record(SEAL.to_vec(), IMAGE_ID, JOURNAL_DIGEST),
+            Ok(true)
+        );
+        assert_eq!(vm.get_emitted_logs().len(), logs + 1);
+        let event = last_log::<ProofVerified>(&vm);
+        assert_eq!(event.system, VMType::Risc0 as u8);
+        assert_eq!(
+            (event.programId, event.publicInputsDigest, event.caller),
+            (IMAGE_ID, JOURNAL_DIGEST, OTHER)
+        );
+        // Only the event is recorded; the claim is not usable as an assumption.
+        let claim_digest = ReceiptClaim::ok(IMAGE_ID, JOURNAL_DIGEST).digest();
+        assert!(!verifier.is_claim_verified(claim_digest));
+    }
 
-        if !verified {
-            return Err(RiscZeroError::VERIFICATION_FAILED.abi_encode());
+    /// ABI form of `ReceiptClaim::ok(IMAGE_ID, JOURNAL_DIGEST)`, the claim `SEAL` proves.
+    fn ok_claim() -> abi::ReceiptClaim {
+        abi::ReceiptClaim {
+            preStateDigest: IMAGE_ID,
+            postStateDigest: config::system_state_zero_digest(),
+            exitCode: ExitCode {
+                system: SystemExitCode::Halted,
+                user: 0,
+            }
+            .into(),
+            input: B256::ZERO,
+            output: abi::Output {
+                journalDigest: JOURNAL_DIGEST,
+                assumptionsDigest: B256::ZERO,
+            },
         }
+    }
 
-        Ok(true)
+    #[test]
+    fn verify_claim_checks_every_field() {
+        let vm = TestVM::default();
+        let verifier = deploy(&vm);
+        let verify = |claim| verifier.verify_claim(SEAL.to_vec(), claim);
+        let failed = Err(RiscZeroError::VERIFICATION_FAILED.abi_encode());
+
+        assert_eq!(verify(ok_claim()), Ok(true));
+
+        let mut claim = ok_claim();
+        claim.preStateDigest = B256::repeat_byte(1);
+        assert_eq!(verify(claim), failed);
+        let mut claim = ok_claim();
+        claim.postStateDigest = B256::repeat_byte(1);
+        assert_eq!(verify(claim), failed);
+        let mut claim = ok_claim();
+        claim.exitCode.user = 1;
+        assert_eq!(verify(claim), failed);
+        let mut claim = ok_claim();
+        claim.input = B256::repeat_byte(1);
+        assert_eq!(verify(claim), failed);
+        let mut claim = ok_claim();
+        claim.output.journalDigest = B256::repeat_byte(1);
+        assert_eq!(verify(claim), failed);
+        let mut claim = ok_claim();
+        claim.output.assumptionsDigest = B256::repeat_byte(1);
+        assert_eq!(verify(claim), failed);
+
+        let uninitialized = RiscZeroVerifier::from(&TestVM::default());
+        assert_eq!(
+            uninitialized.verify_claim(SEAL.to_vec(), ok_claim()),
+            Err(RiscZeroError::INVALID_INITIALIZATION.abi_encode())
+        );
+    }
+
+    /// A seal of `claim_digest` under `key`, for the parameters `deploy` sets.
+    fn trapdoor_seal(key: &TrapdoorKey, claim_digest: B256) -> Vec<u8> {
+        key.seal(
+            v2_0::calculate_selector(),
+            v2_0::CONTROL_ROOT,
+            v2_0::BN254_CONTROL_ID,
+            claim_digest,
+        )
+    }
+
+    fn exit_code(system: SystemExitCode, user: u8) -> abi::ExitCode {
+        ExitCode { system, user }.into()
+    }
+
+    #[test]
+    fn verify_with_exit_code_checks_the_exit_code() {
+        let vm = TestVM::default();
+        let verifier = deploy(&vm);
+        let key = testing::use_trapdoor_key();
+        let failed = Err(RiscZeroError::VERIFICATION_FAILED.abi_encode());
+        let seal = trapdoor_seal(
+            key,
+            ReceiptClaim::halted(IMAGE_ID, JOURNAL_DIGEST, 3).digest(),
+        );
+        let verify = |exit_code| {
+            verifier.verify_with_exit_code(seal.clone(), IMAGE_ID, JOURNAL_DIGEST, exit_code)
+        };
+
+        assert_eq!(verify(exit_code(SystemExitCode::Halted, 3)), Ok(true));
+        assert_eq!(verify(exit_code(SystemExitCode::Halted, 4)), failed);
+        assert_eq!(
+            verifier.verify(seal.clone(), IMAGE_ID, JOURNAL_DIGEST),
+            failed
+        );
+        assert_eq!(
+            verify(exit_code(SystemExitCode::Paused, 3)),
+            Err(RiscZeroError::UnsupportedExitCode { system: 1 }.abi_encode())
+        );
+        assert_eq!(
+            verify(exit_code(SystemExitCode::SystemSplit, 3)),
+            Err(RiscZeroError::UnsupportedExitCode { system: 2 }.abi_encode())
+        );
+
+        // A split claim can only be checked in full.
+        let mut split = ok_claim();
+        split.postStateDigest = B256::repeat_byte(4);
+        split.exitCode = exit_code(SystemExitCode::SystemSplit, 0);
+        let split_seal =
+            trapdoor_seal(key, ReceiptClaim::try_from(split.clone()).unwrap().digest());
+        assert_eq!(verifier.verify_claim(split_seal, split), Ok(true));
+    }
+
+    #[test]
+    fn verify_paused_binds_the_post_state() {
+        let vm = TestVM::default();
+        let verifier = deploy(&vm);
+        let key = testing::use_trapdoor_key();
+        let failed = Err(RiscZeroError::VERIFICATION_FAILED.abi_encode());
+        let post_state = B256::repeat_byte(4);
+        let claim = ReceiptClaim::paused(IMAGE_ID, post_state, JOURNAL_DIGEST, 5);
+        let seal = trapdoor_seal(key, claim.digest());
+        let verify = |post_state, user| {
+            verifier.verify_paused(seal.clone(), IMAGE_ID, post_state, JOURNAL_DIGEST, user)
+        };
+
+        assert_eq!(verify(post_state, 5), Ok(true));
+        assert_eq!(verify(post_state, 6), failed);
+        assert_eq!(verify(B256::repeat_byte(5), 5), failed);
+        assert_eq!(verify(config::system_state_zero_digest(), 5), failed);
+        assert_eq!(
+            verifier.verify_with_exit_code(
+                seal.clone(),
+                IMAGE_ID,
+                JOURNAL_DIGEST,
+                exit_code(SystemExitCode::Halted, 5)
+            ),
+            failed
+        );
+    }
+
+    fn assumption(claim: B256, control_root: B256) -> abi::Assumption {
+        abi::Assumption {
+            claim,
+            controlRoot: control_root,
+        }
+    }
+
+    /// `ok_claim` conditioned on `assumptions`, with its digest.
+    fn conditional_claim(assumptions: &[abi::Assumption]) -> (abi::ReceiptClaim, B256) {
+        let assumptions: Vec<Assumption> = assumptions.iter().cloned().map(Into::into).collect();
+        let mut claim = ok_claim();
+        claim.output.assumptionsDigest = assumptions_digest(&assumptions);
+        let digest = ReceiptClaim::try_from(claim.clone()).unwrap().digest();
+        (claim, digest)
+    }
+
+    #[test]
+    fn verify_with_assumptions_accepts_an_empty_list() {
+        let vm = TestVM::default();
+        let verifier = deploy(&vm);
+
+        // No assumptions digest to zero, as in an unconditional claim.
+        assert_eq!(
+            verifier.verify_with_assumptions(SEAL.to_vec(), ok_claim(), Vec::new(), Vec::new()),
+            Ok(true)
+        );
+        // The claim's own assumptions digest is replaced.
+        let mut claim = ok_claim();
+        claim.output.assumptionsDigest = B256::repeat_byte(1);
+        assert_eq!(
+            verifier.verify_with_assumptions(SEAL.to_vec(), claim, Vec::new(), Vec::new()),
+            Ok(true)
+        );
+        assert_eq!(
+            verifier.verify_with_assumptions(
+                SEAL.to_vec(),
+                ok_claim(),
+                Vec::new(),
+                vec![Vec::new()]
+            ),
+            Err(RiscZeroError::INVALID_PROOF_DATA.abi_encode())
+        );
+    }
+
+    #[test]
+    fn verify_with_assumptions_proves_each_assumption() {
+        let vm = TestVM::default();
+        let verifier = deploy(&vm);
+        let key = testing::use_trapdoor_key();
+        let failed = Err(RiscZeroError::VERIFICATION_FAILED.abi_encode());
+        let first = ReceiptClaim::ok(B256::repeat_byte(1), B256::repeat_byte(2)).digest();
+        let second = ReceiptClaim::ok(B256::repeat_byte(3), B256::repeat_byte(4)).digest();
+        let seals = || vec![trapdoor_seal(key, first), trapdoor_seal(key, second)];
+
+        // One assumption, under the verifier's control root spelled out.
+        let one = vec![assumption(first, v2_0::CONTROL_ROOT)];
+        let (claim, digest) = conditional_claim(&one);
+        let seal = trapdoor_seal(key, digest);
+        assert_eq!(
+            verifier.verify_with_assumptions(
+                seal.clone(),
+                claim.clone(),
+                one.clone(),
+                seals()[..1].to_vec()
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            verifier.verify_with_assumptions(
+                seal.clone(),
+                claim.clone(),
+                one.clone(),
+                vec![trapdoor_seal(key, second)]
+            ),
+            failed
+        );
+        // The seal commits to the assumptions it was made with.
+        assert_eq!(
+            verifier.verify_with_assumptions(seal.clone(), claim.clone(), Vec::new(), Vec::new()),
+            failed
+        );
+
+        // Two assumptions, under the zero control root that stands for the verifier's own.
+        let two = vec![
+            assumption(first, B256::ZERO),
+            assumption(second, B256::ZERO),
+        ];
+        let (claim, digest) = conditional_claim(&two);
+        let seal = trapdoor_seal(key, digest);
+        assert_eq!(
+            verifier.verify_with_assumptions(seal.clone(), claim.clone(), two.clone(), seals()),
+            Ok(true)
+        );
+        // The list is ordered.
+        let swapped = vec![two[1].clone(), two[0].clone()];
+        assert_eq!(
+            verifier.verify_with_assumptions(
+                seal.clone(),
+                claim.clone(),
+                swapped,
+                seals().into_iter().rev().collect()
+            ),
+            failed
+        );
+        assert_eq!(
+            verifier.verify_with_assumptions(
+                seal.clone(),
+                claim.clone(),
+                one,
+                seals()[..1].to_vec()
+            ),
+            failed
+        );
+
+        let other_root = vec![
+            assumption(first, B256::ZERO),
+            assumption(second, B256::repeat_byte(5)),
+        ];
+        assert_eq!(
+            verifier.verify_with_assumptions(seal, claim, other_root, seals()),
+            Err(RiscZeroError::ControlRootMismatch {
+                received: B256::repeat_byte(5),
+                expected: v2_0::CONTROL_ROOT,
+            }
+            .abi_encode())
+        );
+    }
+
+    #[test]
+    fn verify_with_assumptions_uses_recorded_claims() {
+        let vm = TestVM::default();
+        let mut verifier = deploy(&vm);
+        let key = testing::use_trapdoor_key();
+        let resolved = ReceiptClaim::ok(B256::repeat_byte(1), B256::repeat_byte(2)).digest();
+        let assumptions = vec![assumption(resolved, B256::ZERO)];
+        let (claim, digest) = conditional_claim(&assumptions);
+        let seal = trapdoor_seal(key, digest);
+        let verify = |verifier: &RiscZeroVerifier| {
+            verifier.verify_with_assumptions(
+                seal.clone(),
+                claim.clone(),
+                assumptions.clone(),
+                vec![Vec::new()],
+            )
+        };
+
+        assert_eq!(
+            verify(&verifier),
+            Err(RiscZeroError::AssumptionNotVerified { claim: resolved }.abi_encode())
+        );
+        assert_eq!(
+            verifier.record_verified_claim(trapdoor_seal(key, digest), resolved),
+            Err(RiscZeroError::VERIFICATION_FAILED.abi_encode())
+        );
+        assert!(!verifier.is_claim_verified(resolved));
+
+        verifier
+            .record_verified_claim(trapdoor_seal(key, resolved), resolved)
+            .unwrap();
+        assert!(verifier.is_claim_verified(resolved));
+        assert_eq!(verify(&verifier), Ok(true));
+    }
+
+    #[test]
+    fn verify_journal_hashes_and_decodes_the_journal() {
+        type Journal = (sol_data::Uint<256>, sol_data::Address);
+
+        let vm = TestVM::default();
+        let verifier = deploy(&vm);
+        let key = testing::use_trapdoor_key();
+        let failed = RiscZeroError::VERIFICATION_FAILED.abi_encode();
+        let seal_journal = |journal: &[u8]| {
+            let journal_digest = B256::from_slice(&Sha256::digest(journal));
+            trapdoor_seal(key, ReceiptClaim::ok(IMAGE_ID, journal_digest).digest())
+        };
+
+        let value = (U256::from(42), OTHER);
+        let journal = Journal::abi_encode(&value);
+        let seal = seal_journal(&journal);
+        assert_eq!(
+            verifier.verify_journal(seal.clone(), IMAGE_ID, journal.clone()),
+            Ok(true)
+        );
+        assert_eq!(
+            verifier.verify_journal_decoded::<Journal>(seal.clone(), IMAGE_ID, &journal),
+            Ok(value)
+        );
+
+        let mut tampered = journal.clone();
+        tampered[31] ^= 1;
+        assert_eq!(
+            verifier.verify_journal(seal.clone(), IMAGE_ID, tampered.clone()),
+            Err(failed.clone())
+        );
+        assert_eq!(
+            verifier.verify_journal_decoded::<Journal>(seal.clone(), IMAGE_ID, &tampered),
+            Err(failed.clone())
+        );
+        assert_eq!(
+            verifier.verify_journal(seal, B256::repeat_byte(1), journal),
+            Err(failed.clone())
+        );
+
+        // A proven journal that is not an ABI-encoded `Journal`.
+        let journal = b"not an abi-encoded journal".to_vec();
+        let seal = seal_journal(&journal);
+        assert_eq!(
+            verifier.verify_journal(seal.clone(), IMAGE_ID, journal.clone()),
+            Ok(true)
+        );
+        assert_eq!(
+            verifier.verify_journal_decoded::<Journal>(seal, IMAGE_ID, &journal),
+            Err(RiscZeroError::INVALID_PROOF_DATA.abi_encode())
+        );
     }
 }
@@ -1,5 +1,5 @@
+use crate::common::{G1Point, G2Point, VerificationKey};
 use stylus_sdk::alloy_primitives::uint;
-use crate::common::{VerificationKey, G1Point, G2Point};
 
 pub mod vk {
     use super::*;
@@ -80,7 +80,7 @@ pub mod vk {
         },
     ];
 
-    pub fn get_verification_key() -> VerificationKey {
+    pub fn get_verification_key() -> VerificationKey<'static> {
         VerificationKey {
             alpha1: ALPHA1,
             beta2: BETA2,
@@ -89,4 +89,4 @@ pub mod vk {
             ic: &IC,
         }
     }
-} 
\ No newline at end of file
+}
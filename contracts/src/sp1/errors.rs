@@ -1,5 +1,5 @@
 use stylus_sdk::{
-    alloy_primitives::FixedBytes,
+    alloy_primitives::{FixedBytes, B256},
     alloy_sol_types::{sol, SolError},
 };
 
@@ -7,6 +7,9 @@ use crate::common::VerificationError;
 
 sol! {
     error WrongVerifierSelector(bytes4 received, bytes4 expected);
+    error InvalidRecursionShape();
+    error ProgramVkeyMismatch(bytes32 received, bytes32 expected);
+    error PublicValuesDigestMismatch(bytes32 received, bytes32 expected);
 }
 
 #[derive(Debug)]
@@ -16,6 +19,15 @@ pub enum Sp1Error {
         received: FixedBytes<4>,
         expected: FixedBytes<4>,
     },
+    InvalidRecursionShape,
+    ProgramVkeyMismatch {
+        received: B256,
+        expected: B256,
+    },
+    PublicValuesDigestMismatch {
+        received: B256,
+        expected: B256,
+    },
 }
 
 impl Sp1Error {
@@ -27,6 +39,19 @@ impl Sp1Error {
                 expected: *expected,
             }
             .abi_encode(),
+            Sp1Error::InvalidRecursionShape => InvalidRecursionShape {}.abi_encode(),
+            Sp1Error::ProgramVkeyMismatch { received, expected } => ProgramVkeyMismatch {
+                received: *received,
+                expected: *expected,
+            }
+            .abi_encode(),
+            Sp1Error::PublicValuesDigestMismatch { received, expected } => {
+                PublicValuesDigestMismatch {
+                    received: *received,
+                    expected: *expected,
+                }
+                .abi_encode()
+            }
         }
     }
 }
@@ -38,6 +63,11 @@ impl From<VerificationError> for Sp1Error {
 }
 
 impl Sp1Error {
-    pub const VERIFICATION_FAILED: Sp1Error = Sp1Error::Common(VerificationError::VerificationFailed);
+    pub const VERIFICATION_FAILED: Sp1Error =
+        Sp1Error::Common(VerificationError::VerificationFailed);
+    pub const INVALID_INITIALIZATION: Sp1Error =
+        Sp1Error::Common(VerificationError::InvalidInitialization);
+    pub const ALREADY_INITIALIZED: Sp1Error =
+        Sp1Error::Common(VerificationError::AlreadyInitialized);
     pub const INVALID_PROOF_DATA: Sp1Error = Sp1Error::Common(VerificationError::InvalidProofData);
-} 
\ No newline at end of file
+}
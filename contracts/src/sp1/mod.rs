@@ -1,9 +1,13 @@
 pub mod config;
 pub mod crypto;
 pub mod errors;
+#[cfg(feature = "sp1-stark")]
+pub mod stark;
 pub mod types;
 pub mod verifier;
 
 pub use errors::Sp1Error;
+#[cfg(feature = "sp1-stark")]
+pub use stark::ISp1StarkVerifier;
 pub use types::{Sp1Proof, Sp1PublicInputs};
-pub use verifier::{Sp1Verifier, ISp1Verifier}; 
\ No newline at end of file
+pub use verifier::{ISp1Verifier, Sp1Verifier};
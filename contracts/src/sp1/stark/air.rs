@@ -0,0 +1,369 @@
+//! Recursion shape: the Poseidon2 instance, FRI parameters, verifying key and chip AIRs of
+//! the SP1 compress program.
+//!
+//! SP1 proves compressed shards with a fixed set of recursion chips. Their constraints are
+//! exported from the symbolic AIR builder as a straight-line program and passed in as a
+//! blob whose hash the verifier pins on initialization. All fields are little-endian `u32` words:
+//!
+//! ```text
+//! u32 rounds_f | u32 rounds_p | rounds_f × 16 external | rounds_p internal | 16 diagonal
+//! u32 log_blowup | u32 num_queries | u32 pow_bits
+//! 8 preprocessed_commit | pc_start
+//! u32 n_public_values | u32 vk_digest_index | u32 committed_value_digest_index
+//! u32 n_fixed | n_fixed × (u32 index, value)
+//! u32 logup_batch_size
+//! u32 n_chips | n_chips × chip
+//! ```
+//!
+//! with each chip laid out as
+//!
+//! ```text
+//! u32 preprocessed_width | u32 preprocessed_log_degree | u32 main_width
+//! u32 log_quotient_degree
+//! u32 n_exprs | n_exprs × (u32 opcode, operands)
+//! u32 n_constraints | n_constraints × u32 expr
+//! u32 n_interactions | n_interactions × (u32 kind, argument_index, u32 multiplicity,
+//!                                        u32 n_values, n_values × u32 expr)
+//! ```
+//!
+//! Opcodes: `0 Const(value)`, `1 Preprocessed(next, col)`, `2 Main(next, col)`,
+//! `3 Public(index)`, `4 IsFirstRow`, `5 IsLastRow`, `6 IsTransition`, `7 Add`, `8 Sub`,
+//! `9 Mul`, `10 Neg`, where arithmetic operands index earlier expressions. Interaction
+//! kind `0` is a send and `1` a receive.
+
+use alloc::vec::Vec;
+
+use crate::sp1::stark::{
+    baby_bear::{Elem, ExtElem},
+    poseidon2::{Digest, Poseidon2, WIDTH},
+    reader::Reader,
+};
+
+pub struct FriParams {
+    pub log_blowup: usize,
+    pub num_queries: usize,
+    pub pow_bits: usize,
+}
+
+/// Where the program binding lives in the compress public values.
+pub struct PublicValuesLayout {
+    pub len: usize,
+    /// Eight elements: the program verifying key digest.
+    pub vk_digest: usize,
+    /// Thirty-two elements: the bytes of the SHA-256 digest of the public values.
+    pub committed_value_digest: usize,
+    /// Public values that must take a fixed value, such as `is_complete`.
+    pub fixed: Vec<(usize, Elem)>,
+}
+
+enum Expr {
+    Const(Elem),
+    Preprocessed(bool, usize),
+    Main(bool, usize),
+    Public(usize),
+    IsFirstRow,
+    IsLastRow,
+    IsTransition,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Neg(usize),
+}
+
+pub struct Interaction {
+    pub is_send: bool,
+    pub argument_index: Elem,
+    pub multiplicity: usize,
+    pub values: Vec<usize>,
+}
+
+pub struct Chip {
+    pub preprocessed_width: usize,
+    pub preprocessed_log_degree: usize,
+    pub main_width: usize,
+    pub log_quotient_degree: usize,
+    exprs: Vec<Expr>,
+    constraints: Vec<usize>,
+    interactions: Vec<Interaction>,
+}
+
+pub struct RecursionShape {
+    pub perm: Poseidon2,
+    pub fri: FriParams,
+    pub preprocessed_commit: Digest,
+    pub pc_start: Elem,
+    pub public_values: PublicValuesLayout,
+    pub logup_batch_size: usize,
+    pub chips: Vec<Chip>,
+}
+
+/// Lagrange selectors of the trace domain and `1 / Z_H`, at the out-of-domain point.
+pub struct Selectors {
+    pub is_first_row: ExtElem,
+    pub is_last_row: ExtElem,
+    pub is_transition: ExtElem,
+    pub inv_zeroifier: ExtElem,
+}
+
+/// Openings of one chip at `zeta` (`local`) and `zeta · g` (`next`). The permutation
+/// trace is opened per base column of its extension-field columns.
+pub struct ChipOpenings<'a> {
+    pub preprocessed: [&'a [ExtElem]; 2],
+    pub main: [&'a [ExtElem]; 2],
+    pub permutation: [&'a [ExtElem]; 2],
+    pub cumulative_sum: ExtElem,
+}
+
+impl RecursionShape {
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(bytes);
+
+        let rounds_f = r.length()?;
+        let rounds_p = r.length()?;
+        if rounds_f == 0 || rounds_f % 2 == 1 {
+            return None;
+        }
+        let mut external_constants = Vec::with_capacity(rounds_f);
+        for _ in 0..rounds_f {
+            external_constants.push(r.elems(WIDTH)?.try_into().ok()?);
+        }
+        let perm = Poseidon2 {
+            external_constants,
+            internal_constants: r.elems(rounds_p)?,
+            internal_diag: r.elems(WIDTH)?.try_into().ok()?,
+        };
+
+        let fri = FriParams {
+            log_blowup: r.usize()?,
+            num_queries: r.usize()?,
+            pow_bits: r.usize()?,
+        };
+        if fri.log_blowup == 0 || fri.num_queries == 0 || fri.pow_bits > 30 {
+            return None;
+        }
+        let preprocessed_commit = r.digest()?;
+        let pc_start = r.elem()?;
+
+        let len = r.usize()?;
+        let vk_digest = r.usize()?;
+        let committed_value_digest = r.usize()?;
+        let n_fixed = r.length()?;
+        let mut fixed = Vec::with_capacity(n_fixed);
+        for _ in 0..n_fixed {
+            fixed.push((Some(r.usize()?).filter(|i| *i < len)?, r.elem()?));
+        }
+        if vk_digest.checked_add(8)? > len || committed_value_digest.checked_add(32)? > len {
+            return None;
+        }
+        let public_values = PublicValuesLayout {
+            len,
+            vk_digest,
+            committed_value_digest,
+            fixed,
+        };
+
+        let logup_batch_size = r.usize()?;
+        if logup_batch_size == 0 {
+            return None;
+        }
+        let n_chips = r.length()?;
+        let mut chips = Vec::with_capacity(n_chips);
+        for _ in 0..n_chips {
+            let chip = Chip::read(&mut r, len)?;
+            if chip.log_quotient_degree > fri.log_blowup {
+                return None;
+            }
+            chips.push(chip);
+        }
+        if chips.is_empty() || !r.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            perm,
+            fri,
+            preprocessed_commit,
+            pc_start,
+            public_values,
+            logup_batch_size,
+            chips,
+        })
+    }
+}
+
+impl Chip {
+    fn read(r: &mut Reader, num_public_values: usize) -> Option<Self> {
+        let preprocessed_width = r.usize()?;
+        let preprocessed_log_degree = r.usize()?;
+        let main_width = r.usize()?;
+        let log_quotient_degree = r.usize()?;
+        if preprocessed_log_degree > 27 || log_quotient_degree > 27 {
+            return None;
+        }
+
+        let n_exprs = r.length()?;
+        let mut exprs = Vec::with_capacity(n_exprs);
+        for i in 0..n_exprs {
+            let operand = |x: usize| (x < i).then_some(x);
+            let column = |next: usize, col: usize, width: usize| {
+                (next < 2 && col < width).then_some((next == 1, col))
+            };
+            let expr = match r.u32()? {
+                0 => Expr::Const(r.elem()?),
+                1 => {
+                    let (next, col) = column(r.usize()?, r.usize()?, preprocessed_width)?;
+                    Expr::Preprocessed(next, col)
+                }
+                2 => {
+                    let (next, col) = column(r.usize()?, r.usize()?, main_width)?;
+                    Expr::Main(next, col)
+                }
+                3 => Expr::Public(Some(r.usize()?).filter(|x| *x < num_public_values)?),
+                4 => Expr::IsFirstRow,
+                5 => Expr::IsLastRow,
+                6 => Expr::IsTransition,
+                7 => Expr::Add(operand(r.usize()?)?, operand(r.usize()?)?),
+                8 => Expr::Sub(operand(r.usize()?)?, operand(r.usize()?)?),
+                9 => Expr::Mul(operand(r.usize()?)?, operand(r.usize()?)?),
+                10 => Expr::Neg(operand(r.usize()?)?),
+                _ => return None,
+            };
+            exprs.push(expr);
+        }
+        let expr = |x: usize| (x < n_exprs).then_some(x);
+
+        let n_constraints = r.length()?;
+        let mut constraints = Vec::with_capacity(n_constraints);
+        for _ in 0..n_constraints {
+            constraints.push(expr(r.usize()?)?);
+        }
+
+        let n_interactions = r.length()?;
+        let mut interactions = Vec::with_capacity(n_interactions);
+        for _ in 0..n_interactions {
+            let is_send = match r.u32()? {
+                0 => true,
+                1 => false,
+                _ => return None,
+            };
+            let argument_index = r.elem()?;
+            let multiplicity = expr(r.usize()?)?;
+            let n_values = r.length()?;
+            let mut values = Vec::with_capacity(n_values);
+            for _ in 0..n_values {
+                values.push(expr(r.usize()?)?);
+            }
+            interactions.push(Interaction {
+                is_send,
+                argument_index,
+                multiplicity,
+                values,
+            });
+        }
+
+        Some(Self {
+            preprocessed_width,
+            preprocessed_log_degree,
+            main_width,
+            log_quotient_degree,
+            exprs,
+            constraints,
+            interactions,
+        })
+    }
+
+    /// Extension-field columns of the LogUp trace: one per batch of interactions and the
+    /// running sum.
+    pub fn permutation_width(&self, batch_size: usize) -> usize {
+        self.interactions.len().div_ceil(batch_size) + 1
+    }
+
+    /// Folds the AIR and LogUp constraints with powers of `alpha`.
+    pub fn eval_constraints(
+        &self,
+        openings: &ChipOpenings,
+        public_values: &[Elem],
+        selectors: &Selectors,
+        alpha: ExtElem,
+        challenges: [ExtElem; 2],
+        batch_size: usize,
+    ) -> Option<ExtElem> {
+        let mut vars: Vec<ExtElem> = Vec::with_capacity(self.exprs.len());
+        for expr in &self.exprs {
+            let value = match *expr {
+                Expr::Const(x) => ExtElem::from_base(x),
+                Expr::Preprocessed(next, col) => openings.preprocessed[next as usize][col],
+                Expr::Main(next, col) => openings.main[next as usize][col],
+                Expr::Public(i) => ExtElem::from_base(public_values[i]),
+                Expr::IsFirstRow => selectors.is_first_row,
+                Expr::IsLastRow => selectors.is_last_row,
+                Expr::IsTransition => selectors.is_transition,
+                Expr::Add(a, b) => vars[a] + vars[b],
+                Expr::Sub(a, b) => vars[a] - vars[b],
+                Expr::Mul(a, b) => vars[a] * vars[b],
+                Expr::Neg(a) => -vars[a],
+            };
+            vars.push(value);
+        }
+
+        let mut acc = ExtElem::ZERO;
+        let mut assert_zero = |x: ExtElem| acc = acc * alpha + x;
+        for constraint in &self.constraints {
+            assert_zero(vars[*constraint]);
+        }
+
+        // LogUp: each batch column holds Σ ±m_i / (α + Σ_j β^j · v_ij), with the
+        // argument index as v_i0, and the last column accumulates the batches.
+        let [perm_alpha, beta] = challenges;
+        let width = self.permutation_width(batch_size);
+        let column = |row: usize, col: usize| -> Option<ExtElem> {
+            let base = openings.permutation[row].get(4 * col..4 * col + 4)?;
+            Some(
+                base.iter()
+                    .enumerate()
+                    .fold(ExtElem::ZERO, |acc, (i, x)| acc + ExtElem::monomial(i) * *x),
+            )
+        };
+        let mut batch_sum = [ExtElem::ZERO; 2];
+        for (col, batch) in self.interactions.chunks(batch_size).enumerate() {
+            let denominators: Vec<ExtElem> = batch
+                .iter()
+                .map(|interaction| {
+                    let mut beta_pow = ExtElem::ONE;
+                    let mut rlc = perm_alpha + ExtElem::from_base(interaction.argument_index);
+                    for value in &interaction.values {
+                        beta_pow *= beta;
+                        rlc += beta_pow * vars[*value];
+                    }
+                    rlc
+                })
+                .collect();
+            let product = denominators.iter().fold(ExtElem::ONE, |acc, d| acc * *d);
+            let mut numerator = ExtElem::ZERO;
+            for (i, interaction) in batch.iter().enumerate() {
+                let others = denominators
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .fold(ExtElem::ONE, |acc, (_, d)| acc * *d);
+                let multiplicity = vars[interaction.multiplicity] * others;
+                if interaction.is_send {
+                    numerator += multiplicity;
+                } else {
+                    numerator -= multiplicity;
+                }
+            }
+            let local = column(0, col)?;
+            assert_zero(local * product - numerator);
+            batch_sum[0] += local;
+            batch_sum[1] += column(1, col)?;
+        }
+        let phi_local = column(0, width - 1)?;
+        let phi_next = column(1, width - 1)?;
+        assert_zero(selectors.is_first_row * (phi_local - batch_sum[0]));
+        assert_zero(selectors.is_transition * (phi_next - phi_local - batch_sum[1]));
+        assert_zero(selectors.is_last_row * (phi_local - openings.cumulative_sum));
+
+        Some(acc)
+    }
+}
@@ -0,0 +1,5 @@
+//! BabyBear and the Plonky3 quartic extension `F_p[X]/(X^4 - 11)`.
+//!
+//! Proofs and shapes carry canonical values.
+
+pub use crate::common::stark::{BabyBear as Elem, BabyBearExt4 as ExtElem};
@@ -0,0 +1,79 @@
+use alloc::vec::Vec;
+
+use crate::sp1::stark::{
+    baby_bear::{Elem, ExtElem},
+    poseidon2::{Digest, Poseidon2, RATE, WIDTH},
+};
+
+/// Plonky3 duplex challenger: observed elements overwrite the rate, samples are popped
+/// from the back of the squeezed rate.
+pub struct Challenger<'a> {
+    perm: &'a Poseidon2,
+    state: [Elem; WIDTH],
+    input: Vec<Elem>,
+    output: Vec<Elem>,
+}
+
+impl<'a> Challenger<'a> {
+    pub fn new(perm: &'a Poseidon2) -> Self {
+        Self {
+            perm,
+            state: [Elem::ZERO; WIDTH],
+            input: Vec::with_capacity(RATE),
+            output: Vec::with_capacity(RATE),
+        }
+    }
+
+    fn duplexing(&mut self) {
+        for (cell, elem) in self.state.iter_mut().zip(self.input.drain(..)) {
+            *cell = elem;
+        }
+        self.perm.permute(&mut self.state);
+        self.output.clear();
+        self.output.extend_from_slice(&self.state[..RATE]);
+    }
+
+    pub fn observe(&mut self, elem: Elem) {
+        self.output.clear();
+        self.input.push(elem);
+        if self.input.len() == RATE {
+            self.duplexing();
+        }
+    }
+
+    pub fn observe_slice(&mut self, elems: &[Elem]) {
+        for elem in elems {
+            self.observe(*elem);
+        }
+    }
+
+    pub fn observe_digest(&mut self, digest: &Digest) {
+        self.observe_slice(digest);
+    }
+
+    pub fn observe_ext(&mut self, elem: &ExtElem) {
+        self.observe_slice(&elem.0);
+    }
+
+    pub fn sample(&mut self) -> Elem {
+        if !self.input.is_empty() || self.output.is_empty() {
+            self.duplexing();
+        }
+        // The output is refilled above whenever it runs empty.
+        self.output.pop().unwrap_or_default()
+    }
+
+    pub fn sample_ext(&mut self) -> ExtElem {
+        ExtElem::new([self.sample(), self.sample(), self.sample(), self.sample()])
+    }
+
+    pub fn sample_bits(&mut self, bits: usize) -> usize {
+        self.sample().as_canonical_u32() as usize & ((1 << bits) - 1)
+    }
+
+    /// Observes the grinding witness and checks that the next `bits` sampled bits are zero.
+    pub fn check_witness(&mut self, bits: usize, witness: Elem) -> bool {
+        self.observe(witness);
+        self.sample_bits(bits) == 0
+    }
+}
@@ -0,0 +1,91 @@
+use alloc::{vec, vec::Vec};
+
+use crate::common::stark::{
+    fri::{verify_query, CosetOrder},
+    TwoAdicField,
+};
+use crate::sp1::stark::{
+    air::FriParams,
+    baby_bear::{Elem, ExtElem},
+    challenger::Challenger,
+    mmcs,
+    poseidon2::Poseidon2,
+    reader::Reader,
+};
+
+/// Plonky3 two-adic FRI with arity 2 and a constant final polynomial.
+///
+/// The proof is read as
+///
+/// ```text
+/// u32 n_commits | n_commits × 8 commit | 4 final_poly | pow_witness
+/// num_queries × (input openings | n_commits × (4 sibling | path))
+/// ```
+///
+/// `open_input` reads and checks the input openings of a query at the given index and
+/// returns the reduced openings per log height, tallest first. `log_max_height` is the
+/// height of the tallest committed LDE.
+pub fn verify<F>(
+    perm: &Poseidon2,
+    params: &FriParams,
+    challenger: &mut Challenger,
+    proof: &mut Reader,
+    log_max_height: usize,
+    mut open_input: F,
+) -> Option<()>
+where
+    F: FnMut(usize, &mut Reader) -> Option<Vec<(usize, ExtElem)>>,
+{
+    let n_commits = proof.length()?;
+    if n_commits + params.log_blowup != log_max_height {
+        return None;
+    }
+    let commits = proof.digests(n_commits)?;
+    let betas: Vec<ExtElem> = commits
+        .iter()
+        .map(|commit| {
+            challenger.observe_digest(commit);
+            challenger.sample_ext()
+        })
+        .collect();
+    let final_poly = proof.ext()?;
+    challenger.observe_ext(&final_poly);
+    if !challenger.check_witness(params.pow_bits, proof.elem()?) {
+        return None;
+    }
+
+    for _ in 0..params.num_queries {
+        let index = challenger.sample_bits(log_max_height);
+        let reduced_openings = open_input(index, proof)?;
+        let mut reduced_openings = reduced_openings.into_iter().peekable();
+
+        let (_, folded) = verify_query(
+            CosetOrder::BitReversed,
+            1,
+            index,
+            log_max_height,
+            &betas,
+            ExtElem::ZERO,
+            Elem::two_adic_generator,
+            |round, pair, position, mut folded| {
+                let log_folded_height = log_max_height - round - 1;
+                if let Some((_, ro)) =
+                    reduced_openings.next_if(|(h, _)| *h == log_folded_height + 1)
+                {
+                    folded += ro;
+                }
+                let sibling = proof.ext()?;
+                let mut evals = vec![folded; 2];
+                evals[position ^ 1] = sibling;
+                let row: Vec<Elem> = evals.iter().flat_map(|e| e.0).collect();
+                let commit = &commits[round];
+                mmcs::verify_batch(perm, commit, &[log_folded_height], pair, &[row], proof)?;
+                Some(evals)
+            },
+        )?;
+        if reduced_openings.next().is_some() || folded != final_poly {
+            return None;
+        }
+    }
+    Some(())
+}
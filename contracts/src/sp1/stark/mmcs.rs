@@ -0,0 +1,47 @@
+use alloc::vec::Vec;
+
+use crate::common::stark::{merkle::hash_with_sibling, Compress};
+use crate::sp1::stark::{
+    baby_bear::Elem,
+    poseidon2::{Digest, Poseidon2},
+    reader::Reader,
+};
+
+/// Verifies one opening of a Plonky3 Merkle commitment to matrices of mixed heights.
+///
+/// `log_heights` and `rows` are given per matrix. Leaves hash the rows of the tallest
+/// matrices; rows of shorter matrices are hashed and compressed into the path at the
+/// layer of their height. The path (one sibling per layer) is read from `proof`.
+pub fn verify_batch(
+    perm: &Poseidon2,
+    root: &Digest,
+    log_heights: &[usize],
+    mut index: usize,
+    rows: &[Vec<Elem>],
+    proof: &mut Reader,
+) -> Option<()> {
+    let log_max_height = *log_heights.iter().max()?;
+    if log_heights.len() != rows.len() || index >> log_max_height != 0 {
+        return None;
+    }
+    let hash_rows = |log_height: usize| {
+        perm.hash_elems(
+            log_heights
+                .iter()
+                .zip(rows)
+                .filter(|(h, _)| **h == log_height)
+                .flat_map(|(_, row)| row.iter().copied()),
+        )
+    };
+
+    let mut node = hash_rows(log_max_height);
+    for log_height in (0..log_max_height).rev() {
+        let sibling = proof.digest()?;
+        node = hash_with_sibling(perm, &node, &sibling, index);
+        index >>= 1;
+        if log_heights.contains(&log_height) {
+            node = perm.compress(&node, &hash_rows(log_height));
+        }
+    }
+    (node == *root).then_some(())
+}
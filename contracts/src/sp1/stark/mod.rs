@@ -0,0 +1,11 @@
+pub mod air;
+pub mod baby_bear;
+pub mod challenger;
+pub mod fri;
+pub mod mmcs;
+pub mod poseidon2;
+pub mod proof;
+pub mod reader;
+pub mod verifier;
+
+pub use verifier::{verify_compressed, ISp1StarkVerifier};
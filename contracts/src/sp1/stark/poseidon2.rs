@@ -0,0 +1,125 @@
+//! Poseidon2 over BabyBear with a 16-cell state, as used by the SP1 recursion config for
+//! Merkle trees and the Fiat-Shamir challenger.
+//!
+//! The round constants are read from the recursion shape so that they are pinned together
+//! with the rest of the verifying key.
+
+use alloc::vec::Vec;
+
+use crate::common::stark::Compress;
+use crate::sp1::stark::baby_bear::Elem;
+
+pub const WIDTH: usize = 16;
+pub const RATE: usize = 8;
+pub const DIGEST_ELEMS: usize = 8;
+
+pub type Digest = [Elem; DIGEST_ELEMS];
+
+pub struct Poseidon2 {
+    /// One row per full round; the first half runs before the partial rounds.
+    pub external_constants: Vec<[Elem; WIDTH]>,
+    pub internal_constants: Vec<Elem>,
+    /// Diagonal of `M_I - 1`.
+    pub internal_diag: [Elem; WIDTH],
+}
+
+fn sbox(x: Elem) -> Elem {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    x4 * x2 * x
+}
+
+/// `M4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]`.
+fn multiply_by_4x4(x: [Elem; 4]) -> [Elem; 4] {
+    let two = Elem::new(2);
+    let four = Elem::new(4);
+    let t0 = x[0] + x[1];
+    let t1 = x[2] + x[3];
+    let t2 = two * x[1] + t1;
+    let t3 = two * x[3] + t0;
+    let t4 = four * t1 + t3;
+    let t5 = four * t0 + t2;
+    [t3 + t5, t5, t2 + t4, t4]
+}
+
+fn external_linear_layer(state: &mut [Elem; WIDTH]) {
+    let mut sums = [Elem::ZERO; 4];
+    for chunk in state.chunks_exact_mut(4) {
+        let out = multiply_by_4x4([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        for ((cell, sum), value) in chunk.iter_mut().zip(sums.iter_mut()).zip(out) {
+            *cell = value;
+            *sum += value;
+        }
+    }
+    for (i, cell) in state.iter_mut().enumerate() {
+        *cell += sums[i % 4];
+    }
+}
+
+impl Poseidon2 {
+    fn internal_linear_layer(&self, state: &mut [Elem; WIDTH]) {
+        let sum = state.iter().fold(Elem::ZERO, |acc, x| acc + *x);
+        for (cell, diag) in state.iter_mut().zip(self.internal_diag) {
+            *cell = sum + diag * *cell;
+        }
+    }
+
+    fn full_round(&self, state: &mut [Elem; WIDTH], constants: &[Elem; WIDTH]) {
+        for (cell, constant) in state.iter_mut().zip(constants) {
+            *cell = sbox(*cell + *constant);
+        }
+        external_linear_layer(state);
+    }
+
+    pub fn permute(&self, state: &mut [Elem; WIDTH]) {
+        let half = self.external_constants.len() / 2;
+        external_linear_layer(state);
+        for constants in &self.external_constants[..half] {
+            self.full_round(state, constants);
+        }
+        for constant in &self.internal_constants {
+            state[0] = sbox(state[0] + *constant);
+            self.internal_linear_layer(state);
+        }
+        for constants in &self.external_constants[half..] {
+            self.full_round(state, constants);
+        }
+    }
+
+    /// Padding-free sponge: each block of `RATE` elements overwrites the rate.
+    pub fn hash_elems<I: IntoIterator<Item = Elem>>(&self, elems: I) -> Digest {
+        let mut state = [Elem::ZERO; WIDTH];
+        let mut absorbed = 0;
+        for elem in elems {
+            state[absorbed] = elem;
+            absorbed += 1;
+            if absorbed == RATE {
+                self.permute(&mut state);
+                absorbed = 0;
+            }
+        }
+        if absorbed != 0 {
+            self.permute(&mut state);
+        }
+        to_digest(&state)
+    }
+}
+
+impl Compress for Poseidon2 {
+    type Digest = Digest;
+
+    /// Two-to-one compression by truncated permutation.
+    fn compress(&self, left: &Digest, right: &Digest) -> Digest {
+        let mut state = [Elem::ZERO; WIDTH];
+        state[..DIGEST_ELEMS].copy_from_slice(left);
+        state[DIGEST_ELEMS..].copy_from_slice(right);
+        self.permute(&mut state);
+        to_digest(&state)
+    }
+}
+
+fn to_digest(state: &[Elem; WIDTH]) -> Digest {
+    let mut digest = [Elem::ZERO; DIGEST_ELEMS];
+    digest.copy_from_slice(&state[..DIGEST_ELEMS]);
+    digest
+}
@@ -0,0 +1,91 @@
+use alloc::vec::Vec;
+
+use crate::sp1::stark::{
+    air::RecursionShape,
+    baby_bear::{Elem, ExtElem},
+    poseidon2::Digest,
+    reader::Reader,
+};
+
+/// Opened values of one chip, at `zeta` (`[0]`) and `zeta · g` (`[1]`).
+pub struct ChipValues {
+    pub log_degree: usize,
+    pub cumulative_sum: ExtElem,
+    pub preprocessed: [Vec<ExtElem>; 2],
+    pub main: [Vec<ExtElem>; 2],
+    /// Base columns of the extension-field permutation trace.
+    pub permutation: [Vec<ExtElem>; 2],
+    /// Base columns of each quotient chunk, at `zeta` only.
+    pub quotient: Vec<Vec<ExtElem>>,
+}
+
+/// A compressed shard proof, up to its FRI opening proof.
+///
+/// ```text
+/// 8 main_commit | 8 permutation_commit | 8 quotient_commit | n_public_values
+/// n_chips × (u32 log_degree | 4 cumulative_sum)
+/// n_chips × (preprocessed local, next | main local, next | permutation local, next
+///            | 2^log_quotient_degree × 4 quotient)
+/// fri proof
+/// ```
+///
+/// Widths come from the recursion shape; opened values are extension elements of four
+/// words each.
+pub struct ShardProof<'a> {
+    pub main_commit: Digest,
+    pub permutation_commit: Digest,
+    pub quotient_commit: Digest,
+    pub public_values: Vec<Elem>,
+    pub chips: Vec<ChipValues>,
+    /// The remaining words: the FRI proof.
+    pub opening_proof: Reader<'a>,
+}
+
+impl<'a> ShardProof<'a> {
+    pub fn from_bytes(shape: &RecursionShape, bytes: &'a [u8]) -> Option<Self> {
+        let mut r = Reader::new(bytes);
+        let main_commit = r.digest()?;
+        let permutation_commit = r.digest()?;
+        let quotient_commit = r.digest()?;
+        let public_values = r.elems(shape.public_values.len)?;
+
+        let mut degrees = Vec::with_capacity(shape.chips.len());
+        for _ in &shape.chips {
+            let log_degree = r.usize()?;
+            if log_degree == 0 || log_degree + shape.fri.log_blowup > 27 {
+                return None;
+            }
+            degrees.push((log_degree, r.ext()?));
+        }
+
+        let mut chips = Vec::with_capacity(shape.chips.len());
+        for (chip, (log_degree, cumulative_sum)) in shape.chips.iter().zip(degrees) {
+            let mut pair = |width: usize| -> Option<[Vec<ExtElem>; 2]> {
+                Some([r.exts(width)?, r.exts(width)?])
+            };
+            let preprocessed = pair(chip.preprocessed_width)?;
+            let main = pair(chip.main_width)?;
+            let permutation = pair(4 * chip.permutation_width(shape.logup_batch_size))?;
+            let quotient = (0..1usize << chip.log_quotient_degree)
+                .map(|_| r.exts(4))
+                .collect::<Option<_>>()?;
+            chips.push(ChipValues {
+                log_degree,
+                cumulative_sum,
+                preprocessed,
+                main,
+                permutation,
+                quotient,
+            });
+        }
+
+        Some(Self {
+            main_commit,
+            permutation_commit,
+            quotient_commit,
+            public_values,
+            chips,
+            opening_proof: r,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use alloc::vec::Vec;
+
+use crate::sp1::stark::{
+    baby_bear::{Elem, ExtElem},
+    poseidon2::{Digest, DIGEST_ELEMS},
+};
+
+/// Cursor over little-endian `u32` words. Field elements must be canonical.
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn u32(&mut self) -> Option<u32> {
+        if self.bytes.len() < 4 {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(4);
+        self.bytes = tail;
+        Some(u32::from_le_bytes(head.try_into().ok()?))
+    }
+
+    pub fn usize(&mut self) -> Option<usize> {
+        self.u32().map(|x| x as usize)
+    }
+
+    /// Reads a length prefix, bounded by the words left so that a corrupted prefix
+    /// cannot trigger a large allocation.
+    pub fn length(&mut self) -> Option<usize> {
+        let n = self.usize()?;
+        (n <= self.bytes.len() / 4).then_some(n)
+    }
+
+    pub fn elem(&mut self) -> Option<Elem> {
+        Elem::from_canonical_u32(self.u32()?)
+    }
+
+    pub fn elems(&mut self, n: usize) -> Option<Vec<Elem>> {
+        if n > self.bytes.len() / 4 {
+            return None;
+        }
+        (0..n).map(|_| self.elem()).collect()
+    }
+
+    pub fn ext(&mut self) -> Option<ExtElem> {
+        Some(ExtElem::new([
+            self.elem()?,
+            self.elem()?,
+            self.elem()?,
+            self.elem()?,
+        ]))
+    }
+
+    pub fn exts(&mut self, n: usize) -> Option<Vec<ExtElem>> {
+        if n > self.bytes.len() / 16 {
+            return None;
+        }
+        (0..n).map(|_| self.ext()).collect()
+    }
+
+    pub fn digest(&mut self) -> Option<Digest> {
+        let mut digest = [Elem::ZERO; DIGEST_ELEMS];
+        for elem in digest.iter_mut() {
+            *elem = self.elem()?;
+        }
+        Some(digest)
+    }
+
+    pub fn digests(&mut self, n: usize) -> Option<Vec<Digest>> {
+        if n > self.bytes.len() / (4 * DIGEST_ELEMS) {
+            return None;
+        }
+        (0..n).map(|_| self.digest()).collect()
+    }
+}
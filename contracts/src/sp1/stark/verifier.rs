@@ -0,0 +1,524 @@
+use alloc::{vec, vec::Vec};
+use sha2::{Digest as _, Sha256};
+use stylus_sdk::{
+    alloy_primitives::{B256, U256},
+    crypto::keccak,
+    prelude::*,
+};
+
+use crate::common::stark::{fri::reverse_bits, Field, TwoAdicField};
+use crate::sp1::{
+    errors::Sp1Error,
+    stark::{
+        air::{ChipOpenings, RecursionShape, Selectors},
+        baby_bear::{Elem, ExtElem},
+        challenger::Challenger,
+        fri, mmcs,
+        poseidon2::Digest,
+        proof::ShardProof,
+        reader::Reader,
+    },
+    verifier::Sp1Verifier,
+};
+
+pub trait ISp1StarkVerifier {
+    type Error;
+
+    /// Pins the recursion shape of the SP1 release to accept (see [`RecursionShape`]);
+    /// owner-only and callable once.
+    fn initialize_recursion_shape(&mut self, shape: Vec<u8>) -> Result<(), Self::Error>;
+
+    fn verify_compressed_proof(
+        &self,
+        program_vkey: B256,
+        public_values: Vec<u8>,
+        proof_bytes: Vec<u8>,
+        shape: Vec<u8>,
+    ) -> Result<(), Self::Error>;
+
+    fn recursion_shape_hash(&self) -> B256;
+}
+
+#[public]
+impl ISp1StarkVerifier for Sp1Verifier {
+    type Error = Vec<u8>;
+
+    fn initialize_recursion_shape(&mut self, shape: Vec<u8>) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        if self.recursion_shape_hash.get() != B256::ZERO {
+            return Err(Sp1Error::ALREADY_INITIALIZED.abi_encode());
+        }
+        if RecursionShape::from_bytes(&shape).is_none() {
+            return Err(Sp1Error::InvalidRecursionShape.abi_encode());
+        }
+
+        self.recursion_shape_hash.set(keccak(&shape));
+
+        Ok(())
+    }
+
+    fn verify_compressed_proof(
+        &self,
+        program_vkey: B256,
+        public_values: Vec<u8>,
+        proof_bytes: Vec<u8>,
+        shape: Vec<u8>,
+    ) -> Result<(), Self::Error> {
+        let shape_hash = self.recursion_shape_hash.get();
+        if shape_hash == B256::ZERO {
+            return Err(Sp1Error::INVALID_INITIALIZATION.abi_encode());
+        }
+        if keccak(&shape) != shape_hash {
+            return Err(Sp1Error::InvalidRecursionShape.abi_encode());
+        }
+        let shape = RecursionShape::from_bytes(&shape)
+            .ok_or_else(|| Sp1Error::InvalidRecursionShape.abi_encode())?;
+
+        verify_compressed(&shape, &proof_bytes, program_vkey, &public_values)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn recursion_shape_hash(&self) -> B256 {
+        self.recursion_shape_hash.get()
+    }
+}
+
+/// Verifies a compressed shard proof and binds its public values to `program_vkey` and
+/// the SHA-256 digest of `public_values`.
+pub fn verify_compressed(
+    shape: &RecursionShape,
+    proof_bytes: &[u8],
+    program_vkey: B256,
+    public_values: &[u8],
+) -> Result<(), Sp1Error> {
+    let proof = ShardProof::from_bytes(shape, proof_bytes).ok_or(Sp1Error::INVALID_PROOF_DATA)?;
+    let layout = &shape.public_values;
+    let pv = &proof.public_values;
+
+    let vk_digest = &pv[layout.vk_digest..layout.vk_digest + 8];
+    let expected = vk_digest.iter().fold(U256::ZERO, |acc, word| {
+        (acc << 31) + U256::from(word.as_canonical_u32())
+    });
+    if U256::from_be_bytes(program_vkey.0) != expected {
+        return Err(Sp1Error::ProgramVkeyMismatch {
+            received: program_vkey,
+            expected: B256::from(expected.to_be_bytes::<32>()),
+        });
+    }
+
+    let mut committed = [0u8; 32];
+    for (byte, elem) in committed
+        .iter_mut()
+        .zip(&pv[layout.committed_value_digest..])
+    {
+        *byte = u8::try_from(elem.as_canonical_u32()).map_err(|_| Sp1Error::INVALID_PROOF_DATA)?;
+    }
+    let digest = B256::from_slice(&Sha256::digest(public_values));
+    if digest.0 != committed {
+        return Err(Sp1Error::PublicValuesDigestMismatch {
+            received: digest,
+            expected: B256::from(committed),
+        });
+    }
+
+    if layout.fixed.iter().any(|(i, value)| pv[*i] != *value) {
+        return Err(Sp1Error::VERIFICATION_FAILED);
+    }
+
+    verify_shard(shape, proof).ok_or(Sp1Error::VERIFICATION_FAILED)
+}
+
+/// A committed matrix with its opening points and claimed values.
+struct Matrix<'a> {
+    log_height: usize,
+    width: usize,
+    openings: Vec<(ExtElem, &'a [ExtElem])>,
+}
+
+impl<'a> Matrix<'a> {
+    /// A trace matrix opened at `zeta` and `zeta · g`.
+    fn trace(log_height: usize, points: [ExtElem; 2], opened: &'a [Vec<ExtElem>; 2]) -> Self {
+        Self {
+            log_height,
+            width: opened[0].len(),
+            openings: vec![(points[0], &opened[0][..]), (points[1], &opened[1][..])],
+        }
+    }
+}
+
+struct Round<'a> {
+    commit: Digest,
+    matrices: Vec<Matrix<'a>>,
+}
+
+fn verify_shard(shape: &RecursionShape, proof: ShardProof) -> Option<()> {
+    let ShardProof {
+        main_commit,
+        permutation_commit,
+        quotient_commit,
+        public_values,
+        chips,
+        mut opening_proof,
+    } = proof;
+    let log_blowup = shape.fri.log_blowup;
+    let mut challenger = Challenger::new(&shape.perm);
+    challenger.observe_digest(&shape.preprocessed_commit);
+    challenger.observe(shape.pc_start);
+    challenger.observe_digest(&main_commit);
+    challenger.observe_slice(&public_values);
+    for chip in &chips {
+        challenger.observe(Elem::new(chip.log_degree as u32));
+    }
+
+    let challenges = [challenger.sample_ext(), challenger.sample_ext()];
+    challenger.observe_digest(&permutation_commit);
+    for chip in &chips {
+        challenger.observe_ext(&chip.cumulative_sum);
+    }
+    let alpha = challenger.sample_ext();
+    challenger.observe_digest(&quotient_commit);
+    let zeta = challenger.sample_ext();
+
+    let mut preprocessed = Vec::new();
+    let mut main = Vec::new();
+    let mut permutation = Vec::new();
+    let mut quotient = Vec::new();
+    let mut cumulative_sum = ExtElem::ZERO;
+    for (chip, values) in shape.chips.iter().zip(&chips) {
+        let log_n = values.log_degree;
+        let g = Elem::two_adic_generator(log_n);
+        let zeta_next = zeta * g;
+        let log_height = log_n + log_blowup;
+        let points = [zeta, zeta_next];
+        if chip.preprocessed_width > 0 {
+            if log_n != chip.preprocessed_log_degree {
+                return None;
+            }
+            preprocessed.push(Matrix::trace(log_height, points, &values.preprocessed));
+        }
+        main.push(Matrix::trace(log_height, points, &values.main));
+        permutation.push(Matrix::trace(log_height, points, &values.permutation));
+
+        // Recombine the quotient from its chunks over the cosets `GENERATOR · h^i · H`.
+        let log_chunks = chip.log_quotient_degree;
+        let h = Elem::two_adic_generator(log_n + log_chunks);
+        let shifts: Vec<Elem> = (0..1 << log_chunks)
+            .map(|i| Elem::GENERATOR * h.pow(i))
+            .collect();
+        let vanishing = |x: ExtElem, shift: Elem| (x * shift.inv()).pow(1 << log_n) - ExtElem::ONE;
+        let mut quotient_at_zeta = ExtElem::ZERO;
+        for (i, chunk) in values.quotient.iter().enumerate() {
+            let mut zp = ExtElem::ONE;
+            let first_point = ExtElem::from_base(shifts[i]);
+            for (j, shift) in shifts.iter().enumerate() {
+                if j != i {
+                    zp *= vanishing(zeta, *shift) * vanishing(first_point, *shift).inv();
+                }
+            }
+            let chunk_at_zeta = chunk
+                .iter()
+                .enumerate()
+                .fold(ExtElem::ZERO, |acc, (e, x)| acc + ExtElem::monomial(e) * *x);
+            quotient_at_zeta += zp * chunk_at_zeta;
+            quotient.push(Matrix {
+                log_height,
+                width: 4,
+                openings: vec![(zeta, &chunk[..])],
+            });
+        }
+
+        let z_h = zeta.pow(1 << log_n) - ExtElem::ONE;
+        let g_inv = ExtElem::from_base(g.inv());
+        let selectors = Selectors {
+            is_first_row: z_h * (zeta - ExtElem::ONE).inv(),
+            is_last_row: z_h * (zeta - g_inv).inv(),
+            is_transition: zeta - g_inv,
+            inv_zeroifier: z_h.inv(),
+        };
+        let openings = ChipOpenings {
+            preprocessed: [&values.preprocessed[0], &values.preprocessed[1]],
+            main: [&values.main[0], &values.main[1]],
+            permutation: [&values.permutation[0], &values.permutation[1]],
+            cumulative_sum: values.cumulative_sum,
+        };
+        let folded = chip.eval_constraints(
+            &openings,
+            &public_values,
+            &selectors,
+            alpha,
+            challenges,
+            shape.logup_batch_size,
+        )?;
+        if folded * selectors.inv_zeroifier != quotient_at_zeta {
+            return None;
+        }
+        cumulative_sum += values.cumulative_sum;
+    }
+    if cumulative_sum != ExtElem::ZERO {
+        return None;
+    }
+
+    let mut rounds = Vec::with_capacity(4);
+    if !preprocessed.is_empty() {
+        rounds.push(Round {
+            commit: shape.preprocessed_commit,
+            matrices: preprocessed,
+        });
+    }
+    rounds.push(Round {
+        commit: main_commit,
+        matrices: main,
+    });
+    rounds.push(Round {
+        commit: permutation_commit,
+        matrices: permutation,
+    });
+    rounds.push(Round {
+        commit: quotient_commit,
+        matrices: quotient,
+    });
+    verify_openings(shape, &rounds, &mut challenger, &mut opening_proof)?;
+    opening_proof.is_empty().then_some(())
+}
+
+/// Two-adic FRI PCS: reduces every opening claim `p(z) = v` to the quotient
+/// `(p(X) - v) / (X - z)`, batched with powers of `alpha` per LDE height.
+fn verify_openings(
+    shape: &RecursionShape,
+    rounds: &[Round],
+    challenger: &mut Challenger,
+    proof: &mut Reader,
+) -> Option<()> {
+    for round in rounds {
+        for matrix in &round.matrices {
+            for (_, values) in &matrix.openings {
+                for value in values.iter() {
+                    challenger.observe_ext(value);
+                }
+            }
+        }
+    }
+    let alpha = challenger.sample_ext();
+    let log_max_height = rounds
+        .iter()
+        .flat_map(|round| round.matrices.iter().map(|m| m.log_height))
+        .max()?;
+
+    let perm = &shape.perm;
+    fri::verify(
+        perm,
+        &shape.fri,
+        challenger,
+        proof,
+        log_max_height,
+        |index, r| {
+            // (log_height, alpha^k, reduced opening), tallest first.
+            let mut reduced: Vec<(usize, ExtElem, ExtElem)> = Vec::new();
+            for round in rounds {
+                let mut rows = Vec::with_capacity(round.matrices.len());
+                for matrix in &round.matrices {
+                    rows.push(r.elems(matrix.width)?);
+                }
+                let log_heights: Vec<usize> = round.matrices.iter().map(|m| m.log_height).collect();
+                let round_max = *log_heights.iter().max()?;
+                let round_index = index >> (log_max_height - round_max);
+                mmcs::verify_batch(perm, &round.commit, &log_heights, round_index, &rows, r)?;
+
+                for (matrix, row) in round.matrices.iter().zip(&rows) {
+                    let log_height = matrix.log_height;
+                    let rev = reverse_bits(index >> (log_max_height - log_height), log_height);
+                    let x = ExtElem::from_base(
+                        Elem::GENERATOR * Elem::two_adic_generator(log_height).pow(rev as u64),
+                    );
+                    let pos = match reduced.iter().position(|(h, _, _)| *h <= log_height) {
+                        Some(pos) if reduced[pos].0 == log_height => pos,
+                        Some(pos) => {
+                            reduced.insert(pos, (log_height, ExtElem::ONE, ExtElem::ZERO));
+                            pos
+                        }
+                        None => {
+                            reduced.push((log_height, ExtElem::ONE, ExtElem::ZERO));
+                            reduced.len() - 1
+                        }
+                    };
+                    let (_, alpha_pow, ro) = &mut reduced[pos];
+                    for (z, values) in &matrix.openings {
+                        let inv = (x - *z).inv();
+                        for (p_x, p_z) in row.iter().zip(values.iter()) {
+                            *ro += *alpha_pow * (ExtElem::from_base(*p_x) - *p_z) * inv;
+                            *alpha_pow *= alpha;
+                        }
+                    }
+                }
+            }
+            Some(reduced.into_iter().map(|(h, _, ro)| (h, ro)).collect())
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::VerificationError;
+    use stylus_sdk::alloy_primitives::b256;
+
+    // A one-chip shape over a trace of 8 rows with a single balanced interaction, and a
+    // compressed proof of it committing to the public values `b"hello sp1"`.
+    const SHAPE: &[u8] = include_bytes!("../../../testdata/sp1/recursion_shape.bin");
+    const PROOF: &[u8] = include_bytes!("../../../testdata/sp1/compressed_proof.bin");
+    const PROGRAM_VKEY: B256 =
+        b256!("00000007d0000010d40000241000004cf00000a38000015a400002db00000603");
+
+    /// Where each part of the fixture proof starts.
+    struct Offsets {
+        cumulative_sum: usize,
+        main_values: usize,
+        quotient_values: usize,
+        fri_commits: usize,
+        final_poly: usize,
+        pow_witness: usize,
+        queries: usize,
+    }
+
+    fn offsets(shape: &RecursionShape) -> Offsets {
+        let [chip] = &shape.chips[..] else {
+            panic!("the fixture shape has one chip");
+        };
+        let chips = 3 * 32 + 4 * shape.public_values.len;
+        let main_values = chips + 20 + 2 * 16 * chip.preprocessed_width;
+        let quotient_values = main_values
+            + 2 * 16 * (chip.main_width + 4 * chip.permutation_width(shape.logup_batch_size));
+        let fri_commits = quotient_values + ((4 * 16) << chip.log_quotient_degree) + 4;
+        let n_commits = PROOF[fri_commits - 4] as usize;
+        let final_poly = fri_commits + 32 * n_commits;
+        Offsets {
+            cumulative_sum: chips + 4,
+            main_values,
+            quotient_values,
+            fri_commits,
+            final_poly,
+            pow_witness: final_poly + 16,
+            queries: final_poly + 20,
+        }
+    }
+
+    fn verify_altered(at: usize) -> Result<(), Sp1Error> {
+        let shape = RecursionShape::from_bytes(SHAPE).unwrap();
+        let mut proof = PROOF.to_vec();
+        proof[at] ^= 1;
+        verify_compressed(&shape, &proof, PROGRAM_VKEY, b"hello sp1")
+    }
+
+    fn is_verification_failure(result: Result<(), Sp1Error>) -> bool {
+        matches!(
+            result,
+            Err(Sp1Error::Common(VerificationError::VerificationFailed))
+        )
+    }
+
+    #[test]
+    fn verifies_fixture_proof() {
+        let shape = RecursionShape::from_bytes(SHAPE).unwrap();
+        verify_compressed(&shape, PROOF, PROGRAM_VKEY, b"hello sp1").unwrap();
+    }
+
+    #[test]
+    fn rejects_other_statements() {
+        let shape = RecursionShape::from_bytes(SHAPE).unwrap();
+        assert!(matches!(
+            verify_compressed(&shape, PROOF, PROGRAM_VKEY, b"other"),
+            Err(Sp1Error::PublicValuesDigestMismatch { expected, .. })
+                if expected == B256::from_slice(&Sha256::digest(b"hello sp1"))
+        ));
+        assert!(matches!(
+            verify_compressed(&shape, PROOF, B256::ZERO, b"hello sp1"),
+            Err(Sp1Error::ProgramVkeyMismatch { expected, .. }) if expected == PROGRAM_VKEY
+        ));
+    }
+
+    #[test]
+    fn rejects_altered_opened_values() {
+        let offsets = offsets(&RecursionShape::from_bytes(SHAPE).unwrap());
+        for at in [
+            offsets.cumulative_sum,
+            offsets.main_values,
+            offsets.quotient_values,
+        ] {
+            assert!(is_verification_failure(verify_altered(at)));
+        }
+    }
+
+    #[test]
+    fn rejects_altered_fri_proofs() {
+        let offsets = offsets(&RecursionShape::from_bytes(SHAPE).unwrap());
+        // A commit-phase commitment, the final polynomial, an input opening of the first
+        // query and the last sibling hash of the last one.
+        for at in [
+            offsets.fri_commits,
+            offsets.final_poly,
+            offsets.queries,
+            PROOF.len() - 4,
+        ] {
+            assert!(is_verification_failure(verify_altered(at)));
+        }
+    }
+
+    // The fixture shape asks for no proof of work, but the witness is still observed before
+    // the queries are drawn.
+    #[test]
+    fn rejects_altered_pow_witness() {
+        let offsets = offsets(&RecursionShape::from_bytes(SHAPE).unwrap());
+        assert!(is_verification_failure(verify_altered(offsets.pow_witness)));
+    }
+
+    #[test]
+    fn rejects_malformed_proofs() {
+        let shape = RecursionShape::from_bytes(SHAPE).unwrap();
+        let offsets = offsets(&shape);
+        assert!(matches!(
+            verify_compressed(
+                &shape,
+                &PROOF[..offsets.main_values],
+                PROGRAM_VKEY,
+                b"hello sp1"
+            ),
+            Err(Sp1Error::Common(VerificationError::InvalidProofData))
+        ));
+        assert!(is_verification_failure(verify_compressed(
+            &shape,
+            &PROOF[..PROOF.len() - 4],
+            PROGRAM_VKEY,
+            b"hello sp1"
+        )));
+
+        let mut extended = PROOF.to_vec();
+        extended.extend([0; 4]);
+        assert!(is_verification_failure(verify_compressed(
+            &shape,
+            &extended,
+            PROGRAM_VKEY,
+            b"hello sp1"
+        )));
+    }
+
+    #[test]
+    fn rejects_other_shapes() {
+        let rounds_f = u32::from_le_bytes(SHAPE[..4].try_into().unwrap()) as usize;
+        let rounds_p = u32::from_le_bytes(SHAPE[4..8].try_into().unwrap()) as usize;
+        let fri = 8 + 4 * (16 * rounds_f + rounds_p + 16);
+        let pc_start = fri + 12 + 32;
+
+        // One more query than the proof has, and another program counter to start from.
+        for at in [fri + 4, pc_start] {
+            let mut altered = SHAPE.to_vec();
+            altered[at] ^= 1;
+            let shape = RecursionShape::from_bytes(&altered).unwrap();
+            assert!(is_verification_failure(verify_compressed(
+                &shape,
+                PROOF,
+                PROGRAM_VKEY,
+                b"hello sp1"
+            )));
+        }
+    }
+}
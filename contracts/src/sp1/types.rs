@@ -4,8 +4,8 @@ use stylus_sdk::{
     alloy_sol_types::sol,
 };
 
-use crate::sp1::config;
 use crate::common::groth16::R;
+use crate::sp1::config;
 sol! {
     struct Sp1Proof {
         uint256[8] proof;
@@ -35,4 +35,4 @@ pub fn hash_public_values(public_values: &[u8]) -> U256 {
     let hash = Sha256::digest(public_values);
     let hash_u256 = U256::from_be_bytes(hash.into());
     (hash_u256 & config::FIELD_MASK) % R
-} 
\ No newline at end of file
+}
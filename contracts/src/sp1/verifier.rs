@@ -1,16 +1,21 @@
 use alloc::{string::String, vec, vec::Vec};
+#[cfg(feature = "sp1-stark")]
+use stylus_sdk::alloy_primitives::Address;
 use stylus_sdk::{
     alloy_primitives::{FixedBytes, B256},
     alloy_sol_types::SolType,
     prelude::*,
+    stylus_core::log,
 };
 
-use crate::common::{Groth16Verifier, VMType};
+#[cfg(feature = "sp1-stark")]
+use crate::common::ownable::{IOwnable, Ownable};
+use crate::common::{Groth16Verifier, ProofVerified, VMType};
 use crate::sp1::{
     config,
     crypto::vk,
     errors::Sp1Error,
-    types::{Sp1Proof, Sp1PublicInputs},
+    types::{hash_public_values, Sp1Proof, Sp1PublicInputs},
 };
 
 pub trait ISp1Verifier {
@@ -22,16 +27,38 @@ pub trait ISp1Verifier {
         public_values: Vec<u8>,
         proof_bytes: Vec<u8>,
     ) -> Result<(), Self::Error>;
-    
+
+    /// Like `verify_proof`, but emits `ProofVerified` for indexers.
+    fn verify_and_record(
+        &mut self,
+        program_vkey: B256,
+        public_values: Vec<u8>,
+        proof_bytes: Vec<u8>,
+    ) -> Result<(), Self::Error>;
+
     fn verifier_hash(&self) -> B256;
 
     fn version(&self) -> String;
+
+    /// Digest of `public_values` as committed in the proof's public inputs.
+    fn hash_public_values(&self, public_values: Vec<u8>) -> B256;
 }
 
+#[cfg(not(feature = "sp1-stark"))]
 sol_storage! {
     pub struct Sp1Verifier {}
 }
 
+// The compressed verifier pins a recursion shape, which only its owner may do. Without
+// `sp1-stark` the Groth16 verifier keeps no state.
+#[cfg(feature = "sp1-stark")]
+sol_storage! {
+    pub struct Sp1Verifier {
+        bytes32 recursion_shape_hash;
+        Ownable ownable;
+    }
+}
+
 #[public]
 impl ISp1Verifier for Sp1Verifier {
     type Error = Vec<u8>;
@@ -45,6 +72,26 @@ impl ISp1Verifier for Sp1Verifier {
         self.verify_proof_internal(program_vkey, public_values, proof_bytes)
     }
 
+    fn verify_and_record(
+        &mut self,
+        program_vkey: B256,
+        public_values: Vec<u8>,
+        proof_bytes: Vec<u8>,
+    ) -> Result<(), Self::Error> {
+        let public_values_digest = hash_public_values(&public_values);
+        self.verify_proof_internal(program_vkey, public_values, proof_bytes)?;
+        log(
+            self.vm(),
+            ProofVerified {
+                system: VMType::Sp1 as u8,
+                programId: program_vkey,
+                publicInputsDigest: public_values_digest.into(),
+                caller: self.vm().msg_sender(),
+            },
+        );
+        Ok(())
+    }
+
     fn verifier_hash(&self) -> B256 {
         config::VERIFIER_HASH
     }
@@ -52,9 +99,50 @@ impl ISp1Verifier for Sp1Verifier {
     fn version(&self) -> String {
         String::from(config::VERSION)
     }
+
+    fn hash_public_values(&self, public_values: Vec<u8>) -> B256 {
+        hash_public_values(&public_values).into()
+    }
+}
+
+#[cfg(feature = "sp1-stark")]
+#[public]
+impl IOwnable for Sp1Verifier {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.ownable.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.ownable
+            .transfer_ownership(new_owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.ownable.accept_ownership().map_err(|e| e.abi_encode())
+    }
 }
 
 impl Sp1Verifier {
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    #[cfg(feature = "sp1-stark")]
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable
+            .set_initial_owner(owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    #[cfg(feature = "sp1-stark")]
+    pub(crate) fn only_owner(&self) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner().map_err(|e| e.abi_encode())
+    }
+
     fn verify_proof_internal(
         &self,
         program_vkey: B256,
@@ -110,3 +198,93 @@ impl Sp1Verifier {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+    use stylus_sdk::{
+        alloy_primitives::{b256, hex, Address},
+        alloy_sol_types::SolEvent,
+        testing::*,
+    };
+
+    /// Groth16 proof of the SP1 v5 fibonacci example for n = 20.
+    const PROGRAM_VKEY: B256 =
+        b256!("00d2f2f7952cbd9ececcf5303b2da21af20dc24953485d345df73c2854f498bc");
+    const PUBLIC_VALUES: [u8; 96] = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000014"
+        "0000000000000000000000000000000000000000000000000000000000001a6d"
+        "0000000000000000000000000000000000000000000000000000000000002ac2"
+    );
+    const PROOF: [u8; 260] = hex!(
+        "a4594c5929754e82587e66fd1bb8d8e4e98e6777a1adf400c405506a09173829f224450f1b17a81870ab2aef"
+        "2fbbb236f1d397bb6c4ff793bf0e350d58fc191b5e85d7233010220b72c9ee5cb184f6c2bf486f3cae5d21c1"
+        "e7145e957f36d8716df245c7028365cbff8d03a827a8fcfadb43af2c15c7ca2434db227ab399719aeae87e2d"
+        "111448ae96af93c333b0a23f9a4be33c6396d1ab823d927d51153d05ec87df332988ebd31b243498e1cb1f8d"
+        "97f84324ad242e7bc3ea9c1bf3165be46b8302952f3ea26440093819356240a700aa424487f6aab1eb664e5a"
+        "ed296c8356b252f11579161a3ec93bdb657e57ba9d5480195da51d0a74ea2f343f85a12f8d2477eb"
+    );
+    const CALLER: Address = Address::repeat_byte(1);
+
+    #[test]
+    fn verifies_a_real_proof() {
+        let vm = TestVM::default();
+        let verifier = Sp1Verifier::from(&vm);
+
+        assert_eq!(
+            verifier.verify_proof(PROGRAM_VKEY, PUBLIC_VALUES.to_vec(), PROOF.to_vec()),
+            Ok(())
+        );
+
+        let mut public_values = PUBLIC_VALUES;
+        public_values[95] ^= 1;
+        assert_eq!(
+            verifier.verify_proof(PROGRAM_VKEY, public_values.to_vec(), PROOF.to_vec()),
+            Err(Sp1Error::VERIFICATION_FAILED.abi_encode())
+        );
+        assert_eq!(
+            verifier.verify_proof(B256::repeat_byte(1), PUBLIC_VALUES.to_vec(), PROOF.to_vec()),
+            Err(Sp1Error::VERIFICATION_FAILED.abi_encode())
+        );
+
+        let mut proof = PROOF;
+        proof[0] ^= 1;
+        assert_eq!(
+            verifier.verify_proof(PROGRAM_VKEY, PUBLIC_VALUES.to_vec(), proof.to_vec()),
+            Err(Sp1Error::WrongVerifierSelector {
+                received: FixedBytes::from_slice(&proof[..4]),
+                expected: config::get_verifier_selector(),
+            }
+            .abi_encode())
+        );
+    }
+
+    #[test]
+    fn verify_and_record_emits_proof_verified() {
+        let vm = TestVM::default();
+        let mut verifier = Sp1Verifier::from(&vm);
+        vm.set_sender(CALLER);
+
+        let mut public_values = PUBLIC_VALUES;
+        public_values[95] ^= 1;
+        assert!(verifier
+            .verify_and_record(PROGRAM_VKEY, public_values.to_vec(), PROOF.to_vec())
+            .is_err());
+        assert!(vm.get_emitted_logs().is_empty());
+
+        verifier
+            .verify_and_record(PROGRAM_VKEY, PUBLIC_VALUES.to_vec(), PROOF.to_vec())
+            .unwrap();
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 1);
+        let event = ProofVerified::decode_raw_log(&logs[0].0, &logs[0].1, true).unwrap();
+        assert_eq!(event.system, VMType::Sp1 as u8);
+        assert_eq!(event.programId, PROGRAM_VKEY);
+        // SHA-256 of the public values with the top three bits cleared.
+        let mut digest = B256::from_slice(&Sha256::digest(PUBLIC_VALUES));
+        digest[0] &= 0x1f;
+        assert_eq!(event.publicInputsDigest, digest);
+        assert_eq!(event.caller, CALLER);
+    }
+}
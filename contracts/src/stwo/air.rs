@@ -0,0 +1,232 @@
+//! The AIR proven by the Stwo Cairo prover and the parameters it is proven under.
+//!
+//! Stwo derives constraints from the Rust evaluators of its components; here they are
+//! exported as a straight-line program over the out-of-domain samples and passed in as a
+//! blob whose hash the verifier pins on initialization. All fields are little-endian `u32`
+//! words:
+//!
+//! ```text
+//! u32 channel
+//! u32 log_blowup | u32 n_queries | u32 pow_bits | u32 log_last_layer_degree
+//! 32 preprocessed_root
+//! 3 × (u32 n_columns | n_columns × (u32 log_size | u32 n_offsets | n_offsets × i32 offset))
+//! u32 n_interaction_elements | u32 n_claimed_sums | u32 log_composition_size
+//! u32 n_exprs | n_exprs × (u32 opcode, operands)
+//! u32 n_constraints | n_constraints × u32 expr
+//! ```
+//!
+//! The channel is `0` for Blake2s and `1` for Poseidon252, and names the Merkle hash too.
+//! The three trees are the preprocessed, base and interaction traces. A column is sampled
+//! at the out-of-domain point shifted by each of its offsets, in rows of its own trace.
+//!
+//! Opcodes: `0 Const(value)`, `1 Sample(index)`, `2 Public(index)`, `3 Interaction(index)`,
+//! `4 ClaimedSum(index)`, `5 Add`, `6 Sub`, `7 Mul`, `8 Div`, `9 CosetVanishing(log_size)`,
+//! `10 PointVanishing(log_size, row)`, `11 X`, `12 Y`, where arithmetic operands index
+//! earlier expressions and samples are numbered tree by tree, column by column.
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::common::stark::Field;
+use crate::stwo::{
+    channel::ChannelKind,
+    circle::{coset_vanishing, point_vanishing, CirclePoint, Coset, PointIndex},
+    field::{secure, SecureField, M31},
+    fri::FriConfig,
+    reader::Reader,
+};
+
+pub const N_TREES: usize = 3;
+
+pub struct Column {
+    pub log_size: usize,
+    pub offsets: Vec<i32>,
+}
+
+enum Expr {
+    Const(M31),
+    Sample(usize),
+    Public(usize),
+    Interaction(usize),
+    ClaimedSum(usize),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Div(usize, usize),
+    CosetVanishing(usize),
+    PointVanishing(usize, usize),
+    X,
+    Y,
+}
+
+pub struct Air {
+    pub channel: ChannelKind,
+    pub fri: FriConfig,
+    pub pow_bits: usize,
+    pub preprocessed_root: B256,
+    pub trees: [Vec<Column>; N_TREES],
+    pub interaction_elements: usize,
+    pub claimed_sums: usize,
+    pub log_composition_size: usize,
+    exprs: Vec<Expr>,
+    constraints: Vec<usize>,
+}
+
+/// Values the constraints are evaluated at.
+pub struct Point<'a> {
+    pub point: CirclePoint<SecureField>,
+    pub samples: &'a [SecureField],
+    pub public_values: &'a [M31],
+    pub interaction_elements: &'a [SecureField],
+    pub claimed_sums: &'a [SecureField],
+}
+
+impl Air {
+    pub fn from_bytes(bytes: &[u8], max_log_size: usize) -> Option<Self> {
+        let mut r = Reader::new(bytes);
+        let channel = match r.u32()? {
+            0 => ChannelKind::Blake2s,
+            1 => ChannelKind::Poseidon252,
+            _ => return None,
+        };
+        let log_blowup = r.usize()?;
+        let n_queries = r.usize()?;
+        let pow_bits = r.usize()?;
+        let fri = FriConfig {
+            log_blowup,
+            n_queries,
+            log_last_layer_degree: r.usize()?,
+        };
+        let preprocessed_root = r.hash()?;
+
+        let column = |r: &mut Reader| -> Option<Column> {
+            let log_size = r.usize()?;
+            let n = r.length()?;
+            let offsets = (0..n)
+                .map(|_| r.u32().map(|o| o as i32))
+                .collect::<Option<_>>()?;
+            (log_size > fri.log_last_layer_degree && log_size + fri.log_blowup <= max_log_size)
+                .then_some(Column { log_size, offsets })
+        };
+        let tree = |r: &mut Reader| -> Option<Vec<Column>> {
+            let n = r.length()?;
+            (0..n).map(|_| column(r)).collect()
+        };
+        let trees = [tree(&mut r)?, tree(&mut r)?, tree(&mut r)?];
+
+        let interaction_elements = r.usize()?;
+        let claimed_sums = r.usize()?;
+        let log_composition_size = r.usize()?;
+        let n_samples: usize = trees.iter().flatten().map(|c| c.offsets.len()).sum();
+        let max_column = trees.iter().flatten().map(|c| c.log_size).max();
+        if fri.n_queries == 0
+            || pow_bits > 64
+            || interaction_elements > 64
+            || claimed_sums > 1024
+            || trees[1].is_empty()
+            || (trees[2].is_empty() && claimed_sums > 0)
+            || max_column > Some(log_composition_size)
+            || log_composition_size <= fri.log_last_layer_degree
+            || log_composition_size + fri.log_blowup > max_log_size
+        {
+            return None;
+        }
+
+        let n_exprs = r.length()?;
+        let mut exprs = Vec::with_capacity(n_exprs);
+        for i in 0..n_exprs {
+            let expr = match r.u32()? {
+                0 => Expr::Const(r.m31()?),
+                1 => Expr::Sample(r.usize().filter(|j| *j < n_samples)?),
+                2 => Expr::Public(r.usize()?),
+                3 => Expr::Interaction(r.usize().filter(|j| *j < interaction_elements)?),
+                4 => Expr::ClaimedSum(r.usize().filter(|j| *j < claimed_sums)?),
+                op @ 5..=8 => {
+                    let (a, b) = (r.usize()?, r.usize()?);
+                    if a >= i || b >= i {
+                        return None;
+                    }
+                    match op {
+                        5 => Expr::Add(a, b),
+                        6 => Expr::Sub(a, b),
+                        7 => Expr::Mul(a, b),
+                        _ => Expr::Div(a, b),
+                    }
+                }
+                9 => Expr::CosetVanishing(r.usize().filter(|l| (1..=max_log_size).contains(l))?),
+                10 => {
+                    let log_size = r.usize().filter(|l| (1..=max_log_size).contains(l))?;
+                    Expr::PointVanishing(log_size, r.usize().filter(|i| i >> log_size == 0)?)
+                }
+                11 => Expr::X,
+                12 => Expr::Y,
+                _ => return None,
+            };
+            exprs.push(expr);
+        }
+
+        let n_constraints = r.length()?;
+        let constraints = (0..n_constraints)
+            .map(|_| r.usize().filter(|i| *i < exprs.len()))
+            .collect::<Option<Vec<_>>>()?;
+
+        r.is_empty().then_some(Self {
+            channel,
+            fri,
+            pow_bits,
+            preprocessed_root,
+            trees,
+            interaction_elements,
+            claimed_sums,
+            log_composition_size,
+            exprs,
+            constraints,
+        })
+    }
+
+    /// Evaluates the random linear combination of the constraints at the out-of-domain
+    /// point, accumulated as `acc · alpha + constraint`.
+    pub fn eval_composition(&self, point: &Point, alpha: SecureField) -> Option<SecureField> {
+        let mut values: Vec<SecureField> = Vec::with_capacity(self.exprs.len());
+        for expr in &self.exprs {
+            let value = match *expr {
+                Expr::Const(x) => secure(x),
+                Expr::Sample(i) => point.samples[i],
+                Expr::Public(i) => secure(*point.public_values.get(i)?),
+                Expr::Interaction(i) => point.interaction_elements[i],
+                Expr::ClaimedSum(i) => point.claimed_sums[i],
+                Expr::Add(a, b) => values[a] + values[b],
+                Expr::Sub(a, b) => values[a] - values[b],
+                Expr::Mul(a, b) => values[a] * values[b],
+                Expr::Div(a, b) => {
+                    if values[b] == SecureField::ZERO {
+                        return None;
+                    }
+                    values[a] * values[b].inv()
+                }
+                Expr::CosetVanishing(log_size) => coset_vanishing(log_size, point.point),
+                Expr::PointVanishing(log_size, row) => {
+                    point_vanishing(canonic_coset(log_size).at(row), point.point)?
+                }
+                Expr::X => point.point.x,
+                Expr::Y => point.point.y,
+            };
+            values.push(value);
+        }
+
+        Some(
+            self.constraints
+                .iter()
+                .fold(SecureField::ZERO, |acc, i| acc * alpha + values[*i]),
+        )
+    }
+}
+
+/// The trace domain of `2^log_size` rows, `G_{2^(log_size + 1)} + <G_{2^log_size}>`.
+pub fn canonic_coset(log_size: usize) -> Coset {
+    Coset {
+        initial: PointIndex::subgroup_gen(log_size + 1),
+        step: PointIndex::subgroup_gen(log_size),
+        log_size,
+    }
+}
@@ -0,0 +1,223 @@
+use alloc::{vec, vec::Vec};
+use stylus_sdk::alloy_primitives::{B256, U256};
+
+use crate::common::stark::PrimeField;
+use crate::stwo::{
+    blake2s::Blake2s,
+    field::{from_m31_array, to_m31_array, SecureField, M31},
+    poseidon252,
+};
+
+/// The channel, and with it the Merkle hash, a proof is generated with.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ChannelKind {
+    Blake2s,
+    /// Used by Stwo proofs verified in Cairo, as the recursion of Starknet's prover.
+    Poseidon252,
+}
+
+/// A Fiat-Shamir channel in Stwo's interface.
+pub trait Channel {
+    fn mix_digest(&mut self, digest: &B256);
+
+    fn mix_felts(&mut self, felts: &[SecureField]);
+
+    fn mix_u64(&mut self, value: u64);
+
+    fn draw_secure_felt(&mut self) -> SecureField;
+
+    fn draw_random_bytes(&mut self) -> Vec<u8>;
+
+    /// Trailing zeros of the digest, read as a little-endian `u128`, for proof of work.
+    fn trailing_zeros(&self) -> u32;
+
+    /// The digest and the draws since the last mix, to resume from in a later call.
+    fn snapshot(&self) -> (B256, u32);
+
+    fn restore(digest: B256, n_draws: u32) -> Self
+    where
+        Self: Sized;
+
+    /// Draws `n` query positions below `2^log_domain`, four bytes each; the result is
+    /// sorted and deduplicated.
+    fn draw_queries(&mut self, log_domain: usize, n: usize) -> Vec<usize> {
+        let mask = (1u32 << log_domain) - 1;
+        let mut queries = Vec::with_capacity(n);
+        while queries.len() < n {
+            let bytes = self.draw_random_bytes();
+            for chunk in bytes.chunks_exact(4).take(n - queries.len()) {
+                let word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                queries.push((word & mask) as usize);
+            }
+        }
+        queries.sort_unstable();
+        queries.dedup();
+        queries
+    }
+}
+
+/// Stwo's Blake2s channel: every mix hashes the input onto the digest, and the `n`-th
+/// draw since hashes `digest || n || 0`.
+#[derive(Default)]
+pub struct Blake2sChannel {
+    digest: [u8; 32],
+    n_draws: u32,
+}
+
+impl Blake2sChannel {
+    fn mix(&mut self, data: &[u8]) {
+        let mut hasher = Blake2s::new();
+        hasher.update(&self.digest);
+        hasher.update(data);
+        self.digest = hasher.finalize();
+        self.n_draws = 0;
+    }
+}
+
+impl Channel for Blake2sChannel {
+    fn mix_digest(&mut self, digest: &B256) {
+        self.mix(digest.as_slice());
+    }
+
+    fn mix_felts(&mut self, felts: &[SecureField]) {
+        let bytes: Vec<u8> = felts
+            .iter()
+            .flat_map(|felt| to_m31_array(*felt))
+            .flat_map(|x| x.as_canonical_u32().to_le_bytes())
+            .collect();
+        self.mix(&bytes);
+    }
+
+    fn mix_u64(&mut self, value: u64) {
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&(value as u32).to_le_bytes());
+        bytes[4..].copy_from_slice(&((value >> 32) as u32).to_le_bytes());
+        self.mix(&bytes);
+    }
+
+    /// Rejects draws with a word not below `2p` so that reducing them is close to uniform.
+    fn draw_secure_felt(&mut self) -> SecureField {
+        loop {
+            let bytes = self.draw_random_bytes();
+            let words: Vec<u32> = bytes
+                .chunks_exact(4)
+                .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect();
+            if words.iter().all(|word| (*word as u64) < 2 * M31::ORDER) {
+                let felts = [words[0], words[1], words[2], words[3]].map(M31::new);
+                return from_m31_array(felts);
+            }
+        }
+    }
+
+    fn draw_random_bytes(&mut self) -> Vec<u8> {
+        let mut hasher = Blake2s::new();
+        hasher.update(&self.digest);
+        hasher.update(&self.n_draws.to_le_bytes());
+        hasher.update(&[0]);
+        self.n_draws += 1;
+        hasher.finalize().to_vec()
+    }
+
+    fn trailing_zeros(&self) -> u32 {
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&self.digest[..16]);
+        u128::from_le_bytes(low).trailing_zeros()
+    }
+
+    fn snapshot(&self) -> (B256, u32) {
+        (B256::new(self.digest), self.n_draws)
+    }
+
+    fn restore(digest: B256, n_draws: u32) -> Self {
+        Self {
+            digest: digest.0,
+            n_draws,
+        }
+    }
+}
+
+/// Stwo's Poseidon252 channel: the digest is a Stark field element, every mix hashes the
+/// input onto it, and the `n`-th draw since is `poseidon_hash(digest, n)`.
+#[derive(Default)]
+pub struct Poseidon252Channel {
+    digest: U256,
+    n_draws: u32,
+}
+
+impl Poseidon252Channel {
+    fn update_digest(&mut self, digest: U256) {
+        self.digest = digest;
+        self.n_draws = 0;
+    }
+
+    fn mix(&mut self, words: impl IntoIterator<Item = U256>) {
+        let mut input = vec![self.digest];
+        input.extend(words);
+        self.update_digest(poseidon252::hash_many(&input));
+    }
+
+    fn draw_felt252(&mut self) -> U256 {
+        let felt = poseidon252::hash(self.digest, U256::from(self.n_draws));
+        self.n_draws += 1;
+        felt
+    }
+}
+
+impl Channel for Poseidon252Channel {
+    /// Roots are field elements; other digests, such as the public input's, are reduced.
+    fn mix_digest(&mut self, digest: &B256) {
+        let digest = U256::from_be_bytes(digest.0).reduce_mod(poseidon252::P);
+        self.update_digest(poseidon252::hash(self.digest, digest));
+    }
+
+    /// Packs every two felts, as eight 31-bit limbs, into one word.
+    fn mix_felts(&mut self, felts: &[SecureField]) {
+        let words: Vec<U256> = felts
+            .chunks(2)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .flat_map(|felt| to_m31_array(*felt))
+                    .fold(U256::ZERO, |word, x| {
+                        (word << 31) + U256::from(x.as_canonical_u32())
+                    })
+            })
+            .collect();
+        self.mix(words);
+    }
+
+    fn mix_u64(&mut self, value: u64) {
+        self.mix([U256::from(value)]);
+    }
+
+    /// Splits a draw into eight 31-bit limbs, least significant first, and keeps four.
+    fn draw_secure_felt(&mut self) -> SecureField {
+        let felt = self.draw_felt252();
+        let limb = |i: usize| M31::from_u64((felt >> (31 * i)).as_limbs()[0] & 0x7fff_ffff);
+        from_m31_array([limb(0), limb(1), limb(2), limb(3)])
+    }
+
+    /// The low 31 bytes of a draw, least significant first.
+    fn draw_random_bytes(&mut self) -> Vec<u8> {
+        self.draw_felt252().to_le_bytes::<32>()[..31].to_vec()
+    }
+
+    fn trailing_zeros(&self) -> u32 {
+        let bytes = self.digest.to_be_bytes::<32>();
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&bytes[..16]);
+        u128::from_le_bytes(low).trailing_zeros()
+    }
+
+    fn snapshot(&self) -> (B256, u32) {
+        (B256::new(self.digest.to_be_bytes()), self.n_draws)
+    }
+
+    fn restore(digest: B256, n_draws: u32) -> Self {
+        Self {
+            digest: U256::from_be_bytes(digest.0),
+            n_draws,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! The circle group `x² + y² = 1` over M31, of order `2^31`, and the cosets and domains
+//! Stwo evaluates on.
+
+use core::ops::Add;
+
+use crate::common::stark::Field;
+use crate::stwo::field::{secure, SecureField, M31};
+
+pub const LOG_ORDER: usize = 31;
+
+/// Generator of the whole group.
+pub const GENERATOR: CirclePoint<M31> = CirclePoint {
+    x: M31::new(2),
+    y: M31::new(1268011823),
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CirclePoint<F> {
+    pub x: F,
+    pub y: F,
+}
+
+impl<F: Field> CirclePoint<F> {
+    pub fn zero() -> Self {
+        Self {
+            x: F::ONE,
+            y: F::ZERO,
+        }
+    }
+
+    pub fn double(self) -> Self {
+        self + self
+    }
+
+    /// The group inverse `(x, -y)`.
+    pub fn conjugate(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+
+    pub fn scale(self, mut n: u64) -> Self {
+        let mut base = self;
+        let mut acc = Self::zero();
+        while n > 0 {
+            if n & 1 == 1 {
+                acc = acc + base;
+            }
+            base = base.double();
+            n >>= 1;
+        }
+        acc
+    }
+
+    /// The x-coordinate of the doubled point, `2x² - 1`.
+    pub fn double_x(x: F) -> F {
+        x.square().double() - F::ONE
+    }
+}
+
+impl<F: Field> Add for CirclePoint<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x * rhs.x - self.y * rhs.y,
+            y: self.x * rhs.y + self.y * rhs.x,
+        }
+    }
+}
+
+impl CirclePoint<M31> {
+    pub fn into_secure(self) -> CirclePoint<SecureField> {
+        CirclePoint {
+            x: secure(self.x),
+            y: secure(self.y),
+        }
+    }
+}
+
+impl CirclePoint<SecureField> {
+    /// The point `((1 - t²) / (1 + t²), 2t / (1 + t²))`, as the out-of-domain point is
+    /// drawn.
+    pub fn from_t(t: SecureField) -> Self {
+        let t_square = t.square();
+        let inv = (SecureField::ONE + t_square).inv();
+        Self {
+            x: (SecureField::ONE - t_square) * inv,
+            y: t.double() * inv,
+        }
+    }
+}
+
+/// A group element as a multiple of `GENERATOR`, so that cosets are walked with integer
+/// arithmetic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PointIndex(u32);
+
+impl PointIndex {
+    const MASK: u64 = (1 << LOG_ORDER) - 1;
+
+    /// Generator of the subgroup of order `2^log_size`.
+    pub fn subgroup_gen(log_size: usize) -> Self {
+        Self(1 << (LOG_ORDER - log_size))
+    }
+
+    pub fn scale(self, n: usize) -> Self {
+        Self(((self.0 as u64).wrapping_mul(n as u64) & Self::MASK) as u32)
+    }
+
+    pub fn to_point(self) -> CirclePoint<M31> {
+        GENERATOR.scale(self.0 as u64)
+    }
+}
+
+impl Add for PointIndex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(((self.0 as u64 + rhs.0 as u64) & Self::MASK) as u32)
+    }
+}
+
+/// `initial + <step>` with `2^log_size` elements.
+#[derive(Clone, Copy, Debug)]
+pub struct Coset {
+    pub initial: PointIndex,
+    pub step: PointIndex,
+    pub log_size: usize,
+}
+
+impl Coset {
+    /// `G_{2^(log_size + 2)} + <G_{2^log_size}>`: half of a canonic circle domain, and the
+    /// domain of the first FRI line layer.
+    pub fn half_odds(log_size: usize) -> Self {
+        Self {
+            initial: PointIndex::subgroup_gen(log_size + 2),
+            step: PointIndex::subgroup_gen(log_size),
+            log_size,
+        }
+    }
+
+    pub fn at(&self, i: usize) -> CirclePoint<M31> {
+        (self.initial + self.step.scale(i)).to_point()
+    }
+
+    pub fn double(&self) -> Self {
+        Self {
+            initial: self.initial.scale(2),
+            step: self.step.scale(2),
+            log_size: self.log_size - 1,
+        }
+    }
+}
+
+/// The `i`-th point, in natural order, of the canonic circle domain of `2^log_size`
+/// points: a half coset followed by its conjugates.
+pub fn circle_domain_at(log_size: usize, i: usize) -> CirclePoint<M31> {
+    let half = Coset::half_odds(log_size - 1);
+    let half_size = 1 << half.log_size;
+    if i < half_size {
+        half.at(i)
+    } else {
+        half.at(i - half_size).conjugate()
+    }
+}
+
+/// Vanishing polynomial of the canonic coset of `2^log_size` points.
+pub fn coset_vanishing(log_size: usize, p: CirclePoint<SecureField>) -> SecureField {
+    (1..log_size).fold(p.x, |x, _| CirclePoint::double_x(x))
+}
+
+/// A polynomial vanishing at `excluded` only, of degree one.
+pub fn point_vanishing(
+    excluded: CirclePoint<M31>,
+    p: CirclePoint<SecureField>,
+) -> Option<SecureField> {
+    let h = p + excluded.conjugate().into_secure();
+    let denominator = SecureField::ONE + h.x;
+    (denominator != SecureField::ZERO).then(|| h.y * denominator.inv())
+}
@@ -0,0 +1,8 @@
+pub const PROGRAM_SEGMENT: usize = 0;
+pub const OUTPUT_SEGMENT: usize = 2;
+
+/// Bound on the log size of evaluation domains; the circle group has order `2^31`.
+pub const MAX_LOG_SIZE: usize = 28;
+
+/// Public memory values are split into this many 9-bit limbs in the memory relation.
+pub const N_VALUE_LIMBS: usize = 28;
@@ -0,0 +1,63 @@
+use stylus_sdk::alloy_sol_types::{sol, SolError};
+
+use crate::common::VerificationError;
+
+sol! {
+    error InvalidAir();
+    error InvalidPublicInput();
+    error OodsMismatch();
+    error LookupSumMismatch();
+}
+
+#[derive(Debug)]
+pub enum StwoError {
+    Common(VerificationError),
+    InvalidAir,
+    InvalidPublicInput,
+    OodsMismatch,
+    LookupSumMismatch,
+}
+
+impl StwoError {
+    pub fn abi_encode(&self) -> alloc::vec::Vec<u8> {
+        match self {
+            StwoError::Common(e) => e.abi_encode(),
+            StwoError::InvalidAir => InvalidAir {}.abi_encode(),
+            StwoError::InvalidPublicInput => InvalidPublicInput {}.abi_encode(),
+            StwoError::OodsMismatch => OodsMismatch {}.abi_encode(),
+            StwoError::LookupSumMismatch => LookupSumMismatch {}.abi_encode(),
+        }
+    }
+}
+
+impl From<VerificationError> for StwoError {
+    fn from(error: VerificationError) -> Self {
+        StwoError::Common(error)
+    }
+}
+
+/// Collapses to the common errors, for verifiers driven through `common::resumable`.
+impl From<StwoError> for VerificationError {
+    fn from(error: StwoError) -> Self {
+        match error {
+            StwoError::Common(e) => e,
+            StwoError::InvalidAir | StwoError::InvalidPublicInput => {
+                VerificationError::InvalidProofData
+            }
+            StwoError::OodsMismatch | StwoError::LookupSumMismatch => {
+                VerificationError::VerificationFailed
+            }
+        }
+    }
+}
+
+impl StwoError {
+    pub const VERIFICATION_FAILED: StwoError =
+        StwoError::Common(VerificationError::VerificationFailed);
+    pub const INVALID_INITIALIZATION: StwoError =
+        StwoError::Common(VerificationError::InvalidInitialization);
+    pub const ALREADY_INITIALIZED: StwoError =
+        StwoError::Common(VerificationError::AlreadyInitialized);
+    pub const INVALID_PROOF_DATA: StwoError =
+        StwoError::Common(VerificationError::InvalidProofData);
+}
@@ -0,0 +1,39 @@
+//! Stwo's base field M31 and secure field QM31, with the conversions its protocol uses.
+
+pub use crate::common::stark::{Cm31, Mersenne31 as M31, Qm31 as SecureField};
+
+pub fn secure(x: M31) -> SecureField {
+    SecureField::from_base(Cm31::from_base(x))
+}
+
+/// `(a + bi) + (c + di)u` from `[a, b, c, d]`.
+pub fn from_m31_array([a, b, c, d]: [M31; 4]) -> SecureField {
+    SecureField::new([Cm31::new([a, b]), Cm31::new([c, d])])
+}
+
+pub fn to_m31_array(x: SecureField) -> [M31; 4] {
+    [x.0[0].0[0], x.0[0].0[1], x.0[1].0[0], x.0[1].0[1]]
+}
+
+/// Recombines values of the four coordinate polynomials of a secure column,
+/// `Σ v_k · e_k` over the basis `1, i, u, iu`.
+pub fn from_partial_evals(evals: [SecureField; 4]) -> SecureField {
+    let one = M31::ONE;
+    let zero = M31::ZERO;
+    evals
+        .iter()
+        .zip([
+            [one, zero, zero, zero],
+            [zero, one, zero, zero],
+            [zero, zero, one, zero],
+            [zero, zero, zero, one],
+        ])
+        .fold(SecureField::ZERO, |acc, (value, basis)| {
+            acc + *value * from_m31_array(basis)
+        })
+}
+
+/// The automorphism `u ↦ -u` fixing CM31.
+pub fn conjugate(x: SecureField) -> SecureField {
+    SecureField::new([x.0[0], -x.0[1]])
+}
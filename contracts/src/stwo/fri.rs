@@ -0,0 +1,275 @@
+//! Circle FRI: the first layer folds circle evaluations onto the x-axis, later layers fold
+//! line evaluations in half until the last layer polynomial.
+//!
+//! Evaluations are committed in bit-reversed order, so that the pairs folded together
+//! (`p` and its conjugate, or `x` and `-x`) sit at adjacent positions. Columns of every
+//! degree bound share the first layer tree, and each is folded into the line layer of
+//! matching size with a factor of `alpha²` per layer.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::iter::Peekable;
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::common::stark::{fri::reverse_bits, Field};
+use crate::stwo::{
+    channel::Channel,
+    circle::{circle_domain_at, CirclePoint, Coset},
+    field::{secure, to_m31_array, SecureField, M31},
+    merkle::{self, MerkleHasher},
+    reader::Reader,
+};
+
+pub struct FriConfig {
+    pub log_blowup: usize,
+    pub log_last_layer_degree: usize,
+    pub n_queries: usize,
+}
+
+pub struct FriVerifier {
+    /// Log sizes of the first layer columns' domains, descending and distinct.
+    column_log_sizes: Vec<usize>,
+    first_layer: (B256, SecureField),
+    inner_layers: Vec<(B256, SecureField)>,
+    last_layer: Vec<SecureField>,
+    last_layer_log_size: usize,
+}
+
+impl FriVerifier {
+    /// Reads the commitments and draws the folding randomness, for columns with the given
+    /// degree bounds.
+    pub fn commit<C: Channel>(
+        channel: &mut C,
+        proof: &mut Reader,
+        config: &FriConfig,
+        log_degree_bounds: &[usize],
+    ) -> Option<Self> {
+        let max_bound = *log_degree_bounds.first()?;
+        if log_degree_bounds.windows(2).any(|w| w[0] <= w[1])
+            || log_degree_bounds
+                .iter()
+                .any(|b| *b <= config.log_last_layer_degree)
+        {
+            return None;
+        }
+
+        let mut layer = |channel: &mut C| {
+            let root = proof.hash()?;
+            channel.mix_digest(&root);
+            Some((root, channel.draw_secure_felt()))
+        };
+        let first_layer = layer(channel)?;
+        let inner_layers = (config.log_last_layer_degree..max_bound - 1)
+            .map(|_| layer(channel))
+            .collect::<Option<Vec<_>>>()?;
+        let last_layer = (0..1usize << config.log_last_layer_degree)
+            .map(|_| proof.secure())
+            .collect::<Option<Vec<_>>>()?;
+        channel.mix_felts(&last_layer);
+
+        Some(Self {
+            column_log_sizes: log_degree_bounds
+                .iter()
+                .map(|b| b + config.log_blowup)
+                .collect(),
+            first_layer,
+            inner_layers,
+            last_layer,
+            last_layer_log_size: config.log_last_layer_degree + config.log_blowup,
+        })
+    }
+
+    /// The domain size queries are drawn over.
+    pub fn max_log_size(&self) -> usize {
+        self.column_log_sizes[0]
+    }
+
+    /// Checks the first layer values at `queries`, given for each column at the queries
+    /// folded to its domain, down to the last layer.
+    pub fn decommit<H: MerkleHasher>(
+        &self,
+        proof: &mut Reader,
+        queries: &[usize],
+        column_values: &[Vec<SecureField>],
+    ) -> Option<()> {
+        let (root, alpha) = self.first_layer;
+        let max_log_size = self.max_log_size();
+
+        // First layer: complete the pairs, check them against the shared tree and fold
+        // every column onto the line.
+        let mut tree_queries = BTreeMap::new();
+        let mut tree_values = Vec::new();
+        let mut folded_columns = Vec::with_capacity(self.column_log_sizes.len());
+        for (log_size, values) in self.column_log_sizes.iter().zip(column_values) {
+            let column_queries = fold_queries(queries, max_log_size - log_size);
+            let (positions, pairs) = complete_pairs(proof, &column_queries, values)?;
+            for (a, b) in &pairs {
+                tree_values.extend(to_m31_array(*a));
+                tree_values.extend(to_m31_array(*b));
+            }
+            tree_queries.insert(*log_size, positions);
+
+            let folded = fold_queries(&column_queries, 1);
+            let evals = folded
+                .iter()
+                .zip(&pairs)
+                .map(|(i, (f_p, f_neg_p))| {
+                    let p = circle_domain_at(*log_size, reverse_bits(i << 1, *log_size));
+                    fold(*f_p, *f_neg_p, p.y, alpha)
+                })
+                .collect::<Vec<_>>();
+            folded_columns.push((log_size - 1, folded, evals));
+        }
+        let tree_log_sizes: Vec<usize> =
+            self.column_log_sizes.iter().flat_map(|l| [*l; 4]).collect();
+        merkle::verify::<H>(proof, &root, &tree_log_sizes, &tree_queries, &tree_values)?;
+
+        // Line layers.
+        let alpha_square = alpha.square();
+        let mut columns = folded_columns.into_iter().peekable();
+        let mut log_size = max_log_size - 1;
+        let mut layer_queries = fold_queries(queries, 1);
+        let mut layer_values = alloc::vec![SecureField::ZERO; layer_queries.len()];
+        let mut domain = Coset::half_odds(log_size);
+        for (root, beta) in &self.inner_layers {
+            accumulate(
+                &mut columns,
+                log_size,
+                &layer_queries,
+                &mut layer_values,
+                alpha_square,
+            )?;
+
+            let (positions, pairs) = complete_pairs(proof, &layer_queries, &layer_values)?;
+            let values: Vec<M31> = pairs
+                .iter()
+                .flat_map(|(a, b)| to_m31_array(*a).into_iter().chain(to_m31_array(*b)))
+                .collect();
+            let queries = BTreeMap::from([(log_size, positions)]);
+            merkle::verify::<H>(proof, root, &[log_size; 4], &queries, &values)?;
+
+            layer_queries = dedup(layer_queries.iter().map(|q| q >> 1).collect());
+            layer_values = layer_queries
+                .iter()
+                .zip(&pairs)
+                .map(|(i, (f_x, f_neg_x))| {
+                    let x = domain.at(reverse_bits(i << 1, log_size)).x;
+                    fold(*f_x, *f_neg_x, x, *beta)
+                })
+                .collect();
+            log_size -= 1;
+            domain = domain.double();
+        }
+        accumulate(
+            &mut columns,
+            log_size,
+            &layer_queries,
+            &mut layer_values,
+            alpha_square,
+        )?;
+        if columns.next().is_some() || log_size != self.last_layer_log_size {
+            return None;
+        }
+
+        layer_queries
+            .iter()
+            .zip(&layer_values)
+            .all(|(q, value)| {
+                let x = domain.at(reverse_bits(*q, log_size)).x;
+                eval_line_poly(&self.last_layer, x) == *value
+            })
+            .then_some(())
+    }
+}
+
+/// Queries on a domain `2^n_folds` times smaller.
+pub fn fold_queries(queries: &[usize], n_folds: usize) -> Vec<usize> {
+    dedup(queries.iter().map(|q| q >> n_folds).collect())
+}
+
+fn dedup(mut queries: Vec<usize>) -> Vec<usize> {
+    queries.dedup();
+    queries
+}
+
+type Pair = (SecureField, SecureField);
+
+/// Pairs every queried value with its sibling, reading siblings that were not queried
+/// from the proof. Returns the positions of the completed pairs and the pairs.
+fn complete_pairs(
+    proof: &mut Reader,
+    queries: &[usize],
+    values: &[SecureField],
+) -> Option<(Vec<usize>, Vec<Pair>)> {
+    if queries.len() != values.len() {
+        return None;
+    }
+    let mut queried = queries
+        .iter()
+        .copied()
+        .zip(values.iter().copied())
+        .peekable();
+    let mut positions = Vec::with_capacity(2 * queries.len());
+    let mut pairs = Vec::with_capacity(queries.len());
+    while let Some((q, _)) = queried.peek() {
+        let left = q & !1;
+        let mut pair = [SecureField::ZERO; 2];
+        for (j, value) in pair.iter_mut().enumerate() {
+            *value = match queried.next_if(|(i, _)| *i == left | j) {
+                Some((_, value)) => value,
+                None => proof.secure()?,
+            };
+            positions.push(left | j);
+        }
+        pairs.push((pair[0], pair[1]));
+    }
+    Some((positions, pairs))
+}
+
+/// `f(p) + f(-p) + alpha · (f(p) - f(-p)) / t`, with `t` the coordinate that tells the pair
+/// apart.
+fn fold(f_p: SecureField, f_neg_p: SecureField, t: M31, alpha: SecureField) -> SecureField {
+    f_p + f_neg_p + alpha * (f_p - f_neg_p) * secure(t.inv())
+}
+
+/// Adds the folded first layer columns that land on this layer.
+fn accumulate(
+    columns: &mut Peekable<impl Iterator<Item = (usize, Vec<usize>, Vec<SecureField>)>>,
+    log_size: usize,
+    queries: &[usize],
+    values: &mut [SecureField],
+    alpha_square: SecureField,
+) -> Option<()> {
+    while let Some((_, column_queries, evals)) = columns.next_if(|(l, _, _)| *l == log_size) {
+        if column_queries != queries {
+            return None;
+        }
+        for (value, eval) in values.iter_mut().zip(evals) {
+            *value = *value * alpha_square + eval;
+        }
+    }
+    Some(())
+}
+
+/// Evaluates a line polynomial given by its coefficients in bit-reversed order over the
+/// basis `x^b0 · π(x)^b1 · π²(x)^b2 ⋯`, with `π(x) = 2x² - 1`.
+pub fn eval_line_poly(coeffs: &[SecureField], x: M31) -> SecureField {
+    let mut factors = Vec::new();
+    let mut x = x;
+    let mut n = coeffs.len();
+    while n > 1 {
+        factors.push(x);
+        x = CirclePoint::double_x(x);
+        n >>= 1;
+    }
+    fold_coeffs(coeffs, &factors)
+}
+
+fn fold_coeffs(coeffs: &[SecureField], factors: &[M31]) -> SecureField {
+    match factors.split_first() {
+        None => coeffs[0],
+        Some((factor, rest)) => {
+            let (lhs, rhs) = coeffs.split_at(coeffs.len() / 2);
+            fold_coeffs(lhs, rest) + fold_coeffs(rhs, rest) * secure(*factor)
+        }
+    }
+}
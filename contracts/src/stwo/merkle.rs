@@ -0,0 +1,133 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::iter::Peekable;
+use stylus_sdk::alloy_primitives::{B256, U256};
+
+use crate::stwo::{blake2s::Blake2s, field::M31, poseidon252, reader::Reader};
+
+pub trait MerkleHasher {
+    /// Hashes a node from its children, if it has any, and the values of the columns
+    /// whose height puts them at this layer.
+    fn hash_node(children: Option<(B256, B256)>, values: &[M31]) -> B256;
+}
+
+pub struct Blake2sMerkleHasher;
+
+impl MerkleHasher for Blake2sMerkleHasher {
+    fn hash_node(children: Option<(B256, B256)>, values: &[M31]) -> B256 {
+        let mut hasher = Blake2s::new();
+        if let Some((left, right)) = children {
+            hasher.update(left.as_slice());
+            hasher.update(right.as_slice());
+        }
+        for value in values {
+            hasher.update(&value.as_canonical_u32().to_le_bytes());
+        }
+        B256::from(hasher.finalize())
+    }
+}
+
+/// Hashes nodes as Stark field elements: the children, then the values zero-padded to
+/// blocks of eight packed into one word each, with `poseidon_hash_many`.
+pub struct Poseidon252MerkleHasher;
+
+impl MerkleHasher for Poseidon252MerkleHasher {
+    fn hash_node(children: Option<(B256, B256)>, values: &[M31]) -> B256 {
+        let mut words = Vec::with_capacity(2 + values.len().div_ceil(8));
+        if let Some((left, right)) = children {
+            words.push(U256::from_be_bytes(left.0));
+            words.push(U256::from_be_bytes(right.0));
+        }
+        for block in values.chunks(8) {
+            let padding = core::iter::repeat_n(M31::ZERO, 8 - block.len());
+            let word = block
+                .iter()
+                .copied()
+                .chain(padding)
+                .fold(U256::ZERO, |word, x| {
+                    (word << 31) + U256::from(x.as_canonical_u32())
+                });
+            words.push(word);
+        }
+        B256::from(poseidon252::hash_many(&words).to_be_bytes::<32>())
+    }
+}
+
+/// Verifies a Stwo mixed-degree Merkle tree, where a column of `2^k` values is hashed
+/// into the nodes of the layer with `2^k` nodes.
+///
+/// `queried_values` lists, layer by layer from the leaves up and node by node, the values
+/// of that layer's columns at its queried nodes. Sibling hashes and the column values
+/// of nodes that were not queried are read from `proof` as they are needed.
+pub fn verify<H: MerkleHasher>(
+    proof: &mut Reader,
+    root: &B256,
+    column_log_sizes: &[usize],
+    queries: &BTreeMap<usize, Vec<usize>>,
+    queried_values: &[M31],
+) -> Option<()> {
+    let max_log_size = *column_log_sizes.iter().max()?;
+    let mut queried_values = queried_values.iter().copied();
+    let mut last_layer: Vec<(usize, B256)> = Vec::new();
+
+    for log_size in (0..=max_log_size).rev() {
+        let n_columns = column_log_sizes.iter().filter(|l| **l == log_size).count();
+        let mut previous = last_layer.iter().copied().peekable();
+        let mut column_queries = queries
+            .get(&log_size)
+            .map(|q| q.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .peekable();
+        if column_queries
+            .clone()
+            .zip(column_queries.clone().skip(1))
+            .any(|(a, b)| a >= b)
+        {
+            return None;
+        }
+
+        let mut layer = Vec::new();
+        while let Some(node) = next_node(&mut previous, &mut column_queries) {
+            if node >> log_size != 0 {
+                return None;
+            }
+            let children = if log_size == max_log_size {
+                None
+            } else {
+                let mut child = |index: usize| match previous.next_if(|(i, _)| *i == index) {
+                    Some((_, hash)) => Some(hash),
+                    None => proof.hash(),
+                };
+                Some((child(2 * node)?, child(2 * node + 1)?))
+            };
+            let values = if column_queries.next_if_eq(&node).is_some() {
+                (0..n_columns)
+                    .map(|_| queried_values.next())
+                    .collect::<Option<Vec<_>>>()?
+            } else {
+                (0..n_columns)
+                    .map(|_| proof.m31())
+                    .collect::<Option<Vec<_>>>()?
+            };
+            layer.push((node, H::hash_node(children, &values)));
+        }
+        last_layer = layer;
+    }
+
+    (queried_values.next().is_none() && last_layer.as_slice() == [(0, *root)]).then_some(())
+}
+
+/// The next node to recompute: the parent of the next hash from the layer below or the
+/// next queried node, whichever is smaller.
+fn next_node(
+    previous: &mut Peekable<impl Iterator<Item = (usize, B256)>>,
+    column_queries: &mut Peekable<impl Iterator<Item = usize>>,
+) -> Option<usize> {
+    match (previous.peek(), column_queries.peek()) {
+        (Some((i, _)), Some(q)) => Some((i / 2).min(*q)),
+        (Some((i, _)), None) => Some(i / 2),
+        (None, Some(q)) => Some(*q),
+        (None, None) => None,
+    }
+}
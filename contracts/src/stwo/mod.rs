@@ -0,0 +1,17 @@
+pub mod air;
+pub mod blake2s;
+pub mod channel;
+pub mod circle;
+pub mod config;
+pub mod errors;
+pub mod field;
+pub mod fri;
+pub mod merkle;
+pub mod poseidon252;
+pub mod reader;
+pub mod types;
+pub mod verifier;
+
+pub use errors::StwoError;
+pub use types::{StwoClaim, StwoPublicInput, StwoSessionProof};
+pub use verifier::{verify_stwo_proof, IStwoVerifier, StwoStepVerifier, StwoVerifier};
@@ -0,0 +1,570 @@
+//! Starknet's Poseidon hash over the Stark field `p = 2^251 + 17·2^192 + 1`, as used by
+//! Stwo's Poseidon252 channel and Merkle hasher.
+//!
+//! The permutation is Hades over a width-3 state with 4 + 4 full rounds around 83 partial
+//! rounds, the S-box `x^3` and the MDS matrix `[[3, 1, 1], [1, -1, 1], [1, 1, -2]]`. The
+//! round constant for state cell `j` of round `i` is `sha256("Hades" || (3i + j)) mod p`.
+
+use stylus_sdk::alloy_primitives::{uint, U256};
+
+pub const P: U256 = U256::from_limbs([1, 0, 0, 0x0800000000000011]);
+
+const HALF_FULL_ROUNDS: usize = 4;
+const PARTIAL_ROUNDS: usize = 83;
+
+const ROUND_CONSTANTS: [[U256; 3]; 2 * HALF_FULL_ROUNDS + PARTIAL_ROUNDS] = [
+    [
+        uint!(0x06861759EA556A2339DD92F9562A30B9E58E2AD98109AE4780B7FD8EAC77FE6F_U256),
+        uint!(0x03827681995D5AF9FFC8397A3D00425A3DA43F76ABF28A64E4AB1A22F27508C4_U256),
+        uint!(0x03A3956D2FAD44D0E7F760A2277DC7CB2CAC75DC279B2D687A0DBE17704A8309_U256),
+    ],
+    [
+        uint!(0x0626C47A7D421FE1F13C4282214AA759291C78F926A2D1C6882031AFE67EF4CD_U256),
+        uint!(0x078985F8E16505035BD6DF5518CFD41F2D327FCC948D772CADFE17BACA05D6A6_U256),
+        uint!(0x05427F10867514A3204C659875341243C6E26A68B456DC1D142DCF34341696FF_U256),
+    ],
+    [
+        uint!(0x05AF083F36E4C729454361733F0883C5847CD2C5D9D4CB8B0465E60EDCE699D7_U256),
+        uint!(0x07D71701BDE3D06D54FA3F74F7B352A52D3975F92FF84B1AC77E709BFD388882_U256),
+        uint!(0x0603DA06882019009C26F8A6320A1C5EAC1B64F699FFEA44E39584467A6B1D3E_U256),
+    ],
+    [
+        uint!(0x04332A6F6BDE2F288E79CE13F47AD1CDEEBD8870FD13A36B613B9721F6453A5D_U256),
+        uint!(0x053D0EBF61664C685310A04C4DEC2E7E4B9A813AAEFF60D6C9E8CAEB5CBA78E7_U256),
+        uint!(0x05346A68894845835AE5EBCB88028D2A6C82F99F928494EE1BFC2D15EAABFEBC_U256),
+    ],
+    [
+        uint!(0x0550A9E24176509EA7631CCAECB7A4AB8694AB61F238797098147E69DD91E5A3_U256),
+        uint!(0x0219DCCCB783B1CBAA62773FEDD3570E0F48AD3ED77C8B262B5794DAA2687000_U256),
+        uint!(0x04B085EB1DF4258C3453CC97445954BF3433B6AB9DD5A99592864C00F54A3F9A_U256),
+    ],
+    [
+        uint!(0x053E8A8E8A404C503AF2BF3C03E420EA5A465939D04B6C72E2DA084E5AABB78D_U256),
+        uint!(0x05CA045C1312C09D1BD14D2537FE5C19FB4049CB137FAF5DF4F9ADA962BE8CA8_U256),
+        uint!(0x07C74922A456802C44997E959F27A5B06820B1ED97596A969939C46C162517F4_U256),
+    ],
+    [
+        uint!(0x00C0BBA6880D2E686BF5088614B9684FF2526A20F91670435DC6F519BB7AB83F_U256),
+        uint!(0x04526BCAEC43E8EBD708DD07234C1B2DC1A6203741DECD72843849CD0F87934A_U256),
+        uint!(0x01CC9A17B00D3607D81EFAEA5A75A434BEF44D92EDC6D5B0BFE1EC7F01D613ED_U256),
+    ],
+    [
+        uint!(0x0028B1E269B84C4012AA8CDBEAD0BC1CE1EB7284E2B28ED90BC7B4A4FDE8F01F_U256),
+        uint!(0x062AF2F41D76C4AD1D9A2482FBDAF6590C19656BCB945B58BB724DC7A994498D_U256),
+        uint!(0x05CFD7E44946DAA6B2618213B0D1BF4A2269BED2DC0D4DBF59E285EEE627DF1A_U256),
+    ],
+    [
+        uint!(0x07FF2AFB40F3300856FDD1B94DA8D3BBCF0312AB9F16AC9BC31955DC8386A747_U256),
+        uint!(0x05CD236BDC15B54183E90BAB8AE37F8AAB40EFAE6FA9CD919B3248EE326E929C_U256),
+        uint!(0x005463841390E22D60C946418BF0E5822BD999084E30688E741A90BBD53A698A_U256),
+    ],
+    [
+        uint!(0x024C940FFF3FE8C8B2021F13EB4D71747EFD44A4E51890AE8226E7406144F805_U256),
+        uint!(0x04E50CB07B3873268DC88F05393D9D03153CA4C02172DD1D7FC77D45E1B04555_U256),
+        uint!(0x062CA053E4DA0FC87B430E53238D2BAB1D9B499C35F375D7D0B32E1189B6DCB5_U256),
+    ],
+    [
+        uint!(0x0719F20AC59D1EBCAAF37FE0B851BC2419CD89100ADFF965951BFF3D3D7E1191_U256),
+        uint!(0x07645CA5E87A9F916A82FE5BB90807F44050AC92CA52F5C798935CF47D55A8FD_U256),
+        uint!(0x015B8AEACA96AB53200EED38D248ECDA23D4B71D17133438015391CA63663767_U256),
+    ],
+    [
+        uint!(0x0053D94DBBCA7CB2AA8252F106292AC3B98799E908F928C196C1B658BF10B2E2_U256),
+        uint!(0x028F90B403E240F1C6F4C0A3B70EDBB3942B447C615C0F033913831C34DE2D1E_U256),
+        uint!(0x02485167DC233BA6E1161C4D0BF025159699DD2FEB36E3E5B70AE6E770E22081_U256),
+    ],
+    [
+        uint!(0x01C8B08A90D6EE46FF7DE548541DD26988F7FDAACDD58698E938607A5FECA6E8_U256),
+        uint!(0x0105C3BF5CBA256466B75E79D146F9880C7C4DF5ECDAD643CE05B16901C4881E_U256),
+        uint!(0x0238019787F4CC0B627A65A21BEF2106D5015B85DFBD77B2965418B02DBC6BD7_U256),
+    ],
+    [
+        uint!(0x015E624D7698FDF9B73DCE29A5F24C465C15B52DEC8172923A6EBC99A6DDC5E1_U256),
+        uint!(0x05D3688BA56F34FDF56BC056AD8BF740CA0C2EFEF23B04A479F612FDE5800A0A_U256),
+        uint!(0x0229ABDEF3FEF7AE9E67ED336E82DC6C2E26D872D98B3CCE811C69AE363B444D_U256),
+    ],
+    [
+        uint!(0x03E8096ECFCBCDE2EE400801A56F236DB2C43D1E33C92B57AC58DAF2D3FC44DB_U256),
+        uint!(0x03AD5FEC670D7039108D605AAE834C7CE6A7CD4E1B47BF6A02265352C57DB9BD_U256),
+        uint!(0x07CF4598C0CF143875877AFDBB4DF6794EF597FFF1F98557ADCA32046AEAEF0A_U256),
+    ],
+    [
+        uint!(0x058AECC0081B55134A4D1C4C8F27932E4170C37841FEF49ACA0EC7A123C00AD6_U256),
+        uint!(0x0757B4B7EE98E0A15460B71995790396E4EF3C859DB5B714EC09308D65D2CA61_U256),
+        uint!(0x06B82800937F8981F3CD974F43322169963D2B54FD2B7ED348DC6CC226718B5D_U256),
+    ],
+    [
+        uint!(0x003A915B1814707273427E34AB8FBB7CA044F14088FEDAE9606B34A60B1E9C64_U256),
+        uint!(0x054AFBF1BD990043F9BC01028FF44195C0BB609D367B76269A627689547BFBEF_U256),
+        uint!(0x05E1CEB846FE1422B9524C7D014931072C3852DF2D991470B08375EDF6E762BB_U256),
+    ],
+    [
+        uint!(0x07F751F98968212EBE5DFF3CE06E8CB916709E0C48E3020C6B2B01C1BEC0814B_U256),
+        uint!(0x036F6B64463F7C29FC3180616E340536BEA7F01D226B68B6D45CD6DFBFF811E4_U256),
+        uint!(0x061135C9846FAF39B4511D74FE8DE8B48DD4D0E469D6703D7ED4FE4FE8E0DBAC_U256),
+    ],
+    [
+        uint!(0x00B58921A3FBDBB559B78F6ACFCA9A21A4BA83CC6E0AE3527FBAAD907FC912B8_U256),
+        uint!(0x022A4F8A5CDC7474B9D16B61C2973847211D84EB2FB27B816E52821C2E2B1B1E_U256),
+        uint!(0x041CF6DB5D6145EDFECCBBC9A50B2CEEDEB1765C61516FFCB112F810AD67036F_U256),
+    ],
+    [
+        uint!(0x00BE44689973DB2B1CFC05FA8F4AEC6FAC6A0FF2FDFAB744ADE9DE11416B6831_U256),
+        uint!(0x039BF209C4E117E16489CDA45128096D6D148A237142DC4951DF0B8239BE148B_U256),
+        uint!(0x0209CF541E5F74FC2B93310B8CE37B092A58282643860B5707C7EB980EA03A06_U256),
+    ],
+    [
+        uint!(0x06B562E6005F34EE0BDC218BA681B6BA7232E122287036D18C22DD5AFA95326D_U256),
+        uint!(0x000E8103A23902BE5DC6D5F59253A627A2A39C8ACA11A914670E7A35DEA38C8F_U256),
+        uint!(0x06A3725548C664FD06BDC1B4D5F9BED83EF8CA7468D68F4FBBF345DE2D552F72_U256),
+    ],
+    [
+        uint!(0x067FCD6997472E8E605D0F01A8ECCC5F11A45C0AA21EB4EBB447B4AF006A4A37_U256),
+        uint!(0x026144C95C8DE3634075784D28C06C162A44366F77792D4064C95DB6ECB5CFF0_U256),
+        uint!(0x05B173C8B0EB7E9C4B3A874EB6307CDA6FD875E3725061DF895DC1466F350239_U256),
+    ],
+    [
+        uint!(0x07E1C2D6FDE8AC9F87BAE06AD491D391C448F877E53298B6370F2165C3D54DDB_U256),
+        uint!(0x04DB779F3E5B7424996F451B156FE4E28F74D61E7771F9E3FA433B57CA6627A9_U256),
+        uint!(0x00BB930D8A6C6583713435EC06B6FED7825C3F71114ACB93E240EED6970993DD_U256),
+    ],
+    [
+        uint!(0x04472D73B2830565D708467E9296FB5599D3A08814C31C4189E9579C046E878F_U256),
+        uint!(0x07BA9C303DFEE2D89E10E3C883CA5CE5614D23739B7CB2052CC23612B11170E2_U256),
+        uint!(0x021C0E3319EDE47F0425DC9B2C1ED30E6356CB133E97579B822548EB9C4DC4B7_U256),
+    ],
+    [
+        uint!(0x02CFD61139E50DDD37B09933816E2A0932E53B7DC4F4947565C1D41E877EB191_U256),
+        uint!(0x05ABEA18941A4976844544D92EE0ECA65BDD10B3F170B0DC2F30ACD37E26D8E7_U256),
+        uint!(0x077088FDB015C7947A6265E44FEF6F724EA28AE28B26E6EEE5A751B7CE6BCC21_U256),
+    ],
+    [
+        uint!(0x03ABDC9D677231325B3E3C43CFD443076B4CE33CDDBC8446120DCE84E6122B73_U256),
+        uint!(0x02250F430B7FE7D12E5D00B6B83E52A52CA94879CCFAB81A7A602662C2D62C4D_U256),
+        uint!(0x05C92EF479C11BB51FB24EF76D57912B12660E7BD156D6CABBB1EFB79A25861B_U256),
+    ],
+    [
+        uint!(0x0235EC597391648B510F616FA8B87900FD08FD4208A785CFFCF784A63A0FD5C6_U256),
+        uint!(0x04ED4E872EB7E736207BE77E9D11E38F396B5C0BA3376E855523C00B372CC668_U256),
+        uint!(0x05F9406FEBCA3879B756EF3F6331890B3D46AFA705908F68FB7D861C4F275A1B_U256),
+    ],
+    [
+        uint!(0x01D9C501D9FF1FBA621A9F61B68873C05F17B0384661F06D97EDF441ABDAA49D_U256),
+        uint!(0x04B0DE22BBD0A58534982C8E28D2F6E169E37BA694774C4DFA530F41C535952E_U256),
+        uint!(0x01B4D48BD38A3F8602186AABB291ECA0D319F0E3648B2574C49D6FD1B033D903_U256),
+    ],
+    [
+        uint!(0x07558BBEA55584BF1725D8AA67DDBA626B6596BBD2F4E65719702CEFCEAD4BAB_U256),
+        uint!(0x01108F1A9500A52F561EA174600E266A70B157D56ECE95B60A44CF7A3EEF17BE_U256),
+        uint!(0x008913D96A4F36B12BECB92B4B6AE3F8C209FB90CAAB6668567289B67087BF60_U256),
+    ],
+    [
+        uint!(0x06502262C51AD8F616926346857DEC8CCA2E99F5742B6BF223F4D8A6F32867A6_U256),
+        uint!(0x07CB5FCDC00892812889280505C915BDE962EA034378B343CD3A5931D2EC0E52_U256),
+        uint!(0x02EB919524A89A26F90BE9781A1515145BAEA3BC96B8CD1F01B221C4D2A1CE87_U256),
+    ],
+    [
+        uint!(0x058EFB6272921BC5EADA46635E3567DCED0662C0161223E3C1C63E8DE3EC3D73_U256),
+        uint!(0x062FCD49CA9C7587B436D205FFC2A39594254A1AC34ACD46D6955E7844D4F88E_U256),
+        uint!(0x0635895330838846E62D9ACCE0B625F885E5941E54BD3A2106FCF837AEF5313B_U256),
+    ],
+    [
+        uint!(0x07DA445B81E9B3D36D47A5F4D23B92A378A17F119D5E6E70629F8B41FEFB12E3_U256),
+        uint!(0x02B22DAB62F0817E9FC5737E189D5096A9027882BEF1738943B7016256118343_U256),
+        uint!(0x01AF01472348F395BACDFED1D27664D0D5BDEA769BE8FCB8FBEF432B790E50D5_U256),
+    ],
+    [
+        uint!(0x076B172DBBEEC5A31DE313B9390F79EC9284163C8E4986BC5B682E5AC6360309_U256),
+        uint!(0x0070EFAEAE36F6AF0F362F6CB423D2009B30DDB4178D46DEF0BDB2905B3E0862_U256),
+        uint!(0x006CB99B36E521AC0A39872686B84EE1D28C4942B8036A1C25A0E4117CCAEEDF_U256),
+    ],
+    [
+        uint!(0x029FD44305A5A9A70BBF9674E544BDA0FB3D0FE5BB3AA743FD1B8A4FC1DC6055_U256),
+        uint!(0x006B447DED1046E83629B184D8C36DB3A11A6778D8848142AA6363D6619F9764_U256),
+        uint!(0x0642A8B4BE4BA812CBFCF55A77339B5D357CCEB6946FDC51C14B58F5B8989B59_U256),
+    ],
+    [
+        uint!(0x0489E0A26F65A1EECC6CC6AA5B6E775CBC51A73700BD794A7ACD79AE1D95882A_U256),
+        uint!(0x03B19D4EF195975BBF78AB5DC2FD1D24816428F45A06293C1B9D57B9A02E9200_U256),
+        uint!(0x07D2DD994756EACBA576B74790B2194971596F9CD59E55AD2884C52039013DF5_U256),
+    ],
+    [
+        uint!(0x01922810CC08F50BF300DF869823B9F18B3327E29E9E765002970EF0F2E8C5F3_U256),
+        uint!(0x052F3AFAF7C9102F1D46E1D79A70745B39C04376AAFFF05771CBD4A88ED418AC_U256),
+        uint!(0x07CCFC88E44A0507A95260F44203086E89552BBE53DCC46B376C5BCAB6EA788E_U256),
+    ],
+    [
+        uint!(0x02949125939E6AD94100228BEFF83823F5157DD8E067BC8819E40A1AB008DD9C_U256),
+        uint!(0x06CB64E3A0D37A6A4273CE4EE6929BA372D6811DDE135AF4078BA6E1912E1014_U256),
+        uint!(0x00D63B53707ACF8962F05F688129BF30AD43714257949CD9DED4BF5953837FAE_U256),
+    ],
+    [
+        uint!(0x00BCB1549C9CABB5D13BB968B4EA22D0BB7D7460A6965702942092B32EF152D4_U256),
+        uint!(0x03D1C5233657CE31F5EAD698FE76F6492792A7205BA0531A0CA25B8D8FE798C1_U256),
+        uint!(0x02240B9755182EE9066C2808B1E16EA448E26A83074558D9279F450B79F97516_U256),
+    ],
+    [
+        uint!(0x00CC203D8B0F90E30FE8E54F343CEF59FE8D70882137DE70C9B43AB6615A646C_U256),
+        uint!(0x0310C6CC475D9346E061BACDC175EA9E119E937DEA9D2100FA68E03C1F77910B_U256),
+        uint!(0x07F84B639F52E57420BC947DEFCED0D8CBDBE033F578699397B83667049106C7_U256),
+    ],
+    [
+        uint!(0x0584CA7F01262C5BD89C4562F57139F47E9F038CB32EC35ABE4E1DA8DE3E164A_U256),
+        uint!(0x01135EEFAF69B6E4AF7D02F562868BE3E02FDC72E01E9510531F9AFA78ABBBDE_U256),
+        uint!(0x0372082B8A6C07100A50A3D33805827AD350C88B56F62C6D36A0D876856A99E8_U256),
+    ],
+    [
+        uint!(0x07C3C12B819A8AAD87499BAC1A143FC59674F132E33898F0C119E3D12462DFE6_U256),
+        uint!(0x04F1354C51E8F6905B84157CFEFF6822C056CE9E29D602EB46BD9B75A23836CF_U256),
+        uint!(0x02DA9F26A8271659075739BA206507A08AC360150E849950EF3973548FBD2FCA_U256),
+    ],
+    [
+        uint!(0x0287173956A2BEB111B5EC29195E38CC3F6A65FF50801AA75FD78DD550702843_U256),
+        uint!(0x07273101C190FF64212420095A51C8411C7F3227F6A7A4A64AE6BA7F9201E126_U256),
+        uint!(0x02DBF2A6B56B26D23EBEB61E500687DE749B03D3D349169699258EE4C98005FC_U256),
+    ],
+    [
+        uint!(0x0085B6CBB29739A6808E67F00AB89B52AB89EF8D92530394E4B910EFD706C7FB_U256),
+        uint!(0x03D55B5F1171EFDA1DACBCBADFD5B910B493FA9589FD937E3E06CE26B08925A3_U256),
+        uint!(0x00AAEDAA6EF2FA707D16B3B295410C0E44F7A2F8135C207824F6AE2A9B16E90C_U256),
+    ],
+    [
+        uint!(0x06ACA6EBF70B1CB46C6331E9F1A5C4CC89B80F8ADC5D18915C1CD0D496CCF5E1_U256),
+        uint!(0x001678602AF36C28ABB010F831D403D94D5E90003E6D37C677E9DD157FB27761_U256),
+        uint!(0x02022036BDF687F041B547FEFDF36D4C2CD3F4B0526A88AAFE60A0A8F508BAD2_U256),
+    ],
+    [
+        uint!(0x007BFC350957C968CA664397414BDFB8F9B8DFE49FB63E32353D4E2E8D1D4AF6_U256),
+        uint!(0x02D639CBD418CB9FC24EA29CCD1D15AB81F43A499B27A06D3C5E2176F7AD79AF_U256),
+        uint!(0x00ECDEA7F959A4D488403D5B39687A1FE0DEE3369E5FBC0F4779569F64506E0C_U256),
+    ],
+    [
+        uint!(0x03F656BDC4FEFD92B70658E2F1992EF9F22E5F2D28C490E21D4E34357154B558_U256),
+        uint!(0x00D1B8CB1561EED32319638CCAB9033DFEC47596F8A6F4CE6594E19FDDD59254_U256),
+        uint!(0x0758FFC77C62E3E0F86EF6EA01545AD76F281EC2941DA7222D1E8B4E2EC1F192_U256),
+    ],
+    [
+        uint!(0x020315CA079570DF995386E96AEAA1B4596AACD28F83C32F29A591C95E6FCAC5_U256),
+        uint!(0x03E55CF341E7C280CB05F3D6FF9C8D9F2CFE76B84A9D1B0F54884B316B740D8D_U256),
+        uint!(0x04D56FEB32CDE74FEEDE9749739BE452E92C029007A06F6E67C81203BF650C68_U256),
+    ],
+    [
+        uint!(0x04EE807AA678A9A433B6171EAA6A2544497F7599FB8145D7E8089F465403C89B_U256),
+        uint!(0x025D2BACC8F1EE7548CB5F394DE2CB6E1F365E56A1BC579D0F9A8AD2EF2B3821_U256),
+        uint!(0x05F573DE597CE1709FC20051F6501268CD4B278811924AF1F237D15FEB17BD49_U256),
+    ],
+    [
+        uint!(0x030297C3C54A505F5826A280E053CF7A3C1E84A1DCF8B33C682CF85DDAC86DEB_U256),
+        uint!(0x02F5E9C47C9A86E043C7526A59783F03C6BC79B69B8709FE6A052B93A8339AE8_U256),
+        uint!(0x01BF75C7A739DA8D29F9C23065FF8CCB1DA7DEEC83E130BCD4A27A416C72B84B_U256),
+    ],
+    [
+        uint!(0x060563D5F852AE875989017BD5C4CFDC29CD27FC4E91EEABDB8E864DF3C3C675_U256),
+        uint!(0x07A4B1D70885AA820969635468DAEC94F8156C20E3131BD71005BE1CD16CCF9E_U256),
+        uint!(0x0347BB025695E497F1E201CD62AA4600B8B85CF718CD1D400F39C10E59CC5852_U256),
+    ],
+    [
+        uint!(0x06783AB1E1EF97BB9E7F9381EB6AB0DE2C4C9C2DE413691BA8AA666292E9E217_U256),
+        uint!(0x0133E0280C6DE90E7B3870A07823C081FD9C4CB99D534DEBD6A7BFB4E5B0DD46_U256),
+        uint!(0x00865D450CE29DC42FB5DB72460B3560A2F093695573DFF94FD0216EB925BEEC_U256),
+    ],
+    [
+        uint!(0x01DE023F840E054A35526DABACF0DEE948EFBA06BCBB414ECD81A6B301664E57_U256),
+        uint!(0x0055FC1E341BFDF7805015A96F724C5AC7CC7B892A292D38190631AB1A5388C4_U256),
+        uint!(0x02DF6557BFD4A4E7E7B27BF51552D2B5162706A3E624FACA01A307EF8D532858_U256),
+    ],
+    [
+        uint!(0x0113A8A66962CE08D92A6BD3E9C1D55EF8F226DA95E4D629046D73D0507F6271_U256),
+        uint!(0x0271577D6EE9FA377F2C889874BA5B44CA1076033DB5C2DE4F3367B08C008E53_U256),
+        uint!(0x03396B33911219B6B0365C09348A561EF1CCB956FC673BC5291D311866538574_U256),
+    ],
+    [
+        uint!(0x01E1392F2DA08549C8A7D89E899189306170BAA3C3436E6A5398F69C8F321636_U256),
+        uint!(0x0661545081032013DF118E1D6E7C61A333E313B1A9A5B6D69C876BD2E7D694CA_U256),
+        uint!(0x06B14294E71CD7FB776EDBD432D20EB8F66D00533574E46573516F0CACDEEC88_U256),
+    ],
+    [
+        uint!(0x07252FBBB06C2848338B1C41DF31E4E51FE2A18E2406C671915CAB6EB1A1D4F2_U256),
+        uint!(0x03CCF71BE7CC2A9ABCF5A09807C69679430C03645747621B7F5327CB00FF99DA_U256),
+        uint!(0x029778DC707504FA6A9F7C97B4CEEF0A9B39001D034441617757CD816DAC919A_U256),
+    ],
+    [
+        uint!(0x039473F6F06BB99E33590D34E3BAE36E491F7BBF86A26AA55A8F5B27BB98D4C5_U256),
+        uint!(0x07BA7C32F875B71B895CAA0215F996FD4AD92BAB187E81417063DDE91C08C027_U256),
+        uint!(0x037C1367E49CBFC403B22AAC82ABF83B0ED083148A5F4C92839E5D769BDAB6B6_U256),
+    ],
+    [
+        uint!(0x05C9EB899931D2F4B53FFCF833CDFA05C2068375FF933EB37AE34157C0B2D951_U256),
+        uint!(0x05F6054A4D48698EC27772FB50A7D2E5C1557FFDC1FFD07331F2CA26C6E3B661_U256),
+        uint!(0x020E6D62A2FE0FE9B0FAB83E8C7D1E8BFD0FEC827960E40A91DF64664DCD7774_U256),
+    ],
+    [
+        uint!(0x06290A56A489AD52120C426FE0E409C2FF17ADF51F528CAFB0D026D14FFD6AAC_U256),
+        uint!(0x03703F16F990342C2267A6F7ECE342705A32CA4C101417286279F6FC315EDC7C_U256),
+        uint!(0x05194962DAF6679B9A0C32B5A9A307BA92E2C630F70E439195B680DD296DF3FD_U256),
+    ],
+    [
+        uint!(0x00E8EAE20A79A7C1242C34617B01340FB5FD4BEA2AA58B98D2400D9B515EE5E2_U256),
+        uint!(0x0369058169D63091AE28BFB28DEF7CD8D00DD7C2894FAE4FFEC65242AFA5CD45_U256),
+        uint!(0x0418C963BC97195A74077503EE472F22CFDFF0973190AB189C7B93103FD78167_U256),
+    ],
+    [
+        uint!(0x068D07A3EEFC78DC5B28B3F4DC93167FB8C97112D14A25B4D4DB559720156386_U256),
+        uint!(0x0517E892228DF2D4F15A3C4241C98BA25BA0B5557375003F8748583A61836372_U256),
+        uint!(0x05CC0F0F6CF9BE94A150116E7932F8FE74AC20AD8100C41DC9C99538792E279B_U256),
+    ],
+    [
+        uint!(0x053D5D7863434C6629BDB1F8A648E4820883543E821F0F5C1668884C0BE41EC8_U256),
+        uint!(0x00A158126B89E6B0A600BF53F8101707B072218912DD0D9DF2528F67DE24FDF5_U256),
+        uint!(0x06B53B807265387EE582069A698323D44C204BED60672B8D8D073BED2FEDE503_U256),
+    ],
+    [
+        uint!(0x01097FB448406B7A6DE0877EFD58C01BE53BE83BDE9601A9ACC9E0CA2091FDA0_U256),
+        uint!(0x00CBC0FF7239D3763902396389D67B3049CE1FEFDE66333CE37CA441F5A31BEC_U256),
+        uint!(0x079A3D91DD8A309C632EB43D57B5C5D838CEEBD64603F68A8141EBEF84280E72_U256),
+    ],
+    [
+        uint!(0x0023FB472FE575135300F74E8F6DE8FE1185078218ECEB938900E7598A368DB9_U256),
+        uint!(0x07AC73134016D2A8A4C63A6B9494C0BD7A6BA87CC33E8A8E23EBDA18BFB67C2A_U256),
+        uint!(0x019A16068C3EAC9C03F1B5C5EE2485CCC163D9AB17BB035D5DF6E31C3DCF8F14_U256),
+    ],
+    [
+        uint!(0x01F24B4356A6BBFD4D4EF9FD1634752820EE86A925725AC392134D90DEF073EA_U256),
+        uint!(0x0003E44E7F7AEEA6ADD59B6B4D11C60A528FB70727F35D817305971592333D36_U256),
+        uint!(0x05F93B02F826741414535A511ED3EB4FE85987AE57BC9807CBD94CD7513D394E_U256),
+    ],
+    [
+        uint!(0x00F0A0A88DB99247D71C3D51D4197FA3FD1CC76E670607E35CA2D3BADA29523A_U256),
+        uint!(0x03432226916D31F3ACAC1E211431FD4CD2B6F2E80626AF6564BDDE3E77608DB0_U256),
+        uint!(0x055625941BFEA6F48175192845A7AD74B0B82940EF5F393CA3830528D59CF919_U256),
+    ],
+    [
+        uint!(0x00DDF48695B204477DFE4F8CB3EF1B39783E9B92F9276B858E2E585E318E20A4_U256),
+        uint!(0x0260730A657FF8F38851A679AB2A1490434EE50D4953E7C5D3194578B08AE8E3_U256),
+        uint!(0x04CFD231373AA46D96283840BDB79BA6D7132775B398D324BCD206842B961AA9_U256),
+    ],
+    [
+        uint!(0x03203843C41CD453F14FA0BC0B2191A27EBC659E74FD48F981E963DE57EFF25D_U256),
+        uint!(0x0002C2F6AE5624D1FB8435D1C86BF76C260F5E77A54B006293705872E647CC46_U256),
+        uint!(0x0780225456E63903B3E561384EF2E73A85B0E142B69752381535022014765F06_U256),
+    ],
+    [
+        uint!(0x07F602EC1A80A051FD21B07F8E2960613082FC954B9A9FF641CC432A75C81887_U256),
+        uint!(0x062561B0A0A72239B60F6AAF7022B7D323FE77CD7C1AB432F0C8C118CA7E6BCA_U256),
+        uint!(0x0604FE5A6A22344AA69B05DEA16B1CF22450C186D093754CB9B84A8A03B70BC8_U256),
+    ],
+    [
+        uint!(0x01CF9987A4044716D3DC140BF5F9B76F6EADA5995905189F8682EAF88AEF2B7B_U256),
+        uint!(0x06BC0B2487C1EECE3DB47A4BDD60CF69DEBEE233E91B50E9EE42CE22CBFBACBF_U256),
+        uint!(0x02F5DBB5055EB749A11403B93E90338B7620C51356D2C6ADCBF87AB7EA0792E6_U256),
+    ],
+    [
+        uint!(0x0446328F4DDDAE6529743C43883D59C45F63B8A623A9CF318489E5FC4A550F61_U256),
+        uint!(0x04BA30C5240CDE5BCA6C4010FB4B481A25817B43D358399958584D2C48F5AF25_U256),
+        uint!(0x05F5275F76425B15C89209117734AE85708351D2CF19AF5FE39A32F89C2C8A89_U256),
+    ],
+    [
+        uint!(0x0576F3B5156F4763E18C7F98DF3B2F7B993CDDA4EB8CB92415E1BE8E6AF2FC17_U256),
+        uint!(0x011DC3F15CBA928AED5A44B55A5B026DF84A61719ED5ADBB93C0E8E12D35EF3D_U256),
+        uint!(0x044C40E6BD52E91AD9896403AE4F543AE1C1D9EA047D75F8A6442B8FEDA04DCA_U256),
+    ],
+    [
+        uint!(0x01836D733A54013EBD0CCBF4974E80AC1954BF90FE9EA4E2C914AD01166026D8_U256),
+        uint!(0x03C553BE9776B628A8159D306EF084727611DF8037761F00F84CA02CE731B3AC_U256),
+        uint!(0x006CE94781C1A23FDA1C7B87E0436B1B401AE11A6D757843E342F5017076A059_U256),
+    ],
+    [
+        uint!(0x0381EC71FBDEF3160253BE9F00F4E6B9E107F457812EFFB7371CC2DAA0ACD0ED_U256),
+        uint!(0x01844DA9CC0EEADC6490D847320D9F3CD4FB574AA687BAFDFE0FFA7BF2A8F1A1_U256),
+        uint!(0x07A8BF471F902D5ABB27FEA5B401483DEDF97101047459682ACFD7F9B65A812F_U256),
+    ],
+    [
+        uint!(0x0633B6FB004DE62441915FB51AC174456F5A9CDFF7AECB6E6B0D063839E56327_U256),
+        uint!(0x0179EE5CEC496194771200382BFC6D17BBE546BA88FED8B17535FD70FBC50AB6_U256),
+        uint!(0x02806C0786185986EA9891B42D565256B0312446F07435AC2CAE194330BF8C42_U256),
+    ],
+    [
+        uint!(0x0438703D948708AE90C7A6B8AF194B8B603BB2CDFD26BFA356AC9BB6EE041393_U256),
+        uint!(0x024446628F56029D7153BD3A482B7F6E1C56F4E02225C628A585D58A920035AF_U256),
+        uint!(0x04C2A76E5CE832E8B0685CDEEEA3A253AE48F6606790D817BD96025E5435E259_U256),
+    ],
+    [
+        uint!(0x078A23323520994592933C079B148AED57D5E4CE1AB122D370983B8CAA0E0300_U256),
+        uint!(0x079CA6C5E1025B2151144EA5937DD07CADCE1AA691B19E6DB87070BA51EC22C0_U256),
+        uint!(0x06B2E4A46E37AF3CF952D9D34F8D6BD84A442EBFD1AC5D17314E48922AF79C5D_U256),
+    ],
+    [
+        uint!(0x00305D6CD95CC2EAB6805D93D3D8D74E1CA7D443F11E34A18E3529E0D03435C2_U256),
+        uint!(0x06097B4B8B90DB14B39743ED23F8956CABB7AEA70CC624A415C7C17B37FBF9A9_U256),
+        uint!(0x0064E1B3F16C26C8845BDB98373E77DAD3BDCC90865B0F0AF96288707C18893F_U256),
+    ],
+    [
+        uint!(0x0649FAFE673F21E623384D841221B73421C56014AF2FFDF57F1579AE911FD335_U256),
+        uint!(0x07D806DCCBF1A2696B294404E849722F2BAA2F4D19005A49D1BA288A77FEFE30_U256),
+        uint!(0x05951A37DA53E3BBC0B3E2DB1A9A235D7A03F48F443BE6D659119C44AAFC7522_U256),
+    ],
+    [
+        uint!(0x06D87FA479FB59524D1912C3554AE3D010496A31BDACB542C816A1607A907731_U256),
+        uint!(0x01451CCCD4200FA9D473AD73466B4E8C0A712A0B12BB6FC9462A3AC892ACC9B2_U256),
+        uint!(0x03CA1B6400B3E51007642535F1CA9B03832CA0FAA15E1C4ED82DD1EFDC0763DA_U256),
+    ],
+    [
+        uint!(0x052C55735B2F0A6560AD1516A8F13592B0DD024FF4162539F993A99C7A1A4D95_U256),
+        uint!(0x07E04DE60AA80132F0149D1DEE29617DE750BD5CE3E9FA5E62951D65F6B924CD_U256),
+        uint!(0x00271784E6920A68E47C4C8FAB71C8F8303EF29E26F289223EDF63291C0A5495_U256),
+    ],
+    [
+        uint!(0x05C7C19061A84D5960A04B8F0ADAA603C8AFE93F17B7F0E56B49514AF43D0C69_U256),
+        uint!(0x0172DB5AFFE783AF419DA337CB79061E090943C2959DEA1B38E4436F5482EAFE_U256),
+        uint!(0x0518B7975A6D8D310EAC9FE4082916F021A7ECBADF18809746A9E061A2CB9456_U256),
+    ],
+    [
+        uint!(0x020C5539DC45DD56D4BBC2440A9F5061D74B8AE5E37B34E8755A0315F1E196DB_U256),
+        uint!(0x01EA6F5FB309FA4A08BC7D516E80EFC3A977B47208283CF35A9D8BC213B90B14_U256),
+        uint!(0x050CE323C5128DC7FDD8DDD8BA9CFE2EFD424B5DE167C7257D1F766541E29DED_U256),
+    ],
+    [
+        uint!(0x0401E37D0E276547695538B41D3C28215B865F5B7D1B497A8919284C613CB7D8_U256),
+        uint!(0x0645A0DE30ACC3117F2893056FC5880255DAA12CC61261CC0FAB9CF57C57397B_U256),
+        uint!(0x069BC3841EB0A310D9E988D75F09F698D4FDC9D0D69219F676B66AE7FA3D495B_U256),
+    ],
+    [
+        uint!(0x002684BBE315AD2C4BDD47C38FE72DB47CF0AE0C455CDA5484BAF523F136BDC6_U256),
+        uint!(0x011E0F83C547CA5C68202E8D34E5595A88858C2AFA664365E4ACB821FD8A13EE_U256),
+        uint!(0x04AF4A7635F8C7515966567CEEC34315D0F86AC66C1E5A5ECAC945F1097B82EF_U256),
+    ],
+    [
+        uint!(0x04FBA58CF8AAF4893CB7158908CCC18B1DC48894D2BB46225C72B11F4C74B271_U256),
+        uint!(0x0397C4C169115B468CC90DA2E664F8C29A7F89BE0EAD679A38B0F44C8A2A0E20_U256),
+        uint!(0x006563B9EBB6450DBAD397FA5DD13C501F326DD7F32BE22E20998F59EC7BACFF_U256),
+    ],
+    [
+        uint!(0x0376EDB238F7B630EA81D307F4C79F9AFEC48562076DD09C36CD79E9CB817165_U256),
+        uint!(0x060D4208BB50EB15F29ED22ADDCD50A1B337504039690EB858584CDA96E2E061_U256),
+        uint!(0x06A37D569D2FBC73DBFF1019DC3465EC0F30DA46918AB020344A52F1DF9A9210_U256),
+    ],
+    [
+        uint!(0x00D3B174C7290C6BF412083FF35D23821DC512F1DF073C1B429130371AC63B1A_U256),
+        uint!(0x0226ED3D763477454B46EB2A5C3B814634D974919689FB489FE55E525B980373_U256),
+        uint!(0x05F3997E7DAFCB2DE0E7A23D33D2FD9EF06F4D79BD7FFA1930E8B0080D218513_U256),
+    ],
+    [
+        uint!(0x07C5EEC716D94634434DF335A10BBAC504F886F7F9D3C1648348C3FAE8FDF14D_U256),
+        uint!(0x0053CC30D7FE0F84E7E24FD22C0F9AD68A89DA85553F871EF63D2F55F57E1A7C_U256),
+        uint!(0x0368821EE335D71819B95769F47418569474A24F6E83B268FEFA4CD58C4EC8FA_U256),
+    ],
+    [
+        uint!(0x005334F75B052C0235119816883040DA72C6D0A61538BDFFF46D6A242BFEB7A1_U256),
+        uint!(0x05D0AF4FCBD9E056C1020CCA9D871AE68F80EE4AF2EC6547CD49D6DCA50AA431_U256),
+        uint!(0x030131BCE2FBA5694114A19C46D24E00B4699DC00F1D53BA5AB99537901B1E65_U256),
+    ],
+    [
+        uint!(0x05646A95A7C1AE86B34C0750ED2E641C538F93F13161BE3C4957660F2E788965_U256),
+        uint!(0x04B9F291D7B430C79FAC36230A11F43E78581F5259692B52C90DF47B7D4EC01A_U256),
+        uint!(0x05006D393D3480F41A98F19127072DC83E00BECF6CEB4D73D890E74ABAE01A13_U256),
+    ],
+    [
+        uint!(0x062C9D42199F3B260E7CB8A115143106ACF4F702E6B346FD202DC3B26A679D80_U256),
+        uint!(0x051274D092DB5099F180B1A8A13B7F2C7606836EABD8AF54BF1D9AC2DC5717A5_U256),
+        uint!(0x061FC552B8EB75E17AD0FB7AAA4CA528F415E14F0D9CDBED861A8DB0BFFF0C5B_U256),
+    ],
+];
+
+fn add(a: U256, b: U256) -> U256 {
+    a.add_mod(b, P)
+}
+
+fn sub(a: U256, b: U256) -> U256 {
+    a.add_mod(P - b, P)
+}
+
+fn cube(x: U256) -> U256 {
+    x.mul_mod(x, P).mul_mod(x, P)
+}
+
+pub fn permute(state: &mut [U256; 3]) {
+    let partial_rounds = HALF_FULL_ROUNDS..HALF_FULL_ROUNDS + PARTIAL_ROUNDS;
+    for (round, constants) in ROUND_CONSTANTS.iter().enumerate() {
+        for (cell, constant) in state.iter_mut().zip(constants) {
+            *cell = add(*cell, *constant);
+        }
+        if partial_rounds.contains(&round) {
+            state[2] = cube(state[2]);
+        } else {
+            for cell in state.iter_mut() {
+                *cell = cube(*cell);
+            }
+        }
+        let [a, b, c] = *state;
+        let sum = add(add(a, b), c);
+        *state = [
+            add(sum, add(a, a)),
+            sub(add(a, c), b),
+            sub(add(a, b), add(c, c)),
+        ];
+    }
+}
+
+/// `poseidon_hash(x, y)`: permutes `[x, y, 2]` and returns the first cell.
+pub fn hash(x: U256, y: U256) -> U256 {
+    let mut state = [x, y, U256::from(2u8)];
+    permute(&mut state);
+    state[0]
+}
+
+/// `poseidon_hash_many`: a rate-2 sponge over `values || 1`, zero-padded to a whole
+/// number of blocks.
+pub fn hash_many(values: &[U256]) -> U256 {
+    let mut state = [U256::ZERO; 3];
+    let mut blocks = values.chunks_exact(2);
+    for block in blocks.by_ref() {
+        state[0] = add(state[0], block[0]);
+        state[1] = add(state[1], block[1]);
+        permute(&mut state);
+    }
+    match blocks.remainder() {
+        [last] => {
+            state[0] = add(state[0], *last);
+            state[1] = add(state[1], U256::from(1u8));
+        }
+        _ => state[0] = add(state[0], U256::from(1u8)),
+    }
+    permute(&mut state);
+    state[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_starknet_vectors() {
+        assert_eq!(
+            hash(U256::from(1), U256::from(2)),
+            uint!(0x5d44a3decb2b2e0cc71071f7b802f45dd792d064f0fc7316c46514f70f9891a_U256)
+        );
+        assert_eq!(
+            hash(
+                uint!(0xb662f9017fa7956fd70e26129b1833e10ad000fd37b4d9f4e0ce6884b7bbe_U256),
+                uint!(0x1fe356bf76102cdae1bfbdc173602ead228b12904c00dad9cf16e035468bea_U256)
+            ),
+            uint!(0x75540825a6ecc5dc7d7c2f5f868164182742227f1367d66c43ee51ec7937a81_U256)
+        );
+    }
+
+    #[test]
+    fn pads_the_sponge_input() {
+        assert_eq!(
+            hash_many(&[]),
+            uint!(0x2272be0f580fd156823304800919530eaa97430e972d7213ee13f4fbf7a5dbc_U256)
+        );
+        assert_eq!(
+            hash_many(&[U256::from(1), U256::from(2)]),
+            uint!(0x371cb6995ea5e7effcd2e174de264b5b407027a75a231a70c2c8d196107f0e7_U256)
+        );
+        assert_eq!(
+            hash_many(&[U256::from(1), U256::from(2), U256::from(3)]),
+            uint!(0x2f0d8840bcf3bc629598d8a6cc80cb7c0d9e52d93dab244bbf9cd0dca0ad082_U256)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::stwo::field::{from_m31_array, SecureField, M31};
+
+/// Cursor over little-endian words. Field elements must be canonical.
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Bytes not read yet.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    pub fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    pub fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    pub fn usize(&mut self) -> Option<usize> {
+        self.u32().map(|x| x as usize)
+    }
+
+    /// Reads a length prefix, bounded by the words left so that a corrupted prefix
+    /// cannot trigger a large allocation.
+    pub fn length(&mut self) -> Option<usize> {
+        let n = self.usize()?;
+        (n <= self.bytes.len() / 4).then_some(n)
+    }
+
+    pub fn m31(&mut self) -> Option<M31> {
+        M31::from_canonical_u32(self.u32()?)
+    }
+
+    pub fn secure(&mut self) -> Option<SecureField> {
+        Some(from_m31_array([
+            self.m31()?,
+            self.m31()?,
+            self.m31()?,
+            self.m31()?,
+        ]))
+    }
+
+    pub fn hash(&mut self) -> Option<B256> {
+        Some(B256::from_slice(self.take(32)?))
+    }
+}
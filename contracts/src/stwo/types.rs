@@ -0,0 +1,97 @@
+use alloc::vec::Vec;
+use stylus_sdk::{
+    alloy_primitives::{B256, U256},
+    alloy_sol_types::{sol, SolValue},
+    crypto::keccak,
+};
+
+sol! {
+    struct MemorySegment {
+        uint32 begin;
+        uint32 stop;
+    }
+
+    struct MemoryCell {
+        uint32 addr;
+        uint256 value;
+    }
+
+    /// Public input of a Stwo Cairo proof: the registers at the start and end of the run,
+    /// the segments in the AIR's order, starting with program, execution and output, and
+    /// the public memory.
+    struct StwoPublicInput {
+        uint32 initialPc;
+        uint32 initialAp;
+        uint32 initialFp;
+        uint32 finalPc;
+        uint32 finalAp;
+        uint32 finalFp;
+        MemorySegment[] segments;
+        MemoryCell[] publicMemory;
+    }
+
+    /// What a Stwo proof establishes: the program that ran and the outputs it wrote.
+    struct StwoClaim {
+        bytes32 programHash;
+        uint256[] outputs;
+    }
+
+    /// A proof uploaded to a resumable session, with the inputs of `verify_proof`.
+    struct StwoSessionProof {
+        bytes air;
+        bytes publicInput;
+        bytes proof;
+    }
+}
+
+impl StwoClaim {
+    /// Key under which the claim is recorded once verified.
+    pub fn digest(&self) -> B256 {
+        keccak(self.abi_encode())
+    }
+}
+
+impl StwoPublicInput {
+    /// Mixed into the channel before any commitment.
+    pub fn hash(&self) -> B256 {
+        keccak(self.abi_encode())
+    }
+
+    /// Registers followed by the begin and stop address of every segment.
+    pub fn public_values(&self) -> Vec<u32> {
+        let mut values = Vec::with_capacity(6 + 2 * self.segments.len());
+        values.extend([
+            self.initialPc,
+            self.initialAp,
+            self.initialFp,
+            self.finalPc,
+            self.finalAp,
+            self.finalFp,
+        ]);
+        for segment in &self.segments {
+            values.extend([segment.begin, segment.stop]);
+        }
+        values
+    }
+
+    /// Values of `segment` in address order, requiring every address exactly once.
+    pub fn read_segment(&self, segment: usize) -> Option<Vec<U256>> {
+        let MemorySegment { begin, stop } = *self.segments.get(segment)?;
+        let len = stop.checked_sub(begin)? as usize;
+        if len > self.publicMemory.len() {
+            return None;
+        }
+        let mut values: Vec<Option<U256>> = alloc::vec![None; len];
+        for cell in &self.publicMemory {
+            if cell.addr < begin || cell.addr >= stop {
+                continue;
+            }
+            let slot = &mut values[(cell.addr - begin) as usize];
+            if slot.is_some_and(|value| value != cell.value) {
+                return None;
+            }
+            *slot = Some(cell.value);
+        }
+        values.into_iter().collect()
+    }
+}
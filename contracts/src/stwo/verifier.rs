@@ -0,0 +1,1071 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{Address, B256, U256},
+    alloy_sol_types::SolType,
+    crypto::keccak,
+    prelude::*,
+};
+
+use crate::common::{
+    ownable::{IOwnable, Ownable},
+    resumable::{IResumableVerifier, ResumableVerification, Step, StepVerifier},
+    stark::{batch_inverse, fri::reverse_bits, ExtensionField},
+    VerificationError,
+};
+use crate::stwo::{
+    air::{Air, Point, N_TREES},
+    blake2s::Blake2s,
+    channel::{Blake2sChannel, Channel, ChannelKind, Poseidon252Channel},
+    circle::{circle_domain_at, CirclePoint, PointIndex},
+    config::{MAX_LOG_SIZE, N_VALUE_LIMBS, OUTPUT_SEGMENT, PROGRAM_SEGMENT},
+    errors::StwoError,
+    field::{conjugate, from_partial_evals, secure, to_m31_array, Cm31, SecureField, M31},
+    fri::{fold_queries, FriVerifier},
+    merkle::{self, Blake2sMerkleHasher, MerkleHasher, Poseidon252MerkleHasher},
+    poseidon252,
+    reader::Reader,
+    types::{StwoClaim, StwoPublicInput, StwoSessionProof},
+};
+
+pub trait IStwoVerifier {
+    type Error;
+
+    /// Pins the AIR to accept (see [`Air`]); owner-only and callable once.
+    fn initialize(&mut self, air: Vec<u8>) -> Result<(), Self::Error>;
+
+    /// Verifies a Stwo Cairo proof and returns the program hash and the program's outputs.
+    fn verify_proof(
+        &self,
+        proof: Vec<u8>,
+        public_input: Vec<u8>,
+        air: Vec<u8>,
+    ) -> Result<(B256, Vec<U256>), Self::Error>;
+
+    fn air_hash(&self) -> B256;
+
+    /// Digest under which a proof of `outputs` from the program hashing to `program_hash`
+    /// is recorded by a resumable session (see [`StwoClaim`]).
+    fn compute_claim_digest(&self, program_hash: B256, outputs: Vec<U256>) -> B256;
+}
+
+sol_storage! {
+    pub struct StwoVerifier {
+        bytes32 air_hash;
+        ResumableVerification sessions;
+        Ownable ownable;
+    }
+}
+
+#[public]
+impl IStwoVerifier for StwoVerifier {
+    type Error = Vec<u8>;
+
+    fn initialize(&mut self, air: Vec<u8>) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        if self.air_hash.get() != B256::ZERO {
+            return Err(StwoError::ALREADY_INITIALIZED.abi_encode());
+        }
+        if Air::from_bytes(&air, MAX_LOG_SIZE).is_none() {
+            return Err(StwoError::InvalidAir.abi_encode());
+        }
+
+        self.air_hash.set(keccak(&air));
+
+        Ok(())
+    }
+
+    fn verify_proof(
+        &self,
+        proof: Vec<u8>,
+        public_input: Vec<u8>,
+        air: Vec<u8>,
+    ) -> Result<(B256, Vec<U256>), Self::Error> {
+        let air_hash = self.air_hash.get();
+        if air_hash == B256::ZERO {
+            return Err(StwoError::INVALID_INITIALIZATION.abi_encode());
+        }
+        verify_pinned(air_hash, &air, &public_input, &proof).map_err(|e| e.abi_encode())
+    }
+
+    fn air_hash(&self) -> B256 {
+        self.air_hash.get()
+    }
+
+    fn compute_claim_digest(&self, program_hash: B256, outputs: Vec<U256>) -> B256 {
+        StwoClaim {
+            programHash: program_hash,
+            outputs,
+        }
+        .digest()
+    }
+}
+
+#[public]
+impl IOwnable for StwoVerifier {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.ownable.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.ownable
+            .transfer_ownership(new_owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.ownable.accept_ownership().map_err(|e| e.abi_encode())
+    }
+}
+
+impl StwoVerifier {
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable
+            .set_initial_owner(owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn only_owner(&self) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner().map_err(|e| e.abi_encode())
+    }
+}
+
+/// Proofs too large for one call are uploaded as an ABI-encoded [`StwoSessionProof`] and
+/// verified in two steps against the pinned AIR (see [`StwoStepVerifier`]).
+#[public]
+impl IResumableVerifier for StwoVerifier {
+    type Error = Vec<u8>;
+
+    fn open_session(&mut self, claim: B256) -> Result<B256, Self::Error> {
+        if self.air_hash.get() == B256::ZERO {
+            return Err(StwoError::INVALID_INITIALIZATION.abi_encode());
+        }
+        let owner = self.vm().msg_sender();
+        let now = self.vm().block_timestamp();
+        Ok(self.sessions.open(owner, claim, now))
+    }
+
+    fn upload_chunk(&mut self, session_id: B256, chunk: Vec<u8>) -> Result<(), Self::Error> {
+        let owner = self.vm().msg_sender();
+        let now = self.vm().block_timestamp();
+        self.sessions
+            .upload(session_id, owner, &chunk, now)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn advance(&mut self, session_id: B256) -> Result<bool, Self::Error> {
+        let owner = self.vm().msg_sender();
+        let now = self.vm().block_timestamp();
+        let verifier = StwoStepVerifier {
+            air_hash: self.air_hash.get(),
+        };
+        self.sessions
+            .advance(session_id, owner, &verifier, now)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn close_session(&mut self, session_id: B256) -> Result<(), Self::Error> {
+        let owner = self.vm().msg_sender();
+        let now = self.vm().block_timestamp();
+        self.sessions
+            .close(session_id, owner, now)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn cleanup_session(&mut self, session_id: B256) -> Result<(), Self::Error> {
+        let now = self.vm().block_timestamp();
+        self.sessions
+            .cleanup(session_id, now)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn is_claim_verified(&self, claim: B256) -> bool {
+        self.sessions.is_verified(claim)
+    }
+}
+
+/// Checks `air` against the pinned hash, then verifies `proof` with it.
+fn verify_pinned(
+    air_hash: B256,
+    air: &[u8],
+    public_input: &[u8],
+    proof: &[u8],
+) -> Result<(B256, Vec<U256>), StwoError> {
+    let (air, public_input) = parse_pinned(air_hash, air, public_input)?;
+    verify_stwo_proof(&air, &public_input, proof)
+}
+
+fn parse_pinned(
+    air_hash: B256,
+    air: &[u8],
+    public_input: &[u8],
+) -> Result<(Air, StwoPublicInput), StwoError> {
+    if keccak(air) != air_hash {
+        return Err(StwoError::InvalidAir);
+    }
+    let air = Air::from_bytes(air, MAX_LOG_SIZE).ok_or(StwoError::InvalidAir)?;
+    let public_input = <StwoPublicInput as SolType>::abi_decode(public_input, true)
+        .map_err(|_| StwoError::InvalidPublicInput)?;
+    Ok((air, public_input))
+}
+
+/// Verifies a [`StwoSessionProof`] and checks that it proves the session's claim, the
+/// digest of the resulting [`StwoClaim`]. The first step replays the transcript up to the
+/// out-of-domain check and keeps it in the session; the second decommits the trees and
+/// runs FRI from there.
+pub struct StwoStepVerifier {
+    pub air_hash: B256,
+}
+
+impl StepVerifier for StwoStepVerifier {
+    fn step(&self, claim: B256, proof: &[u8], state: &[u8]) -> Result<Step, VerificationError> {
+        if self.air_hash == B256::ZERO {
+            return Err(VerificationError::InvalidInitialization);
+        }
+        let session = <StwoSessionProof as SolType>::abi_decode(proof, true)
+            .map_err(|_| VerificationError::InvalidProofData)?;
+        let (air, public_input) = parse_pinned(self.air_hash, &session.air, &session.publicInput)?;
+        let (program_hash, outputs) = statement(&public_input)?;
+        let proven = StwoClaim {
+            programHash: program_hash,
+            outputs,
+        }
+        .digest();
+        if proven != claim {
+            return Err(VerificationError::VerificationFailed);
+        }
+
+        let step = match air.channel {
+            ChannelKind::Blake2s => step_with::<Blake2sChannel, Blake2sMerkleHasher>(
+                &air,
+                &public_input,
+                &session.proof,
+                state,
+            ),
+            ChannelKind::Poseidon252 => step_with::<Poseidon252Channel, Poseidon252MerkleHasher>(
+                &air,
+                &public_input,
+                &session.proof,
+                state,
+            ),
+        };
+        Ok(step?)
+    }
+}
+
+fn step_with<C: Channel + Default, H: MerkleHasher>(
+    air: &Air,
+    public_input: &StwoPublicInput,
+    proof: &[u8],
+    state: &[u8],
+) -> Result<Step, StwoError> {
+    if state.is_empty() {
+        let transcript = commit::<C>(air, public_input, proof)?;
+        return Ok(Step::Continue(transcript.to_bytes()));
+    }
+    let transcript = Transcript::<C>::from_bytes(state).ok_or(StwoError::INVALID_PROOF_DATA)?;
+    decommit::<C, H>(air, transcript, proof)?;
+    Ok(Step::Done)
+}
+
+/// Verifies a Stwo proof of a Cairo run with the channel named by the AIR, returning the
+/// program hash (see [`program_hash`]) and the values of the output segment.
+pub fn verify_stwo_proof(
+    air: &Air,
+    public_input: &StwoPublicInput,
+    proof: &[u8],
+) -> Result<(B256, Vec<U256>), StwoError> {
+    match air.channel {
+        ChannelKind::Blake2s => {
+            verify_with::<Blake2sChannel, Blake2sMerkleHasher>(air, public_input, proof)
+        }
+        ChannelKind::Poseidon252 => {
+            verify_with::<Poseidon252Channel, Poseidon252MerkleHasher>(air, public_input, proof)
+        }
+    }
+}
+
+/// A sampled value with the coefficients of its quotient
+/// `(c · f(P) - a · P.y - b) / V(P)`, where `V` is the line through the sample point and
+/// its conjugate. The numerator vanishes at both, as `f` has M31 coefficients.
+struct Sample {
+    a: SecureField,
+    b: SecureField,
+    c: SecureField,
+    /// The sample point's coordinates as `x0 + x1 · u` and `y0 + y1 · u`.
+    x: [Cm31; 2],
+    y: [Cm31; 2],
+}
+
+impl Sample {
+    fn new(point: CirclePoint<SecureField>, value: SecureField) -> Self {
+        let a = conjugate(value) - value;
+        let c = conjugate(point.y) - point.y;
+        Self {
+            a,
+            b: value * c - a * point.y,
+            c,
+            x: point.x.0,
+            y: point.y.0,
+        }
+    }
+
+    fn vanishing(&self, p: CirclePoint<M31>) -> Cm31 {
+        (self.x[0] - Cm31::from_base(p.x)) * self.y[1]
+            - (self.y[0] - Cm31::from_base(p.y)) * self.x[1]
+    }
+
+    fn numerator(&self, p: CirclePoint<M31>, value: M31) -> SecureField {
+        self.c * secure(value) - self.a * secure(p.y) - self.b
+    }
+}
+
+/// A committed column: its domain, its samples and its values at the queries.
+struct Opening {
+    log_size: usize,
+    samples: Vec<Sample>,
+    values: Vec<M31>,
+}
+
+pub fn verify_with<C: Channel + Default, H: MerkleHasher>(
+    air: &Air,
+    public_input: &StwoPublicInput,
+    proof: &[u8],
+) -> Result<(B256, Vec<U256>), StwoError> {
+    let statement = statement(public_input)?;
+    let transcript = commit::<C>(air, public_input, proof)?;
+    decommit::<C, H>(air, transcript, proof)?;
+    Ok(statement)
+}
+
+/// The program hash and the values of the output segment.
+fn statement(public_input: &StwoPublicInput) -> Result<(B256, Vec<U256>), StwoError> {
+    let program = public_input
+        .read_segment(PROGRAM_SEGMENT)
+        .ok_or(StwoError::InvalidPublicInput)?;
+    let outputs = public_input
+        .read_segment(OUTPUT_SEGMENT)
+        .ok_or(StwoError::InvalidPublicInput)?;
+    Ok((program_hash(&program)?, outputs))
+}
+
+/// The program hash of stwo-cairo's verification output: Blake2s over the program words,
+/// each encoded as in Cairo's Blake builtin, read as a little-endian integer modulo the
+/// Stark prime.
+///
+/// A word below `2^63` is its two low 32-bit limbs, and any other word all eight with the
+/// top bit of the most significant set; limbs go most significant first, each
+/// little-endian.
+fn program_hash(program: &[U256]) -> Result<B256, StwoError> {
+    let mut hasher = Blake2s::new();
+    for word in program {
+        if *word >= poseidon252::P {
+            return Err(StwoError::InvalidPublicInput);
+        }
+        let limbs: [u32; 8] =
+            core::array::from_fn(|i| (word.as_limbs()[i / 2] >> (32 * (i % 2))) as u32);
+        if *word < U256::from(1u64 << 63) {
+            hasher.update(&limbs[1].to_le_bytes());
+            hasher.update(&limbs[0].to_le_bytes());
+        } else {
+            hasher.update(&(limbs[7] | 1 << 31).to_le_bytes());
+            for limb in limbs[..7].iter().rev() {
+                hasher.update(&limb.to_le_bytes());
+            }
+        }
+    }
+    let digest = U256::from_le_bytes(hasher.finalize());
+    Ok(digest.reduce_mod(poseidon252::P).into())
+}
+
+/// The transcript once the out-of-domain samples are checked: the channel, how much of the
+/// proof is read, the roots in tree order and the samples.
+struct Transcript<C> {
+    channel: C,
+    read: usize,
+    roots: Vec<Option<B256>>,
+    oods_t: SecureField,
+    sampled: Vec<SecureField>,
+}
+
+impl<C: Channel> Transcript<C> {
+    /// Little-endian words, as the proof itself.
+    fn to_bytes(&self) -> Vec<u8> {
+        let (digest, n_draws) = self.channel.snapshot();
+        let mut bytes = digest.to_vec();
+        bytes.extend(n_draws.to_le_bytes());
+        bytes.extend((self.read as u32).to_le_bytes());
+        let push_secure = |bytes: &mut Vec<u8>, felt: SecureField| {
+            for x in to_m31_array(felt) {
+                bytes.extend(x.as_canonical_u32().to_le_bytes());
+            }
+        };
+        push_secure(&mut bytes, self.oods_t);
+        for root in &self.roots {
+            bytes.extend((root.is_some() as u32).to_le_bytes());
+            bytes.extend(root.unwrap_or_default());
+        }
+        bytes.extend((self.sampled.len() as u32).to_le_bytes());
+        for felt in &self.sampled {
+            push_secure(&mut bytes, *felt);
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader::new(bytes);
+        let digest = reader.hash()?;
+        let channel = C::restore(digest, reader.u32()?);
+        let read = reader.usize()?;
+        let oods_t = reader.secure()?;
+        let roots = (0..=N_TREES)
+            .map(|_| {
+                let present = reader.u32()?;
+                let root = reader.hash()?;
+                match present {
+                    0 => Some(None),
+                    1 => Some(Some(root)),
+                    _ => None,
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let sampled = (0..reader.length()?)
+            .map(|_| reader.secure())
+            .collect::<Option<Vec<_>>>()?;
+        reader.is_empty().then_some(Self {
+            channel,
+            read,
+            roots,
+            oods_t,
+            sampled,
+        })
+    }
+}
+
+/// Replays the commitments and checks the out-of-domain samples against the AIR.
+fn commit<C: Channel + Default>(
+    air: &Air,
+    public_input: &StwoPublicInput,
+    proof_bytes: &[u8],
+) -> Result<Transcript<C>, StwoError> {
+    let public_values = public_input
+        .public_values()
+        .into_iter()
+        .map(M31::from_canonical_u32)
+        .collect::<Option<Vec<_>>>()
+        .ok_or(StwoError::InvalidPublicInput)?;
+
+    let mut channel = C::default();
+    channel.mix_digest(&public_input.hash());
+    let mut proof = Reader::new(proof_bytes);
+    let read = |value: Option<B256>| value.ok_or(StwoError::INVALID_PROOF_DATA);
+
+    // Commitments, in tree order, with the interaction randomness drawn after the base
+    // trace.
+    let mut roots: Vec<Option<B256>> = vec![None; N_TREES + 1];
+    if !air.trees[0].is_empty() {
+        roots[0] = Some(air.preprocessed_root);
+        channel.mix_digest(&air.preprocessed_root);
+    }
+    let trace_root = read(proof.hash())?;
+    channel.mix_digest(&trace_root);
+    roots[1] = Some(trace_root);
+    let interaction_elements: Vec<SecureField> = (0..air.interaction_elements)
+        .map(|_| channel.draw_secure_felt())
+        .collect();
+    let claimed_sums = (0..air.claimed_sums)
+        .map(|_| proof.secure())
+        .collect::<Option<Vec<_>>>()
+        .ok_or(StwoError::INVALID_PROOF_DATA)?;
+    if !claimed_sums.is_empty() {
+        channel.mix_felts(&claimed_sums);
+    }
+    if !air.trees[2].is_empty() {
+        let root = read(proof.hash())?;
+        channel.mix_digest(&root);
+        roots[2] = Some(root);
+    }
+    let composition_alpha = channel.draw_secure_felt();
+    let composition_root = read(proof.hash())?;
+    channel.mix_digest(&composition_root);
+    roots[3] = Some(composition_root);
+
+    // Out-of-domain samples.
+    let oods_t = channel.draw_secure_felt();
+    let oods_point = CirclePoint::from_t(oods_t);
+    let n_samples: usize = air.trees.iter().flatten().map(|c| c.offsets.len()).sum();
+    let sampled = (0..n_samples + 4)
+        .map(|_| proof.secure())
+        .collect::<Option<Vec<_>>>()
+        .ok_or(StwoError::INVALID_PROOF_DATA)?;
+    channel.mix_felts(&sampled);
+    let (samples, composition) = sampled.split_at(n_samples);
+    let point = Point {
+        point: oods_point,
+        samples,
+        public_values: &public_values,
+        interaction_elements: &interaction_elements,
+        claimed_sums: &claimed_sums,
+    };
+    let expected = air
+        .eval_composition(&point, composition_alpha)
+        .ok_or(StwoError::VERIFICATION_FAILED)?;
+    let composition = from_partial_evals([
+        composition[0],
+        composition[1],
+        composition[2],
+        composition[3],
+    ]);
+    if expected != composition {
+        return Err(StwoError::OodsMismatch);
+    }
+    check_lookup_sum(public_input, &interaction_elements, &claimed_sums)?;
+
+    Ok(Transcript {
+        channel,
+        read: proof_bytes.len() - proof.remaining(),
+        roots,
+        oods_t,
+        sampled,
+    })
+}
+
+/// Decommits every tree at the queries and checks the DEEP quotients with FRI.
+fn decommit<C: Channel, H: MerkleHasher>(
+    air: &Air,
+    transcript: Transcript<C>,
+    proof: &[u8],
+) -> Result<(), StwoError> {
+    let Transcript {
+        mut channel,
+        read,
+        roots,
+        oods_t,
+        sampled,
+    } = transcript;
+    let mut proof = Reader::new(proof.get(read..).ok_or(StwoError::INVALID_PROOF_DATA)?);
+    let oods_point = CirclePoint::from_t(oods_t);
+
+    // Openings of every column, grouped by tree.
+    let log_blowup = air.fri.log_blowup;
+    let mut trees: Vec<Vec<Opening>> = Vec::with_capacity(N_TREES + 1);
+    let mut sampled = sampled.iter();
+    for columns in &air.trees {
+        let openings = columns
+            .iter()
+            .map(|column| {
+                let step = PointIndex::subgroup_gen(column.log_size);
+                let samples = column
+                    .offsets
+                    .iter()
+                    .zip(sampled.by_ref())
+                    .map(|(offset, value)| {
+                        let shift = step.scale(offset.rem_euclid(1 << column.log_size) as usize);
+                        Sample::new(oods_point + shift.to_point().into_secure(), *value)
+                    })
+                    .collect();
+                Opening {
+                    log_size: column.log_size + log_blowup,
+                    samples,
+                    values: Vec::new(),
+                }
+            })
+            .collect();
+        trees.push(openings);
+    }
+    trees.push(
+        sampled
+            .map(|value| Opening {
+                log_size: air.log_composition_size + log_blowup,
+                samples: vec![Sample::new(oods_point, *value)],
+                values: Vec::new(),
+            })
+            .collect(),
+    );
+
+    // FRI commitments and proof of work.
+    let deep_alpha = channel.draw_secure_felt();
+    let mut log_sizes: Vec<usize> = trees.iter().flatten().map(|o| o.log_size).collect();
+    log_sizes.sort_unstable_by(|a, b| b.cmp(a));
+    log_sizes.dedup();
+    let bounds: Vec<usize> = log_sizes.iter().map(|l| l - log_blowup).collect();
+    let fri = FriVerifier::commit(&mut channel, &mut proof, &air.fri, &bounds)
+        .ok_or(StwoError::INVALID_PROOF_DATA)?;
+    let nonce = proof.u64().ok_or(StwoError::INVALID_PROOF_DATA)?;
+    channel.mix_u64(nonce);
+    if channel.trailing_zeros() < air.pow_bits as u32 {
+        return Err(StwoError::VERIFICATION_FAILED);
+    }
+    let max_log_size = fri.max_log_size();
+    let queries = channel.draw_queries(max_log_size, air.fri.n_queries);
+    let queries_at = |log_size: usize| fold_queries(&queries, max_log_size - log_size);
+
+    // Decommit every tree at the queries, folded to each column's domain.
+    for (openings, root) in trees.iter_mut().zip(&roots) {
+        let Some(root) = root else { continue };
+        let column_log_sizes: Vec<usize> = openings.iter().map(|o| o.log_size).collect();
+        let mut layers = column_log_sizes.clone();
+        layers.sort_unstable_by(|a, b| b.cmp(a));
+        layers.dedup();
+
+        let mut tree_queries = BTreeMap::new();
+        let mut values = Vec::new();
+        for log_size in layers {
+            let positions = queries_at(log_size);
+            for _ in &positions {
+                for opening in openings.iter_mut().filter(|o| o.log_size == log_size) {
+                    let value = proof.m31().ok_or(StwoError::INVALID_PROOF_DATA)?;
+                    opening.values.push(value);
+                    values.push(value);
+                }
+            }
+            tree_queries.insert(log_size, positions);
+        }
+        merkle::verify::<H>(&mut proof, root, &column_log_sizes, &tree_queries, &values)
+            .ok_or(StwoError::VERIFICATION_FAILED)?;
+    }
+
+    // DEEP quotients per domain size, then FRI.
+    let openings: Vec<&Opening> = trees.iter().flatten().collect();
+    let column_values: Vec<Vec<SecureField>> = log_sizes
+        .iter()
+        .map(|log_size| quotients(&openings, *log_size, &queries_at(*log_size), deep_alpha))
+        .collect();
+    fri.decommit::<H>(&mut proof, &queries, &column_values)
+        .ok_or(StwoError::VERIFICATION_FAILED)?;
+    if !proof.is_empty() {
+        return Err(StwoError::INVALID_PROOF_DATA);
+    }
+
+    Ok(())
+}
+
+/// `Σ alpha^k · quotient_k`, accumulated over the samples of every column on this domain
+/// in commitment order.
+fn quotients(
+    openings: &[&Opening],
+    log_size: usize,
+    positions: &[usize],
+    alpha: SecureField,
+) -> Vec<SecureField> {
+    let openings: Vec<&&Opening> = openings.iter().filter(|o| o.log_size == log_size).collect();
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, position)| {
+            let p = circle_domain_at(log_size, reverse_bits(*position, log_size));
+            let mut denominators: Vec<Cm31> = openings
+                .iter()
+                .flat_map(|o| o.samples.iter().map(|s| s.vanishing(p)))
+                .collect();
+            batch_inverse(&mut denominators);
+
+            let terms = openings
+                .iter()
+                .flat_map(|o| o.samples.iter().map(|s| s.numerator(p, o.values[i])));
+            terms
+                .zip(denominators)
+                .fold(SecureField::ZERO, |acc, (numerator, inverse)| {
+                    acc * alpha + numerator.mul_base(inverse)
+                })
+        })
+        .collect()
+}
+
+/// Balances the memory relation: the components' claimed sums plus the public memory's
+/// `Σ 1 / (z - (addr + Σ_j α^(j+1) · limb_j))`, over 9-bit limbs of each value, must be
+/// zero. Only applies to AIRs with a memory relation.
+fn check_lookup_sum(
+    public_input: &StwoPublicInput,
+    interaction_elements: &[SecureField],
+    claimed_sums: &[SecureField],
+) -> Result<(), StwoError> {
+    let [z, alpha, ..] = interaction_elements else {
+        return Ok(());
+    };
+    if claimed_sums.is_empty() {
+        return Ok(());
+    }
+
+    let mut denominators = Vec::with_capacity(public_input.publicMemory.len());
+    for cell in &public_input.publicMemory {
+        if cell.value >> (9 * N_VALUE_LIMBS) != U256::ZERO {
+            return Err(StwoError::InvalidPublicInput);
+        }
+        let addr = M31::from_canonical_u32(cell.addr).ok_or(StwoError::InvalidPublicInput)?;
+        let mut combined = secure(addr);
+        let mut alpha_pow = *alpha;
+        for j in 0..N_VALUE_LIMBS {
+            let limb = (cell.value >> (9 * j)).as_limbs()[0] & 0x1ff;
+            combined += alpha_pow * secure(M31::new(limb as u32));
+            alpha_pow *= *alpha;
+        }
+        let denominator = *z - combined;
+        if denominator == SecureField::ZERO {
+            return Err(StwoError::VERIFICATION_FAILED);
+        }
+        denominators.push(denominator);
+    }
+    batch_inverse(&mut denominators);
+
+    let total = claimed_sums
+        .iter()
+        .chain(&denominators)
+        .fold(SecureField::ZERO, |acc, x| acc + *x);
+    if total != SecureField::ZERO {
+        return Err(StwoError::LookupSumMismatch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::{alloy_primitives::b256, alloy_sol_types::SolValue};
+
+    // A two-column Fibonacci-like AIR over 8 rows with a preprocessed column and a memory
+    // lookup, proven once per channel for a run with program `[100, 200]` writing
+    // `42 + 2^200` to the output segment.
+    const BLAKE2S: [&[u8]; 3] = [
+        include_bytes!("../../testdata/stwo/blake2s/air.bin"),
+        include_bytes!("../../testdata/stwo/blake2s/public_input.bin"),
+        include_bytes!("../../testdata/stwo/blake2s/proof.bin"),
+    ];
+    const POSEIDON252: [&[u8]; 3] = [
+        include_bytes!("../../testdata/stwo/poseidon252/air.bin"),
+        include_bytes!("../../testdata/stwo/poseidon252/public_input.bin"),
+        include_bytes!("../../testdata/stwo/poseidon252/proof.bin"),
+    ];
+
+    fn parse([air, public_input, _]: [&[u8]; 3]) -> (Air, StwoPublicInput) {
+        (
+            Air::from_bytes(air, MAX_LOG_SIZE).unwrap(),
+            <StwoPublicInput as SolType>::abi_decode(public_input, true).unwrap(),
+        )
+    }
+
+    /// Where each part of a fixture proof starts.
+    struct Offsets {
+        oods_values: usize,
+        composition_values: usize,
+        fri_roots: usize,
+        last_layer: usize,
+        nonce: usize,
+        openings: usize,
+    }
+
+    fn offsets(air: &Air) -> Offsets {
+        let oods_values = 32 + 16 * air.claimed_sums + 32 * !air.trees[2].is_empty() as usize + 32;
+        let n_samples: usize = air.trees.iter().flatten().map(|c| c.offsets.len()).sum();
+        let composition_values = oods_values + 16 * n_samples;
+        let fri_roots = composition_values + 16 * 4;
+        let max_bound = air
+            .trees
+            .iter()
+            .flatten()
+            .map(|c| c.log_size)
+            .chain([air.log_composition_size])
+            .max()
+            .unwrap();
+        let last_layer = fri_roots + 32 * (max_bound - air.fri.log_last_layer_degree);
+        let nonce = last_layer + (16 << air.fri.log_last_layer_degree);
+        Offsets {
+            oods_values,
+            composition_values,
+            fri_roots,
+            last_layer,
+            nonce,
+            openings: nonce + 8,
+        }
+    }
+
+    fn verify_altered(fixture: [&[u8]; 3], at: usize) -> Result<(B256, Vec<U256>), StwoError> {
+        let (air, public_input) = parse(fixture);
+        let mut proof = fixture[2].to_vec();
+        proof[at] ^= 1;
+        verify_stwo_proof(&air, &public_input, &proof)
+    }
+
+    #[test]
+    fn verifies_fixture_proofs() {
+        let program_hash =
+            b256!("02c26d406c5b9dfb95f100e59457737d5cd273c20bfb102bfc6b810db5f02b56");
+        for (fixture, channel) in [
+            (BLAKE2S, ChannelKind::Blake2s),
+            (POSEIDON252, ChannelKind::Poseidon252),
+        ] {
+            let (air, public_input) = parse(fixture);
+            assert!(air.channel == channel);
+            assert_eq!(
+                verify_stwo_proof(&air, &public_input, fixture[2]).unwrap(),
+                (program_hash, vec![U256::from(42) + (U256::from(1) << 200)])
+            );
+        }
+    }
+
+    // The vector of stwo-cairo's `test_encode_and_hash_memory_section`, whose first word
+    // takes the eight-limb encoding and second the two-limb one.
+    #[test]
+    fn matches_stwo_cairo_program_hash() {
+        let program = [
+            U256::from(0x90abcdef_12345678u64),
+            U256::from(0x34567890_abcdef12u64),
+        ];
+        let expected = U256::from_str_radix(
+            "115645365096977585374207223166120623839439046970571781411593222716768222992",
+            10,
+        )
+        .unwrap();
+        assert_eq!(program_hash(&program).unwrap(), B256::from(expected));
+        assert_eq!(
+            program_hash(&[poseidon252::P - U256::from(1)]).unwrap(),
+            b256!("07c018937c4b4968cc90a67326b1715ca808b7546ad91fb91fbc42a3dc6c52f1")
+        );
+        assert!(matches!(
+            program_hash(&[poseidon252::P]),
+            Err(StwoError::InvalidPublicInput)
+        ));
+    }
+
+    #[test]
+    fn rejects_altered_public_memory() {
+        for fixture in [BLAKE2S, POSEIDON252] {
+            let (air, mut public_input) = parse(fixture);
+            public_input.publicMemory[2].value = U256::from(43);
+            assert!(verify_stwo_proof(&air, &public_input, fixture[2]).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_altered_oods_values() {
+        for fixture in [BLAKE2S, POSEIDON252] {
+            let offsets = offsets(&parse(fixture).0);
+            for at in [offsets.oods_values, offsets.composition_values] {
+                assert!(matches!(
+                    verify_altered(fixture, at),
+                    Err(StwoError::OodsMismatch)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_altered_fri_layers() {
+        for fixture in [BLAKE2S, POSEIDON252] {
+            let offsets = offsets(&parse(fixture).0);
+            let last_fri_value = fixture[2].len() - 4;
+            for at in [offsets.fri_roots, offsets.last_layer, last_fri_value] {
+                assert!(matches!(
+                    verify_altered(fixture, at),
+                    Err(StwoError::Common(VerificationError::VerificationFailed))
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_altered_queries() {
+        for fixture in [BLAKE2S, POSEIDON252] {
+            let offsets = offsets(&parse(fixture).0);
+            // A new nonce draws other queries, which the decommitments do not open.
+            for at in [offsets.nonce, offsets.openings] {
+                assert!(matches!(
+                    verify_altered(fixture, at),
+                    Err(StwoError::Common(VerificationError::VerificationFailed))
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_proofs() {
+        for fixture in [BLAKE2S, POSEIDON252] {
+            let (air, public_input) = parse(fixture);
+            let proof = fixture[2];
+            let offsets = offsets(&air);
+
+            for cut in [offsets.oods_values + 8, offsets.openings] {
+                assert!(matches!(
+                    verify_stwo_proof(&air, &public_input, &proof[..cut]),
+                    Err(StwoError::Common(VerificationError::InvalidProofData))
+                ));
+            }
+
+            let mut extended = proof.to_vec();
+            extended.push(0);
+            assert!(matches!(
+                verify_stwo_proof(&air, &public_input, &extended),
+                Err(StwoError::Common(VerificationError::InvalidProofData))
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_other_airs() {
+        for fixture in [BLAKE2S, POSEIDON252] {
+            let (_, public_input) = parse(fixture);
+
+            // The last constraint swapped for the first expression.
+            let mut altered = fixture[0].to_vec();
+            let last = altered.len() - 4;
+            altered[last..].copy_from_slice(&0u32.to_le_bytes());
+            let air = Air::from_bytes(&altered, MAX_LOG_SIZE).unwrap();
+            assert!(matches!(
+                verify_stwo_proof(&air, &public_input, fixture[2]),
+                Err(StwoError::OodsMismatch)
+            ));
+
+            // One more query than the proof opens.
+            let mut altered = fixture[0].to_vec();
+            altered[8] += 1;
+            let air = Air::from_bytes(&altered, MAX_LOG_SIZE).unwrap();
+            assert!(verify_stwo_proof(&air, &public_input, fixture[2]).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_proofs_for_the_other_channel() {
+        let (blake2s_air, public_input) = parse(BLAKE2S);
+        let (poseidon252_air, _) = parse(POSEIDON252);
+        assert!(verify_stwo_proof(&blake2s_air, &public_input, POSEIDON252[2]).is_err());
+        assert!(verify_stwo_proof(&poseidon252_air, &public_input, BLAKE2S[2]).is_err());
+    }
+
+    fn session([air, public_input, proof]: [&[u8]; 3]) -> Vec<u8> {
+        StwoSessionProof {
+            air: air.to_vec().into(),
+            publicInput: public_input.to_vec().into(),
+            proof: proof.to_vec().into(),
+        }
+        .abi_encode()
+    }
+
+    fn claim(fixture: [&[u8]; 3]) -> B256 {
+        let (air, public_input) = parse(fixture);
+        let (program_hash, outputs) = verify_stwo_proof(&air, &public_input, fixture[2]).unwrap();
+        StwoClaim {
+            programHash: program_hash,
+            outputs,
+        }
+        .digest()
+    }
+
+    #[test]
+    fn verifies_session_proofs_in_two_steps() {
+        for fixture in [BLAKE2S, POSEIDON252] {
+            let verifier = StwoStepVerifier {
+                air_hash: keccak(fixture[0]),
+            };
+            let (proof, claim) = (session(fixture), claim(fixture));
+
+            let Ok(Step::Continue(state)) = verifier.step(claim, &proof, &[]) else {
+                panic!("the first step should stop after the out-of-domain check");
+            };
+            assert!(matches!(
+                verifier.step(claim, &proof, &state),
+                Ok(Step::Done)
+            ));
+
+            // The decommitment is only read in the second step.
+            let mut altered = fixture;
+            let last = fixture[2].len() - 1;
+            let tampered = [&fixture[2][..last], &[fixture[2][last] ^ 1]].concat();
+            altered[2] = &tampered;
+            let Ok(Step::Continue(altered_state)) = verifier.step(claim, &session(altered), &[])
+            else {
+                panic!("the first step should not read the decommitment");
+            };
+            assert_eq!(altered_state, state);
+            assert!(verifier.step(claim, &session(altered), &state).is_err());
+
+            // Resuming from another transcript fails.
+            let mut altered_state = state.clone();
+            altered_state[0] ^= 1;
+            assert!(verifier.step(claim, &proof, &altered_state).is_err());
+            assert!(matches!(
+                verifier.step(claim, &proof, &state[..state.len() - 1]),
+                Err(VerificationError::InvalidProofData)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_session_proofs_of_other_claims() {
+        let verifier = StwoStepVerifier {
+            air_hash: keccak(BLAKE2S[0]),
+        };
+        let claim = claim(BLAKE2S);
+
+        assert!(matches!(
+            verifier.step(B256::repeat_byte(1), &session(BLAKE2S), &[]),
+            Err(VerificationError::VerificationFailed)
+        ));
+        assert!(matches!(
+            verifier.step(claim, &session(POSEIDON252), &[]),
+            Err(VerificationError::InvalidProofData)
+        ));
+        assert!(matches!(
+            verifier.step(claim, &BLAKE2S[2][..64], &[]),
+            Err(VerificationError::InvalidProofData)
+        ));
+        assert!(matches!(
+            StwoStepVerifier {
+                air_hash: B256::ZERO
+            }
+            .step(claim, &session(BLAKE2S), &[]),
+            Err(VerificationError::InvalidInitialization)
+        ));
+    }
+
+    #[test]
+    fn verifies_claims_through_a_session() {
+        use crate::common::resumable::ResumableError;
+        use stylus_sdk::testing::*;
+
+        const OWNER: Address = Address::repeat_byte(1);
+        const PROVER: Address = Address::repeat_byte(2);
+        let vm = TestVM::default();
+        let mut verifier = StwoVerifier::from(&vm);
+        verifier.set_initial_owner(OWNER).unwrap();
+        vm.set_sender(PROVER);
+        assert_eq!(
+            verifier.open_session(B256::ZERO),
+            Err(StwoError::INVALID_INITIALIZATION.abi_encode())
+        );
+        assert!(verifier.initialize(BLAKE2S[0].to_vec()).is_err());
+        vm.set_sender(OWNER);
+        verifier.initialize(BLAKE2S[0].to_vec()).unwrap();
+
+        let claim = claim(BLAKE2S);
+        vm.set_sender(PROVER);
+        let id = verifier.open_session(claim).unwrap();
+        for chunk in session(BLAKE2S).chunks(1000) {
+            verifier.upload_chunk(id, chunk.to_vec()).unwrap();
+        }
+
+        vm.set_sender(OWNER);
+        assert_eq!(
+            verifier.advance(id),
+            Err(ResumableError::NotSessionOwner.abi_encode())
+        );
+        vm.set_sender(PROVER);
+        assert_eq!(verifier.advance(id), Ok(false));
+        assert!(!verifier.is_claim_verified(claim));
+        assert_eq!(
+            verifier.upload_chunk(id, vec![0]),
+            Err(ResumableError::SessionAlreadyVerifying.abi_encode())
+        );
+        assert_eq!(verifier.advance(id), Ok(true));
+        assert!(verifier.is_claim_verified(claim));
+        assert_eq!(
+            verifier.advance(id),
+            Err(ResumableError::SessionNotFound.abi_encode())
+        );
+    }
+}
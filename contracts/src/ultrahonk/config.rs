@@ -0,0 +1,47 @@
+use stylus_sdk::alloy_primitives::{uint, U256};
+
+use crate::common::G2Point;
+
+/// Barretenberg release whose `HonkVerifier.sol` this module follows.
+pub const VERSION: &str = "bb v0.84.0";
+
+/// Proofs are padded to this many sumcheck/Gemini rounds regardless of the circuit size.
+pub const CONST_PROOF_SIZE_LOG_N: usize = 28;
+pub const BATCHED_RELATION_PARTIAL_LENGTH: usize = 8;
+pub const NUMBER_OF_SUBRELATIONS: usize = 26;
+pub const NUMBER_OF_ALPHAS: usize = NUMBER_OF_SUBRELATIONS - 1;
+pub const NUMBER_OF_ENTITIES: usize = 40;
+pub const NUMBER_UNSHIFTED: usize = 35;
+pub const NUMBER_TO_BE_SHIFTED: usize = 5;
+pub const NUMBER_OF_PRECOMPUTED: usize = 27;
+/// Limbs of the recursion pairing points appended to the public inputs.
+pub const PAIRING_POINTS_SIZE: usize = 16;
+
+/// Proof size in 32-byte words; G1 points take four words (136-bit limb split).
+pub const PROOF_SIZE: usize = PAIRING_POINTS_SIZE
+    + 8 * 4
+    + CONST_PROOF_SIZE_LOG_N * BATCHED_RELATION_PARTIAL_LENGTH
+    + NUMBER_OF_ENTITIES
+    + (CONST_PROOF_SIZE_LOG_N - 1) * 4
+    + CONST_PROOF_SIZE_LOG_N
+    + 2 * 4;
+
+/// `[x]_2` of the Aztec Ignition SRS, in `ecPairing` limb order.
+pub const SRS_G2_X: G2Point = G2Point {
+    x: [
+        uint!(0x260e01b251f6f1c7e7ff4e580791dee8ea51d87a358e038b4efe30fac09383c1_U256),
+        uint!(0x0118c4d5b837bcc2bc89b5b398b5974e9f5944073b32078b7e231fec938883b0_U256),
+    ],
+    y: [
+        uint!(0x04fc6369f7110fe3d25156c1bb9a72859cf2a04641f99ba4ee413c80da6a5fe4_U256),
+        uint!(0x22febda3c0c0632a56475b4214e5615e11e6dd3f96e6cea2854a87d4dacc5e55_U256),
+    ],
+};
+
+/// Diagonal of the Poseidon2 (t = 4) internal matrix, minus one.
+pub const POSEIDON2_INTERNAL_MATRIX_DIAGONAL: [U256; 4] = [
+    uint!(0x10dc6e9c006ea38b04b1e03b4bd9490c0d03f98929ca1d7fb56821fd19d3b6e7_U256),
+    uint!(0x0c28145b6a44df3e0149b3d0a30b3bb599df9756d4dd9b84a86b38cfb45a740b_U256),
+    uint!(0x00544b8338791518b2c7645a50392798b21f75bb60e3596170067d00141cac15_U256),
+    uint!(0x222c01175718386f2e2e82eb122789e352e105a3b8fa852613bc534433ee428b_U256),
+];
@@ -0,0 +1,64 @@
+use stylus_sdk::{
+    alloy_primitives::U256,
+    alloy_sol_types::{sol, SolError},
+};
+
+use crate::common::VerificationError;
+
+sol! {
+    error InvalidVerificationKey();
+    error ProofLengthMismatch(uint256 received, uint256 expected);
+    error PublicInputsLengthMismatch(uint256 received, uint256 expected);
+    error SumcheckFailed();
+    error ShpleminiFailed();
+}
+
+#[derive(Debug)]
+pub enum UltraHonkError {
+    Common(VerificationError),
+    InvalidVerificationKey,
+    ProofLengthMismatch { received: U256, expected: U256 },
+    PublicInputsLengthMismatch { received: U256, expected: U256 },
+    SumcheckFailed,
+    ShpleminiFailed,
+}
+
+impl UltraHonkError {
+    pub fn abi_encode(&self) -> alloc::vec::Vec<u8> {
+        match self {
+            UltraHonkError::Common(e) => e.abi_encode(),
+            UltraHonkError::InvalidVerificationKey => InvalidVerificationKey {}.abi_encode(),
+            UltraHonkError::ProofLengthMismatch { received, expected } => ProofLengthMismatch {
+                received: *received,
+                expected: *expected,
+            }
+            .abi_encode(),
+            UltraHonkError::PublicInputsLengthMismatch { received, expected } => {
+                PublicInputsLengthMismatch {
+                    received: *received,
+                    expected: *expected,
+                }
+                .abi_encode()
+            }
+            UltraHonkError::SumcheckFailed => SumcheckFailed {}.abi_encode(),
+            UltraHonkError::ShpleminiFailed => ShpleminiFailed {}.abi_encode(),
+        }
+    }
+}
+
+impl From<VerificationError> for UltraHonkError {
+    fn from(error: VerificationError) -> Self {
+        UltraHonkError::Common(error)
+    }
+}
+
+impl UltraHonkError {
+    pub const VERIFICATION_FAILED: UltraHonkError =
+        UltraHonkError::Common(VerificationError::VerificationFailed);
+    pub const INVALID_INITIALIZATION: UltraHonkError =
+        UltraHonkError::Common(VerificationError::InvalidInitialization);
+    pub const ALREADY_INITIALIZED: UltraHonkError =
+        UltraHonkError::Common(VerificationError::AlreadyInitialized);
+    pub const INVALID_PROOF_DATA: UltraHonkError =
+        UltraHonkError::Common(VerificationError::InvalidProofData);
+}
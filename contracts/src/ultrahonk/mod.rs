@@ -0,0 +1,10 @@
+pub mod config;
+pub mod errors;
+pub mod relations;
+pub mod transcript;
+pub mod types;
+pub mod verifier;
+
+pub use errors::UltraHonkError;
+pub use types::{UltraHonkProof, UltraHonkVerificationKey};
+pub use verifier::{IUltraHonkVerifier, UltraHonkVerifier};
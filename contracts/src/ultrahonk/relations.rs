@@ -0,0 +1,445 @@
+//! UltraHonk relations evaluated at the sumcheck point, as in `RelationsLib` of
+//! `HonkVerifier.sol`. Each subrelation is scaled by the pow-polynomial partial evaluation
+//! (except the linearly dependent lookup subrelation) and batched with the alphas.
+
+use stylus_sdk::alloy_primitives::{uint, U256};
+
+use crate::common::fr;
+use crate::ultrahonk::{
+    config::{NUMBER_OF_ALPHAS, NUMBER_OF_SUBRELATIONS, POSEIDON2_INTERNAL_MATRIX_DIAGONAL},
+    transcript::RelationParameters,
+    types::{UltraHonkProof, Wire},
+};
+
+/// `-1/2 mod r`
+const NEG_HALF: U256 =
+    uint!(0x183227397098d014dc2822db40c0ac2e9419f4243cdcb848a1f0fac9f8000000_U256);
+/// Negated `b` of the Grumpkin curve `y^2 = x^3 - 17`.
+const GRUMPKIN_CURVE_B_PARAMETER_NEGATED: U256 = uint!(17_U256);
+const LIMB_SIZE: U256 = uint!(0x100000000000000000_U256);
+const SUBLIMB_SHIFT: U256 = uint!(0x4000_U256);
+
+fn c(value: u64) -> U256 {
+    U256::from(value)
+}
+
+pub fn accumulate_relation_evaluations(
+    proof: &UltraHonkProof,
+    rp: &RelationParameters,
+    alphas: &[U256; NUMBER_OF_ALPHAS],
+    pow_partial_eval: U256,
+) -> U256 {
+    let w = |wire: Wire| proof.evaluation(wire);
+    let mut evals = [U256::ZERO; NUMBER_OF_SUBRELATIONS];
+
+    accumulate_arithmetic(&w, &mut evals, pow_partial_eval);
+    accumulate_permutation(&w, rp, &mut evals, pow_partial_eval);
+    accumulate_log_derivative_lookup(&w, rp, &mut evals, pow_partial_eval);
+    accumulate_delta_range(&w, &mut evals, pow_partial_eval);
+    accumulate_elliptic(&w, &mut evals, pow_partial_eval);
+    accumulate_auxiliary(&w, rp, &mut evals, pow_partial_eval);
+    accumulate_poseidon_external(&w, &mut evals, pow_partial_eval);
+    accumulate_poseidon_internal(&w, &mut evals, pow_partial_eval);
+
+    evals[1..]
+        .iter()
+        .zip(alphas)
+        .fold(evals[0], |acc, (eval, alpha)| {
+            fr::add(acc, fr::mul(*eval, *alpha))
+        })
+}
+
+fn accumulate_arithmetic(
+    w: &impl Fn(Wire) -> U256,
+    evals: &mut [U256; NUMBER_OF_SUBRELATIONS],
+    domain_sep: U256,
+) {
+    let q_arith = w(Wire::QArith);
+
+    let mut accum = fr::mul(
+        fr::mul(
+            fr::sub(q_arith, c(3)),
+            fr::mul(fr::mul(w(Wire::QM), w(Wire::WR)), w(Wire::WL)),
+        ),
+        NEG_HALF,
+    );
+    accum = fr::add(accum, fr::mul(w(Wire::QL), w(Wire::WL)));
+    accum = fr::add(accum, fr::mul(w(Wire::QR), w(Wire::WR)));
+    accum = fr::add(accum, fr::mul(w(Wire::QO), w(Wire::WO)));
+    accum = fr::add(accum, fr::mul(w(Wire::Q4), w(Wire::W4)));
+    accum = fr::add(accum, w(Wire::QC));
+    accum = fr::add(accum, fr::mul(fr::sub(q_arith, c(1)), w(Wire::W4Shift)));
+    evals[0] = fr::mul(fr::mul(accum, q_arith), domain_sep);
+
+    let mut accum = fr::add(
+        fr::sub(fr::add(w(Wire::WL), w(Wire::W4)), w(Wire::WLShift)),
+        w(Wire::QM),
+    );
+    accum = fr::mul(accum, fr::sub(q_arith, c(2)));
+    accum = fr::mul(accum, fr::sub(q_arith, c(1)));
+    evals[1] = fr::mul(fr::mul(accum, q_arith), domain_sep);
+}
+
+fn accumulate_permutation(
+    w: &impl Fn(Wire) -> U256,
+    rp: &RelationParameters,
+    evals: &mut [U256; NUMBER_OF_SUBRELATIONS],
+    domain_sep: U256,
+) {
+    let term =
+        |wire: Wire, sigma: Wire| fr::add(fr::add(w(wire), fr::mul(w(sigma), rp.beta)), rp.gamma);
+    let numerator = fr::mul(
+        fr::mul(term(Wire::WL, Wire::Id1), term(Wire::WR, Wire::Id2)),
+        fr::mul(term(Wire::WO, Wire::Id3), term(Wire::W4, Wire::Id4)),
+    );
+    let denominator = fr::mul(
+        fr::mul(term(Wire::WL, Wire::Sigma1), term(Wire::WR, Wire::Sigma2)),
+        fr::mul(term(Wire::WO, Wire::Sigma3), term(Wire::W4, Wire::Sigma4)),
+    );
+
+    let mut acc = fr::mul(fr::add(w(Wire::ZPerm), w(Wire::LagrangeFirst)), numerator);
+    acc = fr::sub(
+        acc,
+        fr::mul(
+            fr::add(
+                w(Wire::ZPermShift),
+                fr::mul(w(Wire::LagrangeLast), rp.public_inputs_delta),
+            ),
+            denominator,
+        ),
+    );
+    evals[2] = fr::mul(acc, domain_sep);
+    evals[3] = fr::mul(
+        fr::mul(w(Wire::LagrangeLast), w(Wire::ZPermShift)),
+        domain_sep,
+    );
+}
+
+fn accumulate_log_derivative_lookup(
+    w: &impl Fn(Wire) -> U256,
+    rp: &RelationParameters,
+    evals: &mut [U256; NUMBER_OF_SUBRELATIONS],
+    domain_sep: U256,
+) {
+    let write_term = fr::add(
+        fr::add(
+            fr::add(w(Wire::Table1), rp.gamma),
+            fr::mul(w(Wire::Table2), rp.eta),
+        ),
+        fr::add(
+            fr::mul(w(Wire::Table3), rp.eta_two),
+            fr::mul(w(Wire::Table4), rp.eta_three),
+        ),
+    );
+
+    let derived_entry_1 = fr::add(
+        fr::add(w(Wire::WL), rp.gamma),
+        fr::mul(w(Wire::QR), w(Wire::WLShift)),
+    );
+    let derived_entry_2 = fr::add(w(Wire::WR), fr::mul(w(Wire::QM), w(Wire::WRShift)));
+    let derived_entry_3 = fr::add(w(Wire::WO), fr::mul(w(Wire::QC), w(Wire::WOShift)));
+
+    let read_term = fr::add(
+        fr::add(derived_entry_1, fr::mul(derived_entry_2, rp.eta)),
+        fr::add(
+            fr::mul(derived_entry_3, rp.eta_two),
+            fr::mul(w(Wire::QO), rp.eta_three),
+        ),
+    );
+
+    let inverses = w(Wire::LookupInverses);
+    let read_inverse = fr::mul(inverses, write_term);
+    let write_inverse = fr::mul(inverses, read_term);
+
+    let read_tag = w(Wire::LookupReadTags);
+    let q_lookup = w(Wire::QLookup);
+    let inverse_exists_xor = fr::sub(fr::add(read_tag, q_lookup), fr::mul(read_tag, q_lookup));
+
+    let accumulator_none = fr::sub(
+        fr::mul(fr::mul(read_term, write_term), inverses),
+        inverse_exists_xor,
+    );
+    evals[4] = fr::mul(accumulator_none, domain_sep);
+    evals[5] = fr::sub(
+        fr::mul(q_lookup, read_inverse),
+        fr::mul(w(Wire::LookupReadCounts), write_inverse),
+    );
+}
+
+fn accumulate_delta_range(
+    w: &impl Fn(Wire) -> U256,
+    evals: &mut [U256; NUMBER_OF_SUBRELATIONS],
+    domain_sep: U256,
+) {
+    let deltas = [
+        fr::sub(w(Wire::WR), w(Wire::WL)),
+        fr::sub(w(Wire::WO), w(Wire::WR)),
+        fr::sub(w(Wire::W4), w(Wire::WO)),
+        fr::sub(w(Wire::WLShift), w(Wire::W4)),
+    ];
+    let scale = fr::mul(w(Wire::QRange), domain_sep);
+
+    // delta · (delta - 1) · (delta - 2) · (delta - 3)
+    for (eval, delta) in evals[6..10].iter_mut().zip(deltas) {
+        let mut acc = delta;
+        for k in 1..=3 {
+            acc = fr::mul(acc, fr::sub(delta, c(k)));
+        }
+        *eval = fr::mul(acc, scale);
+    }
+}
+
+fn accumulate_elliptic(
+    w: &impl Fn(Wire) -> U256,
+    evals: &mut [U256; NUMBER_OF_SUBRELATIONS],
+    domain_sep: U256,
+) {
+    let x_1 = w(Wire::WR);
+    let y_1 = w(Wire::WO);
+    let x_2 = w(Wire::WLShift);
+    let y_2 = w(Wire::W4Shift);
+    let y_3 = w(Wire::WOShift);
+    let x_3 = w(Wire::WRShift);
+
+    let q_sign = w(Wire::QL);
+    let q_is_double = w(Wire::QM);
+    let q_elliptic = w(Wire::QElliptic);
+
+    let x_diff = fr::sub(x_2, x_1);
+    let y1_sqr = fr::square(y_1);
+
+    let add_scale = fr::mul(fr::mul(domain_sep, q_elliptic), fr::sub(c(1), q_is_double));
+    let double_scale = fr::mul(fr::mul(domain_sep, q_elliptic), q_is_double);
+
+    // Point addition, x-coordinate.
+    let y2_sqr = fr::square(y_2);
+    let y1y2 = fr::mul(fr::mul(y_1, y_2), q_sign);
+    let mut x_add_identity = fr::add(fr::add(x_3, x_2), x_1);
+    x_add_identity = fr::mul(fr::mul(x_add_identity, x_diff), x_diff);
+    x_add_identity = fr::add(
+        fr::sub(fr::sub(x_add_identity, y2_sqr), y1_sqr),
+        fr::add(y1y2, y1y2),
+    );
+    evals[10] = fr::mul(x_add_identity, add_scale);
+
+    // Point addition, y-coordinate.
+    let y1_plus_y3 = fr::add(y_1, y_3);
+    let y_diff = fr::sub(fr::mul(y_2, q_sign), y_1);
+    let y_add_identity = fr::add(
+        fr::mul(y1_plus_y3, x_diff),
+        fr::mul(fr::sub(x_3, x_1), y_diff),
+    );
+    evals[11] = fr::mul(y_add_identity, add_scale);
+
+    // Point doubling, x-coordinate.
+    let x_pow_4 = fr::mul(fr::add(y1_sqr, GRUMPKIN_CURVE_B_PARAMETER_NEGATED), x_1);
+    let y1_sqr_mul_4 = fr::mul(y1_sqr, c(4));
+    let x1_pow_4_mul_9 = fr::mul(x_pow_4, c(9));
+    let x_double_identity = fr::sub(
+        fr::mul(fr::add(fr::add(x_3, x_1), x_1), y1_sqr_mul_4),
+        x1_pow_4_mul_9,
+    );
+    evals[10] = fr::add(evals[10], fr::mul(x_double_identity, double_scale));
+
+    // Point doubling, y-coordinate.
+    let x1_sqr_mul_3 = fr::mul(fr::mul(x_1, c(3)), x_1);
+    let y_double_identity = fr::sub(
+        fr::mul(x1_sqr_mul_3, fr::sub(x_1, x_3)),
+        fr::mul(fr::add(y_1, y_1), fr::add(y_1, y_3)),
+    );
+    evals[11] = fr::add(evals[11], fr::mul(y_double_identity, double_scale));
+}
+
+/// Memory (ROM/RAM), non-native field and limb accumulation gates.
+fn accumulate_auxiliary(
+    w: &impl Fn(Wire) -> U256,
+    rp: &RelationParameters,
+    evals: &mut [U256; NUMBER_OF_SUBRELATIONS],
+    domain_sep: U256,
+) {
+    let (w_l, w_r, w_o, w_4) = (w(Wire::WL), w(Wire::WR), w(Wire::WO), w(Wire::W4));
+    let (w_l_shift, w_r_shift, w_o_shift, w_4_shift) = (
+        w(Wire::WLShift),
+        w(Wire::WRShift),
+        w(Wire::WOShift),
+        w(Wire::W4Shift),
+    );
+    let (q_l, q_r, q_o, q_4, q_m, q_c) = (
+        w(Wire::QL),
+        w(Wire::QR),
+        w(Wire::QO),
+        w(Wire::Q4),
+        w(Wire::QM),
+        w(Wire::QC),
+    );
+    let q_arith = w(Wire::QArith);
+    let aux_scale = fr::mul(w(Wire::QAux), domain_sep);
+
+    // Non-native field arithmetic.
+    let mut limb_subproduct = fr::add(fr::mul(w_l, w_r_shift), fr::mul(w_l_shift, w_r));
+    let mut non_native_field_gate_2 =
+        fr::sub(fr::add(fr::mul(w_l, w_4), fr::mul(w_r, w_o)), w_o_shift);
+    non_native_field_gate_2 = fr::mul(non_native_field_gate_2, LIMB_SIZE);
+    non_native_field_gate_2 = fr::sub(non_native_field_gate_2, w_4_shift);
+    non_native_field_gate_2 = fr::add(non_native_field_gate_2, limb_subproduct);
+    non_native_field_gate_2 = fr::mul(non_native_field_gate_2, q_4);
+
+    limb_subproduct = fr::mul(limb_subproduct, LIMB_SIZE);
+    limb_subproduct = fr::add(limb_subproduct, fr::mul(w_l_shift, w_r_shift));
+    let non_native_field_gate_1 = fr::mul(fr::sub(limb_subproduct, fr::add(w_o, w_4)), q_o);
+    let non_native_field_gate_3 = fr::mul(
+        fr::sub(fr::add(limb_subproduct, w_4), fr::add(w_o_shift, w_4_shift)),
+        q_m,
+    );
+    let non_native_field_identity = fr::mul(
+        fr::add(
+            fr::add(non_native_field_gate_1, non_native_field_gate_2),
+            non_native_field_gate_3,
+        ),
+        q_r,
+    );
+
+    // Limb accumulators: ((((w2' · 2^14 + w1') · 2^14 + w3) · 2^14 + w2) · 2^14 + w1 - w4) · q4
+    let horner = |terms: [U256; 5]| {
+        terms[1..]
+            .iter()
+            .fold(terms[0], |acc, t| fr::add(fr::mul(acc, SUBLIMB_SHIFT), *t))
+    };
+    let limb_accumulator_1 = fr::mul(
+        fr::sub(horner([w_r_shift, w_l_shift, w_o, w_r, w_l]), w_4),
+        q_4,
+    );
+    let limb_accumulator_2 = fr::mul(
+        fr::sub(
+            horner([w_o_shift, w_r_shift, w_l_shift, w_4, w_o]),
+            w_4_shift,
+        ),
+        q_m,
+    );
+    let limb_accumulator_identity = fr::mul(fr::add(limb_accumulator_1, limb_accumulator_2), q_o);
+
+    // Memory records: w3 · η₃ + w2 · η₂ + w1 · η + q_c
+    let partial_record_check = fr::add(
+        fr::add(fr::mul(w_o, rp.eta_three), fr::mul(w_r, rp.eta_two)),
+        fr::add(fr::mul(w_l, rp.eta), q_c),
+    );
+    let memory_record_check = fr::sub(partial_record_check, w_4);
+
+    // ROM consistency.
+    let index_delta = fr::sub(w_l_shift, w_l);
+    let record_delta = fr::sub(w_4_shift, w_4);
+    let index_is_monotonically_increasing = fr::sub(fr::square(index_delta), index_delta);
+    let one_minus_index_delta = fr::sub(c(1), index_delta);
+    let adjacent_values_match_if_adjacent_indices_match =
+        fr::mul(one_minus_index_delta, record_delta);
+
+    let rom_scale = fr::mul(fr::mul(q_l, q_r), aux_scale);
+    evals[13] = fr::mul(adjacent_values_match_if_adjacent_indices_match, rom_scale);
+    evals[14] = fr::mul(index_is_monotonically_increasing, rom_scale);
+    let rom_consistency_check_identity = fr::mul(memory_record_check, fr::mul(q_l, q_r));
+
+    // RAM consistency.
+    let access_type = fr::sub(w_4, partial_record_check);
+    let access_check = fr::sub(fr::square(access_type), access_type);
+
+    let next_gate_access_type = fr::sub(
+        w_4_shift,
+        fr::add(
+            fr::add(
+                fr::mul(w_o_shift, rp.eta_three),
+                fr::mul(w_r_shift, rp.eta_two),
+            ),
+            fr::mul(w_l_shift, rp.eta),
+        ),
+    );
+    let value_delta = fr::sub(w_o_shift, w_o);
+    let adjacent_values_match_if_adjacent_indices_match_and_next_access_is_a_write = fr::mul(
+        fr::mul(one_minus_index_delta, value_delta),
+        fr::sub(c(1), next_gate_access_type),
+    );
+    let next_gate_access_type_is_boolean =
+        fr::sub(fr::square(next_gate_access_type), next_gate_access_type);
+
+    let ram_scale = fr::mul(q_arith, aux_scale);
+    evals[15] = fr::mul(
+        adjacent_values_match_if_adjacent_indices_match_and_next_access_is_a_write,
+        ram_scale,
+    );
+    evals[16] = fr::mul(index_is_monotonically_increasing, ram_scale);
+    evals[17] = fr::mul(next_gate_access_type_is_boolean, ram_scale);
+    let ram_consistency_check_identity = fr::mul(access_check, q_arith);
+
+    // RAM timestamps.
+    let timestamp_delta = fr::sub(w_r_shift, w_r);
+    let ram_timestamp_check_identity =
+        fr::sub(fr::mul(one_minus_index_delta, timestamp_delta), w_o);
+
+    let mut memory_identity = rom_consistency_check_identity;
+    memory_identity = fr::add(
+        memory_identity,
+        fr::mul(ram_timestamp_check_identity, fr::mul(q_4, q_l)),
+    );
+    memory_identity = fr::add(
+        memory_identity,
+        fr::mul(memory_record_check, fr::mul(q_m, q_l)),
+    );
+    memory_identity = fr::add(memory_identity, ram_consistency_check_identity);
+
+    let auxiliary_identity = fr::add(
+        fr::add(memory_identity, non_native_field_identity),
+        limb_accumulator_identity,
+    );
+    evals[12] = fr::mul(auxiliary_identity, aux_scale);
+}
+
+fn pow5(x: U256) -> U256 {
+    fr::mul(fr::square(fr::square(x)), x)
+}
+
+fn accumulate_poseidon_external(
+    w: &impl Fn(Wire) -> U256,
+    evals: &mut [U256; NUMBER_OF_SUBRELATIONS],
+    domain_sep: U256,
+) {
+    let u1 = pow5(fr::add(w(Wire::WL), w(Wire::QL)));
+    let u2 = pow5(fr::add(w(Wire::WR), w(Wire::QR)));
+    let u3 = pow5(fr::add(w(Wire::WO), w(Wire::QO)));
+    let u4 = pow5(fr::add(w(Wire::W4), w(Wire::Q4)));
+
+    // External matrix [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]].
+    let t0 = fr::add(u1, u2);
+    let t1 = fr::add(u3, u4);
+    let t2 = fr::add(fr::add(u2, u2), t1);
+    let t3 = fr::add(fr::add(u4, u4), t0);
+    let v4 = fr::add(fr::mul(t1, c(4)), t3);
+    let v2 = fr::add(fr::mul(t0, c(4)), t2);
+    let v1 = fr::add(t3, v2);
+    let v3 = fr::add(t2, v4);
+
+    let scale = fr::mul(w(Wire::QPoseidon2External), domain_sep);
+    let shifts = [Wire::WLShift, Wire::WRShift, Wire::WOShift, Wire::W4Shift];
+    for ((eval, v), shift) in evals[18..22].iter_mut().zip([v1, v2, v3, v4]).zip(shifts) {
+        *eval = fr::add(*eval, fr::mul(scale, fr::sub(v, w(shift))));
+    }
+}
+
+fn accumulate_poseidon_internal(
+    w: &impl Fn(Wire) -> U256,
+    evals: &mut [U256; NUMBER_OF_SUBRELATIONS],
+    domain_sep: U256,
+) {
+    let u = [
+        pow5(fr::add(w(Wire::WL), w(Wire::QL))),
+        w(Wire::WR),
+        w(Wire::WO),
+        w(Wire::W4),
+    ];
+    let u_sum = u.iter().fold(U256::ZERO, |acc, x| fr::add(acc, *x));
+
+    let scale = fr::mul(w(Wire::QPoseidon2Internal), domain_sep);
+    let shifts = [Wire::WLShift, Wire::WRShift, Wire::WOShift, Wire::W4Shift];
+    for (i, eval) in evals[22..26].iter_mut().enumerate() {
+        let v = fr::add(fr::mul(u[i], POSEIDON2_INTERNAL_MATRIX_DIAGONAL[i]), u_sum);
+        *eval = fr::add(*eval, fr::mul(scale, fr::sub(v, w(shifts[i]))));
+    }
+}
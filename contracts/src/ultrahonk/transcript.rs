@@ -0,0 +1,171 @@
+use alloc::vec::Vec;
+use stylus_sdk::{alloy_primitives::U256, crypto::keccak};
+
+use crate::common::fr;
+use crate::ultrahonk::{
+    config::{CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ALPHAS},
+    types::{ProofPoint, UltraHonkProof, UltraHonkVerificationKey},
+};
+
+pub struct RelationParameters {
+    pub eta: U256,
+    pub eta_two: U256,
+    pub eta_three: U256,
+    pub beta: U256,
+    pub gamma: U256,
+    pub public_inputs_delta: U256,
+}
+
+/// Fiat-Shamir challenges of an UltraHonk proof, derived as in `HonkVerifier.sol`.
+pub struct Transcript {
+    pub relation_parameters: RelationParameters,
+    pub alphas: [U256; NUMBER_OF_ALPHAS],
+    pub gate_challenges: [U256; CONST_PROOF_SIZE_LOG_N],
+    pub sumcheck_u_challenges: [U256; CONST_PROOF_SIZE_LOG_N],
+    pub rho: U256,
+    pub gemini_r: U256,
+    pub shplonk_nu: U256,
+    pub shplonk_z: U256,
+}
+
+impl Transcript {
+    pub fn generate(
+        proof: &UltraHonkProof,
+        vk: &UltraHonkVerificationKey,
+        public_inputs: &[U256],
+    ) -> Self {
+        let mut round = Vec::new();
+        push_word(&mut round, U256::from(vk.circuit_size));
+        push_word(&mut round, U256::from(vk.public_inputs_size));
+        push_word(&mut round, U256::from(vk.pub_inputs_offset));
+        for input in public_inputs.iter().chain(&proof.pairing_point_object) {
+            push_word(&mut round, *input);
+        }
+        for point in [&proof.w1, &proof.w2, &proof.w3] {
+            push_point(&mut round, point);
+        }
+        let mut previous = hash(&round);
+        let (eta, eta_two) = split_challenge(previous);
+        previous = hash_challenge(previous);
+        let (eta_three, _) = split_challenge(previous);
+
+        let mut round = challenge_round(previous);
+        for point in [
+            &proof.lookup_read_counts,
+            &proof.lookup_read_tags,
+            &proof.w4,
+        ] {
+            push_point(&mut round, point);
+        }
+        previous = hash(&round);
+        let (beta, gamma) = split_challenge(previous);
+
+        let mut round = challenge_round(previous);
+        push_point(&mut round, &proof.lookup_inverses);
+        push_point(&mut round, &proof.z_perm);
+        previous = hash(&round);
+        let mut alphas = [U256::ZERO; NUMBER_OF_ALPHAS];
+        (alphas[0], alphas[1]) = split_challenge(previous);
+        for i in 1..NUMBER_OF_ALPHAS / 2 {
+            previous = hash_challenge(previous);
+            (alphas[2 * i], alphas[2 * i + 1]) = split_challenge(previous);
+        }
+        if NUMBER_OF_ALPHAS % 2 == 1 {
+            previous = hash_challenge(previous);
+            alphas[NUMBER_OF_ALPHAS - 1] = split_challenge(previous).0;
+        }
+
+        let mut gate_challenges = [U256::ZERO; CONST_PROOF_SIZE_LOG_N];
+        for challenge in gate_challenges.iter_mut() {
+            previous = hash_challenge(previous);
+            *challenge = split_challenge(previous).0;
+        }
+
+        let mut sumcheck_u_challenges = [U256::ZERO; CONST_PROOF_SIZE_LOG_N];
+        for (challenge, univariate) in sumcheck_u_challenges
+            .iter_mut()
+            .zip(&proof.sumcheck_univariates)
+        {
+            let mut round = challenge_round(previous);
+            for eval in univariate {
+                push_word(&mut round, *eval);
+            }
+            previous = hash(&round);
+            *challenge = split_challenge(previous).0;
+        }
+
+        let mut round = challenge_round(previous);
+        for eval in &proof.sumcheck_evaluations {
+            push_word(&mut round, *eval);
+        }
+        previous = hash(&round);
+        let rho = split_challenge(previous).0;
+
+        let mut round = challenge_round(previous);
+        for point in &proof.gemini_fold_comms {
+            push_point(&mut round, point);
+        }
+        previous = hash(&round);
+        let gemini_r = split_challenge(previous).0;
+
+        let mut round = challenge_round(previous);
+        for eval in &proof.gemini_a_evaluations {
+            push_word(&mut round, *eval);
+        }
+        previous = hash(&round);
+        let shplonk_nu = split_challenge(previous).0;
+
+        let mut round = challenge_round(previous);
+        push_point(&mut round, &proof.shplonk_q);
+        previous = hash(&round);
+        let shplonk_z = split_challenge(previous).0;
+
+        Self {
+            relation_parameters: RelationParameters {
+                eta,
+                eta_two,
+                eta_three,
+                beta,
+                gamma,
+                public_inputs_delta: U256::ZERO,
+            },
+            alphas,
+            gate_challenges,
+            sumcheck_u_challenges,
+            rho,
+            gemini_r,
+            shplonk_nu,
+            shplonk_z,
+        }
+    }
+}
+
+fn push_word(buf: &mut Vec<u8>, word: U256) {
+    buf.extend_from_slice(&word.to_be_bytes::<32>());
+}
+
+fn push_point(buf: &mut Vec<u8>, point: &ProofPoint) {
+    for limb in point.limbs {
+        push_word(buf, limb);
+    }
+}
+
+fn challenge_round(previous: U256) -> Vec<u8> {
+    let mut round = Vec::new();
+    push_word(&mut round, previous);
+    round
+}
+
+fn hash(buf: &[u8]) -> U256 {
+    fr::from_be_bytes_mod_order(keccak(buf).as_slice())
+}
+
+fn hash_challenge(previous: U256) -> U256 {
+    hash(&previous.to_be_bytes::<32>())
+}
+
+/// Splits a challenge into its low and high 128 bits.
+fn split_challenge(challenge: U256) -> (U256, U256) {
+    let lo = challenge & ((U256::from(1u8) << 128) - U256::from(1u8));
+    (lo, challenge >> 128)
+}
@@ -0,0 +1,254 @@
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::common::{
+    groth16::{Q, R},
+    G1Point,
+};
+use crate::ultrahonk::config::{
+    BATCHED_RELATION_PARTIAL_LENGTH, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ENTITIES,
+    NUMBER_OF_PRECOMPUTED, PAIRING_POINTS_SIZE, PROOF_SIZE,
+};
+
+/// Sumcheck entities, in the order of `sumcheckEvaluations`.
+#[derive(Clone, Copy)]
+pub enum Wire {
+    QM,
+    QC,
+    QL,
+    QR,
+    QO,
+    Q4,
+    QLookup,
+    QArith,
+    QRange,
+    QElliptic,
+    QAux,
+    QPoseidon2External,
+    QPoseidon2Internal,
+    Sigma1,
+    Sigma2,
+    Sigma3,
+    Sigma4,
+    Id1,
+    Id2,
+    Id3,
+    Id4,
+    Table1,
+    Table2,
+    Table3,
+    Table4,
+    LagrangeFirst,
+    LagrangeLast,
+    WL,
+    WR,
+    WO,
+    W4,
+    ZPerm,
+    LookupInverses,
+    LookupReadCounts,
+    LookupReadTags,
+    WLShift,
+    WRShift,
+    WOShift,
+    W4Shift,
+    ZPermShift,
+}
+
+/// UltraHonk verification key as written by `bb write_vk -s ultra_honk --oracle_hash keccak`.
+///
+/// ```text
+/// u64 circuit_size | u64 log_circuit_size | u64 num_public_inputs | u64 pub_inputs_offset
+/// 27 × (32 x || 32 y)   precomputed commitments, in `Wire` order up to `LagrangeLast`
+/// ```
+pub struct UltraHonkVerificationKey {
+    pub circuit_size: u64,
+    pub log_circuit_size: u64,
+    /// Includes the `PAIRING_POINTS_SIZE` recursion limbs.
+    pub public_inputs_size: u64,
+    pub pub_inputs_offset: u64,
+    pub commitments: [G1Point; NUMBER_OF_PRECOMPUTED],
+}
+
+impl UltraHonkVerificationKey {
+    pub const ENCODED_SIZE: usize = 4 * 8 + NUMBER_OF_PRECOMPUTED * 64;
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::ENCODED_SIZE {
+            return None;
+        }
+
+        let word = |i: usize| {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(&bytes[i * 8..(i + 1) * 8]);
+            u64::from_be_bytes(b)
+        };
+        let circuit_size = word(0);
+        let log_circuit_size = word(1);
+        let public_inputs_size = word(2);
+        let pub_inputs_offset = word(3);
+
+        if log_circuit_size == 0
+            || log_circuit_size > CONST_PROOF_SIZE_LOG_N as u64
+            || circuit_size != 1 << log_circuit_size
+            || public_inputs_size < PAIRING_POINTS_SIZE as u64
+        {
+            return None;
+        }
+
+        let mut commitments = [G1Point::ZERO; NUMBER_OF_PRECOMPUTED];
+        for (i, commitment) in commitments.iter_mut().enumerate() {
+            let offset = 32 + i * 64;
+            *commitment = G1Point {
+                x: U256::from_be_slice(&bytes[offset..offset + 32]),
+                y: U256::from_be_slice(&bytes[offset + 32..offset + 64]),
+            };
+            if commitment.x >= Q || commitment.y >= Q {
+                return None;
+            }
+        }
+
+        Some(Self {
+            circuit_size,
+            log_circuit_size,
+            public_inputs_size,
+            pub_inputs_offset,
+            commitments,
+        })
+    }
+}
+
+/// A proof commitment together with its four transcript limbs `x_0, x_1, y_0, y_1`,
+/// where `x = x_0 + x_1 · 2^136`.
+#[derive(Clone, Copy)]
+pub struct ProofPoint {
+    pub point: G1Point,
+    pub limbs: [U256; 4],
+}
+
+/// UltraHonk proof in the padded layout of `HonkVerifier.sol`, `PROOF_SIZE` 32-byte words.
+pub struct UltraHonkProof {
+    pub pairing_point_object: [U256; PAIRING_POINTS_SIZE],
+    pub w1: ProofPoint,
+    pub w2: ProofPoint,
+    pub w3: ProofPoint,
+    pub w4: ProofPoint,
+    pub z_perm: ProofPoint,
+    pub lookup_read_counts: ProofPoint,
+    pub lookup_read_tags: ProofPoint,
+    pub lookup_inverses: ProofPoint,
+    pub sumcheck_univariates: Vec<[U256; BATCHED_RELATION_PARTIAL_LENGTH]>,
+    pub sumcheck_evaluations: [U256; NUMBER_OF_ENTITIES],
+    pub gemini_fold_comms: Vec<ProofPoint>,
+    pub gemini_a_evaluations: [U256; CONST_PROOF_SIZE_LOG_N],
+    pub shplonk_q: ProofPoint,
+    pub kzg_quotient: ProofPoint,
+}
+
+impl UltraHonkProof {
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != PROOF_SIZE * 32 {
+            return None;
+        }
+        let mut r = WordReader { bytes };
+
+        let mut pairing_point_object = [U256::ZERO; PAIRING_POINTS_SIZE];
+        for limb in pairing_point_object.iter_mut() {
+            *limb = r.fr()?;
+        }
+
+        let w1 = r.point()?;
+        let w2 = r.point()?;
+        let w3 = r.point()?;
+        let lookup_read_counts = r.point()?;
+        let lookup_read_tags = r.point()?;
+        let w4 = r.point()?;
+        let lookup_inverses = r.point()?;
+        let z_perm = r.point()?;
+
+        let mut sumcheck_univariates = Vec::with_capacity(CONST_PROOF_SIZE_LOG_N);
+        for _ in 0..CONST_PROOF_SIZE_LOG_N {
+            let mut univariate = [U256::ZERO; BATCHED_RELATION_PARTIAL_LENGTH];
+            for eval in univariate.iter_mut() {
+                *eval = r.fr()?;
+            }
+            sumcheck_univariates.push(univariate);
+        }
+
+        let mut sumcheck_evaluations = [U256::ZERO; NUMBER_OF_ENTITIES];
+        for eval in sumcheck_evaluations.iter_mut() {
+            *eval = r.fr()?;
+        }
+
+        let mut gemini_fold_comms = Vec::with_capacity(CONST_PROOF_SIZE_LOG_N - 1);
+        for _ in 0..CONST_PROOF_SIZE_LOG_N - 1 {
+            gemini_fold_comms.push(r.point()?);
+        }
+
+        let mut gemini_a_evaluations = [U256::ZERO; CONST_PROOF_SIZE_LOG_N];
+        for eval in gemini_a_evaluations.iter_mut() {
+            *eval = r.fr()?;
+        }
+
+        Some(Self {
+            pairing_point_object,
+            w1,
+            w2,
+            w3,
+            w4,
+            z_perm,
+            lookup_read_counts,
+            lookup_read_tags,
+            lookup_inverses,
+            sumcheck_univariates,
+            sumcheck_evaluations,
+            gemini_fold_comms,
+            gemini_a_evaluations,
+            shplonk_q: r.point()?,
+            kzg_quotient: r.point()?,
+        })
+    }
+
+    pub fn evaluation(&self, wire: Wire) -> U256 {
+        self.sumcheck_evaluations[wire as usize]
+    }
+}
+
+struct WordReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl WordReader<'_> {
+    fn word(&mut self) -> U256 {
+        let (head, tail) = self.bytes.split_at(32);
+        self.bytes = tail;
+        U256::from_be_slice(head)
+    }
+
+    fn fr(&mut self) -> Option<U256> {
+        Some(self.word()).filter(|x| *x < R)
+    }
+
+    fn point(&mut self) -> Option<ProofPoint> {
+        let limbs = [self.word(), self.word(), self.word(), self.word()];
+        // Low limbs hold 136 bits; high limbs must not overflow once shifted.
+        let low_bound = U256::from(1u8) << 136;
+        let high_bound = U256::from(1u8) << 120;
+        if limbs[0] >= low_bound
+            || limbs[2] >= low_bound
+            || limbs[1] >= high_bound
+            || limbs[3] >= high_bound
+        {
+            return None;
+        }
+        let x = limbs[0] | (limbs[1] << 136);
+        let y = limbs[2] | (limbs[3] << 136);
+        if x >= Q || y >= Q {
+            return None;
+        }
+        Some(ProofPoint {
+            point: G1Point { x, y },
+            limbs,
+        })
+    }
+}
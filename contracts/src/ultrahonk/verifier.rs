@@ -0,0 +1,499 @@
+use alloc::{vec, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{uint, Address, B256, U256},
+    crypto::keccak,
+    prelude::*,
+};
+
+use crate::common::{
+    fr,
+    groth16::{ec_add, ec_mul, negate_g1, Q, R},
+    ownable::{IOwnable, Ownable},
+    G1Point, KzgVerifier,
+};
+use crate::ultrahonk::{
+    config::{
+        BATCHED_RELATION_PARTIAL_LENGTH, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ENTITIES,
+        NUMBER_UNSHIFTED, PAIRING_POINTS_SIZE, PROOF_SIZE, SRS_G2_X,
+    },
+    errors::UltraHonkError,
+    relations::accumulate_relation_evaluations,
+    transcript::Transcript,
+    types::{UltraHonkProof, UltraHonkVerificationKey},
+};
+
+pub trait IUltraHonkVerifier {
+    type Error;
+
+    /// Imports the encoded verification key (see [`UltraHonkVerificationKey`]); owner-only
+    /// and callable once.
+    fn initialize(&mut self, verification_key: Vec<u8>) -> Result<(), Self::Error>;
+
+    fn verify(&self, proof: Vec<u8>, public_inputs: Vec<B256>) -> Result<bool, Self::Error>;
+
+    fn get_verification_key_hash(&self) -> B256;
+    fn is_initialized(&self) -> bool;
+}
+
+sol_storage! {
+    pub struct UltraHonkVerifier {
+        bytes verification_key;
+        bytes32 verification_key_hash;
+        bool initialized;
+        Ownable ownable;
+    }
+}
+
+#[public]
+impl IUltraHonkVerifier for UltraHonkVerifier {
+    type Error = Vec<u8>;
+
+    fn initialize(&mut self, verification_key: Vec<u8>) -> Result<(), Self::Error> {
+        self.only_owner()?;
+        if self.initialized.get() {
+            return Err(UltraHonkError::ALREADY_INITIALIZED.abi_encode());
+        }
+        if UltraHonkVerificationKey::from_bytes(&verification_key).is_none() {
+            return Err(UltraHonkError::InvalidVerificationKey.abi_encode());
+        }
+
+        self.verification_key_hash.set(keccak(&verification_key));
+        self.verification_key.set_bytes(&verification_key);
+        self.initialized.set(true);
+
+        Ok(())
+    }
+
+    fn verify(&self, proof: Vec<u8>, public_inputs: Vec<B256>) -> Result<bool, Self::Error> {
+        if !self.initialized.get() {
+            return Err(UltraHonkError::INVALID_INITIALIZATION.abi_encode());
+        }
+
+        let vk = UltraHonkVerificationKey::from_bytes(&self.verification_key.get_bytes())
+            .ok_or_else(|| UltraHonkError::InvalidVerificationKey.abi_encode())?;
+        let public_inputs: Vec<U256> = public_inputs
+            .iter()
+            .map(|input| U256::from_be_bytes(input.0))
+            .collect();
+
+        verify_proof_with_key(&vk, &proof, &public_inputs).map_err(|e| e.abi_encode())?;
+
+        Ok(true)
+    }
+
+    fn get_verification_key_hash(&self) -> B256 {
+        self.verification_key_hash.get()
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.initialized.get()
+    }
+}
+
+#[public]
+impl IOwnable for UltraHonkVerifier {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.ownable.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.ownable
+            .transfer_ownership(new_owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.ownable.accept_ownership().map_err(|e| e.abi_encode())
+    }
+}
+
+impl UltraHonkVerifier {
+    /// Sets the owner; to be called from the deploying contract's constructor.
+    pub fn set_initial_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable
+            .set_initial_owner(owner)
+            .map_err(|e| e.abi_encode())
+    }
+
+    fn only_owner(&self) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner().map_err(|e| e.abi_encode())
+    }
+}
+
+/// Verifies a Barretenberg UltraHonk proof (Keccak transcript) against `vk`.
+pub fn verify_proof_with_key(
+    vk: &UltraHonkVerificationKey,
+    proof_bytes: &[u8],
+    public_inputs: &[U256],
+) -> Result<(), UltraHonkError> {
+    let expected_proof_len = PROOF_SIZE * 32;
+    if proof_bytes.len() != expected_proof_len {
+        return Err(UltraHonkError::ProofLengthMismatch {
+            received: U256::from(proof_bytes.len()),
+            expected: U256::from(expected_proof_len),
+        });
+    }
+
+    let expected_inputs = vk.public_inputs_size as usize - PAIRING_POINTS_SIZE;
+    if public_inputs.len() != expected_inputs {
+        return Err(UltraHonkError::PublicInputsLengthMismatch {
+            received: U256::from(public_inputs.len()),
+            expected: U256::from(expected_inputs),
+        });
+    }
+    if public_inputs.iter().any(|input| *input >= R) {
+        return Err(UltraHonkError::INVALID_PROOF_DATA);
+    }
+
+    let proof =
+        UltraHonkProof::from_bytes(proof_bytes).ok_or(UltraHonkError::INVALID_PROOF_DATA)?;
+
+    let mut tp = Transcript::generate(&proof, vk, public_inputs);
+    tp.relation_parameters.public_inputs_delta = compute_public_input_delta(
+        public_inputs,
+        &proof.pairing_point_object,
+        tp.relation_parameters.beta,
+        tp.relation_parameters.gamma,
+        vk.circuit_size,
+        vk.pub_inputs_offset,
+    )
+    .ok_or(UltraHonkError::INVALID_PROOF_DATA)?;
+
+    if !verify_sumcheck(&proof, &tp, vk.log_circuit_size as usize).unwrap_or(false) {
+        return Err(UltraHonkError::SumcheckFailed);
+    }
+
+    if !verify_shplemini(&proof, vk, &tp).unwrap_or(false) {
+        return Err(UltraHonkError::ShpleminiFailed);
+    }
+
+    Ok(())
+}
+
+/// `∏ (γ + β·(n + offset + i) + pᵢ) / ∏ (γ - β·(offset + 1 + i) + pᵢ)` over the public
+/// inputs followed by the pairing point limbs.
+fn compute_public_input_delta(
+    public_inputs: &[U256],
+    pairing_point_object: &[U256],
+    beta: U256,
+    gamma: U256,
+    domain_size: u64,
+    offset: u64,
+) -> Option<U256> {
+    let mut numerator = U256::from(1u8);
+    let mut denominator = U256::from(1u8);
+
+    let mut numerator_acc = fr::add(gamma, fr::mul(beta, U256::from(domain_size + offset)));
+    let mut denominator_acc = fr::sub(gamma, fr::mul(beta, U256::from(offset + 1)));
+
+    for input in public_inputs.iter().chain(pairing_point_object) {
+        numerator = fr::mul(numerator, fr::add(numerator_acc, *input));
+        denominator = fr::mul(denominator, fr::add(denominator_acc, *input));
+        numerator_acc = fr::add(numerator_acc, beta);
+        denominator_acc = fr::sub(denominator_acc, beta);
+    }
+
+    Some(fr::mul(numerator, fr::inv(denominator)?))
+}
+
+fn verify_sumcheck(proof: &UltraHonkProof, tp: &Transcript, log_n: usize) -> Option<bool> {
+    let mut round_target = U256::ZERO;
+    let mut pow_partial_evaluation = U256::from(1u8);
+
+    for round in 0..log_n {
+        let univariate = &proof.sumcheck_univariates[round];
+        if fr::add(univariate[0], univariate[1]) != round_target {
+            return Some(false);
+        }
+
+        let challenge = tp.sumcheck_u_challenges[round];
+        round_target = compute_next_target_sum(univariate, challenge)?;
+        pow_partial_evaluation = fr::mul(
+            pow_partial_evaluation,
+            fr::add(
+                U256::from(1u8),
+                fr::mul(
+                    challenge,
+                    fr::sub(tp.gate_challenges[round], U256::from(1u8)),
+                ),
+            ),
+        );
+    }
+
+    let grand_honk_relation_sum = accumulate_relation_evaluations(
+        proof,
+        &tp.relation_parameters,
+        &tp.alphas,
+        pow_partial_evaluation,
+    );
+    Some(grand_honk_relation_sum == round_target)
+}
+
+/// Evaluates the round univariate, given on `{0, .., 7}`, at `challenge` by barycentric
+/// interpolation.
+fn compute_next_target_sum(
+    univariate: &[U256; BATCHED_RELATION_PARTIAL_LENGTH],
+    challenge: U256,
+) -> Option<U256> {
+    let domain: Vec<U256> = (0..BATCHED_RELATION_PARTIAL_LENGTH as u64)
+        .map(U256::from)
+        .collect();
+
+    let mut numerator = U256::from(1u8);
+    let mut denominators = Vec::with_capacity(BATCHED_RELATION_PARTIAL_LENGTH);
+    for (i, xi) in domain.iter().enumerate() {
+        numerator = fr::mul(numerator, fr::sub(challenge, *xi));
+        let weight = domain
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(U256::from(1u8), |acc, (_, xj)| {
+                fr::mul(acc, fr::sub(*xi, *xj))
+            });
+        denominators.push(fr::mul(weight, fr::sub(challenge, *xi)));
+    }
+
+    let inverses = fr::batch_inverse(&denominators)?;
+    let sum = univariate
+        .iter()
+        .zip(inverses)
+        .fold(U256::ZERO, |acc, (eval, inv)| {
+            fr::add(acc, fr::mul(*eval, inv))
+        });
+    Some(fr::mul(sum, numerator))
+}
+
+/// Shplemini batch opening of all entities at the sumcheck point, reduced to a single KZG
+/// pairing check and aggregated with the recursion pairing points carried in the proof.
+fn verify_shplemini(
+    proof: &UltraHonkProof,
+    vk: &UltraHonkVerificationKey,
+    tp: &Transcript,
+) -> Option<bool> {
+    let log_n = vk.log_circuit_size as usize;
+    let one = U256::from(1u8);
+
+    // r^{2^i}
+    let mut powers = [U256::ZERO; CONST_PROOF_SIZE_LOG_N];
+    powers[0] = tp.gemini_r;
+    for i in 1..CONST_PROOF_SIZE_LOG_N {
+        powers[i] = fr::square(powers[i - 1]);
+    }
+
+    let size = NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 2;
+    let mut scalars = vec![U256::ZERO; size];
+    let mut commitments = vec![G1Point::ZERO; size];
+
+    let mut pos_inverted_denominator = fr::inv(fr::sub(tp.shplonk_z, powers[0]))?;
+    let mut neg_inverted_denominator = fr::inv(fr::add(tp.shplonk_z, powers[0]))?;
+    let unshifted_scalar = fr::add(
+        pos_inverted_denominator,
+        fr::mul(tp.shplonk_nu, neg_inverted_denominator),
+    );
+    let shifted_scalar = fr::mul(
+        fr::inv(tp.gemini_r)?,
+        fr::sub(
+            pos_inverted_denominator,
+            fr::mul(tp.shplonk_nu, neg_inverted_denominator),
+        ),
+    );
+
+    scalars[0] = one;
+    commitments[0] = proof.shplonk_q.point;
+
+    let mut batching_challenge = one;
+    let mut batched_evaluation = U256::ZERO;
+    for (i, (slot, eval)) in scalars[1..=NUMBER_OF_ENTITIES]
+        .iter_mut()
+        .zip(&proof.sumcheck_evaluations)
+        .enumerate()
+    {
+        let scalar = if i < NUMBER_UNSHIFTED {
+            unshifted_scalar
+        } else {
+            shifted_scalar
+        };
+        *slot = fr::neg(fr::mul(scalar, batching_challenge));
+        batched_evaluation = fr::add(batched_evaluation, fr::mul(*eval, batching_challenge));
+        batching_challenge = fr::mul(batching_challenge, tp.rho);
+    }
+
+    let witness = [
+        proof.w1.point,
+        proof.w2.point,
+        proof.w3.point,
+        proof.w4.point,
+        proof.z_perm.point,
+        proof.lookup_inverses.point,
+        proof.lookup_read_counts.point,
+        proof.lookup_read_tags.point,
+    ];
+    let shifted = [
+        proof.w1.point,
+        proof.w2.point,
+        proof.w3.point,
+        proof.w4.point,
+        proof.z_perm.point,
+    ];
+    for (slot, point) in commitments[1..=NUMBER_OF_ENTITIES]
+        .iter_mut()
+        .zip(vk.commitments.iter().chain(&witness).chain(&shifted))
+    {
+        *slot = *point;
+    }
+
+    let fold_pos_evaluations = compute_fold_pos_evaluations(
+        &tp.sumcheck_u_challenges,
+        batched_evaluation,
+        &proof.gemini_a_evaluations,
+        &powers,
+        log_n,
+    )?;
+
+    let mut constant_term_accumulator = fr::mul(fold_pos_evaluations[0], pos_inverted_denominator);
+    constant_term_accumulator = fr::add(
+        constant_term_accumulator,
+        fr::mul(
+            fr::mul(proof.gemini_a_evaluations[0], tp.shplonk_nu),
+            neg_inverted_denominator,
+        ),
+    );
+    batching_challenge = fr::square(tp.shplonk_nu);
+
+    for i in 0..CONST_PROOF_SIZE_LOG_N - 1 {
+        // Rounds past the circuit size are padding and contribute nothing.
+        if i < log_n - 1 {
+            pos_inverted_denominator = fr::inv(fr::sub(tp.shplonk_z, powers[i + 1]))?;
+            neg_inverted_denominator = fr::inv(fr::add(tp.shplonk_z, powers[i + 1]))?;
+
+            let scaling_factor_pos = fr::mul(batching_challenge, pos_inverted_denominator);
+            let scaling_factor_neg = fr::mul(
+                fr::mul(batching_challenge, tp.shplonk_nu),
+                neg_inverted_denominator,
+            );
+            scalars[NUMBER_OF_ENTITIES + 1 + i] =
+                fr::neg(fr::add(scaling_factor_neg, scaling_factor_pos));
+
+            let contribution = fr::add(
+                fr::mul(scaling_factor_neg, proof.gemini_a_evaluations[i + 1]),
+                fr::mul(scaling_factor_pos, fold_pos_evaluations[i + 1]),
+            );
+            constant_term_accumulator = fr::add(constant_term_accumulator, contribution);
+            batching_challenge = fr::mul(fr::square(tp.shplonk_nu), batching_challenge);
+        }
+        commitments[NUMBER_OF_ENTITIES + 1 + i] = proof.gemini_fold_comms[i].point;
+    }
+
+    commitments[NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N] = G1Point::GENERATOR;
+    scalars[NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N] = constant_term_accumulator;
+    commitments[NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 1] = proof.kzg_quotient.point;
+    scalars[NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 1] = tp.shplonk_z;
+
+    let mut p_0 = batch_mul(&commitments, &scalars)?;
+    let mut p_1 = negate_g1(&proof.kzg_quotient.point);
+
+    // Aggregate with the pairing points of any recursively verified proofs.
+    let (p_0_other, p_1_other) = convert_pairing_points_to_g1(&proof.pairing_point_object)?;
+    let separator = generate_recursion_separator(&p_0_other, &p_1_other, &p_0, &p_1);
+    p_0 = ec_add(&ec_mul(&p_0, separator)?, &p_0_other)?;
+    p_1 = ec_add(&ec_mul(&p_1, separator)?, &p_1_other)?;
+
+    // e(P_0, [1]_2) · e(P_1, [x]_2) == 1, with P_1 carrying the negated quotient.
+    Some(KzgVerifier::new(SRS_G2_X).verify_accumulator(&negate_g1(&p_1), &p_0))
+}
+
+/// Computes `A_l(r^{2^l})` for `l = 0, .., log_n - 1` from the negative-point evaluations.
+fn compute_fold_pos_evaluations(
+    sumcheck_u_challenges: &[U256; CONST_PROOF_SIZE_LOG_N],
+    mut batched_eval_accumulator: U256,
+    gemini_evaluations: &[U256; CONST_PROOF_SIZE_LOG_N],
+    gemini_eval_challenge_powers: &[U256; CONST_PROOF_SIZE_LOG_N],
+    log_size: usize,
+) -> Option<[U256; CONST_PROOF_SIZE_LOG_N]> {
+    let one = U256::from(1u8);
+    let mut fold_pos_evaluations = [U256::ZERO; CONST_PROOF_SIZE_LOG_N];
+
+    for i in (1..=CONST_PROOF_SIZE_LOG_N).rev() {
+        let challenge_power = gemini_eval_challenge_powers[i - 1];
+        let u = sumcheck_u_challenges[i - 1];
+
+        let numerator = fr::sub(
+            fr::mul(
+                fr::mul(challenge_power, batched_eval_accumulator),
+                U256::from(2u8),
+            ),
+            fr::mul(
+                gemini_evaluations[i - 1],
+                fr::sub(fr::mul(challenge_power, fr::sub(one, u)), u),
+            ),
+        );
+        let denominator = fr::add(fr::mul(challenge_power, fr::sub(one, u)), u);
+        let batched_eval_round_acc = fr::mul(numerator, fr::inv(denominator)?);
+
+        if i <= log_size {
+            batched_eval_accumulator = batched_eval_round_acc;
+            fold_pos_evaluations[i - 1] = batched_eval_round_acc;
+        }
+    }
+
+    Some(fold_pos_evaluations)
+}
+
+fn batch_mul(points: &[G1Point], scalars: &[U256]) -> Option<G1Point> {
+    points
+        .iter()
+        .zip(scalars)
+        .filter(|(_, scalar)| !scalar.is_zero())
+        .try_fold(G1Point::ZERO, |acc, (point, scalar)| {
+            ec_add(&acc, &ec_mul(point, *scalar)?)
+        })
+}
+
+/// Recombines the 68-bit limbs of the two accumulated pairing points.
+fn convert_pairing_points_to_g1(limbs: &[U256; PAIRING_POINTS_SIZE]) -> Option<(G1Point, G1Point)> {
+    let coordinate =
+        |offset: usize| (0..4).fold(U256::ZERO, |acc, i| acc | (limbs[offset + i] << (68 * i)));
+    let lhs = G1Point {
+        x: coordinate(0),
+        y: coordinate(4),
+    };
+    let rhs = G1Point {
+        x: coordinate(8),
+        y: coordinate(12),
+    };
+
+    (is_on_curve(&lhs) && is_on_curve(&rhs)).then_some((lhs, rhs))
+}
+
+fn is_on_curve(point: &G1Point) -> bool {
+    if point.x >= Q || point.y >= Q {
+        return false;
+    }
+    let lhs = point.y.mul_mod(point.y, Q);
+    let rhs = point
+        .x
+        .mul_mod(point.x, Q)
+        .mul_mod(point.x, Q)
+        .add_mod(uint!(3_U256), Q);
+    lhs == rhs
+}
+
+fn generate_recursion_separator(
+    proof_lhs: &G1Point,
+    proof_rhs: &G1Point,
+    acc_lhs: &G1Point,
+    acc_rhs: &G1Point,
+) -> U256 {
+    let mut buf = Vec::with_capacity(8 * 32);
+    for point in [proof_lhs, proof_rhs, acc_lhs, acc_rhs] {
+        buf.extend_from_slice(&point.x.to_be_bytes::<32>());
+        buf.extend_from_slice(&point.y.to_be_bytes::<32>());
+    }
+    fr::from_be_bytes_mod_order(keccak(&buf).as_slice())
+}
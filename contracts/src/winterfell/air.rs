@@ -0,0 +1,99 @@
+//! AIRs are described in Rust, as in Winterfell: an implementation of [`Air`] states the
+//! trace shape, evaluates its transition constraints at a frame of two consecutive rows
+//! and lists its boundary assertions.
+//!
+//! Transition constraints hold on every step but the last. Only single-step assertions
+//! are supported, which is what Miden's AIR uses.
+
+use alloc::vec::Vec;
+
+use crate::winterfell::{
+    field::{Felt, WinterField},
+    proof::TraceInfo,
+};
+
+/// Two consecutive rows of one trace segment.
+pub struct EvaluationFrame<E> {
+    pub current: Vec<E>,
+    pub next: Vec<E>,
+}
+
+/// `column` of the segment equals `value` at `step`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Assertion<E> {
+    pub column: usize,
+    pub step: usize,
+    pub value: E,
+}
+
+impl<E> Assertion<E> {
+    pub fn single(column: usize, step: usize, value: E) -> Self {
+        Self {
+            column,
+            step,
+            value,
+        }
+    }
+}
+
+/// Seed contribution of an AIR's public inputs.
+pub trait ToElements {
+    fn to_elements(&self) -> Vec<Felt>;
+}
+
+pub trait Air: Sized {
+    type PublicInputs: ToElements;
+
+    /// Builds the AIR for a proof's trace, or `None` if the trace shape is not the one
+    /// the AIR describes.
+    fn new(trace_info: &TraceInfo, public_inputs: Self::PublicInputs) -> Option<Self>;
+
+    fn trace_info(&self) -> &TraceInfo;
+
+    fn public_inputs(&self) -> &Self::PublicInputs;
+
+    /// Degrees of the main transition constraints, in evaluation order.
+    fn transition_degrees(&self) -> Vec<usize>;
+
+    fn evaluate_transition<E: WinterField>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    );
+
+    fn assertions(&self) -> Vec<Assertion<Felt>>;
+
+    /// Degrees of the auxiliary transition constraints.
+    fn aux_transition_degrees(&self) -> Vec<usize> {
+        Vec::new()
+    }
+
+    /// Evaluates the auxiliary transition constraints given the random elements drawn
+    /// after the main trace commitment.
+    fn evaluate_aux_transition<E: WinterField>(
+        &self,
+        _main_frame: &EvaluationFrame<E>,
+        _aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        _aux_rands: &[E],
+        _result: &mut [E],
+    ) {
+    }
+
+    fn aux_assertions<E: WinterField>(&self, _aux_rands: &[E]) -> Vec<Assertion<E>> {
+        Vec::new()
+    }
+
+    /// Values of each periodic column over one cycle, whose length is a power of two
+    /// dividing the trace length.
+    fn periodic_columns(&self) -> Vec<Vec<Felt>> {
+        Vec::new()
+    }
+}
+
+impl ToElements for Vec<Felt> {
+    fn to_elements(&self) -> Vec<Felt> {
+        self.clone()
+    }
+}
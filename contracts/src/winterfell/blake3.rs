@@ -0,0 +1,127 @@
+//! BLAKE3 with 32-byte output and no key, as used by Winterfell's `Blake3_256` hasher.
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+const CHUNK_START: u32 = 1;
+const CHUNK_END: u32 = 2;
+const PARENT: u32 = 4;
+const ROOT: u32 = 8;
+
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+
+fn g(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(x);
+    state[d] = (state[d] ^ state[a]).rotate_right(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(12);
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(y);
+    state[d] = (state[d] ^ state[a]).rotate_right(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(7);
+}
+
+fn compress(
+    cv: &[u32; 8],
+    block: &[u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+) -> [u32; 8] {
+    let mut state = [
+        cv[0],
+        cv[1],
+        cv[2],
+        cv[3],
+        cv[4],
+        cv[5],
+        cv[6],
+        cv[7],
+        IV[0],
+        IV[1],
+        IV[2],
+        IV[3],
+        counter as u32,
+        (counter >> 32) as u32,
+        block_len,
+        flags,
+    ];
+    let mut m = *block;
+    for round in 0..7 {
+        g(&mut state, 0, 4, 8, 12, m[0], m[1]);
+        g(&mut state, 1, 5, 9, 13, m[2], m[3]);
+        g(&mut state, 2, 6, 10, 14, m[4], m[5]);
+        g(&mut state, 3, 7, 11, 15, m[6], m[7]);
+        g(&mut state, 0, 5, 10, 15, m[8], m[9]);
+        g(&mut state, 1, 6, 11, 12, m[10], m[11]);
+        g(&mut state, 2, 7, 8, 13, m[12], m[13]);
+        g(&mut state, 3, 4, 9, 14, m[14], m[15]);
+        if round < 6 {
+            m = MSG_PERMUTATION.map(|i| m[i]);
+        }
+    }
+    core::array::from_fn(|i| state[i] ^ state[i + 8])
+}
+
+fn block_words(bytes: &[u8]) -> [u32; 16] {
+    let mut block = [0u8; BLOCK_LEN];
+    block[..bytes.len()].copy_from_slice(bytes);
+    core::array::from_fn(|i| u32::from_le_bytes(block[4 * i..4 * i + 4].try_into().unwrap()))
+}
+
+/// Chaining value of chunk `index`; `root` is set when the chunk is the whole input.
+fn chunk_cv(chunk: &[u8], index: u64, root: bool) -> [u32; 8] {
+    let mut cv = IV;
+    let n_blocks = chunk.len().div_ceil(BLOCK_LEN).max(1);
+    for i in 0..n_blocks {
+        let block = &chunk[i * BLOCK_LEN..chunk.len().min((i + 1) * BLOCK_LEN)];
+        let mut flags = 0;
+        if i == 0 {
+            flags |= CHUNK_START;
+        }
+        if i == n_blocks - 1 {
+            flags |= CHUNK_END;
+            if root {
+                flags |= ROOT;
+            }
+        }
+        cv = compress(&cv, &block_words(block), index, block.len() as u32, flags);
+    }
+    cv
+}
+
+fn parent_cv(left: &[u32; 8], right: &[u32; 8], root: bool) -> [u32; 8] {
+    let mut block = [0u32; 16];
+    block[..8].copy_from_slice(left);
+    block[8..].copy_from_slice(right);
+    let flags = if root { PARENT | ROOT } else { PARENT };
+    compress(&IV, &block, 0, BLOCK_LEN as u32, flags)
+}
+
+/// Chaining value of the subtree over `input`, whose first chunk has index `first`. The
+/// left subtree takes the largest power-of-two number of chunks that leaves the right
+/// one non-empty.
+fn subtree_cv(input: &[u8], first: u64, root: bool) -> [u32; 8] {
+    if input.len() <= CHUNK_LEN {
+        return chunk_cv(input, first, root);
+    }
+    let n_chunks = input.len().div_ceil(CHUNK_LEN);
+    let left_chunks = 1 << (usize::BITS - 1 - (n_chunks - 1).leading_zeros());
+    let (left, right) = input.split_at(left_chunks * CHUNK_LEN);
+    let left_cv = subtree_cv(left, first, false);
+    let right_cv = subtree_cv(right, first + left_chunks as u64, false);
+    parent_cv(&left_cv, &right_cv, root)
+}
+
+pub fn hash(input: &[u8]) -> [u8; 32] {
+    let words = subtree_cv(input, 0, true);
+    let mut out = [0u8; 32];
+    for (chunk, word) in out.chunks_exact_mut(4).zip(words) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    out
+}
@@ -0,0 +1,155 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::winterfell::{
+    field::{Felt, WinterField},
+    hash::{ElementHasher, Rpo256},
+    rpo::{self, STATE_WIDTH},
+};
+
+/// The verifier's source of Fiat-Shamir randomness.
+pub trait RandomCoin: Sized {
+    fn new<E: WinterField>(seed: &[E]) -> Self;
+
+    fn reseed(&mut self, data: &B256);
+
+    /// Number of trailing zeros of the proof-of-work output for `nonce`.
+    fn check_leading_zeros(&self, nonce: u64) -> u32;
+
+    fn draw<E: WinterField>(&mut self) -> Option<E>;
+
+    fn draw_many<E: WinterField>(&mut self, n: usize) -> Option<Vec<E>> {
+        (0..n).map(|_| self.draw()).collect()
+    }
+
+    /// Draws `n` positions in a domain of size `domain_size` (a power of two) after mixing
+    /// in the proof-of-work nonce. Positions may repeat.
+    fn draw_integers(&mut self, n: usize, domain_size: usize, nonce: u64) -> Vec<usize>;
+}
+
+/// Winterfell's `DefaultRandomCoin`: draws hash the seed with an incrementing counter,
+/// and reseeding merges a digest into the seed.
+pub struct DefaultRandomCoin<H: ElementHasher> {
+    seed: B256,
+    counter: u64,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> DefaultRandomCoin<H> {
+    pub fn reseed_with_int(&mut self, value: u64) {
+        self.seed = H::merge_with_int(&self.seed, value);
+        self.counter = 0;
+    }
+
+    fn next(&mut self) -> B256 {
+        self.counter += 1;
+        H::merge_with_int(&self.seed, self.counter)
+    }
+}
+
+impl<H: ElementHasher> RandomCoin for DefaultRandomCoin<H> {
+    fn new<E: WinterField>(seed: &[E]) -> Self {
+        Self {
+            seed: H::hash_elements(seed),
+            counter: 0,
+            _hasher: PhantomData,
+        }
+    }
+
+    fn reseed(&mut self, data: &B256) {
+        self.seed = H::merge(&self.seed, data);
+        self.counter = 0;
+    }
+
+    /// Reads the first eight bytes of `H(seed || nonce)`.
+    fn check_leading_zeros(&self, nonce: u64) -> u32 {
+        let digest = H::merge_with_int(&self.seed, nonce);
+        u64::from_le_bytes(digest[..8].try_into().unwrap()).trailing_zeros()
+    }
+
+    /// Draws an element from the leading bytes of successive outputs, skipping those that
+    /// are not canonical.
+    fn draw<E: WinterField>(&mut self) -> Option<E> {
+        (0..1000).find_map(|_| E::from_bytes(&self.next()[..E::ELEMENT_BYTES]))
+    }
+
+    fn draw_integers(&mut self, n: usize, domain_size: usize, nonce: u64) -> Vec<usize> {
+        self.reseed_with_int(nonce);
+        let mask = (domain_size - 1) as u64;
+        (0..n)
+            .map(|_| {
+                let value = self.next();
+                (u64::from_le_bytes(value[..8].try_into().unwrap()) & mask) as usize
+            })
+            .collect()
+    }
+}
+
+/// Miden's `RpoRandomCoin`: a duplex sponge over the `Rpo256` state that adds reseeding
+/// data to the first half of the rate and draws the rate elements in order.
+pub struct RpoRandomCoin {
+    state: [Felt; STATE_WIDTH],
+    current: usize,
+}
+
+const RATE_START: usize = 4;
+
+impl RpoRandomCoin {
+    fn absorb(&mut self, data: &[Felt]) {
+        for (x, value) in self.state[RATE_START..].iter_mut().zip(data) {
+            *x += *value;
+        }
+        rpo::permute(&mut self.state);
+        self.current = RATE_START;
+    }
+
+    fn draw_base(&mut self) -> Felt {
+        if self.current == STATE_WIDTH {
+            rpo::permute(&mut self.state);
+            self.current = RATE_START;
+        }
+        self.current += 1;
+        self.state[self.current - 1]
+    }
+}
+
+impl RandomCoin for RpoRandomCoin {
+    fn new<E: WinterField>(seed: &[E]) -> Self {
+        let mut coin = Self {
+            state: [Felt::ZERO; STATE_WIDTH],
+            current: RATE_START,
+        };
+        coin.absorb(&Rpo256::digest_elements(&Rpo256::hash_elements(seed)));
+        coin
+    }
+
+    fn reseed(&mut self, data: &B256) {
+        self.absorb(&Rpo256::digest_elements(data));
+    }
+
+    /// Reads the first rate element after absorbing `nonce`.
+    fn check_leading_zeros(&self, nonce: u64) -> u32 {
+        let mut state = self.state;
+        state[RATE_START] += Felt::new(nonce);
+        rpo::permute(&mut state);
+        state[RATE_START].as_canonical_u64().trailing_zeros()
+    }
+
+    /// Takes one base element per coordinate; rate elements are always canonical.
+    fn draw<E: WinterField>(&mut self) -> Option<E> {
+        let mut bytes = Vec::with_capacity(E::ELEMENT_BYTES);
+        for _ in 0..E::ELEMENT_BYTES / 8 {
+            bytes.extend_from_slice(&self.draw_base().as_canonical_u64().to_le_bytes());
+        }
+        E::from_bytes(&bytes)
+    }
+
+    fn draw_integers(&mut self, n: usize, domain_size: usize, nonce: u64) -> Vec<usize> {
+        self.absorb(&[Felt::new(nonce)]);
+        let mask = (domain_size - 1) as u64;
+        (0..n)
+            .map(|_| (self.draw_base().as_canonical_u64() & mask) as usize)
+            .collect()
+    }
+}
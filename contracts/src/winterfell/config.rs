@@ -0,0 +1,8 @@
+/// Conjectured security, in bits, a proof's options must reach. Miden's default options
+/// (27 queries, blowup 8, 16 grinding bits) give 96.
+pub const MIN_CONJECTURED_SECURITY: u32 = 96;
+
+/// Collision resistance of the 256-bit hashers, in bits.
+pub const COLLISION_RESISTANCE: u32 = 128;
+
+pub const MIN_LOG_TRACE_LENGTH: usize = 3;
@@ -0,0 +1,48 @@
+use stylus_sdk::alloy_sol_types::{sol, SolError};
+
+use crate::common::VerificationError;
+
+sol! {
+    error InvalidAir();
+    error UnsupportedOptions();
+    error InsufficientSecurity();
+    error InvalidPublicInputs();
+    error OodConstraintMismatch();
+}
+
+#[derive(Debug)]
+pub enum WinterfellError {
+    Common(VerificationError),
+    /// The AIR does not accept the proof's trace shape or lists invalid assertions.
+    InvalidAir,
+    UnsupportedOptions,
+    InsufficientSecurity,
+    InvalidPublicInputs,
+    OodConstraintMismatch,
+}
+
+impl WinterfellError {
+    pub fn abi_encode(&self) -> alloc::vec::Vec<u8> {
+        match self {
+            WinterfellError::Common(e) => e.abi_encode(),
+            WinterfellError::InvalidAir => InvalidAir {}.abi_encode(),
+            WinterfellError::UnsupportedOptions => UnsupportedOptions {}.abi_encode(),
+            WinterfellError::InsufficientSecurity => InsufficientSecurity {}.abi_encode(),
+            WinterfellError::InvalidPublicInputs => InvalidPublicInputs {}.abi_encode(),
+            WinterfellError::OodConstraintMismatch => OodConstraintMismatch {}.abi_encode(),
+        }
+    }
+}
+
+impl From<VerificationError> for WinterfellError {
+    fn from(error: VerificationError) -> Self {
+        WinterfellError::Common(error)
+    }
+}
+
+impl WinterfellError {
+    pub const VERIFICATION_FAILED: WinterfellError =
+        WinterfellError::Common(VerificationError::VerificationFailed);
+    pub const INVALID_PROOF_DATA: WinterfellError =
+        WinterfellError::Common(VerificationError::InvalidProofData);
+}
@@ -0,0 +1,165 @@
+//! Winterfell's 64-bit field and its quadratic extension `F_p[X]/(X^2 - X + 2)`.
+//!
+//! The base field is the Goldilocks field of `common::stark`. Winterfell picks a different
+//! two-adic root of unity from Plonky3, so domains are generated with [`root_of_unity`].
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::common::stark::{ExtensionField, Field, PrimeField};
+
+pub use crate::common::stark::Goldilocks as Felt;
+
+/// Generator of the subgroup of order `2^32`, chosen so that the one of order 64 is 8.
+const TWO_ADIC_ROOT_OF_UNITY: Felt = Felt::new(7277203076849721926);
+pub const TWO_ADICITY: usize = 32;
+
+/// Generator of the subgroup of order `2^log_n`.
+pub fn root_of_unity(log_n: usize) -> Felt {
+    TWO_ADIC_ROOT_OF_UNITY.pow(1 << (TWO_ADICITY - log_n))
+}
+
+/// A field the verifier runs in: the base field or its quadratic extension, as selected by
+/// the proof's `field_extension` option.
+pub trait WinterField: ExtensionField<Felt> {
+    /// The `field_extension` option selecting this field.
+    const EXTENSION: u8;
+    const ELEMENT_BYTES: usize;
+
+    /// Reads `ELEMENT_BYTES` little-endian bytes, rejecting non-canonical coordinates.
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+
+    fn write_bytes(&self, out: &mut alloc::vec::Vec<u8>);
+}
+
+impl ExtensionField<Felt> for Felt {
+    const DEGREE: usize = 1;
+
+    fn from_base(x: Felt) -> Self {
+        x
+    }
+
+    fn mul_base(self, x: Felt) -> Self {
+        self * x
+    }
+}
+
+impl WinterField for Felt {
+    const EXTENSION: u8 = 1;
+    const ELEMENT_BYTES: usize = 8;
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Felt::from_canonical_u64(u64::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    fn write_bytes(&self, out: &mut alloc::vec::Vec<u8>) {
+        out.extend_from_slice(&self.as_canonical_u64().to_le_bytes());
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuadExt(pub Felt, pub Felt);
+
+impl QuadExt {
+    pub const fn new(a: Felt, b: Felt) -> Self {
+        Self(a, b)
+    }
+}
+
+impl Field for QuadExt {
+    const ZERO: Self = Self(Felt::ZERO, Felt::ZERO);
+    const ONE: Self = Self(Felt::ONE, Felt::ZERO);
+
+    fn inv(self) -> Self {
+        // The conjugate of `a + bX` is `(a + b) - bX`, as the roots of `X^2 - X + 2` sum
+        // to one; the norm is `a^2 + ab + 2b^2`.
+        let Self(a, b) = self;
+        let norm = a * a + a * b + b * b.double();
+        let norm_inv = norm.inv();
+        Self((a + b) * norm_inv, -b * norm_inv)
+    }
+}
+
+impl ExtensionField<Felt> for QuadExt {
+    const DEGREE: usize = 2;
+
+    fn from_base(x: Felt) -> Self {
+        Self(x, Felt::ZERO)
+    }
+
+    fn mul_base(self, x: Felt) -> Self {
+        Self(self.0 * x, self.1 * x)
+    }
+}
+
+impl WinterField for QuadExt {
+    const EXTENSION: u8 = 2;
+    const ELEMENT_BYTES: usize = 16;
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 16 {
+            return None;
+        }
+        Some(Self(
+            Felt::from_bytes(&bytes[..8])?,
+            Felt::from_bytes(&bytes[8..])?,
+        ))
+    }
+
+    fn write_bytes(&self, out: &mut alloc::vec::Vec<u8>) {
+        self.0.write_bytes(out);
+        self.1.write_bytes(out);
+    }
+}
+
+impl Add for QuadExt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub for QuadExt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Mul for QuadExt {
+    type Output = Self;
+
+    /// Uses `X^2 = X - 2`.
+    fn mul(self, rhs: Self) -> Self {
+        let a0b0 = self.0 * rhs.0;
+        let a1b1 = self.1 * rhs.1;
+        Self(a0b0 - a1b1.double(), self.0 * rhs.1 + self.1 * rhs.0 + a1b1)
+    }
+}
+
+impl Neg for QuadExt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl AddAssign for QuadExt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for QuadExt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for QuadExt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
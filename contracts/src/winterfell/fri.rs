@@ -0,0 +1,96 @@
+//! Winterfell's FRI over the natural-order LDE domain `offset · <g>`.
+//!
+//! With folding factor `f`, a layer over `N` points commits to leaves of `f` values, leaf
+//! `i` holding the evaluations at positions `i + j · N / f`. These points form the coset
+//! `offset · g^i · <g^(N / f)>`, and the next layer takes at `i` the value at `alpha` of the
+//! polynomial interpolating them. As in Winterfell, every layer keeps the offset of the LDE
+//! domain rather than its power. The last layer is sent as the coefficients of the
+//! remainder polynomial.
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::common::stark::{poly, Field, TwoAdicField};
+use crate::winterfell::{
+    field::{root_of_unity, Felt, WinterField},
+    hash::ElementHasher,
+    merkle,
+    proof::{parse_elements, FriProof},
+};
+
+/// Verifies that `evaluations` at the sorted, distinct `positions` of the LDE domain of
+/// size `2^log_domain` lie on a polynomial of degree below `2^log_domain / blowup`.
+///
+/// `commitments` holds the root of every layer followed by the hash of the remainder, and
+/// `alphas` the element drawn after each of them.
+#[allow(clippy::too_many_arguments)]
+pub fn verify<H: ElementHasher, E: WinterField>(
+    proof: &FriProof,
+    commitments: &[B256],
+    alphas: &[E],
+    log_domain: usize,
+    blowup: usize,
+    folding_factor: usize,
+    positions: &[usize],
+    evaluations: &[E],
+) -> Option<()> {
+    let n_layers = commitments.len().checked_sub(1)?;
+    if proof.layers.len() != n_layers || alphas.len() != commitments.len() {
+        return None;
+    }
+    let log_folding = folding_factor.trailing_zeros() as usize;
+    let mut log_domain = log_domain;
+    let mut g = root_of_unity(log_domain);
+    let offset = Felt::GENERATOR;
+    let mut positions = positions.to_vec();
+    let mut evaluations = evaluations.to_vec();
+
+    for ((layer, root), alpha) in proof.layers.iter().zip(commitments).zip(alphas) {
+        let log_row_length = log_domain.checked_sub(log_folding)?;
+        let row_length = 1 << log_row_length;
+        let mut folded_positions: Vec<usize> = positions.iter().map(|p| p % row_length).collect();
+        folded_positions.sort_unstable();
+        folded_positions.dedup();
+
+        let rows = layer.rows::<E>(folded_positions.len(), folding_factor)?;
+        for (position, value) in positions.iter().zip(&evaluations) {
+            let i = folded_positions
+                .binary_search(&(position % row_length))
+                .ok()?;
+            if rows[i][position / row_length] != *value {
+                return None;
+            }
+        }
+        merkle::verify_batch::<H>(
+            root,
+            log_row_length,
+            &merkle::leaves::<H, E>(&folded_positions, &rows),
+            &layer.paths,
+        )?;
+
+        let omega = g.pow(row_length as u64);
+        evaluations = folded_positions
+            .iter()
+            .zip(&rows)
+            .map(|(i, row)| poly::interpolate_coset(row, offset * g.pow(*i as u64), omega, *alpha))
+            .collect();
+        positions = folded_positions;
+        log_domain = log_row_length;
+        g = g.pow(folding_factor as u64);
+    }
+
+    let domain_size = 1usize << log_domain;
+    let n_coeffs = proof.remainder.len() / E::ELEMENT_BYTES;
+    if n_coeffs == 0 || n_coeffs > (domain_size / blowup).max(1) {
+        return None;
+    }
+    let remainder = parse_elements::<E>(&proof.remainder, n_coeffs)?;
+    if H::hash_elements(&remainder) != commitments[n_layers] {
+        return None;
+    }
+    positions
+        .iter()
+        .zip(&evaluations)
+        .all(|(p, value)| poly::eval(&remainder, E::from_base(offset * g.pow(*p as u64))) == *value)
+        .then_some(())
+}
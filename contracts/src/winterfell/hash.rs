@@ -0,0 +1,202 @@
+//! Hash functions behind Winterfell's random coin and vector commitments.
+//!
+//! Digests are 32 bytes. `Rpo256` digests are four field elements, each written as a
+//! canonical little-endian `u64`, which is also how Miden serializes them.
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::common::stark::PrimeField;
+use crate::winterfell::{
+    blake3,
+    field::{Felt, WinterField},
+    rpo::{self, STATE_WIDTH},
+};
+
+/// The hash function a proof is generated with, numbered as Miden's `HashFunction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashFunction {
+    Blake3_256 = 1,
+    Rpo256 = 2,
+}
+
+pub trait ElementHasher {
+    fn hash_elements<E: WinterField>(elements: &[E]) -> B256;
+
+    fn merge(left: &B256, right: &B256) -> B256;
+
+    fn merge_with_int(seed: &B256, value: u64) -> B256;
+}
+
+/// Winterfell's `Blake3_256`.
+pub struct Blake3;
+
+impl Blake3 {
+    fn hash(bytes: &[u8]) -> B256 {
+        B256::new(blake3::hash(bytes))
+    }
+}
+
+impl ElementHasher for Blake3 {
+    /// Hashes the canonical little-endian encoding of `elements`.
+    fn hash_elements<E: WinterField>(elements: &[E]) -> B256 {
+        let mut bytes = Vec::with_capacity(elements.len() * E::ELEMENT_BYTES);
+        for element in elements {
+            element.write_bytes(&mut bytes);
+        }
+        Self::hash(&bytes)
+    }
+
+    fn merge(left: &B256, right: &B256) -> B256 {
+        Self::hash(&[left.as_slice(), right.as_slice()].concat())
+    }
+
+    fn merge_with_int(seed: &B256, value: u64) -> B256 {
+        Self::hash(&[seed.as_slice(), &value.to_le_bytes()].concat())
+    }
+}
+
+/// Miden's `Rpo256`: a sponge with capacity `state[0..4]`, rate `state[4..12]` and the
+/// digest read from `state[4..8]`.
+pub struct Rpo256;
+
+const RATE_START: usize = 4;
+const RATE_WIDTH: usize = 8;
+
+impl Rpo256 {
+    pub fn digest_elements(digest: &B256) -> [Felt; 4] {
+        let mut out = [Felt::ZERO; 4];
+        for (x, chunk) in out.iter_mut().zip(digest.chunks_exact(8)) {
+            *x = Felt::new(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        out
+    }
+
+    fn digest(state: &[Felt; STATE_WIDTH]) -> B256 {
+        let mut out = [0u8; 32];
+        for (chunk, x) in out.chunks_exact_mut(8).zip(&state[RATE_START..]) {
+            chunk.copy_from_slice(&x.as_canonical_u64().to_le_bytes());
+        }
+        B256::new(out)
+    }
+}
+
+impl ElementHasher for Rpo256 {
+    /// Absorbs the base field coordinates of `elements`, zero-padding the last block, with
+    /// the count modulo the rate in the first capacity element.
+    fn hash_elements<E: WinterField>(elements: &[E]) -> B256 {
+        let mut bytes = Vec::with_capacity(elements.len() * E::ELEMENT_BYTES);
+        for element in elements {
+            element.write_bytes(&mut bytes);
+        }
+        let base: Vec<Felt> = bytes
+            .chunks_exact(8)
+            .map(|chunk| Felt::new(u64::from_le_bytes(chunk.try_into().unwrap())))
+            .collect();
+
+        let mut state = [Felt::ZERO; STATE_WIDTH];
+        state[0] = Felt::new((base.len() % RATE_WIDTH) as u64);
+        for block in base.chunks(RATE_WIDTH) {
+            let padded = block.iter().copied().chain(core::iter::repeat(Felt::ZERO));
+            for (x, value) in state[RATE_START..].iter_mut().zip(padded) {
+                *x = value;
+            }
+            rpo::permute(&mut state);
+        }
+        Self::digest(&state)
+    }
+
+    fn merge(left: &B256, right: &B256) -> B256 {
+        let mut state = [Felt::ZERO; STATE_WIDTH];
+        state[RATE_START..RATE_START + 4].copy_from_slice(&Self::digest_elements(left));
+        state[RATE_START + 4..].copy_from_slice(&Self::digest_elements(right));
+        rpo::permute(&mut state);
+        Self::digest(&state)
+    }
+
+    /// Values of at least `p` take two rate elements, which the first capacity element
+    /// records.
+    fn merge_with_int(seed: &B256, value: u64) -> B256 {
+        let mut state = [Felt::ZERO; STATE_WIDTH];
+        state[RATE_START..RATE_START + 4].copy_from_slice(&Self::digest_elements(seed));
+        state[RATE_START + 4] = Felt::new(value);
+        if value < Felt::ORDER {
+            state[0] = Felt::new(5);
+        } else {
+            state[RATE_START + 5] = Felt::new(value / Felt::ORDER);
+            state[0] = Felt::new(6);
+        }
+        rpo::permute(&mut state);
+        Self::digest(&state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(elements: [u64; 4]) -> B256 {
+        let mut out = [0u8; 32];
+        for (chunk, x) in out.chunks_exact_mut(8).zip(elements) {
+            chunk.copy_from_slice(&x.to_le_bytes());
+        }
+        B256::new(out)
+    }
+
+    #[test]
+    fn rpo256_matches_miden_vectors() {
+        assert_eq!(
+            Rpo256::hash_elements(&[Felt::ZERO]),
+            word([
+                18126731724905382595,
+                7388557040857728717,
+                14290750514634285295,
+                7852282086160480146,
+            ])
+        );
+        assert_eq!(Rpo256::hash_elements::<Felt>(&[]), B256::ZERO);
+    }
+
+    #[test]
+    fn rpo256_absorbs_and_merges() {
+        let elements: Vec<Felt> = (0..9).map(Felt::new).collect();
+        assert_eq!(
+            Rpo256::hash_elements(&elements),
+            word([
+                5218076004221736204,
+                17169400568680971304,
+                8840075572473868990,
+                12382372614369863623,
+            ])
+        );
+
+        let seed = word([1, 2, 3, 4]);
+        assert_eq!(
+            Rpo256::merge(&seed, &word([5, 6, 7, 8])),
+            word([
+                15975159621759139720,
+                15720844923951376941,
+                16013969809933496273,
+                13608701685256682132,
+            ])
+        );
+        assert_eq!(
+            Rpo256::merge_with_int(&seed, 7),
+            word([
+                8636881338198863691,
+                2630987132516182837,
+                4209150369614245175,
+                8928806371618291094,
+            ])
+        );
+        assert_eq!(
+            Rpo256::merge_with_int(&seed, u64::MAX),
+            word([
+                11760743757470960339,
+                10969796497897022346,
+                17761486171697620377,
+                13168379323400584940,
+            ])
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::winterfell::{field::WinterField, hash::ElementHasher, reader::Reader};
+
+/// Verifies a batch of leaves under `root` in a tree of `2^log_height` leaves, where node
+/// `i` is `merge(node 2i, node 2i + 1)` and the root is node 1.
+///
+/// `leaves` must be sorted by index without duplicates. `paths` holds the siblings that
+/// cannot be computed from the batch, in the order a breadth-first walk up from the leaves
+/// needs them, and must be consumed exactly.
+pub fn verify_batch<H: ElementHasher>(
+    root: &B256,
+    log_height: usize,
+    leaves: &[(usize, B256)],
+    paths: &[u8],
+) -> Option<()> {
+    let offset = 1usize.checked_shl(log_height as u32)?;
+    if leaves.is_empty()
+        || leaves.iter().any(|(index, _)| *index >= offset)
+        || leaves.windows(2).any(|w| w[0].0 >= w[1].0)
+    {
+        return None;
+    }
+    let mut paths = Reader::new(paths);
+    let mut queue: VecDeque<(usize, B256)> = leaves
+        .iter()
+        .map(|(index, hash)| (offset + index, *hash))
+        .collect();
+
+    while let Some((index, hash)) = queue.pop_front() {
+        if index == 1 {
+            return (hash == *root && queue.is_empty() && paths.is_empty()).then_some(());
+        }
+        let sibling = match queue.front() {
+            Some((next, next_hash)) if *next == index ^ 1 => {
+                let next_hash = *next_hash;
+                queue.pop_front();
+                next_hash
+            }
+            _ => paths.hash()?,
+        };
+        let parent = if index & 1 == 0 {
+            H::merge(&hash, &sibling)
+        } else {
+            H::merge(&sibling, &hash)
+        };
+        queue.push_back((index >> 1, parent));
+    }
+    None
+}
+
+/// Hashes each row into a leaf at its position.
+pub fn leaves<H: ElementHasher, E: WinterField>(
+    positions: &[usize],
+    rows: &[Vec<E>],
+) -> Vec<(usize, B256)> {
+    positions
+        .iter()
+        .zip(rows)
+        .map(|(position, row)| (*position, H::hash_elements(row)))
+        .collect()
+}
@@ -0,0 +1,336 @@
+//! Binding of Miden VM proofs to a program and its stack.
+//!
+//! Miden's public inputs are the program hash (the MAST root), the hashes of the kernel
+//! procedures, and the 16 stack inputs and outputs, absorbed in that order. A proof that
+//! verifies against them shows the program ran from those inputs to those outputs.
+//!
+//! The AIR itself is supplied by the integrator as an [`Air`] over [`MidenPublicInputs`].
+//! Proofs may be generated with Blake3 or, as Miden does by default for recursion, with
+//! `Rpo256`; the caller passes the hash function from the `ExecutionProof`.
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::common::stark::PrimeField;
+use crate::winterfell::{
+    air::{Air, ToElements},
+    errors::WinterfellError,
+    field::Felt,
+    hash::HashFunction,
+    verifier,
+};
+
+/// Depth of the operand stack Miden reads inputs from and writes outputs to.
+pub const MIN_STACK_DEPTH: usize = 16;
+
+/// A Miden digest: four field elements.
+pub type Digest = [Felt; 4];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MidenPublicInputs {
+    pub program_hash: Digest,
+    pub kernel_procedures: Vec<Digest>,
+    pub stack_inputs: [Felt; MIN_STACK_DEPTH],
+    pub stack_outputs: [Felt; MIN_STACK_DEPTH],
+}
+
+impl MidenPublicInputs {
+    /// Reads digests as four canonical little-endian `u64`s, Miden's byte encoding, and
+    /// pads the stacks with zeros.
+    pub fn new(
+        program_hash: B256,
+        kernel_procedures: &[B256],
+        stack_inputs: &[u64],
+        stack_outputs: &[u64],
+    ) -> Result<Self, WinterfellError> {
+        Ok(Self {
+            program_hash: digest(&program_hash)?,
+            kernel_procedures: kernel_procedures
+                .iter()
+                .map(digest)
+                .collect::<Result<_, _>>()?,
+            stack_inputs: stack(stack_inputs)?,
+            stack_outputs: stack(stack_outputs)?,
+        })
+    }
+}
+
+impl ToElements for MidenPublicInputs {
+    fn to_elements(&self) -> Vec<Felt> {
+        let mut out = self.program_hash.to_vec();
+        out.extend(self.kernel_procedures.iter().flatten());
+        out.extend(self.stack_inputs);
+        out.extend(self.stack_outputs);
+        out
+    }
+}
+
+fn felt(x: u64) -> Result<Felt, WinterfellError> {
+    Felt::from_canonical_u64(x).ok_or(WinterfellError::InvalidPublicInputs)
+}
+
+fn digest(bytes: &B256) -> Result<Digest, WinterfellError> {
+    let mut out = [Felt::ZERO; 4];
+    for (x, chunk) in out.iter_mut().zip(bytes.chunks_exact(8)) {
+        *x = felt(u64::from_le_bytes(chunk.try_into().unwrap()))?;
+    }
+    Ok(out)
+}
+
+fn stack(values: &[u64]) -> Result<[Felt; MIN_STACK_DEPTH], WinterfellError> {
+    if values.len() > MIN_STACK_DEPTH {
+        return Err(WinterfellError::InvalidPublicInputs);
+    }
+    let mut out = [Felt::ZERO; MIN_STACK_DEPTH];
+    for (x, value) in out.iter_mut().zip(values) {
+        *x = felt(*value)?;
+    }
+    Ok(out)
+}
+
+/// Verifies a Miden execution proof of `program_hash` taking the operand stack from
+/// `stack_inputs` to `stack_outputs`.
+pub fn verify_miden<A: Air<PublicInputs = MidenPublicInputs>>(
+    proof: &[u8],
+    hash_fn: HashFunction,
+    program_hash: B256,
+    kernel_procedures: &[B256],
+    stack_inputs: &[u64],
+    stack_outputs: &[u64],
+) -> Result<(), WinterfellError> {
+    let inputs =
+        MidenPublicInputs::new(program_hash, kernel_procedures, stack_inputs, stack_outputs)?;
+    verifier::verify::<A>(proof, inputs, hash_fn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::winterfell::{
+        air::{Assertion, EvaluationFrame},
+        field::WinterField,
+        proof::{StarkProof, TraceInfo},
+    };
+    use alloc::vec;
+    use stylus_sdk::alloy_primitives::b256;
+
+    // A Winterfell 0.9 `Rpo256` proof for the Stack AIR below, written by
+    // `testdata/winterfell/generate`. Its public inputs are Miden's; the hashes are
+    // `Rpo256` digests of labels, not of real MAST roots.
+    const PROOF: &[u8] = include_bytes!("../../testdata/winterfell/miden.bin");
+    const PROGRAM_HASH: B256 =
+        b256!("4359c21896e2aa172b9a4b845fde07e4be751facc1181235c8827ee8f9151adc");
+    const KERNEL_PROCEDURE: B256 =
+        b256!("fe907593c3ecdb603a6a55e102c50b4a1f13bb45fea8e36c5177d8f641547a1a");
+    const STACK_INPUTS: [u64; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+    const STACK_OUTPUTS: [u64; 16] = [
+        1808862350348476556,
+        2,
+        3,
+        4,
+        5,
+        6,
+        7,
+        8,
+        9,
+        10,
+        11,
+        12,
+        13,
+        14,
+        15,
+        16,
+    ];
+
+    /// A 16-column operand stack whose every step replaces the top with `s0^4 + s1` and
+    /// rotates the rest, with the stack inputs and outputs asserted on the first and last
+    /// rows.
+    struct Stack {
+        trace_info: TraceInfo,
+        inputs: MidenPublicInputs,
+    }
+
+    impl Air for Stack {
+        type PublicInputs = MidenPublicInputs;
+
+        fn new(trace_info: &TraceInfo, inputs: MidenPublicInputs) -> Option<Self> {
+            (trace_info.main_width == MIN_STACK_DEPTH && !trace_info.has_aux()).then(|| Self {
+                trace_info: trace_info.clone(),
+                inputs,
+            })
+        }
+
+        fn trace_info(&self) -> &TraceInfo {
+            &self.trace_info
+        }
+
+        fn public_inputs(&self) -> &MidenPublicInputs {
+            &self.inputs
+        }
+
+        fn transition_degrees(&self) -> Vec<usize> {
+            let mut degrees = vec![1; MIN_STACK_DEPTH];
+            degrees[0] = 4;
+            degrees
+        }
+
+        fn evaluate_transition<E: WinterField>(
+            &self,
+            frame: &EvaluationFrame<E>,
+            _periodic_values: &[E],
+            result: &mut [E],
+        ) {
+            let (current, next) = (&frame.current, &frame.next);
+            let s0 = current[0];
+            result[0] = next[0] - s0 * s0 * s0 * s0 - current[1];
+            for i in 1..MIN_STACK_DEPTH {
+                result[i] = next[i] - current[i % 15 + 1];
+            }
+        }
+
+        fn assertions(&self) -> Vec<Assertion<Felt>> {
+            let last = self.trace_info.length() - 1;
+            let inputs = self.inputs.stack_inputs.iter().enumerate();
+            let outputs = self.inputs.stack_outputs.iter().enumerate();
+            inputs
+                .map(|(i, v)| Assertion::single(i, 0, *v))
+                .chain(outputs.map(|(i, v)| Assertion::single(i, last, *v)))
+                .collect()
+        }
+    }
+
+    fn verify(
+        proof: &[u8],
+        program_hash: B256,
+        kernel_procedures: &[B256],
+        stack_inputs: &[u64],
+        stack_outputs: &[u64],
+    ) -> Result<(), WinterfellError> {
+        verify_miden::<Stack>(
+            proof,
+            HashFunction::Rpo256,
+            program_hash,
+            kernel_procedures,
+            stack_inputs,
+            stack_outputs,
+        )
+    }
+
+    #[test]
+    fn verifies_fixture_proof() {
+        verify(
+            PROOF,
+            PROGRAM_HASH,
+            &[KERNEL_PROCEDURE],
+            &STACK_INPUTS,
+            &STACK_OUTPUTS,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn rejects_other_public_inputs() {
+        let kernel = [KERNEL_PROCEDURE];
+        // The program and kernel hashes only seed the random coin.
+        assert!(verify(
+            PROOF,
+            KERNEL_PROCEDURE,
+            &kernel,
+            &STACK_INPUTS,
+            &STACK_OUTPUTS
+        )
+        .is_err());
+        assert!(verify(PROOF, PROGRAM_HASH, &[], &STACK_INPUTS, &STACK_OUTPUTS).is_err());
+        assert!(verify(
+            PROOF,
+            PROGRAM_HASH,
+            &[PROGRAM_HASH],
+            &STACK_INPUTS,
+            &STACK_OUTPUTS
+        )
+        .is_err());
+
+        let mut inputs = STACK_INPUTS;
+        inputs[3] += 1;
+        assert!(verify(PROOF, PROGRAM_HASH, &kernel, &inputs, &STACK_OUTPUTS).is_err());
+        let mut outputs = STACK_OUTPUTS;
+        outputs[0] += 1;
+        assert!(verify(PROOF, PROGRAM_HASH, &kernel, &STACK_INPUTS, &outputs).is_err());
+        // Missing values are zeros.
+        assert!(verify(
+            PROOF,
+            PROGRAM_HASH,
+            &kernel,
+            &STACK_INPUTS,
+            &STACK_OUTPUTS[..15]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_altered_proofs() {
+        let proof = StarkProof::from_bytes(PROOF).unwrap();
+        let ood = &proof.ood_frame.trace_states;
+        let at = PROOF
+            .windows(ood.len())
+            .position(|w| w == &ood[..])
+            .unwrap();
+        for at in [at, PROOF.len() / 2, PROOF.len() - 9] {
+            let mut altered = PROOF.to_vec();
+            altered[at] ^= 1;
+            assert!(verify(
+                &altered,
+                PROGRAM_HASH,
+                &[KERNEL_PROCEDURE],
+                &STACK_INPUTS,
+                &STACK_OUTPUTS
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_public_inputs() {
+        let invalid = |result| matches!(result, Err(WinterfellError::InvalidPublicInputs));
+        let kernel = [KERNEL_PROCEDURE];
+        let too_deep = [0; MIN_STACK_DEPTH + 1];
+        assert!(invalid(verify(
+            PROOF,
+            PROGRAM_HASH,
+            &kernel,
+            &too_deep,
+            &STACK_OUTPUTS
+        )));
+        assert!(invalid(verify(
+            PROOF,
+            PROGRAM_HASH,
+            &kernel,
+            &STACK_INPUTS,
+            &too_deep
+        )));
+        let mut outputs = STACK_OUTPUTS;
+        outputs[1] = Felt::ORDER;
+        assert!(invalid(verify(
+            PROOF,
+            PROGRAM_HASH,
+            &kernel,
+            &STACK_INPUTS,
+            &outputs
+        )));
+        let non_canonical = B256::repeat_byte(0xff);
+        assert!(invalid(verify(
+            PROOF,
+            non_canonical,
+            &kernel,
+            &STACK_INPUTS,
+            &STACK_OUTPUTS
+        )));
+        assert!(invalid(verify(
+            PROOF,
+            PROGRAM_HASH,
+            &[non_canonical],
+            &STACK_INPUTS,
+            &STACK_OUTPUTS
+        )));
+    }
+}
@@ -0,0 +1,21 @@
+pub mod air;
+pub mod blake3;
+pub mod coin;
+pub mod config;
+pub mod errors;
+pub mod field;
+pub mod fri;
+pub mod hash;
+pub mod merkle;
+pub mod miden;
+pub mod proof;
+pub mod reader;
+pub mod rpo;
+pub mod verifier;
+
+pub use air::{Air, Assertion, EvaluationFrame, ToElements};
+pub use errors::WinterfellError;
+pub use hash::HashFunction;
+pub use miden::{verify_miden, MidenPublicInputs};
+pub use proof::{StarkProof, TraceInfo};
+pub use verifier::verify;
@@ -0,0 +1,290 @@
+//! Winterfell's `StarkProof` and its serialization, all little-endian:
+//!
+//! ```text
+//! trace info: u8 main_width | u8 num_aux_segments (0 or 1)
+//!             [u8 aux_width | u8 num_aux_rands] | u8 log_trace_length | u16 n | n meta
+//! u8 n | n field modulus bytes
+//! options:    u8 num_queries | u8 blowup | u8 grinding | u8 field_extension
+//!             | u8 fri_folding_factor | u8 fri_remainder_max_degree
+//! u8 num_unique_queries
+//! u16 n | n commitments: trace roots | constraint root | FRI layer roots | remainder hash
+//! u8 n | n × queries (one per trace segment) | queries (constraint evaluations)
+//! ood frame:  u16 n | n trace states | u16 m | m constraint evaluations
+//! fri:        u8 n | n × queries (one per layer) | u16 n | n remainder
+//!             | u8 log_num_partitions
+//! u64 pow_nonce
+//! ```
+//!
+//! where `queries` is `u32 n | n values | u32 m | m paths`. Values hold one row per unique
+//! query, in position order; paths hold the batch Merkle siblings as read by
+//! [`merkle::verify_batch`](crate::winterfell::merkle::verify_batch). The trace states
+//! give each column's value at `z` and then at `z · g`, column after column, the order in
+//! which Winterfell hashes them. Field elements are canonical `u64`s, two per element of
+//! the quadratic extension.
+//!
+//! `testdata/winterfell/generate` proves the fixtures with Winterfell 0.9 and writes them
+//! in this layout.
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::common::stark::PrimeField;
+use crate::winterfell::{
+    field::{Felt, WinterField},
+    reader::Reader,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceInfo {
+    pub main_width: usize,
+    pub aux_width: usize,
+    pub num_aux_rands: usize,
+    pub log_length: usize,
+    pub meta: Vec<u8>,
+}
+
+impl TraceInfo {
+    pub fn length(&self) -> usize {
+        1 << self.log_length
+    }
+
+    pub fn has_aux(&self) -> bool {
+        self.aux_width > 0
+    }
+
+    fn read(r: &mut Reader) -> Option<Self> {
+        let main_width = r.u8()? as usize;
+        let (aux_width, num_aux_rands) = match r.u8()? {
+            0 => (0, 0),
+            1 => (r.u8()? as usize, r.u8()? as usize),
+            _ => return None,
+        };
+        let log_length = r.u8()? as usize;
+        let n = r.u16()? as usize;
+        let meta = r.bytes(n)?.to_vec();
+        (main_width > 0 && (aux_width == 0) == (num_aux_rands == 0)).then_some(Self {
+            main_width,
+            aux_width,
+            num_aux_rands,
+            log_length,
+            meta,
+        })
+    }
+
+    /// Packs the widths into one element, then the length and the metadata in 7-byte
+    /// chunks.
+    fn to_elements(&self) -> Vec<Felt> {
+        let mut buf = self.main_width as u64;
+        buf = (buf << 8) | self.has_aux() as u64;
+        if self.has_aux() {
+            buf = (buf << 8) | self.aux_width as u64;
+            buf = (buf << 8) | self.num_aux_rands as u64;
+        }
+        let mut out = Vec::from([Felt::new(buf), Felt::new(self.length() as u64)]);
+        out.extend(self.meta.chunks(7).map(from_bytes_with_padding));
+        out
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofOptions {
+    pub num_queries: usize,
+    pub blowup_factor: usize,
+    pub grinding_factor: u32,
+    pub field_extension: u8,
+    pub fri_folding_factor: usize,
+    pub fri_remainder_max_degree: usize,
+}
+
+impl ProofOptions {
+    fn read(r: &mut Reader) -> Option<Self> {
+        Some(Self {
+            num_queries: r.u8()? as usize,
+            blowup_factor: r.u8()? as usize,
+            grinding_factor: r.u8()? as u32,
+            field_extension: r.u8()?,
+            fri_folding_factor: r.u8()? as usize,
+            fri_remainder_max_degree: r.u8()? as usize,
+        })
+    }
+
+    fn to_elements(&self) -> [Felt; 4] {
+        let mut buf = self.field_extension as u64;
+        buf = (buf << 8) | self.fri_folding_factor as u64;
+        buf = (buf << 8) | self.fri_remainder_max_degree as u64;
+        [
+            Felt::new(buf),
+            Felt::new(self.grinding_factor as u64),
+            Felt::new(self.blowup_factor as u64),
+            Felt::new(self.num_queries as u64),
+        ]
+    }
+
+    /// Number of committed FRI layers before the remainder for an LDE domain of
+    /// `domain_size`.
+    pub fn num_fri_layers(&self, mut domain_size: usize) -> usize {
+        let max_remainder_size = (self.fri_remainder_max_degree + 1) * self.blowup_factor;
+        let mut n = 0;
+        while domain_size > max_remainder_size {
+            domain_size /= self.fri_folding_factor;
+            n += 1;
+        }
+        n
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Context {
+    pub trace_info: TraceInfo,
+    pub field_modulus_bytes: Vec<u8>,
+    pub options: ProofOptions,
+}
+
+impl Context {
+    /// The context's contribution to the random coin's seed.
+    pub fn to_elements(&self) -> Vec<Felt> {
+        let mut out = self.trace_info.to_elements();
+        let (m1, m2) = self
+            .field_modulus_bytes
+            .split_at(self.field_modulus_bytes.len() / 2);
+        out.push(from_bytes_with_padding(m1));
+        out.push(from_bytes_with_padding(m2));
+        out.extend(self.options.to_elements());
+        out
+    }
+}
+
+fn from_bytes_with_padding(bytes: &[u8]) -> Felt {
+    let mut buf = [0u8; 8];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    Felt::new(u64::from_le_bytes(buf))
+}
+
+pub struct Queries {
+    pub values: Vec<u8>,
+    pub paths: Vec<u8>,
+}
+
+impl Queries {
+    fn read(r: &mut Reader) -> Option<Self> {
+        let n = r.u32()? as usize;
+        let values = r.bytes(n)?.to_vec();
+        let n = r.u32()? as usize;
+        let paths = r.bytes(n)?.to_vec();
+        Some(Self { values, paths })
+    }
+
+    /// Splits the values into `n_rows` rows of `width` elements.
+    pub fn rows<E: WinterField>(&self, n_rows: usize, width: usize) -> Option<Vec<Vec<E>>> {
+        if self.values.len() != n_rows * width * E::ELEMENT_BYTES {
+            return None;
+        }
+        let mut r = Reader::new(&self.values);
+        (0..n_rows).map(|_| r.elements(width)).collect()
+    }
+}
+
+pub struct OodFrame {
+    pub trace_states: Vec<u8>,
+    pub evaluations: Vec<u8>,
+}
+
+pub struct FriProof {
+    pub layers: Vec<Queries>,
+    pub remainder: Vec<u8>,
+    pub log_num_partitions: u8,
+}
+
+pub struct StarkProof {
+    pub context: Context,
+    pub num_unique_queries: usize,
+    pub commitments: Vec<u8>,
+    pub trace_queries: Vec<Queries>,
+    pub constraint_queries: Queries,
+    pub ood_frame: OodFrame,
+    pub fri_proof: FriProof,
+    pub pow_nonce: u64,
+}
+
+impl StarkProof {
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(bytes);
+        let trace_info = TraceInfo::read(&mut r)?;
+        let n = r.u8()? as usize;
+        let field_modulus_bytes = r.bytes(n)?.to_vec();
+        let options = ProofOptions::read(&mut r)?;
+        if field_modulus_bytes != Felt::ORDER.to_le_bytes() {
+            return None;
+        }
+        let context = Context {
+            trace_info,
+            field_modulus_bytes,
+            options,
+        };
+        let num_unique_queries = r.u8()? as usize;
+        let n = r.u16()? as usize;
+        let commitments = r.bytes(n)?.to_vec();
+        let n = r.u8()? as usize;
+        let trace_queries = (0..n)
+            .map(|_| Queries::read(&mut r))
+            .collect::<Option<Vec<_>>>()?;
+        let constraint_queries = Queries::read(&mut r)?;
+        let ood_frame = OodFrame {
+            trace_states: {
+                let n = r.u16()? as usize;
+                r.bytes(n)?.to_vec()
+            },
+            evaluations: {
+                let n = r.u16()? as usize;
+                r.bytes(n)?.to_vec()
+            },
+        };
+        let n = r.u8()? as usize;
+        let fri_proof = FriProof {
+            layers: (0..n)
+                .map(|_| Queries::read(&mut r))
+                .collect::<Option<Vec<_>>>()?,
+            remainder: {
+                let n = r.u16()? as usize;
+                r.bytes(n)?.to_vec()
+            },
+            log_num_partitions: r.u8()?,
+        };
+        let pow_nonce = r.u64()?;
+        r.is_empty().then_some(Self {
+            context,
+            num_unique_queries,
+            commitments,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+            fri_proof,
+            pow_nonce,
+        })
+    }
+
+    /// Splits the commitments into the trace roots, the constraint root and the FRI
+    /// commitments, the last of which binds the remainder.
+    pub fn parse_commitments(
+        &self,
+        n_trace: usize,
+        n_fri: usize,
+    ) -> Option<(Vec<B256>, B256, Vec<B256>)> {
+        if self.commitments.len() != (n_trace + 1 + n_fri) * 32 {
+            return None;
+        }
+        let mut r = Reader::new(&self.commitments);
+        let trace = (0..n_trace).map(|_| r.hash()).collect::<Option<_>>()?;
+        let constraint = r.hash()?;
+        let fri = (0..n_fri).map(|_| r.hash()).collect::<Option<_>>()?;
+        Some((trace, constraint, fri))
+    }
+}
+
+/// Reads exactly `n` elements from `bytes`.
+pub fn parse_elements<E: WinterField>(bytes: &[u8], n: usize) -> Option<Vec<E>> {
+    if bytes.len() != n * E::ELEMENT_BYTES {
+        return None;
+    }
+    Reader::new(bytes).elements(n)
+}
@@ -0,0 +1,56 @@
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::B256;
+
+use crate::winterfell::field::WinterField;
+
+/// Cursor over Winterfell's little-endian serialization.
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    pub fn u8(&mut self) -> Option<u8> {
+        Some(self.bytes(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.bytes(2)?.try_into().ok()?))
+    }
+
+    pub fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes(4)?.try_into().ok()?))
+    }
+
+    pub fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.bytes(8)?.try_into().ok()?))
+    }
+
+    pub fn hash(&mut self) -> Option<B256> {
+        Some(B256::from_slice(self.bytes(32)?))
+    }
+
+    pub fn element<E: WinterField>(&mut self) -> Option<E> {
+        E::from_bytes(self.bytes(E::ELEMENT_BYTES)?)
+    }
+
+    pub fn elements<E: WinterField>(&mut self, n: usize) -> Option<Vec<E>> {
+        (0..n).map(|_| self.element()).collect()
+    }
+}
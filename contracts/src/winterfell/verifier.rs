@@ -0,0 +1,600 @@
+use alloc::{vec, vec::Vec};
+
+use crate::common::stark::{batch_inverse, poly, Field, TwoAdicField};
+use crate::winterfell::{
+    air::{Air, Assertion, EvaluationFrame, ToElements},
+    coin::{DefaultRandomCoin, RandomCoin, RpoRandomCoin},
+    config::{COLLISION_RESISTANCE, MIN_CONJECTURED_SECURITY, MIN_LOG_TRACE_LENGTH},
+    errors::WinterfellError,
+    field::{root_of_unity, Felt, QuadExt, WinterField, TWO_ADICITY},
+    fri,
+    hash::{Blake3, ElementHasher, HashFunction, Rpo256},
+    merkle,
+    proof::{parse_elements, ProofOptions, StarkProof},
+};
+
+/// Verifies a serialized Winterfell proof against the AIR `A` built from the proof's trace
+/// shape and `public_inputs`. Blake3 proofs use Winterfell's default random coin and
+/// `Rpo256` proofs Miden's.
+pub fn verify<A: Air>(
+    proof: &[u8],
+    public_inputs: A::PublicInputs,
+    hash_fn: HashFunction,
+) -> Result<(), WinterfellError> {
+    let proof = StarkProof::from_bytes(proof).ok_or(WinterfellError::INVALID_PROOF_DATA)?;
+    let air =
+        A::new(&proof.context.trace_info, public_inputs).ok_or(WinterfellError::InvalidAir)?;
+    type Blake3Coin = DefaultRandomCoin<Blake3>;
+    match (hash_fn, proof.context.options.field_extension) {
+        (HashFunction::Blake3_256, Felt::EXTENSION) => {
+            verify_with::<A, Felt, Blake3, Blake3Coin>(&air, &proof)
+        }
+        (HashFunction::Blake3_256, QuadExt::EXTENSION) => {
+            verify_with::<A, QuadExt, Blake3, Blake3Coin>(&air, &proof)
+        }
+        (HashFunction::Rpo256, Felt::EXTENSION) => {
+            verify_with::<A, Felt, Rpo256, RpoRandomCoin>(&air, &proof)
+        }
+        (HashFunction::Rpo256, QuadExt::EXTENSION) => {
+            verify_with::<A, QuadExt, Rpo256, RpoRandomCoin>(&air, &proof)
+        }
+        _ => Err(WinterfellError::UnsupportedOptions),
+    }
+}
+
+/// Winterfell's conjectured security: the smaller of the field security, the query
+/// security plus grinding, less one bit, and the hash collision resistance.
+fn conjectured_security(options: &ProofOptions, log_lde: usize, extension_degree: usize) -> u32 {
+    let field_security = (64 * extension_degree - log_lde) as u32;
+    let query_security = options.blowup_factor.trailing_zeros() * options.num_queries as u32
+        + options.grinding_factor;
+    (field_security.min(query_security) - 1).min(COLLISION_RESISTANCE)
+}
+
+fn check_options(options: &ProofOptions) -> bool {
+    options.num_queries > 0
+        && options.blowup_factor.is_power_of_two()
+        && options.blowup_factor >= 2
+        && matches!(options.fri_folding_factor, 2 | 4 | 8 | 16)
+        && (options.fri_remainder_max_degree + 1).is_power_of_two()
+        && options.grinding_factor <= 32
+}
+
+fn check_assertions<E>(assertions: &[Assertion<E>], width: usize, trace_length: usize) -> bool {
+    assertions
+        .iter()
+        .all(|a| a.column < width && a.step < trace_length)
+}
+
+fn verify_with<A: Air, E: WinterField, H: ElementHasher, C: RandomCoin>(
+    air: &A,
+    proof: &StarkProof,
+) -> Result<(), WinterfellError> {
+    let invalid = || WinterfellError::INVALID_PROOF_DATA;
+    let failed = || WinterfellError::VERIFICATION_FAILED;
+
+    let context = &proof.context;
+    let info = &context.trace_info;
+    let options = &context.options;
+    if *info != *air.trace_info() {
+        return Err(WinterfellError::InvalidAir);
+    }
+    if !check_options(options) {
+        return Err(WinterfellError::UnsupportedOptions);
+    }
+    let log_n = info.log_length;
+    let n = info.length();
+    let log_lde = log_n + options.blowup_factor.trailing_zeros() as usize;
+    if log_n < MIN_LOG_TRACE_LENGTH || log_lde > TWO_ADICITY {
+        return Err(WinterfellError::UnsupportedOptions);
+    }
+    if conjectured_security(options, log_lde, E::DEGREE) < MIN_CONJECTURED_SECURITY {
+        return Err(WinterfellError::InsufficientSecurity);
+    }
+
+    let main_degrees = air.transition_degrees();
+    let aux_degrees = if info.has_aux() {
+        air.aux_transition_degrees()
+    } else {
+        Vec::new()
+    };
+    let max_degree = main_degrees
+        .iter()
+        .chain(&aux_degrees)
+        .copied()
+        .max()
+        .unwrap_or(1);
+    // The composition polynomial has degree `(max_degree - 1) · n` once divided by the
+    // transition divisor, and is committed as that many columns of degree below `n`.
+    let n_columns = max_degree.saturating_sub(1).max(1);
+    if n_columns.next_power_of_two() > options.blowup_factor {
+        return Err(WinterfellError::UnsupportedOptions);
+    }
+
+    let n_trace = 1 + info.has_aux() as usize;
+    let n_fri_layers = options.num_fri_layers(1 << log_lde);
+    let (trace_roots, constraint_root, fri_roots) = proof
+        .parse_commitments(n_trace, n_fri_layers + 1)
+        .ok_or_else(invalid)?;
+
+    let mut seed = context.to_elements();
+    seed.extend(air.public_inputs().to_elements());
+    let mut coin = C::new(&seed);
+    coin.reseed(&trace_roots[0]);
+    let aux_rands: Vec<E> = if info.has_aux() {
+        let rands = coin.draw_many(info.num_aux_rands).ok_or_else(failed)?;
+        coin.reseed(&trace_roots[1]);
+        rands
+    } else {
+        Vec::new()
+    };
+
+    // Coefficients go to the assertions in Winterfell's order: by step, then by column.
+    let mut assertions = air.assertions();
+    assertions.sort_by_key(|a| (a.step, a.column));
+    let mut aux_assertions = if info.has_aux() {
+        air.aux_assertions(&aux_rands)
+    } else {
+        Vec::new()
+    };
+    aux_assertions.sort_by_key(|a| (a.step, a.column));
+    if !check_assertions(&assertions, info.main_width, n)
+        || !check_assertions(&aux_assertions, info.aux_width, n)
+    {
+        return Err(WinterfellError::InvalidAir);
+    }
+    let transition_coeffs: Vec<E> = coin
+        .draw_many(main_degrees.len() + aux_degrees.len())
+        .ok_or_else(failed)?;
+    let boundary_coeffs: Vec<E> = coin
+        .draw_many(assertions.len() + aux_assertions.len())
+        .ok_or_else(failed)?;
+    coin.reseed(&constraint_root);
+
+    // Out-of-domain point and the frame the prover claims there.
+    let z: E = coin.draw().ok_or_else(failed)?;
+    let z_n = z.pow(n as u64);
+    if z_n == E::ONE {
+        return Err(failed());
+    }
+    let width = info.main_width + info.aux_width;
+    let states: Vec<E> =
+        parse_elements(&proof.ood_frame.trace_states, 2 * width).ok_or_else(invalid)?;
+    coin.reseed(&H::hash_elements(&states));
+    let (current, next): (Vec<E>, Vec<E>) = states
+        .chunks_exact(2)
+        .map(|column| (column[0], column[1]))
+        .unzip();
+    let main_frame = EvaluationFrame {
+        current: current[..info.main_width].to_vec(),
+        next: next[..info.main_width].to_vec(),
+    };
+    let aux_frame = EvaluationFrame {
+        current: current[info.main_width..].to_vec(),
+        next: next[info.main_width..].to_vec(),
+    };
+
+    let mut periodic_values = Vec::new();
+    for column in air.periodic_columns() {
+        let cycle = column.len();
+        if !cycle.is_power_of_two() || cycle > n {
+            return Err(WinterfellError::InvalidAir);
+        }
+        let values: Vec<E> = column.into_iter().map(E::from_base).collect();
+        let omega = root_of_unity(cycle.trailing_zeros() as usize);
+        periodic_values.push(poly::interpolate_coset(
+            &values,
+            Felt::ONE,
+            omega,
+            z.pow((n / cycle) as u64),
+        ));
+    }
+
+    let mut evaluations = vec![E::ZERO; main_degrees.len()];
+    air.evaluate_transition(&main_frame, &periodic_values, &mut evaluations);
+    let mut aux_evaluations = vec![E::ZERO; aux_degrees.len()];
+    if info.has_aux() {
+        air.evaluate_aux_transition(
+            &main_frame,
+            &aux_frame,
+            &periodic_values,
+            &aux_rands,
+            &mut aux_evaluations,
+        );
+    }
+    evaluations.extend(aux_evaluations);
+
+    // Transition constraints vanish on every step but the last.
+    let g = root_of_unity(log_n);
+    let last_step = E::from_base(g.pow(n as u64 - 1));
+    let transition = evaluations
+        .iter()
+        .zip(&transition_coeffs)
+        .fold(E::ZERO, |acc, (e, c)| acc + *e * *c)
+        * (z - last_step)
+        * (z_n - E::ONE).inv();
+
+    let mut boundary = E::ZERO;
+    let boundary_terms = assertions
+        .iter()
+        .map(|a| (main_frame.current[a.column], E::from_base(a.value), a.step))
+        .chain(
+            aux_assertions
+                .iter()
+                .map(|a| (aux_frame.current[a.column], a.value, a.step)),
+        );
+    for ((value, expected, step), coeff) in boundary_terms.zip(&boundary_coeffs) {
+        let divisor = z - E::from_base(g.pow(step as u64));
+        boundary += (value - expected) * divisor.inv() * *coeff;
+    }
+
+    // The composition polynomial is `Σ x^(i·n) · H_i(x)`.
+    let composition: Vec<E> =
+        parse_elements(&proof.ood_frame.evaluations, n_columns).ok_or_else(invalid)?;
+    let composition_at_z = composition
+        .iter()
+        .rev()
+        .fold(E::ZERO, |acc, h| acc * z_n + *h);
+    if composition_at_z != transition + boundary {
+        return Err(WinterfellError::OodConstraintMismatch);
+    }
+    coin.reseed(&H::hash_elements(&composition));
+
+    let trace_deep_coeffs: Vec<E> = coin.draw_many(width).ok_or_else(failed)?;
+    let constraint_deep_coeffs: Vec<E> = coin.draw_many(n_columns).ok_or_else(failed)?;
+
+    let mut alphas: Vec<E> = Vec::with_capacity(fri_roots.len());
+    for root in &fri_roots {
+        coin.reseed(root);
+        alphas.push(coin.draw().ok_or_else(failed)?);
+    }
+    if proof.fri_proof.log_num_partitions != 0 {
+        return Err(WinterfellError::UnsupportedOptions);
+    }
+
+    if coin.check_leading_zeros(proof.pow_nonce) < options.grinding_factor {
+        return Err(failed());
+    }
+    let mut positions = coin.draw_integers(options.num_queries, 1 << log_lde, proof.pow_nonce);
+    positions.sort_unstable();
+    positions.dedup();
+    let q = positions.len();
+    if q != proof.num_unique_queries || proof.trace_queries.len() != n_trace {
+        return Err(invalid());
+    }
+
+    let main_rows = proof.trace_queries[0]
+        .rows::<Felt>(q, info.main_width)
+        .ok_or_else(invalid)?;
+    merkle::verify_batch::<H>(
+        &trace_roots[0],
+        log_lde,
+        &merkle::leaves::<H, Felt>(&positions, &main_rows),
+        &proof.trace_queries[0].paths,
+    )
+    .ok_or_else(failed)?;
+    let aux_rows = if info.has_aux() {
+        let rows = proof.trace_queries[1]
+            .rows::<E>(q, info.aux_width)
+            .ok_or_else(invalid)?;
+        merkle::verify_batch::<H>(
+            &trace_roots[1],
+            log_lde,
+            &merkle::leaves::<H, E>(&positions, &rows),
+            &proof.trace_queries[1].paths,
+        )
+        .ok_or_else(failed)?;
+        rows
+    } else {
+        vec![Vec::new(); q]
+    };
+    let constraint_rows = proof
+        .constraint_queries
+        .rows::<E>(q, n_columns)
+        .ok_or_else(invalid)?;
+    merkle::verify_batch::<H>(
+        &constraint_root,
+        log_lde,
+        &merkle::leaves::<H, E>(&positions, &constraint_rows),
+        &proof.constraint_queries.paths,
+    )
+    .ok_or_else(failed)?;
+
+    // DEEP composition: Σ γ_j (T_j(x) - T_j(z)) / (x - z) + Σ γ_j (T_j(x) - T_j(zg)) / (x - zg)
+    // + Σ δ_k (H_k(x) - H_k(z)) / (x - z).
+    let g_lde = root_of_unity(log_lde);
+    let z_next = z.mul_base(g);
+    let xs: Vec<Felt> = positions
+        .iter()
+        .map(|p| Felt::GENERATOR * g_lde.pow(*p as u64))
+        .collect();
+    let mut denominators: Vec<E> = xs
+        .iter()
+        .flat_map(|x| [E::from_base(*x) - z, E::from_base(*x) - z_next])
+        .collect();
+    if denominators.contains(&E::ZERO) {
+        return Err(failed());
+    }
+    batch_inverse(&mut denominators);
+
+    let mut deep_values = Vec::with_capacity(q);
+    for (i, inverses) in denominators.chunks_exact(2).enumerate() {
+        let row = main_rows[i]
+            .iter()
+            .map(|x| E::from_base(*x))
+            .chain(aux_rows[i].iter().copied());
+        let (mut t1, mut t2) = (E::ZERO, E::ZERO);
+        for (j, value) in row.enumerate() {
+            t1 += (value - current[j]) * trace_deep_coeffs[j];
+            t2 += (value - next[j]) * trace_deep_coeffs[j];
+        }
+        for (k, value) in constraint_rows[i].iter().enumerate() {
+            t1 += (*value - composition[k]) * constraint_deep_coeffs[k];
+        }
+        deep_values.push(t1 * inverses[0] + t2 * inverses[1]);
+    }
+
+    fri::verify::<H, E>(
+        &proof.fri_proof,
+        &fri_roots,
+        &alphas,
+        log_lde,
+        options.blowup_factor,
+        options.fri_folding_factor,
+        &positions,
+        &deep_values,
+    )
+    .ok_or_else(failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::VerificationError;
+    use crate::winterfell::proof::TraceInfo;
+
+    // Winterfell 0.9 proofs over the quadratic extension with blowup 8, folding factor 4
+    // and a degree-3 remainder, of 16 steps of `(a, b) -> (b, a^3 + b + k)` from `(1, 1)`
+    // with `k` cycling through `[3, 5, 0, 9]`, plus a running-product auxiliary column.
+    // Written by `testdata/winterfell/generate`.
+    const BLAKE3: &[u8] = include_bytes!("../../testdata/winterfell/blake3.bin");
+    const RPO256: &[u8] = include_bytes!("../../testdata/winterfell/rpo256.bin");
+    const RESULT: u64 = 14058761740603417203;
+
+    /// The fixture AIR, with the last round constant as a parameter so that tests can
+    /// build another AIR over the same trace.
+    struct Toy<const K3: u64 = 9> {
+        trace_info: TraceInfo,
+        result: Vec<Felt>,
+    }
+
+    impl<const K3: u64> Air for Toy<K3> {
+        type PublicInputs = Vec<Felt>;
+
+        fn new(trace_info: &TraceInfo, result: Vec<Felt>) -> Option<Self> {
+            (trace_info.main_width == 2 && trace_info.aux_width == 1).then(|| Self {
+                trace_info: trace_info.clone(),
+                result,
+            })
+        }
+
+        fn trace_info(&self) -> &TraceInfo {
+            &self.trace_info
+        }
+
+        fn public_inputs(&self) -> &Vec<Felt> {
+            &self.result
+        }
+
+        fn transition_degrees(&self) -> Vec<usize> {
+            vec![1, 3]
+        }
+
+        fn evaluate_transition<E: WinterField>(
+            &self,
+            frame: &EvaluationFrame<E>,
+            periodic_values: &[E],
+            result: &mut [E],
+        ) {
+            let (a, b) = (frame.current[0], frame.current[1]);
+            result[0] = frame.next[0] - b;
+            result[1] = frame.next[1] - a * a * a - b - periodic_values[0];
+        }
+
+        fn assertions(&self) -> Vec<Assertion<Felt>> {
+            let last = self.trace_info.length() - 1;
+            vec![
+                Assertion::single(0, 0, Felt::ONE),
+                Assertion::single(1, 0, Felt::ONE),
+                Assertion::single(1, last, self.result[0]),
+            ]
+        }
+
+        fn aux_transition_degrees(&self) -> Vec<usize> {
+            vec![2]
+        }
+
+        fn evaluate_aux_transition<E: WinterField>(
+            &self,
+            main_frame: &EvaluationFrame<E>,
+            aux_frame: &EvaluationFrame<E>,
+            _periodic_values: &[E],
+            aux_rands: &[E],
+            result: &mut [E],
+        ) {
+            result[0] =
+                aux_frame.next[0] - aux_frame.current[0] * (main_frame.current[0] + aux_rands[0]);
+        }
+
+        fn aux_assertions<E: WinterField>(&self, _aux_rands: &[E]) -> Vec<Assertion<E>> {
+            vec![Assertion::single(0, 0, E::ONE)]
+        }
+
+        fn periodic_columns(&self) -> Vec<Vec<Felt>> {
+            vec![[3, 5, 0, K3].map(Felt::new).to_vec()]
+        }
+    }
+
+    #[test]
+    fn verifies_fixture_proofs() {
+        let result = vec![Felt::new(RESULT)];
+        verify::<Toy>(BLAKE3, result.clone(), HashFunction::Blake3_256).unwrap();
+        verify::<Toy>(RPO256, result, HashFunction::Rpo256).unwrap();
+    }
+
+    const FIXTURES: [(&[u8], HashFunction); 2] = [
+        (BLAKE3, HashFunction::Blake3_256),
+        (RPO256, HashFunction::Rpo256),
+    ];
+
+    /// Where `part`, a section of the parsed proof, starts in `proof`.
+    fn offset_of(proof: &[u8], part: &[u8]) -> usize {
+        proof
+            .windows(part.len())
+            .position(|window| window == part)
+            .unwrap()
+    }
+
+    fn verify_altered(
+        proof: &[u8],
+        hash_fn: HashFunction,
+        at: usize,
+    ) -> Result<(), WinterfellError> {
+        let mut altered = proof.to_vec();
+        altered[at] ^= 1;
+        verify::<Toy>(&altered, vec![Felt::new(RESULT)], hash_fn)
+    }
+
+    fn is_verification_failure(result: Result<(), WinterfellError>) -> bool {
+        matches!(
+            result,
+            Err(WinterfellError::Common(
+                VerificationError::VerificationFailed
+            ))
+        )
+    }
+
+    #[test]
+    fn rejects_other_results() {
+        for (proof, hash_fn) in FIXTURES {
+            assert!(matches!(
+                verify::<Toy>(proof, vec![Felt::new(RESULT + 1)], hash_fn),
+                Err(WinterfellError::OodConstraintMismatch)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_altered_ood_frames() {
+        for (proof, hash_fn) in FIXTURES {
+            let parsed = StarkProof::from_bytes(proof).unwrap();
+            for part in [
+                &parsed.ood_frame.trace_states,
+                &parsed.ood_frame.evaluations,
+            ] {
+                assert!(matches!(
+                    verify_altered(proof, hash_fn, offset_of(proof, part)),
+                    Err(WinterfellError::OodConstraintMismatch)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_altered_queries() {
+        for (proof, hash_fn) in FIXTURES {
+            let parsed = StarkProof::from_bytes(proof).unwrap();
+            let queries = parsed
+                .trace_queries
+                .iter()
+                .chain([&parsed.constraint_queries]);
+            for query in queries {
+                for part in [&query.values, &query.paths] {
+                    let at = offset_of(proof, part);
+                    assert!(is_verification_failure(verify_altered(proof, hash_fn, at)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_altered_fri_proofs() {
+        for (proof, hash_fn) in FIXTURES {
+            let parsed = StarkProof::from_bytes(proof).unwrap();
+            let layers = &parsed.fri_proof.layers;
+            assert!(!layers.is_empty());
+            for part in layers
+                .iter()
+                .flat_map(|layer| [&layer.values, &layer.paths])
+                .chain([&parsed.fri_proof.remainder])
+            {
+                let at = offset_of(proof, part);
+                assert!(is_verification_failure(verify_altered(proof, hash_fn, at)));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_altered_commitments() {
+        for (proof, hash_fn) in FIXTURES {
+            let parsed = StarkProof::from_bytes(proof).unwrap();
+            let commitments = offset_of(proof, &parsed.commitments);
+            // Every root reseeds the coin, so a new one moves the out-of-domain point or
+            // the queries.
+            for root in 0..parsed.commitments.len() / 32 {
+                assert!(verify_altered(proof, hash_fn, commitments + 32 * root).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_altered_pow_nonces() {
+        for (proof, hash_fn) in FIXTURES {
+            // The nonce seeds the query positions.
+            assert!(verify_altered(proof, hash_fn, proof.len() - 8).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_proofs() {
+        let result = vec![Felt::new(RESULT)];
+        for (proof, hash_fn) in FIXTURES {
+            for malformed in [&proof[..proof.len() - 1], &[proof, &[0]].concat()] {
+                assert!(matches!(
+                    verify::<Toy>(malformed, result.clone(), hash_fn),
+                    Err(WinterfellError::Common(VerificationError::InvalidProofData))
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_weakened_options() {
+        for (proof, hash_fn) in FIXTURES {
+            let parsed = StarkProof::from_bytes(proof).unwrap();
+            let mut altered = proof.to_vec();
+            // `num_queries` is the first option byte, after the field modulus.
+            let at = offset_of(proof, &parsed.context.field_modulus_bytes)
+                + parsed.context.field_modulus_bytes.len();
+            assert_eq!(altered[at] as usize, parsed.context.options.num_queries);
+            altered[at] = 1;
+            assert!(matches!(
+                verify::<Toy>(&altered, vec![Felt::new(RESULT)], hash_fn),
+                Err(WinterfellError::InsufficientSecurity)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_other_airs() {
+        let result = vec![Felt::new(RESULT)];
+        for (proof, hash_fn) in FIXTURES {
+            assert!(matches!(
+                verify::<Toy<8>>(proof, result.clone(), hash_fn),
+                Err(WinterfellError::OodConstraintMismatch)
+            ));
+        }
+        assert!(verify::<Toy>(BLAKE3, result.clone(), HashFunction::Rpo256).is_err());
+        assert!(verify::<Toy>(RPO256, result, HashFunction::Blake3_256).is_err());
+    }
+}
@@ -0,0 +1,431 @@
+#!/usr/bin/env python3
+"""Compiles a Stone layout into the blob read by `cairo::layout::Layout::from_bytes`.
+
+Stone generates the constraints of each layout as code. This reads the Rust rendering of
+that code in the `swiftness_air` crate (`src/layout/<name>/autogenerated/
+autogenerated_composition.rs`, with the periodic columns of `src/periodic_columns.rs`)
+and turns the composition polynomial into the blob's expression program. Only the
+`small` layout's global values are mapped; other layouts need their own table below.
+
+    python3 compile_layout.py <swiftness_air/src> small > small/layout.bin
+
+The prover parameters are those of `cpu_air_params.json` for the proof being verified.
+"""
+
+import re
+import struct
+import sys
+
+P = 2**251 + 17 * 2**192 + 1
+TWO_ADICITY = 192
+
+# Prover parameters of the small layout fixture: blowup 16, 18 queries, 24 bits of
+# grinding, last layer of degree below 64 and FRI steps [0, 4, 3].
+PARAMS = {
+    "small": dict(
+        code=int.from_bytes(b"small", "big"),
+        log_rows_per_step=4,
+        constraint_degree=2,
+        original_columns=23,
+        interaction_columns=2,
+        interaction_elements=3,
+        log_public_memory_step=3,
+        log_blowup=4,
+        n_queries=18,
+        pow_bits=24,
+        log_last_layer_degree=6,
+        fri_steps=[0, 4, 3],
+        # (name, log period) of each periodic column, in the order they are numbered.
+        periodic=[
+            ("pedersen_points_x", "eval_pedersen_x", 9),
+            ("pedersen_points_y", "eval_pedersen_y", 9),
+            ("ecdsa_generator_points_x", "eval_ecdsa_x", 13),
+            ("ecdsa_generator_points_y", "eval_ecdsa_y", 13),
+        ],
+    ),
+}
+
+STARK_BETA = 0x6F21413EFBE40DE150E596D72F7A8C5609AD26C15C915C1F4CDFCB99CEE9E89
+SHIFT_X = 0x49EE3EBA8C1600700EE1B87EB599F16716B0B1022947733551FDE4050CA6804
+SHIFT_Y = 0x3CA0CFE4B3BC6DDF346D49D06EA0ED34E621062C0E056C1D0405D266E10268A
+
+# Public values: rc_min, rc_max, the public memory quotient, then the begin and stop of
+# each segment (program, execution, output, pedersen, range_check, ecdsa).
+SMALL_GLOBALS = {
+    "range_check_min": ("public", 0),
+    "range_check_max": ("public", 1),
+    "memory_multi_column_perm_perm_public_memory_prod": ("public", 2),
+    "initial_pc": ("public", 3),
+    "final_pc": ("public", 4),
+    "initial_ap": ("public", 5),
+    "final_ap": ("public", 6),
+    "initial_pedersen_addr": ("public", 9),
+    "initial_range_check_addr": ("public", 11),
+    "initial_ecdsa_addr": ("public", 13),
+    "memory_multi_column_perm_perm_interaction_elm": ("interaction", 0),
+    "memory_multi_column_perm_hash_interaction_elm0": ("interaction", 1),
+    "range_check16_perm_interaction_elm": ("interaction", 2),
+    "range_check16_perm_public_memory_prod": ("const", 1),
+    "offset_size": ("const", 2**16),
+    "half_offset_size": ("const", 2**15),
+    "pedersen_shift_point.x": ("const", SHIFT_X),
+    "pedersen_shift_point.y": ("const", SHIFT_Y),
+    "ecdsa_sig_config.alpha": ("const", 1),
+    "ecdsa_sig_config.beta": ("const", STARK_BETA),
+    "ecdsa_sig_config.shift_point.x": ("const", SHIFT_X),
+    "ecdsa_sig_config.shift_point.y": ("const", SHIFT_Y),
+}
+
+
+def two_adic_generator(bits):
+    return pow(3, (P - 1) >> bits, P)
+
+
+class Program:
+    """The expression list, with structurally equal nodes shared."""
+
+    def __init__(self):
+        self.nodes = []
+        self.index = {}
+
+    def add(self, node):
+        if node not in self.index:
+            self.index[node] = len(self.nodes)
+            self.nodes.append(node)
+        return self.index[node]
+
+    def const(self, value):
+        return self.add(("const", value % P))
+
+    def encode(self):
+        out = b""
+        for node in self.nodes:
+            kind = node[0]
+            if kind == "const":
+                out += struct.pack(">I", 0) + node[1].to_bytes(32, "big")
+            elif kind in ("mask", "periodic", "public", "interaction"):
+                op = {"mask": 1, "periodic": 2, "public": 3, "interaction": 4}[kind]
+                out += struct.pack(">II", op, node[1])
+            elif kind in ("+", "-", "*", "/"):
+                op = {"+": 5, "-": 6, "*": 7, "/": 8}[kind]
+                out += struct.pack(">III", op, node[1], node[2])
+            elif kind == "point_pow":
+                out += struct.pack(">IQ", 9, node[1])
+            elif kind == "trace_gen_pow":
+                out += struct.pack(">Iq", 10, node[1])
+            elif kind == "point_pow_trace":
+                out += struct.pack(">II", 11, node[1])
+            else:
+                raise ValueError(node)
+        return out
+
+
+TOKEN = re.compile(
+    r"\s*(?:(?P<num>FELT_\d+)|(?P<macro>felt_nonzero!)"
+    r"|(?P<ident>[A-Za-z_][A-Za-z_0-9]*(?:\.[A-Za-z_][A-Za-z_0-9]*)*)|(?P<op>[-+*().&\[\]]))"
+)
+
+
+def tokenize(text):
+    tokens, pos = [], 0
+    text = text.strip()
+    while pos < len(text):
+        m = TOKEN.match(text, pos)
+        if not m:
+            raise ValueError(f"cannot tokenize {text[pos:pos + 40]!r}")
+        pos = m.end()
+        tokens.append(next((k, v) for k, v in m.groupdict().items() if v is not None))
+    return tokens
+
+
+class Exponent:
+    """`a · T / b + c` for the trace length `T`."""
+
+    def __init__(self, a=0, b=1, c=0):
+        self.a, self.b, self.c = a, b, c
+
+
+class Parser:
+    def __init__(self, tokens, compiler):
+        self.tokens, self.pos, self.c = tokens, 0, compiler
+
+    def peek(self):
+        return self.tokens[self.pos] if self.pos < len(self.tokens) else (None, None)
+
+    def expect(self, value):
+        kind, got = self.peek()
+        if got != value:
+            raise ValueError(f"expected {value!r}, got {got!r}")
+        self.pos += 1
+
+    def expr(self):
+        left = self.term()
+        while self.peek()[1] in ("+", "-"):
+            op = self.peek()[1]
+            self.pos += 1
+            left = self.c.program.add((op, left, self.term()))
+        return left
+
+    def term(self):
+        left = self.postfix()
+        while self.peek()[1] == "*":
+            self.pos += 1
+            left = self.c.program.add(("*", left, self.postfix()))
+        return left
+
+    def postfix(self):
+        kind, value = self.peek()
+        if kind == "ident" and value.endswith((".field_div", ".pow_felt")):
+            base, method = value.rsplit(".", 1)
+            self.pos += 1
+            if method == "pow_felt":
+                return self.pow(base)
+            left = self.c.program.add(("/", self.c.name(base), self.nonzero()))
+        else:
+            left = self.primary()
+        while self.peek()[1] == ".":
+            self.pos += 1
+            _, method = self.peek()
+            self.pos += 1
+            if method != "field_div":
+                raise ValueError(method)
+            left = self.c.program.add(("/", left, self.nonzero()))
+        return left
+
+    def nonzero(self):
+        self.expect("(")
+        self.expect("&")
+        self.expect("felt_nonzero!")
+        self.expect("(")
+        value = self.expr()
+        self.expect(")")
+        self.expect(")")
+        return value
+
+    def primary(self):
+        kind, value = self.peek()
+        self.pos += 1
+        if value == "(":
+            inner = self.expr()
+            self.expect(")")
+            return inner
+        if kind == "num":
+            return self.c.program.const(int(value[5:]))
+        if kind == "ident":
+            return self.c.name(value)
+        raise ValueError(f"unexpected {value!r}")
+
+    def pow(self, base):
+        self.expect("(")
+        self.expect("&")
+        self.expect("(")
+        e = self.exponent()
+        self.expect(")")
+        self.expect(")")
+        return self.c.power(base, e)
+
+    def exponent(self):
+        left = self.exponent_term()
+        while self.peek()[1] in ("+", "-"):
+            sign = 1 if self.peek()[1] == "+" else -1
+            self.pos += 1
+            right = self.exponent_term()
+            if right.a:
+                raise ValueError("unsupported exponent")
+            left = Exponent(left.a, left.b, left.c + sign * right.c)
+        return left
+
+    def exponent_term(self):
+        left = self.exponent_atom()
+        while True:
+            kind, value = self.peek()
+            if value == "*":
+                self.pos += 1
+                right = self.exponent_atom()
+                if left.a and right.a:
+                    raise ValueError("unsupported exponent")
+                if right.a:
+                    left, right = right, left
+                left = Exponent(left.a * right.c, left.b, left.c * right.c)
+            elif value == ".":
+                self.pos += 1
+                self.expect("floor_div")
+                self.expect("(")
+                self.expect("&")
+                self.expect("felt_nonzero!")
+                self.expect("(")
+                divisor = self.exponent_atom().c
+                self.expect(")")
+                self.expect(")")
+                if left.c:
+                    raise ValueError("unsupported exponent")
+                left = Exponent(left.a, left.b * divisor, 0)
+            else:
+                return left
+
+    def exponent_atom(self):
+        kind, value = self.peek()
+        self.pos += 1
+        if value == "(":
+            inner = self.exponent()
+            self.expect(")")
+            return inner
+        if kind == "num":
+            return Exponent(c=int(value[5:]))
+        if value == "global_values.trace_length":
+            return Exponent(a=1)
+        if value == "global_values.trace_length.floor_div":
+            self.pos -= 1
+            self.tokens[self.pos] = ("ident", "global_values.trace_length")
+            self.tokens.insert(self.pos + 1, ("op", "."))
+            self.tokens.insert(self.pos + 2, ("ident", "floor_div"))
+            return self.exponent_atom()
+        raise ValueError(f"unexpected exponent token {value!r}")
+
+
+class Compiler:
+    def __init__(self, globals_):
+        self.program = Program()
+        self.globals = globals_
+        self.names = {}
+        self.mask = []
+        self.constraints = []
+
+    def name(self, name):
+        if name in self.names:
+            return self.names[name]
+        if name == "point":
+            return self.power("point", Exponent(c=1))
+        if name.startswith("global_values."):
+            kind, value = self.globals[name[len("global_values."):]]
+            if kind == "const":
+                return self.program.const(value)
+            return self.program.add((kind, value))
+        raise ValueError(f"unknown name {name}")
+
+    def power(self, base, e):
+        if base == "point":
+            if e.a == 0:
+                return self.program.add(("point_pow", e.c))
+            if e.a != 1 or e.c or e.b & (e.b - 1):
+                raise ValueError("unsupported power of the point")
+            return self.program.add(("point_pow_trace", e.b.bit_length() - 1))
+        if base == "trace_generator":
+            # `g` has order `T`.
+            if e.a % e.b == 0:
+                return self.program.add(("trace_gen_pow", e.c))
+            # `g^(a·T/b)` is the `a`-th power of the primitive `b`-th root of unity.
+            if e.b & (e.b - 1) or e.c:
+                raise ValueError("unsupported power of the trace generator")
+            root = two_adic_generator(e.b.bit_length() - 1)
+            return self.program.const(pow(root, e.a % e.b, P))
+        raise ValueError(f"unsupported power of {base}")
+
+    def statement(self, name, text):
+        m = re.fullmatch(r"mask_values\[(\d+)\]", text.strip())
+        if m:
+            column, row = parse_mask_name(name)
+            if int(m.group(1)) != len(self.mask):
+                raise ValueError("mask out of order")
+            self.mask.append((column, row))
+            self.names[name] = self.program.add(("mask", len(self.mask) - 1))
+            return
+        m = re.fullmatch(r"total_sum \+ constraint_coefficients\[(\d+)\] \* value", text.strip())
+        if m:
+            if int(m.group(1)) != len(self.constraints):
+                raise ValueError("constraints out of order")
+            self.constraints.append(self.names["value"])
+            return
+        if name == "total_sum":
+            return
+        parser = Parser(tokenize(text), self)
+        self.names[name] = parser.expr()
+        if parser.pos != len(parser.tokens):
+            raise ValueError(f"trailing tokens in {name}: {parser.tokens[parser.pos:]}")
+
+
+def parse_mask_name(name):
+    m = re.fullmatch(r"column(\d+)(?:_inter1)?_row(\d+)", name)
+    if not m:
+        raise ValueError(name)
+    return int(m.group(1)), int(m.group(2))
+
+
+def body(source, function):
+    start = source.index(f"fn {function}")
+    start = source.index("{", source.index(")", start)) + 1
+    depth, i = 1, start
+    while depth:
+        depth += {"{": 1, "}": -1}.get(source[i], 0)
+        i += 1
+    return source[start:i - 1]
+
+
+def statements(block):
+    block = re.sub(r"//[^\n]*", "", block)
+    for stmt in block.split(";"):
+        stmt = re.sub(r"\s+\.", ".", " ".join(stmt.split()))
+        m = re.fullmatch(r"let (\w+) = (.*)", stmt)
+        if m:
+            yield m.group(1), m.group(2)
+        elif stmt:
+            # The tail expression adds the last constraint.
+            yield "total_sum", stmt
+
+
+def periodic_coefficients(source, function):
+    values = re.findall(r'"(0x[0-9a-fA-F]+)"', body(source, function))
+    # Horner from the leading coefficient.
+    return [int(v, 16) for v in reversed(values)]
+
+
+def main():
+    src, layout = sys.argv[1], sys.argv[2]
+    params = PARAMS[layout]
+    composition = open(
+        f"{src}/layout/{layout}/autogenerated/autogenerated_composition.rs"
+    ).read()
+    periodic_source = open(f"{src}/periodic_columns.rs").read()
+
+    globals_ = dict(SMALL_GLOBALS)
+    for i, (name, _, _) in enumerate(params["periodic"]):
+        globals_[name] = ("periodic", i)
+    compiler = Compiler(globals_)
+    for name, text in statements(body(composition, "eval_composition_polynomial_inner")):
+        compiler.statement(name, text)
+
+    out = params["code"].to_bytes(32, "big")
+    out += struct.pack(
+        ">IIIIII",
+        params["log_rows_per_step"],
+        params["constraint_degree"],
+        params["original_columns"],
+        params["interaction_columns"],
+        params["interaction_elements"],
+        params["log_public_memory_step"],
+    )
+    out += struct.pack(
+        ">IIII",
+        params["log_blowup"],
+        params["n_queries"],
+        params["pow_bits"],
+        params["log_last_layer_degree"],
+    )
+    out += struct.pack(">I", len(params["fri_steps"]))
+    out += b"".join(struct.pack(">I", s) for s in params["fri_steps"])
+    out += struct.pack(">I", len(compiler.mask))
+    out += b"".join(struct.pack(">Ii", c, r) for c, r in compiler.mask)
+    out += struct.pack(">I", len(params["periodic"]))
+    for _, function, log_period in params["periodic"]:
+        coeffs = periodic_coefficients(periodic_source, function)
+        out += struct.pack(">II", log_period, len(coeffs))
+        out += b"".join(c.to_bytes(32, "big") for c in coeffs)
+    out += struct.pack(">I", len(compiler.program.nodes))
+    out += compiler.program.encode()
+    out += struct.pack(">I", len(compiler.constraints))
+    out += b"".join(struct.pack(">I", c) for c in compiler.constraints)
+    sys.stdout.buffer.write(out)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,515 @@
+//! Generates the halo2 fixtures in `contracts/testdata/halo2`.
+//!
+//! One circuit is proven with GWC and SHPLONK openings over the EVM (Keccak) and Poseidon
+//! transcripts of snark-verifier, and exported as the protocol blob read by
+//! `halo2::protocol::Protocol`. Every proof is checked with halo2's own verifier first.
+//!
+//! ```text
+//! cargo run --release    # from this directory, writes to `..`
+//! ```
+//!
+//! halo2-axiom needs a nightly toolchain. The SRS is seeded, so rerunning reproduces the
+//! protocols byte for byte; the proofs are freshly blinded.
+
+use std::{fs, io::Cursor, path::Path};
+
+use poseidon_primitives::poseidon::primitives::Spec;
+use rand::{
+    rngs::{OsRng, StdRng},
+    SeedableRng,
+};
+use snark_verifier::{
+    halo2_base::halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        halo2curves::{
+            bn256::{Bn256, Fq, Fr, G1Affine},
+            ff::{Field, PrimeField},
+        },
+        plonk::{
+            create_proof, keygen_pk, keygen_vk, verify_proof, Advice, Any, Challenge, Circuit,
+            Column, ConstraintSystem, Error, Expression, FirstPhase, Instance, SecondPhase,
+            Selector, TableColumn, VerifyingKey,
+        },
+        poly::{
+            commitment::ParamsProver,
+            kzg::{
+                commitment::{KZGCommitmentScheme, ParamsKZG},
+                multiopen::{ProverGWC, ProverSHPLONK, VerifierGWC, VerifierSHPLONK},
+                strategy::SingleStrategy,
+            },
+            Rotation,
+        },
+        transcript::{EncodedChallenge, TranscriptReadBuffer, TranscriptWriterBuffer},
+    },
+    loader::native::NativeLoader,
+    system::halo2::transcript::{evm::EvmTranscript, halo2::PoseidonTranscript},
+};
+
+const K: u32 = 6;
+const ROWS: usize = 4;
+
+// Poseidon parameters of snark-verifier-sdk's transcript.
+const T: usize = 3;
+const RATE: usize = 2;
+const R_F: usize = 8;
+const R_P: usize = 57;
+
+type Keccak<S> = EvmTranscript<G1Affine, NativeLoader, S, Vec<u8>>;
+type Poseidon<S> = PoseidonTranscript<G1Affine, NativeLoader, S, T, RATE, R_F, R_P>;
+
+#[derive(Clone)]
+struct Config {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    d: Column<Advice>,
+    instance: Column<Instance>,
+    table: TableColumn,
+    q_mul: Selector,
+    q_next: Selector,
+    q_range: Selector,
+    alpha: Challenge,
+}
+
+/// `c = a · b` and `a' = b + c` over `ROWS` rows starting from the first instance, with
+/// `b` range-checked against a table, `d = a + α · b` in a second phase, and the last
+/// product exposed as the second instance.
+#[derive(Clone, Default)]
+struct Chain;
+
+impl Circuit<Fr> for Chain {
+    type Config = Config;
+    type FloorPlanner = SimpleFloorPlanner;
+    type Params = ();
+
+    fn without_witnesses(&self) -> Self {
+        Self
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Config {
+        let [a, b, c] = [(); 3].map(|_| meta.advice_column_in(FirstPhase));
+        let alpha = meta.challenge_usable_after(FirstPhase);
+        let d = meta.advice_column_in(SecondPhase);
+        let instance = meta.instance_column();
+        let constants = meta.fixed_column();
+        let table = meta.lookup_table_column();
+        let [q_mul, q_next] = [(); 2].map(|_| meta.selector());
+        let q_range = meta.complex_selector();
+
+        meta.enable_constant(constants);
+        for column in [a, b, c] {
+            meta.enable_equality(column);
+        }
+        meta.enable_equality(instance);
+
+        meta.create_gate("mul", |meta| {
+            let q = meta.query_selector(q_mul);
+            let [a, b, c, d] = [a, b, c, d].map(|col| meta.query_advice(col, Rotation::cur()));
+            let alpha = meta.query_challenge(alpha);
+            vec![
+                q.clone() * (a.clone() * b.clone() - c),
+                q * (d - (a + alpha * b)),
+            ]
+        });
+        meta.create_gate("next", |meta| {
+            let q = meta.query_selector(q_next);
+            let next = meta.query_advice(a, Rotation::next());
+            let [b, c] = [b, c].map(|col| meta.query_advice(col, Rotation::cur()));
+            vec![q * (next - b - c)]
+        });
+        meta.lookup("range", |meta| {
+            let q = meta.query_selector(q_range);
+            let b = meta.query_advice(b, Rotation::cur());
+            vec![(q * b, table)]
+        });
+
+        Config {
+            a,
+            b,
+            c,
+            d,
+            instance,
+            table,
+            q_mul,
+            q_next,
+            q_range,
+            alpha,
+        }
+    }
+
+    fn synthesize(&self, config: Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "range",
+            |mut table| {
+                for i in 0..16 {
+                    table.assign_cell(
+                        || "range",
+                        config.table,
+                        i,
+                        || Value::known(Fr::from(i as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )?;
+
+        let (out, rows) = layouter.assign_region(
+            || "chain",
+            |mut region| {
+                let mut a = region
+                    .assign_advice_from_instance(|| "a0", config.instance, 0, config.a, 0)?
+                    .value()
+                    .copied();
+                let mut rows = Vec::with_capacity(ROWS);
+                let mut out = None;
+                for row in 0..ROWS {
+                    config.q_mul.enable(&mut region, row)?;
+                    config.q_range.enable(&mut region, row)?;
+                    let b = Fr::from(row as u64 + 2);
+                    if row == 0 {
+                        region.assign_advice_from_constant(|| "b0", config.b, 0, b)?;
+                    } else {
+                        region.assign_advice(config.b, row, Value::known(b));
+                    }
+                    let c = a.map(|a| a * b);
+                    out = Some(region.assign_advice(config.c, row, c).cell());
+                    rows.push((a, b));
+                    if row + 1 < ROWS {
+                        config.q_next.enable(&mut region, row)?;
+                        a = c.map(|c| b + c);
+                        region.assign_advice(config.a, row + 1, a);
+                    }
+                }
+                Ok((out.unwrap(), rows))
+            },
+        )?;
+        layouter.constrain_instance(out, config.instance, 1);
+
+        // `d` is only assigned once `α` is drawn; the region lands on the rows of `chain`
+        // as it uses no other column.
+        layouter.next_phase();
+        let alpha = layouter.get_challenge(config.alpha);
+        layouter.assign_region(
+            || "rlc",
+            |mut region| {
+                for (row, (a, b)) in rows.iter().enumerate() {
+                    region.assign_advice(
+                        config.d,
+                        row,
+                        a.zip(alpha).map(|(a, alpha)| a + alpha * b),
+                    );
+                }
+                Ok(())
+            },
+        )?;
+        Ok(())
+    }
+}
+
+fn instances() -> Vec<Fr> {
+    let mut a = Fr::from(3);
+    let mut c = Fr::ZERO;
+    for row in 0..ROWS {
+        let b = Fr::from(row as u64 + 2);
+        c = a * b;
+        a = b + c;
+    }
+    vec![Fr::from(3), c]
+}
+
+/// Non-optimized round constants and MDS matrix, as `OptimizedPoseidonSpec` derives them.
+#[derive(Debug)]
+struct Pow5<const R_F: usize, const R_P: usize>;
+
+impl<const R_F: usize, const R_P: usize> Spec<Fr, T, RATE> for Pow5<R_F, R_P> {
+    fn full_rounds() -> usize {
+        R_F
+    }
+    fn partial_rounds() -> usize {
+        R_P
+    }
+    fn sbox(val: Fr) -> Fr {
+        val.pow_vartime([5])
+    }
+    fn secure_mds() -> usize {
+        0
+    }
+}
+
+fn be(repr: impl AsRef<[u8]>) -> Vec<u8> {
+    repr.as_ref().iter().rev().copied().collect()
+}
+
+fn fr(x: &Fr) -> Vec<u8> {
+    be(x.to_repr())
+}
+
+fn fq(x: &Fq) -> Vec<u8> {
+    be(x.to_repr())
+}
+
+fn g1(p: &G1Affine) -> Vec<u8> {
+    [fq(&p.x), fq(&p.y)].concat()
+}
+
+struct Blob(Vec<u8>);
+
+impl Blob {
+    fn u8(&mut self, v: u8) {
+        self.0.push(v);
+    }
+    fn u16(&mut self, v: usize) {
+        self.0.extend(u16::try_from(v).unwrap().to_be_bytes());
+    }
+    fn bytes(&mut self, v: &[u8]) {
+        self.0.extend_from_slice(v);
+    }
+    fn queries(&mut self, queries: impl ExactSizeIterator<Item = (usize, Rotation)>) {
+        self.u16(queries.len());
+        for (column, rotation) in queries {
+            self.u16(column);
+            self.bytes(&rotation.0.to_be_bytes());
+        }
+    }
+}
+
+fn query_index(queries: &[(usize, Rotation)], column: usize, rotation: Rotation) -> usize {
+    queries
+        .iter()
+        .position(|(c, r)| *c == column && *r == rotation)
+        .unwrap()
+}
+
+struct Queries {
+    fixed: Vec<(usize, Rotation)>,
+    advice: Vec<(usize, Rotation)>,
+    instance: Vec<(usize, Rotation)>,
+}
+
+fn expression(q: &Queries, e: &Expression<Fr>) -> Vec<u8> {
+    let index = |v: usize| u16::try_from(v).unwrap().to_be_bytes().to_vec();
+    let body = e.evaluate(
+        &|c| [vec![0], fr(&c)].concat(),
+        &|_| panic!("selectors are compressed into fixed columns by keygen"),
+        &|f| {
+            [
+                vec![1],
+                index(query_index(&q.fixed, f.column_index(), f.rotation())),
+            ]
+            .concat()
+        },
+        &|a| {
+            [
+                vec![2],
+                index(query_index(&q.advice, a.column_index(), a.rotation())),
+            ]
+            .concat()
+        },
+        &|i| {
+            [
+                vec![3],
+                index(query_index(&q.instance, i.column_index(), i.rotation())),
+            ]
+            .concat()
+        },
+        &|c| [vec![4], index(c.index())].concat(),
+        &|a| [a, vec![5]].concat(),
+        &|a, b| [a, b, vec![6]].concat(),
+        &|a, b| [a, b, vec![7]].concat(),
+        &|a, s| [a, vec![8], fr(&s)].concat(),
+    );
+    let count = e.evaluate(
+        &|_| 1,
+        &|_| 1,
+        &|_| 1,
+        &|_| 1,
+        &|_| 1,
+        &|_| 1,
+        &|a| a + 1,
+        &|a, b| a + b + 1,
+        &|a, b| a + b + 1,
+        &|a, _| a + 1,
+    );
+    [index(count), body].concat()
+}
+
+fn protocol(
+    params: &ParamsKZG<Bn256>,
+    vk: &VerifyingKey<G1Affine>,
+    poseidon: bool,
+    shplonk: bool,
+) -> Vec<u8> {
+    let cs = vk.cs();
+    let q = Queries {
+        fixed: cs
+            .fixed_queries()
+            .iter()
+            .map(|(c, r)| (c.index(), *r))
+            .collect(),
+        advice: cs
+            .advice_queries()
+            .iter()
+            .map(|(c, r)| (c.index(), *r))
+            .collect(),
+        instance: cs
+            .instance_queries()
+            .iter()
+            .map(|(c, r)| (c.index(), *r))
+            .collect(),
+    };
+
+    let mut blob = Blob(Vec::new());
+    blob.u8(1);
+    blob.u8(K as u8);
+    blob.u8(poseidon as u8);
+    blob.u8(shplonk as u8);
+    blob.bytes(&fr(&vk.transcript_repr()));
+    let s_g2 = params.s_g2();
+    for limb in [&s_g2.x.c1, &s_g2.x.c0, &s_g2.y.c1, &s_g2.y.c0] {
+        blob.bytes(&fq(limb));
+    }
+    blob.u16(cs.blinding_factors());
+    blob.u16(vk.get_domain().get_quotient_poly_degree());
+    blob.u16(cs.degree());
+    blob.u16(cs.num_instance_columns());
+    blob.queries(q.instance.iter().copied());
+    blob.u16(cs.num_advice_columns());
+    blob.bytes(&cs.advice_column_phase());
+    blob.u16(cs.num_challenges());
+    blob.bytes(&cs.challenge_phase());
+    blob.queries(q.advice.iter().copied());
+    blob.u16(vk.fixed_commitments().len());
+    for c in vk.fixed_commitments() {
+        blob.bytes(&g1(c));
+    }
+    blob.queries(q.fixed.iter().copied());
+
+    let columns = cs.permutation().get_columns();
+    let commitments = vk.permutation().commitments();
+    assert_eq!(columns.len(), commitments.len());
+    blob.u16(columns.len());
+    for (column, commitment) in columns.iter().zip(commitments) {
+        let (kind, queries) = match column.column_type() {
+            Any::Advice(_) => (0, &q.advice),
+            Any::Fixed => (1, &q.fixed),
+            Any::Instance => (2, &q.instance),
+        };
+        blob.u8(kind);
+        blob.u16(query_index(queries, column.index(), Rotation::cur()));
+        blob.bytes(&g1(commitment));
+    }
+
+    let gates: Vec<_> = cs.gates().iter().flat_map(|g| g.polynomials()).collect();
+    blob.u16(gates.len());
+    for gate in gates {
+        blob.bytes(&expression(&q, gate));
+    }
+    blob.u16(cs.lookups().len());
+    for lookup in cs.lookups() {
+        for expressions in [lookup.input_expressions(), lookup.table_expressions()] {
+            blob.u16(expressions.len());
+            for e in expressions {
+                blob.bytes(&expression(&q, e));
+            }
+        }
+    }
+
+    if poseidon {
+        let (constants, mds, _) = Pow5::<R_F, R_P>::constants();
+        blob.u8(T as u8);
+        blob.u8(R_F as u8);
+        blob.u8(R_P as u8);
+        // One limb: coordinates are absorbed reduced into the scalar field.
+        blob.u8(1);
+        blob.u8(254);
+        for c in constants.iter().flatten().chain(mds.iter().flatten()) {
+            blob.bytes(&fr(c));
+        }
+    }
+    blob.0
+}
+
+fn prove<E, W, R>(params: &ParamsKZG<Bn256>, shplonk: bool) -> (VerifyingKey<G1Affine>, Vec<u8>)
+where
+    E: EncodedChallenge<G1Affine>,
+    W: TranscriptWriterBuffer<Vec<u8>, G1Affine, E>,
+    R: TranscriptReadBuffer<Cursor<Vec<u8>>, G1Affine, E>,
+{
+    let vk = keygen_vk(params, &Chain).unwrap();
+    let pk = keygen_pk(params, vk.clone(), &Chain).unwrap();
+    let instances = instances();
+    let instances: &[&[Fr]] = &[&instances];
+
+    let mut transcript = W::init(Vec::new());
+    if shplonk {
+        create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<_>, _, _, _, _>(
+            params,
+            &pk,
+            &[Chain],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        )
+    } else {
+        create_proof::<KZGCommitmentScheme<Bn256>, ProverGWC<_>, _, _, _, _>(
+            params,
+            &pk,
+            &[Chain],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        )
+    }
+    .unwrap();
+    let proof = transcript.finalize();
+
+    let verifier_params = params.verifier_params();
+    let mut transcript = R::init(Cursor::new(proof.clone()));
+    let verified = if shplonk {
+        verify_proof::<_, VerifierSHPLONK<_>, _, _, _>(
+            verifier_params,
+            &vk,
+            SingleStrategy::new(params),
+            &[instances],
+            &mut transcript,
+        )
+    } else {
+        verify_proof::<_, VerifierGWC<_>, _, _, _>(
+            verifier_params,
+            &vk,
+            SingleStrategy::new(params),
+            &[instances],
+            &mut transcript,
+        )
+    };
+    verified.unwrap();
+    (vk, proof)
+}
+
+fn main() {
+    let out = Path::new(&std::env::args().nth(1).unwrap_or_else(|| "..".into())).to_path_buf();
+    let params = ParamsKZG::<Bn256>::setup(K, StdRng::seed_from_u64(0));
+
+    let words: Vec<u8> = instances().iter().flat_map(fr).collect();
+    fs::write(out.join("instances.bin"), words).unwrap();
+
+    for (poseidon, shplonk) in [(false, false), (false, true), (true, false), (true, true)] {
+        let (vk, proof) = match poseidon {
+            false => prove::<_, Keccak<Vec<u8>>, Keccak<Cursor<Vec<u8>>>>(&params, shplonk),
+            true => prove::<_, Poseidon<Vec<u8>>, Poseidon<Cursor<Vec<u8>>>>(&params, shplonk),
+        };
+        let name = format!(
+            "{}_{}",
+            if poseidon { "poseidon" } else { "keccak" },
+            if shplonk { "shplonk" } else { "gwc" }
+        );
+        let dir = out.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("protocol.bin"),
+            protocol(&params, &vk, poseidon, shplonk),
+        )
+        .unwrap();
+        fs::write(dir.join("proof.bin"), proof).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+//! Generates the RISC Zero receipt fixtures in `contracts/testdata/risc0`.
+//!
+//! The seal, image ID and journal are those of `TestReceiptV2_0.sol` in risc0-ethereum
+//! 3.0.1, a receipt of the RISC Zero 2.0 prover. They are wrapped in a
+//! `risc0_zkvm::Receipt`, verified by risc0-zkvm against the 2.0 verifier parameters,
+//! and serialized as JSON and bincode for `risc0::host::Groth16Receipt`.
+//!
+//! ```text
+//! cargo run --release    # from this directory, writes to `..`
+//! ```
+
+use std::{fs, path::Path};
+
+use risc0_circuit_recursion::control_id::BN254_IDENTITY_CONTROL_ID;
+use risc0_zkvm::{
+    digest, sha::Digestible, Digest, Groth16Receipt, Groth16ReceiptVerifierParameters,
+    InnerReceipt, MaybePruned, Receipt, ReceiptClaim, VerifierContext,
+};
+
+/// Selector followed by the Groth16 seal.
+const SEAL: &str = concat!(
+    "9f39696c2c1cd42efd2ea081de900b08eb97ff95c2df709622928fdc93976baa4730a7502018b1654a232c0614a591ea",
+    "42d8c210d63aa49c19a2009016b08255175d413712932a948d9569c41e39a7618fe801263768e32aa1780bff62c39432",
+    "f208d66c272ea7ff083bceb60d70b317bbc6ae9656883ee1f3db197ffa4df9f581e7b8022acac6495e3dc193edf57762",
+    "d43b460d767cb2f6bde5fb5f89097a7921622c0117c3c6f84a0d6e5cc251a9025a6812155c50212ed17c2dfab5c0805b",
+    "5fa522cf08318d8bf05cba911dc6305dcd87ed1e90a0c27c80caaa05c0560871c8b6273c0333dfa7f0ca652760a1da86",
+    "6a2a9e222a87a422d988d5b75917be1ae4fbec67",
+);
+const JOURNAL: &[u8] = b"just a simple receipt";
+const IMAGE_ID: Digest =
+    digest!("192f3eb31ce4f578a52abb305490d63e4700f24fd460aa4cd4aa801e9672c869");
+/// `ALLOWED_CONTROL_ROOT` of risc0-circuit-recursion 1.4 to 2.0.1, used by risc0-zkvm 2.0.
+const CONTROL_ROOT: Digest =
+    digest!("539032186827b06719244873b17b2d4c122e2d02cfb1994fe958b2523b844576");
+
+fn main() {
+    let params = Groth16ReceiptVerifierParameters {
+        control_root: CONTROL_ROOT,
+        bn254_control_id: BN254_IDENTITY_CONTROL_ID,
+        verifying_key: risc0_groth16::verifying_key(),
+    };
+    let verifier_parameters = params.digest();
+    let seal = hex::decode(SEAL).unwrap();
+    assert_eq!(seal[..4], verifier_parameters.as_bytes()[..4], "selector");
+
+    let claim = ReceiptClaim::ok(IMAGE_ID, JOURNAL.to_vec());
+    let inner = Groth16Receipt::new(
+        seal[4..].to_vec(),
+        MaybePruned::Value(claim),
+        verifier_parameters,
+    );
+    let receipt = Receipt::new(InnerReceipt::Groth16(inner), JOURNAL.to_vec());
+    let ctx = VerifierContext::empty().with_groth16_verifier_parameters(params);
+    receipt
+        .verify_with_context(&ctx, IMAGE_ID)
+        .expect("receipt verifies");
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    fs::write(
+        dir.join("receipt.json"),
+        serde_json::to_string(&receipt).unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("receipt.bin"),
+        bincode::serialize(&receipt).unwrap(),
+    )
+    .unwrap();
+    println!("wrote {}", dir.display());
+}
@@ -0,0 +1,672 @@
+//! Proves the fixtures of `contracts/src/winterfell` with Winterfell 0.9 and re-encodes
+//! the proofs in the contract's layout (see `proof.rs`):
+//!
+//! - `blake3.bin`, `rpo256.bin`: the Toy AIR, 16 steps of `(a, b) -> (b, a^3 + b + k)`
+//!   from `(1, 1)` with `k` cycling through `[3, 5, 0, 9]`, plus a running-product
+//!   auxiliary column, with Blake3 and Winterfell's default coin and with `Rpo256` and
+//!   Miden's RPO coin.
+//! - `miden.bin`: the Stack AIR, whose public inputs are Miden's: a program hash, kernel
+//!   procedure hashes and 16 stack inputs and outputs, asserted on the first and last
+//!   rows of a 16-column operand stack. Proven with `Rpo256`.
+//!
+//! Every proof is first checked with `winterfell::verify`. Run with `cargo run --release`
+//! from this directory.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fs,
+    marker::PhantomData,
+    sync::Mutex,
+};
+
+use miden_crypto::{hash::rpo::Rpo256, rand::RpoRandomCoin};
+use winter_fri::folding::fold_positions;
+use winterfell::{
+    crypto::{hashers::Blake3_256, DefaultRandomCoin, Digest, ElementHasher, RandomCoin},
+    math::{fields::f64::BaseElement as Felt, ExtensionOf, FieldElement, ToElements},
+    matrix::ColMatrix,
+    AcceptableOptions, Air, AirContext, Assertion, AuxRandElements,
+    ConstraintCompositionCoefficients, DefaultConstraintEvaluator, DefaultTraceLde,
+    EvaluationFrame, FieldExtension, Proof, ProofOptions, Prover, Serializable, StarkDomain, Trace,
+    TraceInfo, TraceLde, TracePolyTable, TransitionConstraintDegree,
+};
+
+type QuadExt = winterfell::math::fields::QuadExtension<Felt>;
+
+const TRACE_LENGTH: usize = 16;
+const ROUND_CONSTANTS: [u64; 4] = [3, 5, 0, 9];
+
+fn options() -> ProofOptions {
+    ProofOptions::new(33, 8, 4, FieldExtension::Quadratic, 4, 3)
+}
+
+// TRACE
+// ================================================================================================
+
+/// A main trace segment with the trace info it was built for, which may announce an
+/// auxiliary segment.
+struct Columns {
+    info: TraceInfo,
+    main: ColMatrix<Felt>,
+}
+
+impl Trace for Columns {
+    type BaseField = Felt;
+
+    fn info(&self) -> &TraceInfo {
+        &self.info
+    }
+
+    fn main_segment(&self) -> &ColMatrix<Felt> {
+        &self.main
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Felt>) {
+        let next = (row_idx + 1) % self.info.length();
+        self.main.read_row_into(row_idx, frame.current_mut());
+        self.main.read_row_into(next, frame.next_mut());
+    }
+}
+
+/// Positions of the last query phase, which the proof only implies.
+static QUERIED: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+/// Winterfell's trace LDE, recording the positions it is queried at.
+struct RecordingLde<E: FieldElement<BaseField = Felt>, H: ElementHasher<BaseField = Felt>>(
+    DefaultTraceLde<E, H>,
+);
+
+impl<E, H> TraceLde<E> for RecordingLde<E, H>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    type HashFn = H;
+
+    fn get_main_trace_commitment(&self) -> H::Digest {
+        self.0.get_main_trace_commitment()
+    }
+
+    fn set_aux_trace(
+        &mut self,
+        aux_trace: &ColMatrix<E>,
+        domain: &StarkDomain<Felt>,
+    ) -> (ColMatrix<E>, H::Digest) {
+        self.0.set_aux_trace(aux_trace, domain)
+    }
+
+    fn read_main_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<Felt>) {
+        self.0.read_main_trace_frame_into(lde_step, frame)
+    }
+
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+        self.0.read_aux_trace_frame_into(lde_step, frame)
+    }
+
+    fn read_lagrange_kernel_frame_into(
+        &self,
+        lde_step: usize,
+        col_idx: usize,
+        frame: &mut winter_air::LagrangeKernelEvaluationFrame<E>,
+    ) {
+        self.0
+            .read_lagrange_kernel_frame_into(lde_step, col_idx, frame)
+    }
+
+    fn query(&self, positions: &[usize]) -> Vec<winter_air::proof::Queries> {
+        *QUERIED.lock().unwrap() = positions.to_vec();
+        self.0.query(positions)
+    }
+
+    fn trace_len(&self) -> usize {
+        self.0.trace_len()
+    }
+
+    fn blowup(&self) -> usize {
+        self.0.blowup()
+    }
+
+    fn trace_info(&self) -> &TraceInfo {
+        self.0.trace_info()
+    }
+}
+
+// TOY AIR
+// ================================================================================================
+
+#[derive(Clone, Copy)]
+struct ToyInputs(Felt);
+
+impl ToElements<Felt> for ToyInputs {
+    fn to_elements(&self) -> Vec<Felt> {
+        vec![self.0]
+    }
+}
+
+struct ToyAir {
+    context: AirContext<Felt>,
+    result: Felt,
+}
+
+impl Air for ToyAir {
+    type BaseField = Felt;
+    type PublicInputs = ToyInputs;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    fn new(trace_info: TraceInfo, inputs: ToyInputs, options: ProofOptions) -> Self {
+        let main_degrees = vec![
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(3),
+        ];
+        let aux_degrees = vec![TransitionConstraintDegree::new(2)];
+        let context = AirContext::new_multi_segment(
+            trace_info,
+            main_degrees,
+            aux_degrees,
+            3,
+            1,
+            None,
+            options,
+        );
+        Self {
+            context,
+            result: inputs.0,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Felt> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Felt>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let (a, b) = (frame.current()[0], frame.current()[1]);
+        result[0] = frame.next()[0] - b;
+        result[1] = frame.next()[1] - a * a * a - b - periodic_values[0];
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Felt>> {
+        let last = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, Felt::ONE),
+            Assertion::single(1, 0, Felt::ONE),
+            Assertion::single(1, last, self.result),
+        ]
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        aux_rand_elements: &[E],
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Felt>,
+        E: FieldElement<BaseField = Felt> + ExtensionOf<F>,
+    {
+        let a: E = main_frame.current()[0].into();
+        result[0] = aux_frame.next()[0] - aux_frame.current()[0] * (a + aux_rand_elements[0]);
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField = Felt>>(
+        &self,
+        _aux_rand_elements: &[E],
+    ) -> Vec<Assertion<E>> {
+        vec![Assertion::single(0, 0, E::ONE)]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Felt>> {
+        vec![ROUND_CONSTANTS.map(Felt::new).to_vec()]
+    }
+}
+
+fn toy_trace() -> Columns {
+    let (mut a, mut b) = (vec![Felt::ONE], vec![Felt::ONE]);
+    for i in 0..TRACE_LENGTH - 1 {
+        let (x, y) = (a[i], b[i]);
+        a.push(y);
+        b.push(x * x * x + y + Felt::new(ROUND_CONSTANTS[i % 4]));
+    }
+    Columns {
+        info: TraceInfo::new_multi_segment(2, 1, 1, TRACE_LENGTH, Vec::new()),
+        main: ColMatrix::new(vec![a, b]),
+    }
+}
+
+// STACK AIR
+// ================================================================================================
+
+/// Miden's public inputs, absorbed in the order of `ToElements for MidenPublicInputs`.
+#[derive(Clone)]
+struct StackInputs {
+    program_hash: [Felt; 4],
+    kernel_procedures: Vec<[Felt; 4]>,
+    stack_inputs: [Felt; 16],
+    stack_outputs: [Felt; 16],
+}
+
+impl ToElements<Felt> for StackInputs {
+    fn to_elements(&self) -> Vec<Felt> {
+        let mut out = self.program_hash.to_vec();
+        out.extend(self.kernel_procedures.iter().flatten());
+        out.extend(self.stack_inputs);
+        out.extend(self.stack_outputs);
+        out
+    }
+}
+
+/// Each step replaces the top of the stack with `s0^4 + s1` and rotates the rest of it:
+/// a degree-4 constraint, so three composition columns.
+struct StackAir {
+    context: AirContext<Felt>,
+    inputs: StackInputs,
+}
+
+impl Air for StackAir {
+    type BaseField = Felt;
+    type PublicInputs = StackInputs;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    fn new(trace_info: TraceInfo, inputs: StackInputs, options: ProofOptions) -> Self {
+        let mut degrees = vec![TransitionConstraintDegree::new(4)];
+        degrees.extend((1..16).map(|_| TransitionConstraintDegree::new(1)));
+        Self {
+            context: AirContext::new(trace_info, degrees, 32, options),
+            inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Felt> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Felt>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let (current, next) = (frame.current(), frame.next());
+        let s0 = current[0];
+        result[0] = next[0] - s0 * s0 * s0 * s0 - current[1];
+        for i in 1..16 {
+            result[i] = next[i] - current[i % 15 + 1];
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Felt>> {
+        let last = self.trace_length() - 1;
+        let inputs = self.inputs.stack_inputs.iter().enumerate();
+        let outputs = self.inputs.stack_outputs.iter().enumerate();
+        inputs
+            .map(|(i, v)| Assertion::single(i, 0, *v))
+            .chain(outputs.map(|(i, v)| Assertion::single(i, last, *v)))
+            .collect()
+    }
+}
+
+fn stack_trace(inputs: [Felt; 16]) -> Columns {
+    let mut rows = vec![inputs];
+    for _ in 0..TRACE_LENGTH - 1 {
+        let s = rows.last().unwrap();
+        let mut next = [Felt::ZERO; 16];
+        next[0] = s[0].exp(4) + s[1];
+        for i in 1..16 {
+            next[i] = s[i % 15 + 1];
+        }
+        rows.push(next);
+    }
+    let columns = (0..16)
+        .map(|i| rows.iter().map(|row| row[i]).collect())
+        .collect();
+    Columns {
+        info: TraceInfo::new(16, TRACE_LENGTH),
+        main: ColMatrix::new(columns),
+    }
+}
+
+// PROVER
+// ================================================================================================
+
+struct FixtureProver<A: Air, H, R> {
+    options: ProofOptions,
+    inputs: A::PublicInputs,
+    _marker: PhantomData<(A, H, R)>,
+}
+
+trait FixtureAir: Air<BaseField = Felt> {
+    fn build_aux_trace<E: FieldElement<BaseField = Felt>>(
+        _main: &ColMatrix<Felt>,
+        _rands: &[E],
+    ) -> ColMatrix<E> {
+        unreachable!("the AIR has no auxiliary segment")
+    }
+}
+
+impl FixtureAir for ToyAir {
+    /// The running product `aux' = aux · (a + r)` from `aux = 1`.
+    fn build_aux_trace<E: FieldElement<BaseField = Felt>>(
+        main: &ColMatrix<Felt>,
+        rands: &[E],
+    ) -> ColMatrix<E> {
+        let mut aux = vec![E::ONE];
+        for i in 0..main.num_rows() - 1 {
+            let a: E = main.get(0, i).into();
+            aux.push(aux[i] * (a + rands[0]));
+        }
+        ColMatrix::new(vec![aux])
+    }
+}
+
+impl FixtureAir for StackAir {}
+
+impl<A, H, R> Prover for FixtureProver<A, H, R>
+where
+    A: FixtureAir + 'static,
+    A::PublicInputs: Clone + Send,
+    H: ElementHasher<BaseField = Felt> + Sync,
+    R: RandomCoin<BaseField = Felt, Hasher = H> + Send + Sync,
+{
+    type BaseField = Felt;
+    type Air = A;
+    type Trace = Columns;
+    type HashFn = H;
+    type RandomCoin = R;
+    type TraceLde<E: FieldElement<BaseField = Felt>> = RecordingLde<E, H>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Felt>> =
+        DefaultConstraintEvaluator<'a, A, E>;
+
+    fn get_pub_inputs(&self, _trace: &Columns) -> A::PublicInputs {
+        self.inputs.clone()
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Felt>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Felt>,
+        domain: &StarkDomain<Felt>,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        let (lde, polys) = DefaultTraceLde::new(trace_info, main_trace, domain);
+        (RecordingLde(lde), polys)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Felt>>(
+        &self,
+        air: &'a A,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_aux_trace<E: FieldElement<BaseField = Felt>>(
+        &self,
+        main_trace: &Columns,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E> {
+        A::build_aux_trace(main_trace.main_segment(), aux_rand_elements.rand_elements())
+    }
+}
+
+/// Proves `trace`, checks the proof with Winterfell's verifier, and returns it with the
+/// positions it was queried at.
+fn prove<A, H, R>(trace: Columns, inputs: A::PublicInputs) -> (Proof, Vec<usize>)
+where
+    A: FixtureAir + 'static,
+    A::PublicInputs: Clone + Send,
+    H: ElementHasher<BaseField = Felt> + Sync,
+    R: RandomCoin<BaseField = Felt, Hasher = H> + Send + Sync,
+{
+    let prover = FixtureProver::<A, H, R> {
+        options: options(),
+        inputs: inputs.clone(),
+        _marker: PhantomData,
+    };
+    let proof = Prover::prove(&prover, trace).unwrap();
+    let positions = QUERIED.lock().unwrap().clone();
+    winterfell::verify::<A, H, R>(
+        proof.clone(),
+        inputs,
+        &AcceptableOptions::OptionSet(vec![options()]),
+    )
+    .unwrap();
+    (proof, positions)
+}
+
+// ENCODING
+// ================================================================================================
+
+fn write_elements<E: FieldElement>(out: &mut Vec<u8>, elements: &[E]) {
+    for element in elements {
+        element.write_into(out);
+    }
+}
+
+/// Siblings of a batch opening in the order the contract's breadth-first walk from the
+/// sorted leaves reads them.
+fn batch_siblings<H: ElementHasher>(
+    paths: &BTreeMap<usize, Vec<H::Digest>>,
+    log_height: usize,
+) -> Vec<u8> {
+    let offset = 1 << log_height;
+    let mut known = BTreeMap::new();
+    for (index, path) in paths {
+        for (level, sibling) in path[1..].iter().enumerate() {
+            known.insert(((offset + index) >> level) ^ 1, *sibling);
+        }
+    }
+    let mut queue: VecDeque<usize> = paths.keys().map(|index| offset + index).collect();
+    let mut out = Vec::new();
+    while let Some(node) = queue.pop_front() {
+        if node == 1 {
+            break;
+        }
+        if queue.front() == Some(&(node ^ 1)) {
+            queue.pop_front();
+        } else {
+            out.extend_from_slice(&known[&(node ^ 1)].as_bytes());
+        }
+        queue.push_back(node >> 1);
+    }
+    out
+}
+
+fn write_queries<H: ElementHasher>(out: &mut Vec<u8>, values: &[u8], paths: &[u8]) {
+    out.extend_from_slice(&(values.len() as u32).to_le_bytes());
+    out.extend_from_slice(values);
+    out.extend_from_slice(&(paths.len() as u32).to_le_bytes());
+    out.extend_from_slice(paths);
+}
+
+/// Re-encodes an opening of the sorted `positions` of a tree of `2^log_height` leaves.
+fn encode_queries<H, E>(
+    out: &mut Vec<u8>,
+    queries: winter_air::proof::Queries,
+    positions: &[usize],
+    log_height: usize,
+    width: usize,
+) where
+    H: ElementHasher<BaseField = Felt>,
+    E: FieldElement<BaseField = Felt>,
+{
+    let (proof, table) = queries
+        .parse::<H, E>(1 << log_height, positions.len(), width)
+        .unwrap();
+    let mut values = Vec::new();
+    for row in table.rows() {
+        write_elements(&mut values, row);
+    }
+    let paths = positions
+        .iter()
+        .copied()
+        .zip(proof.into_paths(positions).unwrap())
+        .collect();
+    write_queries::<H>(out, &values, &batch_siblings::<H>(&paths, log_height));
+}
+
+fn encode<H, E>(proof: Proof, positions: &[usize], n_columns: usize) -> Vec<u8>
+where
+    H: ElementHasher<BaseField = Felt>,
+    E: FieldElement<BaseField = Felt>,
+{
+    let info = proof.trace_info().clone();
+    let options = proof.options().clone();
+    let log_lde = proof.lde_domain_size().ilog2() as usize;
+    let mut out = Vec::new();
+
+    out.push(info.main_trace_width() as u8);
+    if info.is_multi_segment() {
+        out.extend([1, info.aux_segment_width() as u8]);
+        out.push(info.get_num_aux_segment_rand_elements() as u8);
+    } else {
+        out.push(0);
+    }
+    out.push(info.length().ilog2() as u8);
+    out.extend_from_slice(&(info.meta().len() as u16).to_le_bytes());
+    out.extend_from_slice(info.meta());
+    let modulus = proof.context.field_modulus_bytes();
+    out.push(modulus.len() as u8);
+    out.extend_from_slice(modulus);
+    out.extend_from_slice(&options.to_bytes());
+    out.push(proof.num_unique_queries);
+
+    let n_trace = 1 + info.is_multi_segment() as usize;
+    let n_fri = options
+        .to_fri_options()
+        .num_fri_layers(proof.lde_domain_size());
+    let (trace_roots, constraint_root, fri_roots) = proof
+        .commitments
+        .clone()
+        .parse::<H>(n_trace, n_fri)
+        .unwrap();
+    let roots: Vec<u8> = trace_roots
+        .iter()
+        .chain([&constraint_root])
+        .chain(&fri_roots)
+        .flat_map(|root| root.as_bytes())
+        .collect();
+    out.extend_from_slice(&(roots.len() as u16).to_le_bytes());
+    out.extend_from_slice(&roots);
+
+    let mut trace_queries = proof.trace_queries.clone().into_iter();
+    out.push(n_trace as u8);
+    let main_width = info.main_trace_width();
+    let main = trace_queries.next().unwrap();
+    encode_queries::<H, Felt>(&mut out, main, positions, log_lde, main_width);
+    if let Some(aux) = trace_queries.next() {
+        encode_queries::<H, E>(&mut out, aux, positions, log_lde, info.aux_segment_width());
+    }
+    let constraints = proof.constraint_queries.clone();
+    encode_queries::<H, E>(&mut out, constraints, positions, log_lde, n_columns);
+
+    // The frame as Winterfell hashes it: each column's current and next values in turn.
+    let (frame, evaluations) = proof
+        .ood_frame
+        .clone()
+        .parse::<E>(main_width, info.aux_segment_width(), n_columns)
+        .unwrap();
+    let states: Vec<E> = frame
+        .current_row()
+        .iter()
+        .zip(frame.next_row())
+        .flat_map(|(current, next)| [*current, *next])
+        .collect();
+    for elements in [&states, &evaluations] {
+        let mut bytes = Vec::new();
+        write_elements(&mut bytes, elements);
+        out.extend_from_slice(&(bytes.len() as u16).to_le_bytes());
+        out.extend_from_slice(&bytes);
+    }
+
+    let folding = options.to_fri_options().folding_factor();
+    let fri = proof.fri_proof.clone();
+    let num_partitions = fri.num_partitions();
+    assert_eq!(num_partitions, 1);
+    let remainder = fri.parse_remainder::<E>().unwrap();
+    let (layer_values, layer_proofs) = fri
+        .parse_layers::<H, E>(proof.lde_domain_size(), folding)
+        .unwrap();
+    out.push(layer_values.len() as u8);
+    let mut domain_size = proof.lde_domain_size();
+    let mut positions = positions.to_vec();
+    for (values, merkle_proof) in layer_values.into_iter().zip(layer_proofs) {
+        // Winterfell orders the folded positions by first appearance; the contract sorts
+        // them.
+        let folded = fold_positions(&positions, domain_size, folding);
+        domain_size /= folding;
+        let paths = merkle_proof.into_paths(&folded).unwrap();
+        let rows: BTreeMap<usize, (&[E], Vec<H::Digest>)> = folded
+            .iter()
+            .zip(values.chunks(folding))
+            .zip(paths)
+            .map(|((position, row), path)| (*position, (row, path)))
+            .collect();
+        let mut bytes = Vec::new();
+        for (row, _) in rows.values() {
+            write_elements(&mut bytes, row);
+        }
+        let paths = rows
+            .iter()
+            .map(|(p, (_, path))| (*p, path.clone()))
+            .collect();
+        let siblings = batch_siblings::<H>(&paths, domain_size.ilog2() as usize);
+        write_queries::<H>(&mut out, &bytes, &siblings);
+        positions = folded;
+    }
+    let mut bytes = Vec::new();
+    write_elements(&mut bytes, &remainder);
+    out.extend_from_slice(&(bytes.len() as u16).to_le_bytes());
+    out.extend_from_slice(&bytes);
+    // Winterfell stores the number of partitions as its logarithm.
+    out.push(num_partitions.ilog2() as u8);
+    out.extend_from_slice(&proof.pow_nonce.to_le_bytes());
+    out
+}
+
+fn main() {
+    let trace = toy_trace();
+    let result = ToyInputs(trace.main.get(1, TRACE_LENGTH - 1));
+    println!("toy result: {}", result.0.as_int());
+
+    type Blake3 = Blake3_256<Felt>;
+    let (proof, positions) =
+        prove::<ToyAir, Blake3, DefaultRandomCoin<Blake3>>(toy_trace(), result);
+    let bytes = encode::<Blake3, QuadExt>(proof, &positions, 2);
+    fs::write("../blake3.bin", bytes).unwrap();
+    let (proof, positions) = prove::<ToyAir, Rpo256, RpoRandomCoin>(toy_trace(), result);
+    let bytes = encode::<Rpo256, QuadExt>(proof, &positions, 2);
+    fs::write("../rpo256.bin", bytes).unwrap();
+
+    let stack_inputs: [Felt; 16] = core::array::from_fn(|i| Felt::new(i as u64 + 1));
+    let trace = stack_trace(stack_inputs);
+    let stack_outputs = core::array::from_fn(|i| trace.main.get(i, TRACE_LENGTH - 1));
+    let inputs = StackInputs {
+        program_hash: Rpo256::hash(b"stack fixture program").into(),
+        kernel_procedures: vec![Rpo256::hash(b"stack fixture kernel procedure").into()],
+        stack_inputs,
+        stack_outputs,
+    };
+    let digest = |d: &[Felt; 4]| {
+        d.iter()
+            .flat_map(|x| x.as_int().to_le_bytes())
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>()
+    };
+    println!("program hash: {}", digest(&inputs.program_hash));
+    println!("kernel procedure: {}", digest(&inputs.kernel_procedures[0]));
+    println!("stack outputs: {:?}", stack_outputs.map(|x| x.as_int()));
+    let (proof, positions) = prove::<StackAir, Rpo256, RpoRandomCoin>(trace, inputs);
+    let bytes = encode::<Rpo256, QuadExt>(proof, &positions, 3);
+    fs::write("../miden.bin", bytes).unwrap();
+}
@@ -0,0 +1,151 @@
+/*!
+Derive macros for `stylus-zkvm-verifiers`.
+
+`#[derive(TaggedDigest)]` implements `risc0::crypto::digest_utils::TaggedDigest`, hashing a
+struct the way RISC Zero's `tagged_struct` does:
+
+```ignore
+#[derive(TaggedDigest)]
+#[tagged(tag = tags::OUTPUT_TAG)]
+pub struct Output {
+    #[tagged(down)]
+    pub journal_digest: B256,
+    #[tagged(down)]
+    pub assumptions_digest: B256,
+}
+```
+
+Fields marked `down` are hashed as digests and fields marked `data` as 32-bit words, each
+in declaration order. Every field must be marked, `skip` leaving it out of the digest, so
+that adding a field cannot silently change or miss the digest.
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, Fields, Result};
+
+#[proc_macro_derive(TaggedDigest, attributes(tagged))]
+pub fn derive_tagged_digest(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum Role {
+    Down,
+    Data,
+    Skip,
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let tag = struct_tag(&input)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "TaggedDigest can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "TaggedDigest needs named fields",
+        ));
+    };
+
+    let mut down = Vec::new();
+    let mut words = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("named field");
+        match field_role(field)? {
+            Role::Down => down.push(ident),
+            Role::Data => words.push((ident, &field.ty)),
+            Role::Skip => {}
+        }
+    }
+
+    let utils = quote!(::stylus_zkvm_verifiers::risc0::crypto::digest_utils);
+    let data = if words.is_empty() {
+        quote!([])
+    } else {
+        let lens: Vec<_> = words
+            .iter()
+            .map(|(_, ty)| quote!(<#ty as #utils::TaggedData>::WORDS))
+            .collect();
+        let writes = words.iter().enumerate().map(|(i, (ident, _))| {
+            let start = &lens[..i];
+            let end = &lens[..=i];
+            quote! {
+                #utils::TaggedData::write_words(
+                    &self.#ident,
+                    &mut data[0 #(+ #start)*..0 #(+ #end)*],
+                );
+            }
+        });
+        quote! {{
+            let mut data = [0u32; #(#lens)+*];
+            #(#writes)*
+            data
+        }}
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #utils::TaggedDigest for #name #ty_generics #where_clause {
+            const TAG_DIGEST: ::stylus_sdk::alloy_primitives::B256 = #utils::tag_digest(#tag);
+
+            fn tagged_digest(&self) -> ::stylus_sdk::alloy_primitives::B256 {
+                #utils::tagged_struct_with_data(
+                    Self::TAG_DIGEST,
+                    &[#(#utils::DownDigest::down_digest(&self.#down)),*],
+                    &#data,
+                )
+            }
+        }
+    })
+}
+
+fn struct_tag(input: &DeriveInput) -> Result<Expr> {
+    let mut tag = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("tagged")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                tag = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `tag = <expr>`"))
+            }
+        })?;
+    }
+    tag.ok_or_else(|| Error::new(input.ident.span(), "missing #[tagged(tag = <expr>)]"))
+}
+
+fn field_role(field: &syn::Field) -> Result<Role> {
+    let mut role = None;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("tagged")) {
+        attr.parse_nested_meta(|meta| {
+            let parsed = if meta.path.is_ident("down") {
+                Role::Down
+            } else if meta.path.is_ident("data") {
+                Role::Data
+            } else if meta.path.is_ident("skip") {
+                Role::Skip
+            } else {
+                return Err(meta.error("expected `down`, `data` or `skip`"));
+            };
+            if role.replace(parsed).is_some() {
+                return Err(meta.error("field is marked more than once"));
+            }
+            Ok(())
+        })?;
+    }
+    role.ok_or_else(|| {
+        Error::new(
+            field.span(),
+            "field must be marked #[tagged(down)], #[tagged(data)] or #[tagged(skip)]",
+        )
+    })
+}
@@ -0,0 +1,33 @@
+//! Copies the constraint polynomial of risc0-circuit-recursion into `OUT_DIR`.
+//!
+//! The crate keeps it in a private module, so it is taken from the crate's source, as
+//! located by `cargo metadata`. Only the `DEF` constant is kept: the rest of the file
+//! implements the crate's own traits.
+
+use std::{env, fs, path::PathBuf, process::Command};
+
+fn main() {
+    let output = Command::new(env::var("CARGO").unwrap())
+        .args(["metadata", "--format-version", "1"])
+        .current_dir(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .output()
+        .expect("cargo metadata");
+    assert!(output.status.success(), "cargo metadata failed");
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let manifest = metadata["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p["name"] == "risc0-circuit-recursion")
+        .expect("risc0-circuit-recursion in the dependency graph")["manifest_path"]
+        .as_str()
+        .unwrap();
+    let source = PathBuf::from(manifest).with_file_name("src/poly_ext.rs");
+    println!("cargo:rerun-if-changed={}", source.display());
+
+    let text = fs::read_to_string(&source).unwrap();
+    let start = text.find("pub const DEF").expect("DEF in poly_ext.rs");
+    let len = text[start..].find("\n};").expect("end of DEF") + 3;
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("poly_ext.rs");
+    fs::write(out, &text[start..start + len]).unwrap();
+}
@@ -0,0 +1,121 @@
+//! Generates `recursion_circuit.bin`, the recursion circuit read by
+//! `risc0::succinct::circuit::RecursionCircuit`.
+//!
+//! The taps and the constraint polynomial are those of risc0-circuit-recursion 3.0.1,
+//! serialized in the layout documented in `circuit.rs`. Its Keccak-256 is
+//! `RECURSION_CIRCUIT_DIGEST`; a new circuit release needs both updated.
+//!
+//! ```text
+//! cargo run --release    # from this directory, writes to `..`
+//! ```
+
+use std::{fs, path::Path};
+
+use risc0_circuit_recursion::CIRCUIT;
+use risc0_zkp::adapter::{PolyExtStep, PolyExtStepDef, TapsProvider};
+
+mod poly_ext {
+    use super::*;
+
+    include!(concat!(env!("OUT_DIR"), "/poly_ext.rs"));
+}
+
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, x: impl TryInto<u8>) {
+        self.0.push(x.try_into().ok().expect("u8 field overflows"));
+    }
+
+    fn u16(&mut self, x: impl TryInto<u16>) {
+        let x: u16 = x.try_into().ok().expect("u16 field overflows");
+        self.0.extend_from_slice(&x.to_le_bytes());
+    }
+
+    fn u32(&mut self, x: u32) {
+        self.0.extend_from_slice(&x.to_le_bytes());
+    }
+
+    fn list(&mut self, xs: &[u16]) {
+        self.u16(xs.len());
+        xs.iter().for_each(|&x| self.u16(x));
+    }
+}
+
+fn main() {
+    let taps = CIRCUIT.get_taps();
+    assert_eq!(taps.group_begin.len(), 4, "three register groups");
+
+    let mut w = Writer(Vec::new());
+    w.u16(taps.taps.len());
+    for tap in taps.taps {
+        w.u16(tap.offset);
+        w.u16(tap.back);
+        w.u8(tap.group);
+        w.u8(tap.combo);
+        w.u8(tap.skip);
+    }
+    w.list(taps.combo_taps);
+    w.list(taps.combo_begin);
+    taps.group_begin.iter().for_each(|&x| w.u16(x));
+    w.u16(taps.combos_count);
+    w.u16(taps.reg_count);
+    w.u16(taps.tot_combo_backs);
+
+    let def = &poly_ext::DEF;
+    w.u16(def.block.len());
+    w.u16(def.ret);
+    for step in def.block {
+        match *step {
+            PolyExtStep::Const(x) => {
+                w.u8(0);
+                w.u32(x);
+            }
+            PolyExtStep::ConstExt(a, b, c, d) => {
+                w.u8(1);
+                [a, b, c, d].into_iter().for_each(|x| w.u32(x));
+            }
+            PolyExtStep::Get(tap) => {
+                w.u8(2);
+                w.u16(tap);
+            }
+            PolyExtStep::GetGlobal(arg, offset) => {
+                w.u8(3);
+                w.u8(arg);
+                w.u16(offset);
+            }
+            PolyExtStep::Add(a, b) => {
+                w.u8(4);
+                w.u16(a);
+                w.u16(b);
+            }
+            PolyExtStep::Sub(a, b) => {
+                w.u8(5);
+                w.u16(a);
+                w.u16(b);
+            }
+            PolyExtStep::Mul(a, b) => {
+                w.u8(6);
+                w.u16(a);
+                w.u16(b);
+            }
+            PolyExtStep::True => w.u8(7),
+            PolyExtStep::AndEqz(a, b) => {
+                w.u8(8);
+                w.u16(a);
+                w.u16(b);
+            }
+            PolyExtStep::AndCond(a, b, c) => {
+                w.u8(9);
+                w.u16(a);
+                w.u16(b);
+                w.u16(c);
+            }
+            ref step => panic!("no encoding for {step:?}"),
+        }
+    }
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../recursion_circuit.bin");
+    fs::write(&path, &w.0).unwrap();
+    println!("wrote {} bytes to {}", w.0.len(), path.display());
+}
@@ -0,0 +1,120 @@
+/*!
+Ink benchmark of the succinct STARK path against the Groth16 path.
+
+Both calls are estimated against an initialized verifier contract, so the numbers
+include calldata costs. Stylus reports gas; ink is gas × 10,000.
+
+Usage:
+```bash
+# Set environment variables
+export PRIV_KEY="your_private_key"
+export RPC_URL="https://sepolia-rollup.arbitrum.io/rpc"
+export STYLUS_CONTRACT_ADDRESS="your_deployed_contract_address"
+
+# Succinct seal of the same receipt claim, encoded as
+# `control_index | n | n × control digest | seal` (little-endian u32 words)
+export SUCCINCT_SEAL_PATH="path/to/succinct_seal.bin"
+export RECEIPT_CLAIM_DIGEST="0x..."
+
+# Run the script
+cargo run --example benchmark
+```
+*/
+
+use alloy::{
+    network::EthereumWallet,
+    primitives::{hex, Bytes, B256},
+    providers::ProviderBuilder,
+    signers::local::PrivateKeySigner,
+    sol,
+};
+use dotenv::dotenv;
+use eyre::Result;
+
+const INK_PER_GAS: u64 = 10_000;
+const RECURSION_CIRCUIT: &[u8] = include_bytes!("../data/recursion_circuit.bin");
+
+sol! {
+    #[derive(Debug)]
+    #[sol(rpc)]
+    contract RiscZeroVerifier {
+        function verifyIntegrity(bytes memory receipt_seal, bytes32 receipt_claim_digest) external view returns (bool);
+        function verifySuccinctIntegrity(bytes memory seal, bytes memory circuit, bytes32 receipt_claim_digest) external view returns (bool);
+        function getRecursionCircuitDigest() external view returns (bytes32);
+        function isInitialized() external view returns (bool);
+    }
+}
+
+fn report(name: &str, calldata: usize, gas: u64) {
+    println!("  {}", name);
+    println!("    Calldata: {} bytes", calldata);
+    println!("    Gas:      {}", gas);
+    println!("    Ink:      {}", gas * INK_PER_GAS);
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv().ok();
+
+    println!("⏱️  RISC Zero Verifier Benchmark");
+    println!("================================");
+
+    let private_key = std::env::var("PRIV_KEY")?;
+    let rpc_url = std::env::var("RPC_URL")?;
+    let contract_address = std::env::var("STYLUS_CONTRACT_ADDRESS")?;
+    let succinct_seal = std::fs::read(std::env::var("SUCCINCT_SEAL_PATH")?)?;
+    let claim_digest: B256 = std::env::var("RECEIPT_CLAIM_DIGEST")?.parse()?;
+
+    println!("📡 Connecting to RPC: {}", rpc_url);
+    println!("📋 Contract Address: {}", contract_address);
+
+    let signer: PrivateKeySigner = private_key.parse()?;
+    let wallet = EthereumWallet::from(signer);
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(wallet)
+        .on_http(rpc_url.parse()?);
+
+    let verifier = RiscZeroVerifier::new(contract_address.parse()?, provider);
+
+    if !verifier.isInitialized().call().await?._0 {
+        eyre::bail!("contract is not initialized, run `cargo run --example interact` first");
+    }
+
+    let circuit_digest = verifier.getRecursionCircuitDigest().call().await?._0;
+    println!("  Recursion Circuit Digest: 0x{}", hex::encode(circuit_digest));
+    println!("  Receipt Claim Digest: 0x{}", hex::encode(claim_digest));
+
+    // Groth16 seal of the same claim, as produced by `risc0_ethereum_contracts::encode_seal`
+    let groth16_seal = match std::env::var("GROTH16_SEAL") {
+        Ok(seal) => Some(hex::decode(seal.trim())?),
+        Err(_) => None,
+    };
+
+    println!("\n🔍 Estimating verification cost...");
+
+    if let Some(seal) = groth16_seal {
+        let calldata = seal.len();
+        let gas = verifier
+            .verifyIntegrity(Bytes::from(seal), claim_digest)
+            .estimate_gas()
+            .await?;
+        report("Groth16 (verifyIntegrity)", calldata, gas);
+    } else {
+        println!("  Groth16: skipped, GROTH16_SEAL not set");
+    }
+
+    let calldata = succinct_seal.len() + RECURSION_CIRCUIT.len();
+    let gas = verifier
+        .verifySuccinctIntegrity(
+            Bytes::from(succinct_seal),
+            Bytes::from_static(RECURSION_CIRCUIT),
+            claim_digest,
+        )
+        .estimate_gas()
+        .await?;
+    report("Succinct (verifySuccinctIntegrity)", calldata, gas);
+
+    println!("\n🏁 Benchmark complete!");
+    Ok(())
+}
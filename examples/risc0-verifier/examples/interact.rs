@@ -12,6 +12,9 @@ Usage:
 export PRIV_KEY="your_private_key"
 export RPC_URL="https://sepolia-rollup.arbitrum.io/rpc"
 export STYLUS_CONTRACT_ADDRESS="your_deployed_contract_address"
+# Optional: a Groth16 receipt serialized as JSON (*.json) or bincode; a built-in
+# proof is used otherwise
+export RECEIPT_PATH="receipt.json"
 
 # Run the script
 cargo run --example interact
@@ -27,6 +30,7 @@ use alloy::{
 };
 use dotenv::dotenv;
 use eyre::Result;
+use stylus_zkvm_verifiers::risc0::host::Groth16Receipt;
 
 sol! {
     #[derive(Debug)]
@@ -107,36 +111,21 @@ async fn main() -> Result<()> {
         println!("  VK Digest: 0x{}", hex::encode(vk_digest));
     }
 
-    // TODO: Add Proof generation script, currently this is hardcoded but it fine for testing
     // Test proof verification
     println!("\n🧪 Testing proof verification...");
 
-    let seal = vec![
-        159, 57, 105, 108, 8, 181, 34, 166, 199, 54, 98, 123, 10, 68, 94, 138,
-        123, 1, 40, 39, 66, 37, 71, 147, 185, 121, 0, 151, 43, 24, 133, 240,
-        138, 234, 58, 104, 24, 205, 177, 232, 161, 138, 32, 12, 52, 180, 221, 44,
-        150, 181, 206, 188, 65, 75, 236, 189, 30, 243, 4, 57, 13, 230, 122, 207,
-        23, 215, 119, 177, 32, 112, 243, 194, 22, 197, 35, 101, 25, 64, 95, 242,
-        176, 18, 212, 198, 207, 197, 223, 40, 130, 39, 95, 50, 12, 128, 69, 62,
-        83, 160, 227, 36, 11, 124, 218, 194, 155, 36, 205, 45, 105, 77, 59, 166,
-        205, 200, 92, 15, 110, 8, 220, 79, 33, 137, 152, 186, 58, 225, 105, 177,
-        59, 11, 183, 251, 12, 23, 103, 211, 169, 180, 203, 253, 98, 98, 175, 102,
-        190, 123, 61, 17, 209, 140, 50, 60, 218, 93, 182, 0, 230, 21, 17, 11,
-        235, 125, 30, 6, 30, 206, 6, 22, 149, 23, 20, 138, 44, 162, 71, 159,
-        223, 117, 111, 95, 141, 199, 213, 85, 196, 228, 235, 43, 105, 20, 135,
-        172, 43, 225, 248, 67, 15, 237, 246, 196, 212, 187, 126, 66, 215, 154,
-        100, 137, 171, 60, 238, 125, 103, 239, 189, 67, 143, 255, 189, 98, 108,
-        166, 68, 170, 199, 37, 193, 84, 39, 223, 59, 115, 40, 140, 45, 55, 187,
-        163, 79, 119, 182, 165, 134, 168, 89, 242, 89, 214, 213, 36, 96, 74, 130,
-        208, 192, 59, 49, 88, 136, 159
-    ];
-    
-    let image_id = B256::from_slice(&hex!("886c206b82e4f2dbdc4220f32c3a278c357ddc31ea800574b850c93647ddb5ff"));
-    
-    let journal_digest = B256::from_slice(&[
-        209, 236, 103, 89, 2, 239, 22, 51, 66, 124, 163, 96, 178, 144, 176, 179,
-        4, 90, 13, 144, 88, 221, 181, 230, 72, 180, 195, 195, 34, 76, 92, 104
-    ]);
+    let (seal, image_id, journal_digest) = match std::env::var("RECEIPT_PATH") {
+        Ok(path) => {
+            println!("  Receipt: {}", path);
+            let receipt = if path.ends_with(".json") {
+                Groth16Receipt::from_json(&std::fs::read_to_string(&path)?)?
+            } else {
+                Groth16Receipt::from_bincode(&std::fs::read(&path)?)?
+            };
+            receipt.verify_calldata()?
+        }
+        Err(_) => builtin_proof(),
+    };
 
     println!("  Image ID: 0x{}", hex::encode(image_id));
     println!("  Journal Digest: 0x{}", hex::encode(journal_digest));
@@ -165,4 +154,36 @@ async fn main() -> Result<()> {
         }
     }
     Ok(())
-} 
\ No newline at end of file
+}
+
+/// Proof of a sample guest, for when no receipt is given.
+fn builtin_proof() -> (Vec<u8>, B256, B256) {
+    let seal = vec![
+        159, 57, 105, 108, 8, 181, 34, 166, 199, 54, 98, 123, 10, 68, 94, 138,
+        123, 1, 40, 39, 66, 37, 71, 147, 185, 121, 0, 151, 43, 24, 133, 240,
+        138, 234, 58, 104, 24, 205, 177, 232, 161, 138, 32, 12, 52, 180, 221, 44,
+        150, 181, 206, 188, 65, 75, 236, 189, 30, 243, 4, 57, 13, 230, 122, 207,
+        23, 215, 119, 177, 32, 112, 243, 194, 22, 197, 35, 101, 25, 64, 95, 242,
+        176, 18, 212, 198, 207, 197, 223, 40, 130, 39, 95, 50, 12, 128, 69, 62,
+        83, 160, 227, 36, 11, 124, 218, 194, 155, 36, 205, 45, 105, 77, 59, 166,
+        205, 200, 92, 15, 110, 8, 220, 79, 33, 137, 152, 186, 58, 225, 105, 177,
+        59, 11, 183, 251, 12, 23, 103, 211, 169, 180, 203, 253, 98, 98, 175, 102,
+        190, 123, 61, 17, 209, 140, 50, 60, 218, 93, 182, 0, 230, 21, 17, 11,
+        235, 125, 30, 6, 30, 206, 6, 22, 149, 23, 20, 138, 44, 162, 71, 159,
+        223, 117, 111, 95, 141, 199, 213, 85, 196, 228, 235, 43, 105, 20, 135,
+        172, 43, 225, 248, 67, 15, 237, 246, 196, 212, 187, 126, 66, 215, 154,
+        100, 137, 171, 60, 238, 125, 103, 239, 189, 67, 143, 255, 189, 98, 108,
+        166, 68, 170, 199, 37, 193, 84, 39, 223, 59, 115, 40, 140, 45, 55, 187,
+        163, 79, 119, 182, 165, 134, 168, 89, 242, 89, 214, 213, 36, 96, 74, 130,
+        208, 192, 59, 49, 88, 136, 159
+    ];
+    
+    let image_id = B256::from_slice(&hex!("886c206b82e4f2dbdc4220f32c3a278c357ddc31ea800574b850c93647ddb5ff"));
+    
+    let journal_digest = B256::from_slice(&[
+        209, 236, 103, 89, 2, 239, 22, 51, 66, 124, 163, 96, 178, 144, 176, 179,
+        4, 90, 13, 144, 88, 221, 181, 230, 72, 180, 195, 195, 34, 76, 92, 104
+    ]);
+
+    (seal, image_id, journal_digest)
+}
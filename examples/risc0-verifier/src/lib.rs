@@ -3,12 +3,17 @@
 extern crate alloc;
 
 use alloc::vec;
+use alloc::string::String;
 use alloc::vec::Vec;
 use stylus_sdk::{
-    alloy_primitives::{FixedBytes, B256, B128},
+    alloy_primitives::{Address, FixedBytes, B256, B128},
     prelude::*,
 };
-use stylus_zkvm_verifiers::risc0::{RiscZeroVerifier, IRiscZeroVerifier};
+use stylus_zkvm_verifiers::common::ownable::IOwnable;
+use stylus_zkvm_verifiers::risc0::{
+    types::abi, RiscZeroVerifier, IRiscZeroVerifier, IRiscZeroVerifierGovernance,
+    IRiscZeroImageRegistry, IRiscZeroSuccinctVerifier,
+};
 
 #[entrypoint]
 #[storage]
@@ -17,8 +22,19 @@ struct RiscZeroVerifierExample {
 }
 
 #[public]
-#[implements(IRiscZeroVerifier<Error = Vec<u8>>)]
-impl RiscZeroVerifierExample {}
+#[implements(
+    IRiscZeroVerifier<Error = Vec<u8>>,
+    IRiscZeroVerifierGovernance<Error = Vec<u8>>,
+    IOwnable<Error = Vec<u8>>,
+    IRiscZeroImageRegistry<Error = Vec<u8>>,
+    IRiscZeroSuccinctVerifier<Error = Vec<u8>>
+)]
+impl RiscZeroVerifierExample {
+    #[constructor]
+    pub fn constructor(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.verifier.set_initial_owner(owner)
+    }
+}
 
 #[public]
 impl IRiscZeroVerifier for RiscZeroVerifierExample {
@@ -41,6 +57,57 @@ impl IRiscZeroVerifier for RiscZeroVerifierExample {
         self.verifier.verify(seal, image_id, journal_digest)
     }
 
+    fn verify_and_record(
+        &mut self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        self.verifier
+            .verify_and_record(seal, image_id, journal_digest)
+    }
+
+    fn verify_journal(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal: Vec<u8>,
+    ) -> Result<bool, Self::Error> {
+        self.verifier.verify_journal(seal, image_id, journal)
+    }
+
+    fn verify_with_exit_code(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+        exit_code: abi::ExitCode,
+    ) -> Result<bool, Self::Error> {
+        self.verifier
+            .verify_with_exit_code(seal, image_id, journal_digest, exit_code)
+    }
+
+    fn verify_paused(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        post_state_digest: B256,
+        journal_digest: B256,
+        user_exit_code: u8,
+    ) -> Result<bool, Self::Error> {
+        self.verifier.verify_paused(
+            seal,
+            image_id,
+            post_state_digest,
+            journal_digest,
+            user_exit_code,
+        )
+    }
+
+    fn verify_claim(&self, seal: Vec<u8>, claim: abi::ReceiptClaim) -> Result<bool, Self::Error> {
+        self.verifier.verify_claim(seal, claim)
+    }
+
     fn verify_integrity(
         &self,
         receipt_seal: Vec<u8>,
@@ -49,6 +116,29 @@ impl IRiscZeroVerifier for RiscZeroVerifierExample {
         self.verifier.verify_integrity(receipt_seal, receipt_claim_digest)
     }
 
+    fn verify_with_assumptions(
+        &self,
+        seal: Vec<u8>,
+        claim: abi::ReceiptClaim,
+        assumptions: Vec<abi::Assumption>,
+        assumption_seals: Vec<Vec<u8>>,
+    ) -> Result<bool, Self::Error> {
+        self.verifier
+            .verify_with_assumptions(seal, claim, assumptions, assumption_seals)
+    }
+
+    fn record_verified_claim(
+        &mut self,
+        seal: Vec<u8>,
+        claim_digest: B256,
+    ) -> Result<(), Self::Error> {
+        self.verifier.record_verified_claim(seal, claim_digest)
+    }
+
+    fn is_claim_verified(&self, claim_digest: B256) -> bool {
+        self.verifier.is_claim_verified(claim_digest)
+    }
+
     fn get_selector(&self) -> FixedBytes<4> {
         self.verifier.get_selector()
     }
@@ -68,4 +158,137 @@ impl IRiscZeroVerifier for RiscZeroVerifierExample {
     fn is_initialized(&self) -> bool {
         self.verifier.is_initialized()
     }
-} 
\ No newline at end of file
+
+    fn version(&self) -> String {
+        self.verifier.version()
+    }
+
+    fn compute_claim_digest(&self, claim: abi::ReceiptClaim) -> Result<B256, Self::Error> {
+        self.verifier.compute_claim_digest(claim)
+    }
+
+    fn compute_output_digest(&self, output: abi::Output) -> B256 {
+        self.verifier.compute_output_digest(output)
+    }
+
+    fn split_digest(&self, digest: B256) -> (B128, B128) {
+        self.verifier.split_digest(digest)
+    }
+}
+
+#[public]
+impl IRiscZeroVerifierGovernance for RiscZeroVerifierExample {
+    type Error = Vec<u8>;
+
+    fn propose_parameters(
+        &mut self,
+        control_root: B256,
+        bn254_control_id: B256,
+    ) -> Result<u64, Self::Error> {
+        self.verifier.propose_parameters(control_root, bn254_control_id)
+    }
+
+    fn accept_parameters(&mut self) -> Result<(), Self::Error> {
+        self.verifier.accept_parameters()
+    }
+
+    fn cancel_parameters(&mut self) -> Result<(), Self::Error> {
+        self.verifier.cancel_parameters()
+    }
+
+    fn get_pending_parameters(&self) -> (B256, B256, u64) {
+        self.verifier.get_pending_parameters()
+    }
+}
+
+#[public]
+impl IOwnable for RiscZeroVerifierExample {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.verifier.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.verifier.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.verifier.transfer_ownership(new_owner)
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.verifier.accept_ownership()
+    }
+}
+
+#[public]
+impl IRiscZeroImageRegistry for RiscZeroVerifierExample {
+    type Error = Vec<u8>;
+
+    fn register_image(
+        &mut self,
+        image_id: B256,
+        label: String,
+        valid_from: u64,
+        valid_until: u64,
+        callers: Vec<Address>,
+    ) -> Result<(), Self::Error> {
+        self.verifier
+            .register_image(image_id, label, valid_from, valid_until, callers)
+    }
+
+    fn remove_image(&mut self, image_id: B256) -> Result<(), Self::Error> {
+        self.verifier.remove_image(image_id)
+    }
+
+    fn get_image(&self, image_id: B256) -> Result<(String, u64, u64, Vec<Address>), Self::Error> {
+        self.verifier.get_image(image_id)
+    }
+
+    fn verify_registered(
+        &self,
+        seal: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        self.verifier
+            .verify_registered(seal, image_id, journal_digest)
+    }
+}
+
+#[public]
+impl IRiscZeroSuccinctVerifier for RiscZeroVerifierExample {
+    type Error = Vec<u8>;
+
+    fn verify_succinct(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+        image_id: B256,
+        journal_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        self.verifier.verify_succinct(seal, circuit, image_id, journal_digest)
+    }
+
+    fn verify_succinct_integrity(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+        receipt_claim_digest: B256,
+    ) -> Result<bool, Self::Error> {
+        self.verifier.verify_succinct_integrity(seal, circuit, receipt_claim_digest)
+    }
+
+    fn get_succinct_claim_digest(
+        &self,
+        seal: Vec<u8>,
+        circuit: Vec<u8>,
+    ) -> Result<B256, Self::Error> {
+        self.verifier.get_succinct_claim_digest(seal, circuit)
+    }
+
+    fn get_recursion_circuit_digest(&self) -> B256 {
+        self.verifier.get_recursion_circuit_digest()
+    }
+}
@@ -4,10 +4,11 @@ extern crate alloc;
 
 use alloc::{vec, vec::Vec, string::String};
 use stylus_sdk::{
-    alloy_primitives::B256,
+    alloy_primitives::{Address, B256},
     prelude::*,
 };
-use stylus_zkvm_verifiers::sp1::{Sp1Verifier, ISp1Verifier};
+use stylus_zkvm_verifiers::common::ownable::IOwnable;
+use stylus_zkvm_verifiers::sp1::{Sp1Verifier, ISp1Verifier, ISp1StarkVerifier};
 
 #[entrypoint]
 #[storage]
@@ -16,8 +17,17 @@ struct Sp1VerifierExample {
 }
 
 #[public]
-#[implements(ISp1Verifier<Error = Vec<u8>>)]
-impl Sp1VerifierExample {}
+#[implements(
+    ISp1Verifier<Error = Vec<u8>>,
+    ISp1StarkVerifier<Error = Vec<u8>>,
+    IOwnable<Error = Vec<u8>>
+)]
+impl Sp1VerifierExample {
+    #[constructor]
+    pub fn constructor(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.verifier.set_initial_owner(owner)
+    }
+}
 
 #[public]
 impl ISp1Verifier for Sp1VerifierExample {
@@ -32,6 +42,16 @@ impl ISp1Verifier for Sp1VerifierExample {
         self.verifier.verify_proof(program_vkey, public_values, proof_bytes)
     }
 
+    fn verify_and_record(
+        &mut self,
+        program_vkey: B256,
+        public_values: Vec<u8>,
+        proof_bytes: Vec<u8>,
+    ) -> Result<(), Self::Error> {
+        self.verifier
+            .verify_and_record(program_vkey, public_values, proof_bytes)
+    }
+
     fn verifier_hash(&self) -> B256 {
         self.verifier.verifier_hash()
     }
@@ -39,4 +59,53 @@ impl ISp1Verifier for Sp1VerifierExample {
     fn version(&self) -> String {
         self.verifier.version()
     }
-}
\ No newline at end of file
+
+    fn hash_public_values(&self, public_values: Vec<u8>) -> B256 {
+        self.verifier.hash_public_values(public_values)
+    }
+}
+
+#[public]
+impl ISp1StarkVerifier for Sp1VerifierExample {
+    type Error = Vec<u8>;
+
+    fn initialize_recursion_shape(&mut self, shape: Vec<u8>) -> Result<(), Self::Error> {
+        self.verifier.initialize_recursion_shape(shape)
+    }
+
+    fn verify_compressed_proof(
+        &self,
+        program_vkey: B256,
+        public_values: Vec<u8>,
+        proof_bytes: Vec<u8>,
+        shape: Vec<u8>,
+    ) -> Result<(), Self::Error> {
+        self.verifier
+            .verify_compressed_proof(program_vkey, public_values, proof_bytes, shape)
+    }
+
+    fn recursion_shape_hash(&self) -> B256 {
+        self.verifier.recursion_shape_hash()
+    }
+}
+
+#[public]
+impl IOwnable for Sp1VerifierExample {
+    type Error = Vec<u8>;
+
+    fn owner(&self) -> Address {
+        self.verifier.owner()
+    }
+
+    fn pending_owner(&self) -> Address {
+        self.verifier.pending_owner()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Self::Error> {
+        self.verifier.transfer_ownership(new_owner)
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), Self::Error> {
+        self.verifier.accept_ownership()
+    }
+}